@@ -62,7 +62,7 @@ async fn main() -> Result<()> {
         tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
 
         // Make another request - will auto-refresh if token expired
-        let contacts = client.contacts().list().await?;
+        let contacts = client.contacts().list_all().await?;
         info!("Iteration {}: Found {} contacts", i + 1, contacts.len());
     }
 
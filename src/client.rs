@@ -19,23 +19,59 @@ use crate::endpoints::{BASE_URL, XeroEndpoint};
 use crate::entities::{
     MutationResponse,
     account::{self, Account},
+    accounting_employee::{self, AccountingEmployee},
+    allocation::{self, Allocation},
+    batch_payment::{self, BatchPayment},
+    branding_theme::{self, BrandingTheme, PaymentService},
+    budget::{self, Budget},
     contact::{self, Contact},
-    invoice::{self, Invoice},
+    contact_group::{self, ContactGroup},
+    currency::{self, Currency},
+    expense_claim::{self, ExpenseClaim},
+    invoice::{self, Invoice, Payment},
     item::{self, Item},
+    linked_transaction::{self, LinkedTransaction},
+    organisation::{self, Organisation},
+    overpayment::{self, Overpayment},
+    payment::Refund,
+    prepayment::{self, Prepayment},
     purchase_order::{self, PurchaseOrder},
     quote::{self, Quote},
+    receipt::{self, Receipt},
+    repeating_invoice::{self, RepeatingInvoice},
+    reports::{self, Report},
+    setup::{self, ImportSummary},
     timesheet::{self, PostTimesheet, Timesheet},
+    user::{self, User},
 };
 use crate::error::{self, Error, Result};
 use crate::oauth::{KeyPair, OAuthClient};
+#[cfg(feature = "payroll-nz")]
+use crate::payroll::nz;
+#[cfg(feature = "payroll-uk")]
+use crate::payroll::uk;
+#[cfg(any(feature = "payroll-uk", feature = "payroll-nz"))]
+use crate::payroll::v2;
 use crate::payroll::{
-    employee::{self, Employee},
-    leave_application::{self, LeaveApplication, PostLeaveApplication},
+    employee::{self, Employee, PostEmployee},
+    leave_application::{self, LeaveApplication, LeavePeriod, PostLeaveApplication},
+    leave_balance::{self, LeaveProjection, LeaveTypeBalance},
+    pay_run::{self, PayRun, PostPayRun, UpdatePayRun},
+    payslip::{Payslip, UpdatePayslip},
+    region::{PayrollRegion, RegionalPayroll},
     settings::{
+        deduction_types::DeductionType,
         earnings_rates::{self, EarningsRate},
         leave_types::LeaveType,
         pay_calendar::{self, PayCalendar},
+        pay_items::{
+            PayItems, PostDeductionType, PostEarningsRate, PostLeaveType, PostPayItems,
+            PostReimbursementType,
+        },
+        payroll_settings::PayrollSettings,
+        reimbursement_types::ReimbursementType,
     },
+    superfund::{self, PostSuperfund, Superfund, SuperfundProduct},
 };
 use crate::scope::Scope;
 use tracing_error::SpanTrace;
@@ -367,7 +403,7 @@ impl Client {
     /// client.ensure_valid_token().await?;
     ///
     /// // Now make requests knowing the token is fresh
-    /// let contacts = client.contacts().list().await?;
+    /// let contacts = client.contacts().list_all().await?;
     /// # Ok(())
     /// # }
     /// ```
@@ -1360,6 +1396,20 @@ impl Client {
                             })
                         }
                         Err(e) => {
+                            if let Some(api_error) = error::Response::from_problem(&text) {
+                                tracing::error!(
+                                    url = %url,
+                                    status = %status,
+                                    title = ?api_error.title,
+                                    detail = ?api_error.detail,
+                                    "API problem response from Xero payroll"
+                                );
+                                return Err(Error::API {
+                                    response: api_error,
+                                    span_trace: SpanTrace::capture(),
+                                });
+                            }
+
                             tracing::error!(
                                 url = %url,
                                 status = %status,
@@ -1377,6 +1427,13 @@ impl Client {
                         span_trace: SpanTrace::capture(),
                     }),
                     Err(e) => {
+                        if let Some(api_error) = error::Response::from_problem(&text) {
+                            return Err(Error::API {
+                                response: api_error,
+                                span_trace: SpanTrace::capture(),
+                            });
+                        }
+
                         tracing::error!(
                             url = %url,
                             status = %status,
@@ -1396,18 +1453,93 @@ impl Client {
         AccountsApi { client: self }
     }
 
+    /// Access the batch payments API
+    #[must_use]
+    pub fn batch_payments(&self) -> BatchPaymentsApi<'_> {
+        BatchPaymentsApi { client: self }
+    }
+
+    /// Access the branding themes API
+    #[must_use]
+    pub fn branding_themes(&self) -> BrandingThemesApi<'_> {
+        BrandingThemesApi { client: self }
+    }
+
     /// Access the contacts API
     #[must_use]
     pub fn contacts(&self) -> ContactsApi<'_> {
         ContactsApi { client: self }
     }
 
+    /// Access the contact groups API
+    #[must_use]
+    pub fn contact_groups(&self) -> ContactGroupsApi<'_> {
+        ContactGroupsApi { client: self }
+    }
+
+    /// Access the currencies API
+    #[must_use]
+    pub fn currencies(&self) -> CurrenciesApi<'_> {
+        CurrenciesApi { client: self }
+    }
+
+    /// Access the expense claims API
+    #[must_use]
+    pub fn expense_claims(&self) -> ExpenseClaimsApi<'_> {
+        ExpenseClaimsApi { client: self }
+    }
+
     /// Access the invoices API
     #[must_use]
     pub fn invoices(&self) -> InvoicesApi<'_> {
         InvoicesApi { client: self }
     }
 
+    /// Access the linked transactions (billable expenses) API
+    #[must_use]
+    pub fn linked_transactions(&self) -> LinkedTransactionsApi<'_> {
+        LinkedTransactionsApi { client: self }
+    }
+
+    /// Access the accounting employees API
+    ///
+    /// These are the employees used on receipts, expense claims and bank transactions, not
+    /// payroll employees (see [`Client::employees`]).
+    #[must_use]
+    pub fn accounting_employees(&self) -> AccountingEmployeesApi<'_> {
+        AccountingEmployeesApi { client: self }
+    }
+
+    /// Access the budgets API
+    #[must_use]
+    pub fn budgets(&self) -> BudgetsApi<'_> {
+        BudgetsApi { client: self }
+    }
+
+    /// Access the organisation setup API
+    #[must_use]
+    pub fn setup(&self) -> SetupApi<'_> {
+        SetupApi { client: self }
+    }
+
+    /// Access the financial reports API
+    #[must_use]
+    pub fn reports(&self) -> ReportsApi<'_> {
+        ReportsApi { client: self }
+    }
+
+    /// Access the overpayments API
+    #[must_use]
+    pub fn overpayments(&self) -> OverpaymentsApi<'_> {
+        OverpaymentsApi { client: self }
+    }
+
+    /// Access the prepayments API
+    #[must_use]
+    pub fn prepayments(&self) -> PrepaymentsApi<'_> {
+        PrepaymentsApi { client: self }
+    }
+
     /// Access the purchase orders API
     #[must_use]
     pub fn purchase_orders(&self) -> PurchaseOrdersApi<'_> {
@@ -1420,12 +1552,36 @@ impl Client {
         QuotesApi { client: self }
     }
 
+    /// Access the receipts API
+    #[must_use]
+    pub fn receipts(&self) -> ReceiptsApi<'_> {
+        ReceiptsApi { client: self }
+    }
+
+    /// Access the repeating invoices API
+    #[must_use]
+    pub fn repeating_invoices(&self) -> RepeatingInvoicesApi<'_> {
+        RepeatingInvoicesApi { client: self }
+    }
+
     /// Access the timesheets API
     #[must_use]
     pub fn timesheets(&self) -> TimesheetsApi<'_> {
         TimesheetsApi { client: self }
     }
 
+    /// Access the users API
+    #[must_use]
+    pub fn users(&self) -> UsersApi<'_> {
+        UsersApi { client: self }
+    }
+
+    /// Access the organisation API
+    #[must_use]
+    pub fn organisation(&self) -> OrganisationApi<'_> {
+        OrganisationApi { client: self }
+    }
+
     /// Access the employees API
     #[must_use]
     pub fn employees(&self) -> EmployeesApi<'_> {
@@ -1461,6 +1617,116 @@ impl Client {
     pub fn leave_types(&self) -> LeaveTypesApi<'_> {
         LeaveTypesApi { client: self }
     }
+
+    /// Access the pay items API, covering earnings rates, deduction types, leave types and
+    /// reimbursement types
+    #[must_use]
+    pub fn pay_items(&self) -> PayItemsApi<'_> {
+        PayItemsApi { client: self }
+    }
+
+    /// Access the deduction types API
+    #[must_use]
+    pub fn deduction_types(&self) -> DeductionTypesApi<'_> {
+        DeductionTypesApi { client: self }
+    }
+
+    /// Access the reimbursement types API
+    #[must_use]
+    pub fn reimbursement_types(&self) -> ReimbursementTypesApi<'_> {
+        ReimbursementTypesApi { client: self }
+    }
+
+    /// Access the payroll settings API
+    #[must_use]
+    pub fn payroll_settings(&self) -> PayrollSettingsApi<'_> {
+        PayrollSettingsApi { client: self }
+    }
+
+    /// Access the superfunds API
+    #[must_use]
+    pub fn superfunds(&self) -> SuperfundsApi<'_> {
+        SuperfundsApi { client: self }
+    }
+
+    /// Access the superfund products API
+    #[must_use]
+    pub fn superfund_products(&self) -> SuperfundProductsApi<'_> {
+        SuperfundProductsApi { client: self }
+    }
+
+    /// Access the AU payroll API
+    ///
+    /// The AU endpoints are also available directly on the client, e.g. [`Client::employees`].
+    #[must_use]
+    pub fn payroll_au(&self) -> PayrollAuApi<'_> {
+        PayrollAuApi { client: self }
+    }
+
+    /// Access the UK payroll API
+    #[cfg(feature = "payroll-uk")]
+    #[must_use]
+    pub fn payroll_uk(&self) -> PayrollUkApi<'_> {
+        PayrollUkApi { client: self }
+    }
+
+    /// Access the NZ payroll API
+    #[cfg(feature = "payroll-nz")]
+    #[must_use]
+    pub fn payroll_nz(&self) -> PayrollNzApi<'_> {
+        PayrollNzApi { client: self }
+    }
+
+    /// Access the payroll API of the organisation's region
+    ///
+    /// The region is detected from the organisation's country code, which takes a request to
+    /// the organisation endpoint. Use [`Client::payroll_for`] when the region is known.
+    ///
+    /// Returns `Error::UnsupportedPayrollRegion` for countries without Xero Payroll, and
+    /// `Error::PayrollFeatureDisabled` for a UK or NZ organisation when the region's feature
+    /// is not enabled.
+    #[instrument(skip(self))]
+    pub async fn payroll(&self) -> Result<RegionalPayroll<'_>> {
+        let organisation = organisation::get(self).await?;
+        let country_code = organisation.country_code.unwrap_or_default();
+        let Some(region) = PayrollRegion::from_country_code(&country_code) else {
+            return Err(Error::UnsupportedPayrollRegion(country_code));
+        };
+        self.payroll_for(region)
+            .ok_or_else(|| Error::PayrollFeatureDisabled {
+                country_code,
+                // Only the UK and NZ can be disabled, and both have a feature
+                feature: region.feature().unwrap_or_default(),
+            })
+    }
+
+    /// Access the payroll API of a region, or `None` when the region's feature is disabled
+    #[must_use]
+    pub fn payroll_for(&self, region: PayrollRegion) -> Option<RegionalPayroll<'_>> {
+        match region {
+            PayrollRegion::Au => Some(RegionalPayroll::Au(self.payroll_au())),
+            #[cfg(feature = "payroll-uk")]
+            PayrollRegion::Uk => Some(RegionalPayroll::Uk(self.payroll_uk())),
+            #[cfg(not(feature = "payroll-uk"))]
+            PayrollRegion::Uk => None,
+            #[cfg(feature = "payroll-nz")]
+            PayrollRegion::Nz => Some(RegionalPayroll::Nz(self.payroll_nz())),
+            #[cfg(not(feature = "payroll-nz"))]
+            PayrollRegion::Nz => None,
+        }
+    }
+
+    /// Access the payroll pay runs API
+    #[must_use]
+    pub fn pay_runs(&self) -> PayRunsApi<'_> {
+        PayRunsApi { client: self }
+    }
+
+    /// Access the payroll payslips API
+    #[must_use]
+    pub fn payslips(&self) -> PayslipsApi<'_> {
+        PayslipsApi { client: self }
+    }
 }
 
 /// API handler for Accounts (Chart of Accounts) endpoints
@@ -1530,6 +1796,109 @@ impl AccountsApi<'_> {
     }
 }
 
+/// API handler for Batch Payments endpoints
+#[derive(Debug)]
+pub struct BatchPaymentsApi<'a> {
+    client: &'a Client,
+}
+
+impl BatchPaymentsApi<'_> {
+    /// Retrieve a list of batch payments with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(
+        &self,
+        parameters: batch_payment::ListParameters,
+    ) -> Result<Vec<BatchPayment>> {
+        batch_payment::list(self.client, parameters).await
+    }
+
+    /// Retrieve all batch payments
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<BatchPayment>> {
+        batch_payment::list_all(self.client).await
+    }
+
+    /// Retrieve a single batch payment by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, batch_payment_id: Uuid) -> Result<BatchPayment> {
+        batch_payment::get(self.client, batch_payment_id).await
+    }
+
+    /// Create a batch payment paying several invoices from one bank account
+    #[instrument(skip(self, batch_payment))]
+    pub async fn create(&self, batch_payment: &batch_payment::Builder) -> Result<BatchPayment> {
+        batch_payment::create(self.client, batch_payment).await
+    }
+
+    /// Delete a batch payment
+    #[instrument(skip(self))]
+    pub async fn delete(&self, batch_payment_id: Uuid) -> Result<BatchPayment> {
+        batch_payment::delete(self.client, batch_payment_id).await
+    }
+
+    /// Retrieve the history records for a batch payment
+    #[instrument(skip(self))]
+    pub async fn get_history(
+        &self,
+        batch_payment_id: Uuid,
+    ) -> Result<Vec<batch_payment::HistoryRecord>> {
+        batch_payment::get_history(self.client, batch_payment_id).await
+    }
+
+    /// Add a note to the history of a batch payment
+    #[instrument(skip(self))]
+    pub async fn create_history(
+        &self,
+        batch_payment_id: Uuid,
+        details: &str,
+    ) -> Result<Vec<batch_payment::HistoryRecord>> {
+        batch_payment::create_history(self.client, batch_payment_id, details).await
+    }
+}
+
+/// API handler for Branding Themes endpoints
+#[derive(Debug)]
+pub struct BrandingThemesApi<'a> {
+    client: &'a Client,
+}
+
+impl BrandingThemesApi<'_> {
+    /// Retrieve all branding themes
+    #[instrument(skip(self))]
+    pub async fn list(&self) -> Result<Vec<BrandingTheme>> {
+        branding_theme::list(self.client).await
+    }
+
+    /// Retrieve a single branding theme by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, branding_theme_id: Uuid) -> Result<BrandingTheme> {
+        branding_theme::get(self.client, branding_theme_id).await
+    }
+
+    /// Retrieve a single branding theme by name (case-insensitive)
+    #[instrument(skip(self))]
+    pub async fn get_by_name(&self, name: &str) -> Result<BrandingTheme> {
+        branding_theme::get_by_name(self.client, name).await
+    }
+
+    /// List the payment services attached to a branding theme
+    #[instrument(skip(self))]
+    pub async fn payment_services(&self, branding_theme_id: Uuid) -> Result<Vec<PaymentService>> {
+        branding_theme::list_payment_services(self.client, branding_theme_id).await
+    }
+
+    /// Attach a payment service to a branding theme
+    #[instrument(skip(self, payment_service))]
+    pub async fn attach_payment_service(
+        &self,
+        branding_theme_id: Uuid,
+        payment_service: &PaymentService,
+    ) -> Result<Vec<PaymentService>> {
+        branding_theme::attach_payment_service(self.client, branding_theme_id, payment_service)
+            .await
+    }
+}
+
 /// API handler for Contacts endpoints
 #[derive(Debug)]
 pub struct ContactsApi<'a> {
@@ -1537,15 +1906,34 @@ pub struct ContactsApi<'a> {
 }
 
 impl ContactsApi<'_> {
-    /// Retrieve a list of contacts
+    /// Retrieve a list of contacts with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(&self, parameters: contact::ListParameters) -> Result<Vec<Contact>> {
+        contact::list(self.client, parameters).await
+    }
+
+    /// List all contacts without any filtering
     #[instrument(skip(self))]
-    pub async fn list(&self) -> Result<Vec<Contact>> {
-        let empty_vec: Vec<String> = Vec::new();
-        let response: contact::ListResponse = self
-            .client
-            .get_endpoint(XeroEndpoint::Contacts, &empty_vec)
-            .await?;
-        Ok(response.contacts)
+    pub async fn list_all(&self) -> Result<Vec<Contact>> {
+        contact::list_all(self.client).await
+    }
+
+    /// Find a contact by its exact name
+    #[instrument(skip(self))]
+    pub async fn find_by_name(&self, name: &str) -> Result<Option<Contact>> {
+        contact::find_by_name(self.client, name).await
+    }
+
+    /// Find a contact by its exact email address
+    #[instrument(skip(self))]
+    pub async fn find_by_email(&self, email_address: &str) -> Result<Option<Contact>> {
+        contact::find_by_email(self.client, email_address).await
+    }
+
+    /// Find a contact by its exact account number
+    #[instrument(skip(self))]
+    pub async fn find_by_account_number(&self, account_number: &str) -> Result<Option<Contact>> {
+        contact::find_by_account_number(self.client, account_number).await
     }
 
     /// Retrieve a single contact by ID
@@ -1565,96 +1953,349 @@ impl ContactsApi<'_> {
             span_trace: SpanTrace::capture(),
         })
     }
-}
 
-/// API handler for Invoices endpoints
-#[derive(Debug)]
-pub struct InvoicesApi<'a> {
-    client: &'a Client,
-}
+    /// Create a single contact
+    #[instrument(skip(self, contact))]
+    pub async fn create(&self, contact: &contact::Builder) -> Result<Contact> {
+        contact::create_single(self.client, contact).await
+    }
 
-impl InvoicesApi<'_> {
-    /// List invoices with optional parameters
-    #[instrument(skip(self, parameters))]
-    pub async fn list(&self, parameters: invoice::ListParameters) -> Result<Vec<Invoice>> {
-        invoice::list(self.client, parameters).await
+    /// Create multiple contacts
+    #[instrument(skip(self, contacts))]
+    pub async fn create_multiple(&self, contacts: &[contact::Builder]) -> Result<Vec<Contact>> {
+        contact::create(self.client, contacts).await
     }
 
-    /// List all invoices without any filtering
+    /// Update or create a single contact
+    #[instrument(skip(self, contact))]
+    pub async fn update_or_create(&self, contact: &contact::Builder) -> Result<Contact> {
+        let contacts =
+            contact::update_or_create(self.client, std::slice::from_ref(contact)).await?;
+        contacts.into_iter().next().ok_or(Error::NotFound {
+            entity: "Contact".to_string(),
+            url: contact::ENDPOINT.to_string(),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No contact returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+    }
+
+    /// Update or create multiple contacts
+    #[instrument(skip(self, contacts))]
+    pub async fn update_or_create_multiple(
+        &self,
+        contacts: &[contact::Builder],
+    ) -> Result<Vec<Contact>> {
+        contact::update_or_create(self.client, contacts).await
+    }
+
+    /// Update a specific contact
+    #[instrument(skip(self, contact))]
+    pub async fn update(&self, contact_id: Uuid, contact: &contact::Builder) -> Result<Contact> {
+        contact::update(self.client, contact_id, contact).await
+    }
+
+    /// Archive a contact
     #[instrument(skip(self))]
-    pub async fn list_all(&self) -> Result<Vec<Invoice>> {
-        invoice::list_all(self.client).await
+    pub async fn archive(&self, contact_id: Uuid) -> Result<Contact> {
+        contact::archive(self.client, contact_id).await
     }
 
-    /// Get a single invoice by ID
+    /// Get the history for a contact
     #[instrument(skip(self))]
-    pub async fn get(&self, invoice_id: Uuid) -> Result<Invoice> {
-        invoice::get(self.client, invoice_id).await
+    pub async fn get_history(&self, contact_id: Uuid) -> Result<Vec<contact::HistoryRecord>> {
+        contact::get_history(self.client, contact_id).await
     }
 
-    /// Create a new invoice
-    #[instrument(skip(self, invoice))]
-    pub async fn create(&self, invoice: &invoice::Builder) -> Result<Invoice> {
-        invoice::create(self.client, invoice).await
+    /// Create a history record for a contact
+    #[instrument(skip(self))]
+    pub async fn create_history(
+        &self,
+        contact_id: Uuid,
+        details: &str,
+    ) -> Result<Vec<contact::HistoryRecord>> {
+        contact::create_history(self.client, contact_id, details).await
     }
 
-    /// Update an existing invoice
-    #[instrument(skip(self, invoice))]
-    pub async fn update(
-        &mut self,
-        invoice_id: Uuid,
-        invoice: &invoice::Builder,
-    ) -> Result<Invoice> {
-        invoice::update(self.client, invoice_id, invoice).await
+    /// List attachments for a contact
+    #[instrument(skip(self))]
+    pub async fn list_attachments(&self, contact_id: Uuid) -> Result<Vec<contact::Attachment>> {
+        contact::list_attachments(self.client, contact_id).await
     }
 
-    /// Update or create an invoice
-    #[instrument(skip(self, invoice))]
-    pub async fn update_or_create(&self, invoice: &invoice::Builder) -> Result<Invoice> {
-        invoice::update_or_create(self.client, invoice).await
+    /// Upload an attachment to a contact
+    #[instrument(skip(self, attachment_content))]
+    pub async fn upload_attachment(
+        &self,
+        contact_id: Uuid,
+        filename: &str,
+        attachment_content: &[u8],
+    ) -> Result<contact::Attachment> {
+        contact::upload_attachment(self.client, contact_id, filename, attachment_content).await
     }
 
-    /// Get the invoice as a PDF
+    /// Get the Construction Industry Scheme (UK) settings for a contact
     #[instrument(skip(self))]
-    pub async fn get_pdf(&self, invoice_id: Uuid) -> Result<Vec<u8>> {
-        invoice::get_pdf(self.client, invoice_id).await
+    pub async fn get_cis_settings(&self, contact_id: Uuid) -> Result<contact::CisSetting> {
+        contact::get_cis_settings(self.client, contact_id).await
     }
+}
 
-    /// Get an online invoice URL
-    #[instrument(skip(self))]
-    pub async fn get_online_invoice(&self, invoice_id: Uuid) -> Result<String> {
-        invoice::get_online_invoice(self.client, invoice_id).await
+/// API handler for Contact Groups endpoints
+#[derive(Debug)]
+pub struct ContactGroupsApi<'a> {
+    client: &'a Client,
+}
+
+impl ContactGroupsApi<'_> {
+    /// Retrieve a list of active contact groups with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(
+        &self,
+        parameters: contact_group::ListParameters,
+    ) -> Result<Vec<ContactGroup>> {
+        contact_group::list(self.client, parameters).await
     }
 
-    /// Email the invoice to the contact
+    /// List all active contact groups
     #[instrument(skip(self))]
-    pub async fn email(&self, invoice_id: Uuid) -> Result<()> {
-        invoice::email(self.client, invoice_id).await
+    pub async fn list_all(&self) -> Result<Vec<ContactGroup>> {
+        contact_group::list_all(self.client).await
     }
 
-    /// Get the history for an invoice
+    /// Retrieve a single contact group, including its contacts
     #[instrument(skip(self))]
-    pub async fn get_history(&self, invoice_id: Uuid) -> Result<Vec<invoice::HistoryRecord>> {
-        invoice::get_history(self.client, invoice_id).await
+    pub async fn get(&self, contact_group_id: Uuid) -> Result<ContactGroup> {
+        contact_group::get(self.client, contact_group_id).await
     }
 
-    /// Create a history record for an invoice
+    /// Create a new contact group
     #[instrument(skip(self))]
-    pub async fn create_history(
-        &self,
-        invoice_id: Uuid,
-        details: &str,
-    ) -> Result<Vec<invoice::HistoryRecord>> {
-        invoice::create_history(self.client, invoice_id, details).await
+    pub async fn create(&self, name: &str) -> Result<ContactGroup> {
+        contact_group::create(self.client, name).await
     }
 
-    /// List attachments for an invoice
+    /// Rename a contact group
     #[instrument(skip(self))]
-    pub async fn list_attachments(&self, invoice_id: Uuid) -> Result<Vec<invoice::Attachment>> {
-        invoice::list_attachments(self.client, invoice_id).await
+    pub async fn rename(&self, contact_group_id: Uuid, name: &str) -> Result<ContactGroup> {
+        contact_group::rename(self.client, contact_group_id, name).await
     }
 
-    /// Get a specific attachment by ID
+    /// Delete a contact group
+    #[instrument(skip(self))]
+    pub async fn delete(&self, contact_group_id: Uuid) -> Result<ContactGroup> {
+        contact_group::delete(self.client, contact_group_id).await
+    }
+
+    /// Add contacts to a contact group
+    #[instrument(skip(self, contact_ids))]
+    pub async fn add_contacts(&self, contact_group_id: Uuid, contact_ids: &[Uuid]) -> Result<()> {
+        contact_group::add_contacts(self.client, contact_group_id, contact_ids).await
+    }
+
+    /// Remove a single contact from a contact group
+    #[instrument(skip(self))]
+    pub async fn remove_contact(&self, contact_group_id: Uuid, contact_id: Uuid) -> Result<()> {
+        contact_group::remove_contact(self.client, contact_group_id, contact_id).await
+    }
+
+    /// Remove all contacts from a contact group
+    #[instrument(skip(self))]
+    pub async fn remove_all_contacts(&self, contact_group_id: Uuid) -> Result<()> {
+        contact_group::remove_all_contacts(self.client, contact_group_id).await
+    }
+}
+
+/// API handler for Currencies endpoints
+#[derive(Debug)]
+pub struct CurrenciesApi<'a> {
+    client: &'a Client,
+}
+
+impl CurrenciesApi<'_> {
+    /// Retrieve a list of currencies with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(&self, parameters: currency::ListParameters) -> Result<Vec<Currency>> {
+        currency::list(self.client, parameters).await
+    }
+
+    /// List all currencies enabled for the organisation
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<Currency>> {
+        currency::list_all(self.client).await
+    }
+
+    /// Enable a new currency for the organisation
+    #[instrument(skip(self, currency))]
+    pub async fn create(&self, currency: &Currency) -> Result<Currency> {
+        currency::create(self.client, currency).await
+    }
+}
+
+/// API handler for Expense Claims endpoints
+#[derive(Debug)]
+pub struct ExpenseClaimsApi<'a> {
+    client: &'a Client,
+}
+
+impl ExpenseClaimsApi<'_> {
+    /// Retrieve a list of expense claims with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(
+        &self,
+        parameters: expense_claim::ListParameters,
+    ) -> Result<Vec<ExpenseClaim>> {
+        expense_claim::list(self.client, parameters).await
+    }
+
+    /// Retrieve all expense claims
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<ExpenseClaim>> {
+        expense_claim::list_all(self.client).await
+    }
+
+    /// Retrieve a single expense claim by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, expense_claim_id: Uuid) -> Result<ExpenseClaim> {
+        expense_claim::get(self.client, expense_claim_id).await
+    }
+
+    /// Submit an expense claim for one or more draft receipts
+    #[instrument(skip(self, expense_claim))]
+    pub async fn create(&self, expense_claim: &expense_claim::Builder) -> Result<ExpenseClaim> {
+        expense_claim::create(self.client, expense_claim).await
+    }
+
+    /// Change the status of an expense claim, if its current status allows it
+    #[instrument(skip(self))]
+    pub async fn update_status(
+        &self,
+        expense_claim_id: Uuid,
+        status: expense_claim::Status,
+    ) -> Result<ExpenseClaim> {
+        expense_claim::update_status(self.client, expense_claim_id, status).await
+    }
+
+    /// Authorise a submitted expense claim
+    #[instrument(skip(self))]
+    pub async fn authorise(&self, expense_claim_id: Uuid) -> Result<ExpenseClaim> {
+        expense_claim::authorise(self.client, expense_claim_id).await
+    }
+
+    /// Void an expense claim
+    #[instrument(skip(self))]
+    pub async fn void(&self, expense_claim_id: Uuid) -> Result<ExpenseClaim> {
+        expense_claim::void(self.client, expense_claim_id).await
+    }
+
+    /// Retrieve the history records for an expense claim
+    #[instrument(skip(self))]
+    pub async fn get_history(
+        &self,
+        expense_claim_id: Uuid,
+    ) -> Result<Vec<expense_claim::HistoryRecord>> {
+        expense_claim::get_history(self.client, expense_claim_id).await
+    }
+
+    /// Add a note to the history of an expense claim
+    #[instrument(skip(self))]
+    pub async fn create_history(
+        &self,
+        expense_claim_id: Uuid,
+        details: &str,
+    ) -> Result<Vec<expense_claim::HistoryRecord>> {
+        expense_claim::create_history(self.client, expense_claim_id, details).await
+    }
+}
+
+/// API handler for Invoices endpoints
+#[derive(Debug)]
+pub struct InvoicesApi<'a> {
+    client: &'a Client,
+}
+
+impl InvoicesApi<'_> {
+    /// List invoices with optional parameters
+    #[instrument(skip(self, parameters))]
+    pub async fn list(&self, parameters: invoice::ListParameters) -> Result<Vec<Invoice>> {
+        invoice::list(self.client, parameters).await
+    }
+
+    /// List all invoices without any filtering
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<Invoice>> {
+        invoice::list_all(self.client).await
+    }
+
+    /// Get a single invoice by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, invoice_id: Uuid) -> Result<Invoice> {
+        invoice::get(self.client, invoice_id).await
+    }
+
+    /// Create a new invoice
+    #[instrument(skip(self, invoice))]
+    pub async fn create(&self, invoice: &invoice::Builder) -> Result<Invoice> {
+        invoice::create(self.client, invoice).await
+    }
+
+    /// Update an existing invoice
+    #[instrument(skip(self, invoice))]
+    pub async fn update(
+        &mut self,
+        invoice_id: Uuid,
+        invoice: &invoice::Builder,
+    ) -> Result<Invoice> {
+        invoice::update(self.client, invoice_id, invoice).await
+    }
+
+    /// Update or create an invoice
+    #[instrument(skip(self, invoice))]
+    pub async fn update_or_create(&self, invoice: &invoice::Builder) -> Result<Invoice> {
+        invoice::update_or_create(self.client, invoice).await
+    }
+
+    /// Get the invoice as a PDF
+    #[instrument(skip(self))]
+    pub async fn get_pdf(&self, invoice_id: Uuid) -> Result<Vec<u8>> {
+        invoice::get_pdf(self.client, invoice_id).await
+    }
+
+    /// Get an online invoice URL
+    #[instrument(skip(self))]
+    pub async fn get_online_invoice(&self, invoice_id: Uuid) -> Result<String> {
+        invoice::get_online_invoice(self.client, invoice_id).await
+    }
+
+    /// Email the invoice to the contact
+    #[instrument(skip(self))]
+    pub async fn email(&self, invoice_id: Uuid) -> Result<()> {
+        invoice::email(self.client, invoice_id).await
+    }
+
+    /// Get the history for an invoice
+    #[instrument(skip(self))]
+    pub async fn get_history(&self, invoice_id: Uuid) -> Result<Vec<invoice::HistoryRecord>> {
+        invoice::get_history(self.client, invoice_id).await
+    }
+
+    /// Create a history record for an invoice
+    #[instrument(skip(self))]
+    pub async fn create_history(
+        &self,
+        invoice_id: Uuid,
+        details: &str,
+    ) -> Result<Vec<invoice::HistoryRecord>> {
+        invoice::create_history(self.client, invoice_id, details).await
+    }
+
+    /// List attachments for an invoice
+    #[instrument(skip(self))]
+    pub async fn list_attachments(&self, invoice_id: Uuid) -> Result<Vec<invoice::Attachment>> {
+        invoice::list_attachments(self.client, invoice_id).await
+    }
+
+    /// Get a specific attachment by ID
     #[instrument(skip(self))]
     pub async fn get_attachment(
         &mut self,
@@ -1697,595 +2338,2863 @@ impl InvoicesApi<'_> {
     }
 }
 
-/// API handler for Purchase Orders endpoints
+/// API handler for Linked Transactions endpoints
 #[derive(Debug)]
-pub struct PurchaseOrdersApi<'a> {
+pub struct LinkedTransactionsApi<'a> {
     client: &'a Client,
 }
 
-impl PurchaseOrdersApi<'_> {
-    /// Retrieve a list of purchase orders
+impl LinkedTransactionsApi<'_> {
+    /// Retrieve a list of linked transactions with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(
+        &self,
+        parameters: linked_transaction::ListParameters,
+    ) -> Result<Vec<LinkedTransaction>> {
+        linked_transaction::list(self.client, parameters).await
+    }
+
+    /// Retrieve all linked transactions
     #[instrument(skip(self))]
-    pub async fn list(&self) -> Result<Vec<PurchaseOrder>> {
-        let empty_vec: Vec<String> = Vec::new();
-        let response: purchase_order::ListResponse = self
-            .client
-            .get(purchase_order::ENDPOINT, &empty_vec)
-            .await?;
-        Ok(response.purchase_orders)
+    pub async fn list_all(&self) -> Result<Vec<LinkedTransaction>> {
+        linked_transaction::list_all(self.client).await
     }
 
-    /// Retrieve a single purchase order by ID
+    /// Retrieve a single linked transaction by ID
     #[instrument(skip(self))]
-    pub async fn get(&self, purchase_order_id: Uuid) -> Result<PurchaseOrder> {
-        let endpoint = Url::from_str(purchase_order::ENDPOINT)
-            .and_then(|endpoint| endpoint.join(&purchase_order_id.to_string()))
-            .map_err(|_| Error::InvalidEndpoint)?;
-        let endpoint_str = endpoint.to_string();
-        let empty_vec: Vec<String> = Vec::new();
-        let response: purchase_order::ListResponse = self.client.get(endpoint, &empty_vec).await?;
-        response
-            .purchase_orders
-            .into_iter()
-            .next()
-            .ok_or(Error::NotFound {
-                entity: "PurchaseOrder".to_string(),
-                url: endpoint_str,
-                status_code: reqwest::StatusCode::NOT_FOUND,
-                response_body: Some(format!(
-                    "Purchase Order with ID {purchase_order_id} not found"
-                )),
-                span_trace: SpanTrace::capture(),
-            })
+    pub async fn get(&self, linked_transaction_id: Uuid) -> Result<LinkedTransaction> {
+        linked_transaction::get(self.client, linked_transaction_id).await
     }
 
-    /// Create a new purchase order
-    #[instrument(skip(self, builder))]
-    pub async fn create(&self, builder: &purchase_order::Builder) -> Result<PurchaseOrder> {
-        let request = purchase_order::PurchaseOrdersRequest::single(builder);
-        let result: MutationResponse = self.client.put(purchase_order::ENDPOINT, &request).await?;
-        result
-            .data
-            .get_purchase_orders()
-            .and_then(|po| po.into_iter().next())
-            .ok_or(Error::NotFound {
-                entity: "PurchaseOrder".to_string(),
-                url: purchase_order::ENDPOINT.to_string(),
-                status_code: reqwest::StatusCode::NOT_FOUND,
-                response_body: Some(
-                    "Failed to create purchase order - no purchase order in response".to_string(),
-                ),
-                span_trace: SpanTrace::capture(),
-            })
+    /// Create a billable expense from a bill or spend money line item
+    #[instrument(skip(self, linked_transaction))]
+    pub async fn create(
+        &self,
+        linked_transaction: &linked_transaction::Builder,
+    ) -> Result<LinkedTransaction> {
+        linked_transaction::create(self.client, linked_transaction).await
     }
 
-    /// Update an existing purchase order
-    #[instrument(skip(self, builder))]
+    /// Update a linked transaction
+    #[instrument(skip(self, linked_transaction))]
+    pub async fn update(
+        &self,
+        linked_transaction_id: Uuid,
+        linked_transaction: &linked_transaction::Builder,
+    ) -> Result<LinkedTransaction> {
+        linked_transaction::update(self.client, linked_transaction_id, linked_transaction).await
+    }
+
+    /// Bill a linked transaction on a line item of an ACCREC invoice
+    #[instrument(skip(self))]
+    pub async fn link_to_invoice(
+        &self,
+        linked_transaction_id: Uuid,
+        invoice_id: Uuid,
+        line_item_id: Uuid,
+    ) -> Result<LinkedTransaction> {
+        linked_transaction::link_to_invoice(
+            self.client,
+            linked_transaction_id,
+            invoice_id,
+            line_item_id,
+        )
+        .await
+    }
+
+    /// Delete a linked transaction
+    #[instrument(skip(self))]
+    pub async fn delete(&self, linked_transaction_id: Uuid) -> Result<()> {
+        linked_transaction::delete(self.client, linked_transaction_id).await
+    }
+}
+
+/// API handler for accounting Employees endpoints
+#[derive(Debug)]
+pub struct AccountingEmployeesApi<'a> {
+    client: &'a Client,
+}
+
+impl AccountingEmployeesApi<'_> {
+    /// Retrieve a list of accounting employees with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(
+        &self,
+        parameters: accounting_employee::ListParameters,
+    ) -> Result<Vec<AccountingEmployee>> {
+        accounting_employee::list(self.client, parameters).await
+    }
+
+    /// Retrieve all accounting employees
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<AccountingEmployee>> {
+        accounting_employee::list_all(self.client).await
+    }
+
+    /// Retrieve a single accounting employee by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, employee_id: Uuid) -> Result<AccountingEmployee> {
+        accounting_employee::get(self.client, employee_id).await
+    }
+
+    /// Create an accounting employee
+    #[instrument(skip(self, employee))]
+    pub async fn create(
+        &self,
+        employee: &accounting_employee::Builder,
+    ) -> Result<AccountingEmployee> {
+        accounting_employee::create(self.client, employee).await
+    }
+
+    /// Update an accounting employee
+    #[instrument(skip(self, employee))]
     pub async fn update(
         &self,
-        purchase_order_id: Uuid,
-        builder: &purchase_order::Builder,
-    ) -> Result<PurchaseOrder> {
-        let endpoint = format!("{}{}", purchase_order::ENDPOINT, purchase_order_id);
-        let request = purchase_order::PurchaseOrdersRequest::single(builder);
-        let result: MutationResponse = self.client.post(&endpoint, &request).await?;
-        result
-            .data
-            .get_purchase_orders()
-            .and_then(|po| po.into_iter().next())
-            .ok_or(Error::NotFound {
-                entity: "PurchaseOrder".to_string(),
-                url: endpoint,
-                status_code: reqwest::StatusCode::NOT_FOUND,
-                response_body: Some(
-                    "Failed to update purchase order - no purchase order in response".to_string(),
-                ),
-                span_trace: SpanTrace::capture(),
-            })
+        employee_id: Uuid,
+        employee: &accounting_employee::Builder,
+    ) -> Result<AccountingEmployee> {
+        accounting_employee::update(self.client, employee_id, employee).await
+    }
+}
+
+/// API handler for Budgets endpoints
+#[derive(Debug)]
+pub struct BudgetsApi<'a> {
+    client: &'a Client,
+}
+
+impl BudgetsApi<'_> {
+    /// Retrieve a list of budgets with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(&self, parameters: budget::ListParameters) -> Result<Vec<Budget>> {
+        budget::list(self.client, parameters).await
+    }
+
+    /// Retrieve all budgets
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<Budget>> {
+        budget::list_all(self.client).await
+    }
+
+    /// Retrieve a single budget by ID, including its budget lines
+    #[instrument(skip(self, parameters))]
+    pub async fn get(&self, budget_id: Uuid, parameters: budget::GetParameters) -> Result<Budget> {
+        budget::get(self.client, budget_id, parameters).await
+    }
+}
+
+/// API handler for the Setup endpoint
+#[derive(Debug)]
+pub struct SetupApi<'a> {
+    client: &'a Client,
+}
+
+impl SetupApi<'_> {
+    /// Post the chart of accounts, conversion date and opening balances
+    #[instrument(skip(self, setup))]
+    pub async fn post(&self, setup: &setup::Builder) -> Result<ImportSummary> {
+        setup::post(self.client, setup).await
+    }
+}
+
+/// API handler for Reports endpoints
+#[derive(Debug)]
+pub struct ReportsApi<'a> {
+    client: &'a Client,
+}
+
+impl ReportsApi<'_> {
+    /// Retrieve the Profit and Loss report
+    #[instrument(skip(self))]
+    pub async fn profit_and_loss(
+        &self,
+        parameters: reports::ProfitAndLossParameters,
+    ) -> Result<Report> {
+        reports::profit_and_loss(self.client, parameters).await
+    }
+
+    /// Retrieve the Balance Sheet report
+    #[instrument(skip(self))]
+    pub async fn balance_sheet(
+        &self,
+        parameters: reports::BalanceSheetParameters,
+    ) -> Result<Report> {
+        reports::balance_sheet(self.client, parameters).await
+    }
+
+    /// Retrieve the Trial Balance report
+    #[instrument(skip(self))]
+    pub async fn trial_balance(
+        &self,
+        parameters: reports::TrialBalanceParameters,
+    ) -> Result<Report> {
+        reports::trial_balance(self.client, parameters).await
+    }
+
+    /// Retrieve the Aged Receivables by Contact report
+    #[instrument(skip(self))]
+    pub async fn aged_receivables(&self, parameters: reports::AgedParameters) -> Result<Report> {
+        reports::aged_receivables(self.client, parameters).await
+    }
+
+    /// Retrieve the Aged Payables by Contact report
+    #[instrument(skip(self))]
+    pub async fn aged_payables(&self, parameters: reports::AgedParameters) -> Result<Report> {
+        reports::aged_payables(self.client, parameters).await
+    }
+
+    /// Retrieve the Bank Summary report
+    #[instrument(skip(self))]
+    pub async fn bank_summary(&self, parameters: reports::BankSummaryParameters) -> Result<Report> {
+        reports::bank_summary(self.client, parameters).await
+    }
+
+    /// Retrieve the Budget Summary report
+    #[instrument(skip(self))]
+    pub async fn budget_summary(
+        &self,
+        parameters: reports::BudgetSummaryParameters,
+    ) -> Result<Report> {
+        reports::budget_summary(self.client, parameters).await
+    }
+
+    /// Retrieve the Executive Summary report
+    #[instrument(skip(self))]
+    pub async fn executive_summary(
+        &self,
+        parameters: reports::ExecutiveSummaryParameters,
+    ) -> Result<Report> {
+        reports::executive_summary(self.client, parameters).await
+    }
+
+    /// Retrieve the US 1099 report
+    #[instrument(skip(self))]
+    pub async fn ten_ninety_nine(
+        &self,
+        parameters: reports::TenNinetyNineParameters,
+    ) -> Result<Report> {
+        reports::ten_ninety_nine(self.client, parameters).await
+    }
+
+    /// List published reports, such as AU BAS and NZ GST returns
+    #[instrument(skip(self))]
+    pub async fn list_published(&self) -> Result<Vec<Report>> {
+        reports::list_published(self.client).await
+    }
+
+    /// Retrieve a published report, such as an AU BAS or NZ GST return
+    #[instrument(skip(self))]
+    pub async fn get_published(&self, report_id: Uuid) -> Result<Report> {
+        reports::get_published(self.client, report_id).await
+    }
+}
+
+/// API handler for Overpayments endpoints
+#[derive(Debug)]
+pub struct OverpaymentsApi<'a> {
+    client: &'a Client,
+}
+
+impl OverpaymentsApi<'_> {
+    /// Retrieve a list of overpayments with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(&self, parameters: overpayment::ListParameters) -> Result<Vec<Overpayment>> {
+        overpayment::list(self.client, parameters).await
+    }
+
+    /// Retrieve all overpayments
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<Overpayment>> {
+        overpayment::list_all(self.client).await
+    }
+
+    /// Retrieve a single overpayment by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, overpayment_id: Uuid) -> Result<Overpayment> {
+        overpayment::get(self.client, overpayment_id).await
+    }
+
+    /// Allocate part of an overpayment to one or more invoices
+    #[instrument(skip(self, allocations))]
+    pub async fn allocate(
+        &self,
+        overpayment_id: Uuid,
+        allocations: &[allocation::Builder],
+    ) -> Result<Vec<Allocation>> {
+        overpayment::allocate(self.client, overpayment_id, allocations).await
+    }
+
+    /// Delete an allocation from an overpayment
+    #[instrument(skip(self))]
+    pub async fn delete_allocation(&self, overpayment_id: Uuid, allocation_id: Uuid) -> Result<()> {
+        overpayment::delete_allocation(self.client, overpayment_id, allocation_id).await
+    }
+
+    /// Refund the remaining credit on an overpayment
+    #[instrument(skip(self, refund))]
+    pub async fn refund(&self, overpayment_id: Uuid, refund: &Refund) -> Result<Payment> {
+        overpayment::refund(self.client, overpayment_id, refund).await
+    }
+
+    /// Retrieve the history records for an overpayment
+    #[instrument(skip(self))]
+    pub async fn get_history(
+        &self,
+        overpayment_id: Uuid,
+    ) -> Result<Vec<overpayment::HistoryRecord>> {
+        overpayment::get_history(self.client, overpayment_id).await
+    }
+
+    /// Add a note to the history of an overpayment
+    #[instrument(skip(self))]
+    pub async fn create_history(
+        &self,
+        overpayment_id: Uuid,
+        details: &str,
+    ) -> Result<Vec<overpayment::HistoryRecord>> {
+        overpayment::create_history(self.client, overpayment_id, details).await
+    }
+}
+
+/// API handler for Prepayments endpoints
+#[derive(Debug)]
+pub struct PrepaymentsApi<'a> {
+    client: &'a Client,
+}
+
+impl PrepaymentsApi<'_> {
+    /// Retrieve a list of prepayments with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(&self, parameters: prepayment::ListParameters) -> Result<Vec<Prepayment>> {
+        prepayment::list(self.client, parameters).await
+    }
+
+    /// Retrieve all prepayments
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<Prepayment>> {
+        prepayment::list_all(self.client).await
+    }
+
+    /// Retrieve a single prepayment by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, prepayment_id: Uuid) -> Result<Prepayment> {
+        prepayment::get(self.client, prepayment_id).await
+    }
+
+    /// Allocate part of a prepayment to one or more invoices
+    #[instrument(skip(self, allocations))]
+    pub async fn allocate(
+        &self,
+        prepayment_id: Uuid,
+        allocations: &[allocation::Builder],
+    ) -> Result<Vec<Allocation>> {
+        prepayment::allocate(self.client, prepayment_id, allocations).await
+    }
+
+    /// Delete an allocation from a prepayment
+    #[instrument(skip(self))]
+    pub async fn delete_allocation(&self, prepayment_id: Uuid, allocation_id: Uuid) -> Result<()> {
+        prepayment::delete_allocation(self.client, prepayment_id, allocation_id).await
+    }
+
+    /// Refund the remaining credit on a prepayment
+    #[instrument(skip(self, refund))]
+    pub async fn refund(&self, prepayment_id: Uuid, refund: &Refund) -> Result<Payment> {
+        prepayment::refund(self.client, prepayment_id, refund).await
+    }
+
+    /// Retrieve the history records for a prepayment
+    #[instrument(skip(self))]
+    pub async fn get_history(&self, prepayment_id: Uuid) -> Result<Vec<prepayment::HistoryRecord>> {
+        prepayment::get_history(self.client, prepayment_id).await
+    }
+
+    /// Add a note to the history of a prepayment
+    #[instrument(skip(self))]
+    pub async fn create_history(
+        &self,
+        prepayment_id: Uuid,
+        details: &str,
+    ) -> Result<Vec<prepayment::HistoryRecord>> {
+        prepayment::create_history(self.client, prepayment_id, details).await
+    }
+}
+
+/// API handler for Purchase Orders endpoints
+#[derive(Debug)]
+pub struct PurchaseOrdersApi<'a> {
+    client: &'a Client,
+}
+
+impl PurchaseOrdersApi<'_> {
+    /// Retrieve a list of purchase orders
+    #[instrument(skip(self))]
+    pub async fn list(&self) -> Result<Vec<PurchaseOrder>> {
+        let empty_vec: Vec<String> = Vec::new();
+        let response: purchase_order::ListResponse = self
+            .client
+            .get(purchase_order::ENDPOINT, &empty_vec)
+            .await?;
+        Ok(response.purchase_orders)
+    }
+
+    /// Retrieve a single purchase order by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, purchase_order_id: Uuid) -> Result<PurchaseOrder> {
+        let endpoint = Url::from_str(purchase_order::ENDPOINT)
+            .and_then(|endpoint| endpoint.join(&purchase_order_id.to_string()))
+            .map_err(|_| Error::InvalidEndpoint)?;
+        let endpoint_str = endpoint.to_string();
+        let empty_vec: Vec<String> = Vec::new();
+        let response: purchase_order::ListResponse = self.client.get(endpoint, &empty_vec).await?;
+        response
+            .purchase_orders
+            .into_iter()
+            .next()
+            .ok_or(Error::NotFound {
+                entity: "PurchaseOrder".to_string(),
+                url: endpoint_str,
+                status_code: reqwest::StatusCode::NOT_FOUND,
+                response_body: Some(format!(
+                    "Purchase Order with ID {purchase_order_id} not found"
+                )),
+                span_trace: SpanTrace::capture(),
+            })
+    }
+
+    /// Create a new purchase order
+    #[instrument(skip(self, builder))]
+    pub async fn create(&self, builder: &purchase_order::Builder) -> Result<PurchaseOrder> {
+        let request = purchase_order::PurchaseOrdersRequest::single(builder);
+        let result: MutationResponse = self.client.put(purchase_order::ENDPOINT, &request).await?;
+        result
+            .data
+            .get_purchase_orders()
+            .and_then(|po| po.into_iter().next())
+            .ok_or(Error::NotFound {
+                entity: "PurchaseOrder".to_string(),
+                url: purchase_order::ENDPOINT.to_string(),
+                status_code: reqwest::StatusCode::NOT_FOUND,
+                response_body: Some(
+                    "Failed to create purchase order - no purchase order in response".to_string(),
+                ),
+                span_trace: SpanTrace::capture(),
+            })
+    }
+
+    /// Update an existing purchase order
+    #[instrument(skip(self, builder))]
+    pub async fn update(
+        &self,
+        purchase_order_id: Uuid,
+        builder: &purchase_order::Builder,
+    ) -> Result<PurchaseOrder> {
+        let endpoint = format!("{}{}", purchase_order::ENDPOINT, purchase_order_id);
+        let request = purchase_order::PurchaseOrdersRequest::single(builder);
+        let result: MutationResponse = self.client.post(&endpoint, &request).await?;
+        result
+            .data
+            .get_purchase_orders()
+            .and_then(|po| po.into_iter().next())
+            .ok_or(Error::NotFound {
+                entity: "PurchaseOrder".to_string(),
+                url: endpoint,
+                status_code: reqwest::StatusCode::NOT_FOUND,
+                response_body: Some(
+                    "Failed to update purchase order - no purchase order in response".to_string(),
+                ),
+                span_trace: SpanTrace::capture(),
+            })
+    }
+}
+
+/// API handler for Quotes endpoints
+#[derive(Debug)]
+pub struct QuotesApi<'a> {
+    client: &'a Client,
+}
+
+impl QuotesApi<'_> {
+    /// Retrieve a list of quotes with filters
+    #[instrument(skip(self, parameters))]
+    pub async fn list(&self, parameters: quote::ListParameters) -> Result<Vec<Quote>> {
+        quote::list(self.client, parameters).await
+    }
+
+    /// Retrieve a list of all quotes without filtering
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<Quote>> {
+        quote::list_all(self.client).await
+    }
+
+    /// Retrieve a single quote by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, quote_id: Uuid) -> Result<Quote> {
+        quote::get(self.client, quote_id).await
+    }
+
+    /// Create a new quote
+    #[instrument(skip(self, quote))]
+    pub async fn create(&self, quote: &quote::QuoteBuilder) -> Result<Quote> {
+        quote::create(self.client, quote).await
+    }
+
+    /// Update or create a quote
+    #[instrument(skip(self, quote))]
+    pub async fn update_or_create(&self, quote: &quote::QuoteBuilder) -> Result<Quote> {
+        quote::update_or_create(self.client, quote).await
+    }
+
+    /// Update a specific quote
+    #[instrument(skip(self, quote))]
+    pub async fn update(&self, quote_id: Uuid, quote: &quote::QuoteBuilder) -> Result<Quote> {
+        quote::update(self.client, quote_id, quote).await
+    }
+
+    /// Get the history records for a quote
+    #[instrument(skip(self))]
+    pub async fn get_history(&self, quote_id: Uuid) -> Result<Vec<quote::HistoryRecord>> {
+        quote::get_history(self.client, quote_id).await
+    }
+
+    /// Create a history record for a quote
+    #[instrument(skip(self))]
+    pub async fn create_history(
+        &self,
+        quote_id: Uuid,
+        details: &str,
+    ) -> Result<Vec<quote::HistoryRecord>> {
+        quote::create_history(self.client, quote_id, details).await
+    }
+
+    /// Get a quote as PDF
+    #[instrument(skip(self))]
+    pub async fn get_pdf(&self, quote_id: Uuid) -> Result<Vec<u8>> {
+        quote::get_pdf(self.client, quote_id).await
+    }
+
+    /// List all attachments for a quote
+    #[instrument(skip(self))]
+    pub async fn list_attachments(&self, quote_id: Uuid) -> Result<Vec<quote::Attachment>> {
+        quote::list_attachments(self.client, quote_id).await
+    }
+
+    /// Get a specific attachment by ID
+    #[instrument(skip(self))]
+    pub async fn get_attachment(&self, quote_id: Uuid, attachment_id: Uuid) -> Result<Vec<u8>> {
+        quote::get_attachment(self.client, quote_id, attachment_id).await
+    }
+
+    /// Get an attachment by filename
+    #[instrument(skip(self))]
+    pub async fn get_attachment_by_filename(
+        &self,
+        quote_id: Uuid,
+        filename: &str,
+    ) -> Result<Vec<u8>> {
+        quote::get_attachment_by_filename(self.client, quote_id, filename).await
+    }
+
+    /// Upload an attachment to a quote
+    #[instrument(skip(self, attachment_content))]
+    pub async fn upload_attachment(
+        &mut self,
+        quote_id: Uuid,
+        filename: &str,
+        attachment_content: &[u8],
+    ) -> Result<quote::Attachment> {
+        quote::upload_attachment(self.client, quote_id, filename, attachment_content).await
+    }
+
+    /// Update an existing attachment
+    #[instrument(skip(self, attachment_content))]
+    pub async fn update_attachment(
+        &mut self,
+        quote_id: Uuid,
+        filename: &str,
+        attachment_content: &[u8],
+    ) -> Result<quote::Attachment> {
+        quote::update_attachment(self.client, quote_id, filename, attachment_content).await
+    }
+}
+
+/// API handler for Receipts endpoints
+#[derive(Debug)]
+pub struct ReceiptsApi<'a> {
+    client: &'a Client,
+}
+
+impl ReceiptsApi<'_> {
+    /// Retrieve a list of receipts with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(&self, parameters: receipt::ListParameters) -> Result<Vec<Receipt>> {
+        receipt::list(self.client, parameters).await
+    }
+
+    /// Retrieve all receipts
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<Receipt>> {
+        receipt::list_all(self.client).await
+    }
+
+    /// Retrieve a single receipt by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, receipt_id: Uuid) -> Result<Receipt> {
+        receipt::get(self.client, receipt_id).await
+    }
+
+    /// Create a draft receipt
+    #[instrument(skip(self, receipt))]
+    pub async fn create(&self, receipt: &receipt::Builder) -> Result<Receipt> {
+        receipt::create(self.client, receipt).await
+    }
+
+    /// Update a draft receipt
+    #[instrument(skip(self, receipt))]
+    pub async fn update(&self, receipt_id: Uuid, receipt: &receipt::Builder) -> Result<Receipt> {
+        receipt::update(self.client, receipt_id, receipt).await
+    }
+
+    /// Change the status of a receipt
+    #[instrument(skip(self))]
+    pub async fn update_status(
+        &self,
+        receipt_id: Uuid,
+        status: receipt::Status,
+    ) -> Result<Receipt> {
+        receipt::update_status(self.client, receipt_id, status).await
+    }
+
+    /// Void a receipt
+    #[instrument(skip(self))]
+    pub async fn void(&self, receipt_id: Uuid) -> Result<Receipt> {
+        receipt::void(self.client, receipt_id).await
+    }
+
+    /// Retrieve the history records for a receipt
+    #[instrument(skip(self))]
+    pub async fn get_history(&self, receipt_id: Uuid) -> Result<Vec<receipt::HistoryRecord>> {
+        receipt::get_history(self.client, receipt_id).await
+    }
+
+    /// Add a note to the history of a receipt
+    #[instrument(skip(self))]
+    pub async fn create_history(
+        &self,
+        receipt_id: Uuid,
+        details: &str,
+    ) -> Result<Vec<receipt::HistoryRecord>> {
+        receipt::create_history(self.client, receipt_id, details).await
+    }
+
+    /// List the attachments on a receipt
+    #[instrument(skip(self))]
+    pub async fn list_attachments(&self, receipt_id: Uuid) -> Result<Vec<receipt::Attachment>> {
+        receipt::list_attachments(self.client, receipt_id).await
+    }
+
+    /// Upload an attachment to a receipt
+    #[instrument(skip(self, attachment_content))]
+    pub async fn upload_attachment(
+        &self,
+        receipt_id: Uuid,
+        filename: &str,
+        attachment_content: &[u8],
+    ) -> Result<receipt::Attachment> {
+        receipt::upload_attachment(self.client, receipt_id, filename, attachment_content).await
+    }
+}
+
+/// API handler for Repeating Invoices endpoints
+#[derive(Debug)]
+pub struct RepeatingInvoicesApi<'a> {
+    client: &'a Client,
+}
+
+impl RepeatingInvoicesApi<'_> {
+    /// Retrieve a list of repeating invoices with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(
+        &self,
+        parameters: repeating_invoice::ListParameters,
+    ) -> Result<Vec<RepeatingInvoice>> {
+        repeating_invoice::list(self.client, parameters).await
+    }
+
+    /// Retrieve all repeating invoices
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<RepeatingInvoice>> {
+        repeating_invoice::list_all(self.client).await
+    }
+
+    /// Retrieve a single repeating invoice by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, repeating_invoice_id: Uuid) -> Result<RepeatingInvoice> {
+        repeating_invoice::get(self.client, repeating_invoice_id).await
+    }
+
+    /// Create a repeating invoice
+    #[instrument(skip(self, repeating_invoice))]
+    pub async fn create(
+        &self,
+        repeating_invoice: &repeating_invoice::Builder,
+    ) -> Result<RepeatingInvoice> {
+        repeating_invoice::create(self.client, repeating_invoice).await
+    }
+
+    /// Update a repeating invoice
+    #[instrument(skip(self, repeating_invoice))]
+    pub async fn update(
+        &self,
+        repeating_invoice_id: Uuid,
+        repeating_invoice: &repeating_invoice::Builder,
+    ) -> Result<RepeatingInvoice> {
+        repeating_invoice::update(self.client, repeating_invoice_id, repeating_invoice).await
+    }
+
+    /// Delete a repeating invoice
+    #[instrument(skip(self))]
+    pub async fn delete(&self, repeating_invoice_id: Uuid) -> Result<RepeatingInvoice> {
+        repeating_invoice::delete(self.client, repeating_invoice_id).await
+    }
+
+    /// Retrieve the history records for a repeating invoice
+    #[instrument(skip(self))]
+    pub async fn get_history(
+        &self,
+        repeating_invoice_id: Uuid,
+    ) -> Result<Vec<repeating_invoice::HistoryRecord>> {
+        repeating_invoice::get_history(self.client, repeating_invoice_id).await
+    }
+
+    /// Add a note to the history of a repeating invoice
+    #[instrument(skip(self))]
+    pub async fn create_history(
+        &self,
+        repeating_invoice_id: Uuid,
+        details: &str,
+    ) -> Result<Vec<repeating_invoice::HistoryRecord>> {
+        repeating_invoice::create_history(self.client, repeating_invoice_id, details).await
+    }
+
+    /// List the attachments on a repeating invoice
+    #[instrument(skip(self))]
+    pub async fn list_attachments(
+        &self,
+        repeating_invoice_id: Uuid,
+    ) -> Result<Vec<repeating_invoice::Attachment>> {
+        repeating_invoice::list_attachments(self.client, repeating_invoice_id).await
+    }
+
+    /// Upload an attachment to a repeating invoice
+    #[instrument(skip(self, attachment_content))]
+    pub async fn upload_attachment(
+        &self,
+        repeating_invoice_id: Uuid,
+        filename: &str,
+        attachment_content: &[u8],
+    ) -> Result<repeating_invoice::Attachment> {
+        repeating_invoice::upload_attachment(
+            self.client,
+            repeating_invoice_id,
+            filename,
+            attachment_content,
+        )
+        .await
+    }
+}
+
+/// API handler for Timesheets endpoints
+#[derive(Debug)]
+pub struct TimesheetsApi<'a> {
+    client: &'a Client,
+}
+
+impl TimesheetsApi<'_> {
+    /// Retrieve a list of timesheets with optional filtering
+    ///
+    /// # Parameters
+    ///
+    /// * `parameters` - Optional filter parameters
+    /// * `modified_after` - Optional ISO8601 timestamp (format: yyyy-mm-ddThh:mm:ss) to filter by modification date
+    #[instrument(skip(self, parameters, modified_after))]
+    pub async fn list(
+        &self,
+        parameters: Option<timesheet::ListParameters>,
+        modified_after: Option<String>,
+    ) -> Result<Vec<Timesheet>> {
+        Timesheet::list(self.client, parameters.as_ref(), modified_after).await
+    }
+
+    /// List all timesheets without any filtering
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<Timesheet>> {
+        self.list(None::<timesheet::ListParameters>, None).await
+    }
+
+    /// Retrieve a single timesheet by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, timesheet_id: Uuid) -> Result<Timesheet> {
+        Timesheet::get(self.client, timesheet_id).await
+    }
+
+    /// Create a new timesheet
+    #[instrument(skip(self, timesheet))]
+    pub async fn create(&self, timesheet: &PostTimesheet) -> Result<Timesheet> {
+        Timesheet::post(self.client, timesheet).await
+    }
+
+    /// Update a timesheet
+    #[instrument(skip(self, timesheet))]
+    pub async fn update(&self, timesheet: &Timesheet) -> Result<Timesheet> {
+        Timesheet::update(self.client, timesheet).await
+    }
+
+    /// Create many timesheets in one request
+    ///
+    /// On a validation failure, `Error::timesheet_validation_errors` gives the index of each
+    /// rejected timesheet in `timesheets`.
+    #[instrument(skip(self, timesheets), fields(count = timesheets.len()))]
+    pub async fn create_many(&self, timesheets: &[PostTimesheet]) -> Result<Vec<Timesheet>> {
+        Timesheet::post_many(self.client, timesheets).await
+    }
+
+    /// Update many timesheets in one request
+    ///
+    /// On a validation failure, `Error::timesheet_validation_errors` gives the index of each
+    /// rejected timesheet in `timesheets`.
+    #[instrument(skip(self, timesheets), fields(count = timesheets.len()))]
+    pub async fn update_many(&self, timesheets: &[Timesheet]) -> Result<Vec<Timesheet>> {
+        Timesheet::update_many(self.client, timesheets).await
+    }
+
+    /// Approve a draft timesheet
+    #[instrument(skip(self))]
+    pub async fn approve(&self, timesheet_id: Uuid) -> Result<Timesheet> {
+        Timesheet::approve(self.client, timesheet_id).await
+    }
+
+    /// Revert an approved timesheet to draft
+    #[instrument(skip(self))]
+    pub async fn revert_to_draft(&self, timesheet_id: Uuid) -> Result<Timesheet> {
+        Timesheet::revert_to_draft(self.client, timesheet_id).await
+    }
+
+    /// Remove all of a timesheet's lines, leaving an empty draft
+    ///
+    /// The Payroll AU API cannot delete timesheets; see [`Timesheet::clear_lines`].
+    #[instrument(skip(self))]
+    pub async fn clear_lines(&self, timesheet_id: Uuid) -> Result<Timesheet> {
+        Timesheet::clear_lines(self.client, timesheet_id).await
+    }
+}
+
+/// API handler for Users endpoints
+#[derive(Debug)]
+pub struct UsersApi<'a> {
+    client: &'a Client,
+}
+
+impl UsersApi<'_> {
+    /// Retrieve a list of users with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(&self, parameters: user::ListParameters) -> Result<Vec<User>> {
+        user::list(self.client, parameters).await
+    }
+
+    /// Retrieve all users
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<User>> {
+        user::list_all(self.client).await
+    }
+
+    /// Retrieve a single user by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, user_id: Uuid) -> Result<User> {
+        user::get(self.client, user_id).await
+    }
+
+    /// Find the user with the given email address
+    #[instrument(skip(self))]
+    pub async fn find_by_email(&self, email_address: &str) -> Result<Option<User>> {
+        user::find_by_email(self.client, email_address).await
+    }
+}
+
+/// API handler for the Organisation endpoint
+#[derive(Debug)]
+pub struct OrganisationApi<'a> {
+    client: &'a Client,
+}
+
+impl OrganisationApi<'_> {
+    /// Retrieve the organisation the client is connected to
+    #[instrument(skip(self))]
+    pub async fn get(&self) -> Result<Organisation> {
+        organisation::get(self.client).await
+    }
+}
+
+/// API handler for Employees endpoints
+#[derive(Debug)]
+pub struct EmployeesApi<'a> {
+    client: &'a Client,
+}
+
+impl EmployeesApi<'_> {
+    /// Retrieve a list of employees
+    #[instrument(skip(self))]
+    pub async fn list(&self) -> Result<Vec<Employee>> {
+        let empty_vec: Vec<String> = Vec::new();
+        let response: employee::ListResponse =
+            self.client.get(employee::ENDPOINT, &empty_vec).await?;
+        Ok(response.employees)
+    }
+
+    /// Retrieve one page of employees, up to 100 employees are returned per page
+    #[instrument(skip(self))]
+    pub async fn list_page(&self, page: i32) -> Result<Vec<Employee>> {
+        let response: employee::ListResponse = self
+            .client
+            .get(employee::ENDPOINT, &[("page", page.to_string())])
+            .await?;
+        Ok(response.employees)
+    }
+
+    /// Retrieve a single employee by ID, with their full details
+    #[instrument(skip(self))]
+    pub async fn get(&self, employee_id: Uuid) -> Result<Employee> {
+        Employee::get(self.client, employee_id).await
+    }
+
+    /// Create a payroll employee
+    #[instrument(skip(self, employee))]
+    pub async fn create(&self, employee: &PostEmployee) -> Result<Employee> {
+        Employee::create(self.client, employee).await
+    }
+
+    /// Update a payroll employee
+    #[instrument(skip(self, employee))]
+    pub async fn update(&self, employee_id: Uuid, employee: &PostEmployee) -> Result<Employee> {
+        Employee::update(self.client, employee_id, employee).await
+    }
+}
+
+/// API handler for Earnings Rates endpoints
+#[derive(Debug)]
+pub struct EarningsRatesApi<'a> {
+    client: &'a Client,
+}
+
+impl EarningsRatesApi<'_> {
+    /// Retrieve a list of earnings rates
+    #[instrument(skip(self))]
+    pub async fn list(&self) -> Result<Vec<EarningsRate>> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct PayItems {
+            earnings_rates: Vec<EarningsRate>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct ListResponse {
+            pay_items: PayItems,
+        }
+
+        let empty_vec: Vec<String> = Vec::new();
+        let response: ListResponse = self
+            .client
+            .get(earnings_rates::ENDPOINT, &empty_vec)
+            .await?;
+        Ok(response.pay_items.earnings_rates)
+    }
+}
+
+/// API client for interacting with Xero Payroll Calendars
+///
+/// This API provides methods for listing, retrieving, and creating pay calendars.
+pub struct PayCalendarsApi<'a> {
+    client: &'a Client,
+}
+
+impl PayCalendarsApi<'_> {
+    /// Retrieve a list of pay calendars
+    ///
+    /// Returns all pay calendars defined in the Xero organization.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails.
+    #[instrument(skip(self))]
+    pub async fn list(&self) -> Result<Vec<PayCalendar>> {
+        let url = "https://api.xero.com/payroll.xro/1.0/PayrollCalendars";
+        let response: pay_calendar::PayCalendarResponse = self.client.get(url, &()).await?;
+        Ok(response.payroll_calendars)
+    }
+
+    /// Get a pay calendar by ID
+    ///
+    /// Retrieves a specific pay calendar using its unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `pay_calendar_id` - The UUID of the pay calendar to retrieve
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pay calendar is not found or if the API request fails.
+    #[instrument(skip(self))]
+    pub async fn get(&self, pay_calendar_id: Uuid) -> Result<PayCalendar> {
+        let url =
+            format!("https://api.xero.com/payroll.xro/1.0/PayrollCalendars/{pay_calendar_id}");
+        let response: pay_calendar::PayCalendarResponse = self.client.get(&url, &()).await?;
+
+        if response.payroll_calendars.is_empty() {
+            return Err(Error::NotFound {
+                entity: "PayCalendar".to_string(),
+                url,
+                status_code: StatusCode::NOT_FOUND,
+                response_body: Some(format!("Pay Calendar with ID {pay_calendar_id} not found")),
+                span_trace: SpanTrace::capture(),
+            });
+        }
+
+        Ok(response.payroll_calendars.into_iter().next().unwrap())
+    }
+
+    /// Create a new pay calendar
+    ///
+    /// Creates a new pay calendar with the specified details.
+    ///
+    /// # Arguments
+    ///
+    /// * `pay_calendar` - The pay calendar details to create
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or if the response doesn't contain the created pay calendar.
+    #[instrument(skip(self, pay_calendar))]
+    pub async fn create(
+        &mut self,
+        pay_calendar: &pay_calendar::CreatePayCalendar,
+    ) -> Result<PayCalendar> {
+        let url = "https://api.xero.com/payroll.xro/1.0/PayrollCalendars";
+
+        // Create a vector with a single pay calendar
+        let request = vec![pay_calendar.clone()];
+
+        let response: pay_calendar::PayCalendarResponse = self.client.post(url, &request).await?;
+
+        if response.payroll_calendars.is_empty() {
+            return Err(Error::NotFound {
+                entity: "PayCalendar".to_string(),
+                url: url.to_string(),
+                status_code: StatusCode::OK,
+                response_body: Some("No pay calendar was returned after creation".to_string()),
+                span_trace: SpanTrace::capture(),
+            });
+        }
+
+        Ok(response.payroll_calendars.into_iter().next().unwrap())
+    }
+}
+
+/// API handler for Items endpoints
+#[derive(Debug)]
+pub struct ItemsApi<'a> {
+    client: &'a Client,
+}
+
+impl ItemsApi<'_> {
+    /// Retrieve a list of items with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(&self, parameters: item::ListParameters) -> Result<Vec<Item>> {
+        item::list(self.client, parameters).await
+    }
+
+    /// List all items without any filtering
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<Item>> {
+        item::list_all(self.client).await
+    }
+
+    /// Retrieve a single item by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, item_id: Uuid) -> Result<Item> {
+        item::get(self.client, item_id).await
+    }
+
+    /// Retrieve a single item by code
+    #[instrument(skip(self))]
+    pub async fn get_by_code(&self, code: &str) -> Result<Item> {
+        item::get_by_code(self.client, code).await
+    }
+
+    /// Create a single item
+    #[instrument(skip(self, item))]
+    pub async fn create(&self, item: &item::Builder) -> Result<Item> {
+        item::create_single(self.client, item).await
+    }
+
+    /// Create multiple items
+    #[instrument(skip(self, items))]
+    pub async fn create_multiple(&self, items: &[item::Builder]) -> Result<Vec<Item>> {
+        item::create(self.client, items).await
+    }
+
+    /// Update or create a single item
+    #[instrument(skip(self, item))]
+    pub async fn update_or_create(&self, item: &item::Builder) -> Result<Item> {
+        let items = item::update_or_create(self.client, &[item.clone()]).await?;
+        items.into_iter().next().ok_or(Error::NotFound {
+            entity: "Item".to_string(),
+            url: item::ENDPOINT.to_string(),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No item returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+    }
+
+    /// Update or create multiple items
+    #[instrument(skip(self, items))]
+    pub async fn update_or_create_multiple(
+        &mut self,
+        items: &[item::Builder],
+    ) -> Result<Vec<Item>> {
+        item::update_or_create(self.client, items).await
+    }
+
+    /// Update a specific item
+    #[instrument(skip(self, item))]
+    pub async fn update(&self, item_id: Uuid, item: &item::Builder) -> Result<Item> {
+        item::update(self.client, item_id, item).await
+    }
+
+    /// Delete a specific item
+    #[instrument(skip(self))]
+    pub async fn delete(&self, item_id: Uuid) -> Result<()> {
+        item::delete(self.client, item_id).await
+    }
+
+    /// Get the history for an item
+    #[instrument(skip(self))]
+    pub async fn get_history(&self, item_id: Uuid) -> Result<Vec<item::HistoryRecord>> {
+        item::get_history(self.client, item_id).await
+    }
+
+    /// Create a history record for an item
+    #[instrument(skip(self))]
+    pub async fn create_history(
+        &self,
+        item_id: Uuid,
+        details: &str,
+    ) -> Result<Vec<item::HistoryRecord>> {
+        item::create_history(self.client, item_id, details).await
+    }
+}
+
+/// API handler for Leave Applications endpoints
+#[derive(Debug)]
+pub struct LeaveApplicationsApi<'a> {
+    client: &'a Client,
+}
+
+impl LeaveApplicationsApi<'_> {
+    /// List approved leave applications (v1 endpoint)
+    ///
+    /// This endpoint only returns leave applications that have been approved.
+    /// Use `list_v2` to get all leave including pending and rejected.
+    ///
+    /// # Parameters
+    ///
+    /// * `parameters` - Optional filter parameters
+    /// * `modified_after` - Optional ISO8601 timestamp (format: yyyy-mm-ddThh:mm:ss) to filter by modification date
+    #[instrument(skip(self, parameters, modified_after))]
+    pub async fn list(
+        &self,
+        parameters: Option<leave_application::ListParameters>,
+        modified_after: Option<String>,
+    ) -> Result<Vec<LeaveApplication>> {
+        LeaveApplication::list(self.client, parameters.as_ref(), modified_after).await
+    }
+
+    /// List all leave applications (v2 endpoint)
+    ///
+    /// This endpoint returns leave with all statuses: SCHEDULED, PROCESSED,
+    /// REQUESTED (awaiting approval), and REJECTED.
+    ///
+    /// # Parameters
+    ///
+    /// * `parameters` - Optional filter parameters
+    /// * `modified_after` - Optional ISO8601 timestamp to filter by modification date
+    #[instrument(skip(self, parameters, modified_after))]
+    pub async fn list_v2(
+        &self,
+        parameters: Option<leave_application::ListParameters>,
+        modified_after: Option<String>,
+    ) -> Result<Vec<LeaveApplication>> {
+        LeaveApplication::list_v2(self.client, parameters.as_ref(), modified_after).await
+    }
+
+    /// List all approved leave without filtering
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<LeaveApplication>> {
+        self.list(None, None).await
+    }
+
+    /// Retrieve a single leave application by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, leave_application_id: Uuid) -> Result<LeaveApplication> {
+        LeaveApplication::get(self.client, leave_application_id).await
+    }
+
+    /// Create a new leave application
+    #[instrument(skip(self, leave_application))]
+    pub async fn create(
+        &self,
+        leave_application: &PostLeaveApplication,
+    ) -> Result<LeaveApplication> {
+        LeaveApplication::post(self.client, leave_application).await
+    }
+
+    /// Update an existing leave application
+    #[instrument(skip(self, leave_application))]
+    pub async fn update(&self, leave_application: &LeaveApplication) -> Result<LeaveApplication> {
+        LeaveApplication::update(self.client, leave_application).await
+    }
+
+    /// Approve a leave application that is in REQUESTED status
+    #[instrument(skip(self))]
+    pub async fn approve(&self, leave_application_id: Uuid) -> Result<LeaveApplication> {
+        LeaveApplication::approve(self.client, leave_application_id).await
+    }
+
+    /// Reject a leave application that is in REQUESTED status
+    #[instrument(skip(self))]
+    pub async fn reject(&self, leave_application_id: Uuid) -> Result<LeaveApplication> {
+        LeaveApplication::reject(self.client, leave_application_id).await
+    }
+
+    /// Cancel an approved leave application, zeroing the units of its unprocessed periods
+    #[instrument(skip(self))]
+    pub async fn cancel(&self, leave_application_id: Uuid) -> Result<LeaveApplication> {
+        LeaveApplication::cancel(self.client, leave_application_id).await
+    }
+
+    /// Calculate the leave periods, and the units in each, for an employee's leave between two
+    /// dates before submitting it
+    #[instrument(skip(self))]
+    pub async fn preview_periods(
+        &self,
+        employee_id: Uuid,
+        leave_type_id: Uuid,
+        start_date: time::Date,
+        end_date: time::Date,
+    ) -> Result<Vec<LeavePeriod>> {
+        LeaveApplication::preview_periods(
+            self.client,
+            employee_id,
+            leave_type_id,
+            start_date,
+            end_date,
+        )
+        .await
+    }
+
+    /// Retrieve an employee's current leave balances, paired with their leave types
+    #[instrument(skip(self))]
+    pub async fn leave_balances(&self, employee_id: Uuid) -> Result<Vec<LeaveTypeBalance>> {
+        leave_balance::list(self.client, employee_id).await
+    }
+
+    /// Estimate the employee's balance of the application's leave type at its end date, after
+    /// the application and their other scheduled or requested leave
+    #[instrument(skip(self, leave_application))]
+    pub async fn balance_after(
+        &self,
+        leave_application: &LeaveApplication,
+    ) -> Result<LeaveProjection> {
+        leave_balance::project_application(self.client, leave_application).await
+    }
+}
+
+/// API handler for Leave Types endpoints
+#[derive(Debug)]
+pub struct LeaveTypesApi<'a> {
+    client: &'a Client,
+}
+
+impl LeaveTypesApi<'_> {
+    /// Retrieve a list of leave types
+    ///
+    /// Leave types are retrieved from the PayItems endpoint.
+    #[instrument(skip(self))]
+    pub async fn list(&self) -> Result<Vec<LeaveType>> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct PayItems {
+            #[serde(default)]
+            leave_types: Vec<LeaveType>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct ListResponse {
+            pay_items: PayItems,
+        }
+
+        let empty_vec: Vec<String> = Vec::new();
+        let response: ListResponse = self
+            .client
+            .get(earnings_rates::ENDPOINT, &empty_vec)
+            .await?;
+        Ok(response.pay_items.leave_types)
+    }
+}
+
+/// API handler for Deduction Types endpoints
+#[derive(Debug)]
+pub struct DeductionTypesApi<'a> {
+    client: &'a Client,
+}
+
+impl DeductionTypesApi<'_> {
+    /// Retrieve a list of deduction types
+    ///
+    /// Deduction types are retrieved from the `PayItems` endpoint.
+    #[instrument(skip(self))]
+    pub async fn list(&self) -> Result<Vec<DeductionType>> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct PayItems {
+            #[serde(default)]
+            deduction_types: Vec<DeductionType>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct ListResponse {
+            pay_items: PayItems,
+        }
+
+        let empty_vec: Vec<String> = Vec::new();
+        let response: ListResponse = self
+            .client
+            .get(earnings_rates::ENDPOINT, &empty_vec)
+            .await?;
+        Ok(response.pay_items.deduction_types)
+    }
+}
+
+/// API handler for Reimbursement Types endpoints
+#[derive(Debug)]
+pub struct ReimbursementTypesApi<'a> {
+    client: &'a Client,
+}
+
+impl ReimbursementTypesApi<'_> {
+    /// Retrieve a list of reimbursement types
+    ///
+    /// Reimbursement types are retrieved from the `PayItems` endpoint.
+    #[instrument(skip(self))]
+    pub async fn list(&self) -> Result<Vec<ReimbursementType>> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct PayItems {
+            #[serde(default)]
+            reimbursement_types: Vec<ReimbursementType>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct ListResponse {
+            pay_items: PayItems,
+        }
+
+        let empty_vec: Vec<String> = Vec::new();
+        let response: ListResponse = self
+            .client
+            .get(earnings_rates::ENDPOINT, &empty_vec)
+            .await?;
+        Ok(response.pay_items.reimbursement_types)
+    }
+}
+
+/// API handler for payroll Pay Items endpoints
+#[derive(Debug)]
+pub struct PayItemsApi<'a> {
+    client: &'a Client,
+}
+
+impl PayItemsApi<'_> {
+    /// Retrieve all earnings rates, deduction types, leave types and reimbursement types
+    #[instrument(skip(self))]
+    pub async fn get(&self) -> Result<PayItems> {
+        PayItems::get(self.client).await
+    }
+
+    /// Write pay items, returning every pay item after the change
+    ///
+    /// Each collection that is sent replaces the existing one, see
+    /// [`crate::payroll::settings::pay_items`].
+    #[instrument(skip(self, pay_items))]
+    pub async fn post(&self, pay_items: &PostPayItems) -> Result<PayItems> {
+        PayItems::post(self.client, pay_items).await
+    }
+
+    /// Create an earnings rate, keeping the existing ones
+    #[instrument(skip(self, earnings_rate))]
+    pub async fn create_earnings_rate(
+        &self,
+        earnings_rate: &PostEarningsRate,
+    ) -> Result<EarningsRate> {
+        let mut earnings_rate = earnings_rate.clone();
+        earnings_rate.earnings_rate_id = None;
+
+        self.write_pay_item(
+            "EarningsRate",
+            None,
+            |current| {
+                PostPayItems {
+                    earnings_rates: current.earnings_rates.iter().map(Into::into).collect(),
+                    ..PostPayItems::default()
+                }
+                .with_earnings_rate(earnings_rate)
+            },
+            |current, updated| {
+                updated
+                    .earnings_rates
+                    .into_iter()
+                    .find(|rate| current.earnings_rate(rate.earnings_rate_id).is_none())
+            },
+        )
+        .await
+    }
+
+    /// Update an earnings rate, keeping the others
+    #[instrument(skip(self, earnings_rate))]
+    pub async fn update_earnings_rate(
+        &self,
+        earnings_rate_id: Uuid,
+        earnings_rate: &PostEarningsRate,
+    ) -> Result<EarningsRate> {
+        let mut earnings_rate = earnings_rate.clone();
+        earnings_rate.earnings_rate_id = Some(earnings_rate_id);
+
+        self.write_pay_item(
+            "EarningsRate",
+            Some(earnings_rate_id),
+            |current| {
+                PostPayItems {
+                    earnings_rates: current.earnings_rates.iter().map(Into::into).collect(),
+                    ..PostPayItems::default()
+                }
+                .with_earnings_rate(earnings_rate)
+            },
+            |_, updated| {
+                updated
+                    .earnings_rates
+                    .into_iter()
+                    .find(|rate| rate.earnings_rate_id == earnings_rate_id)
+            },
+        )
+        .await
+    }
+
+    /// Create a deduction type, keeping the existing ones
+    #[instrument(skip(self, deduction_type))]
+    pub async fn create_deduction_type(
+        &self,
+        deduction_type: &PostDeductionType,
+    ) -> Result<DeductionType> {
+        let mut deduction_type = deduction_type.clone();
+        deduction_type.deduction_type_id = None;
+
+        self.write_pay_item(
+            "DeductionType",
+            None,
+            |current| {
+                PostPayItems {
+                    deduction_types: current.deduction_types.iter().map(Into::into).collect(),
+                    ..PostPayItems::default()
+                }
+                .with_deduction_type(deduction_type)
+            },
+            |current, updated| {
+                updated.deduction_types.into_iter().find(|deduction| {
+                    current
+                        .deduction_type(deduction.deduction_type_id)
+                        .is_none()
+                })
+            },
+        )
+        .await
+    }
+
+    /// Update a deduction type, keeping the others
+    #[instrument(skip(self, deduction_type))]
+    pub async fn update_deduction_type(
+        &self,
+        deduction_type_id: Uuid,
+        deduction_type: &PostDeductionType,
+    ) -> Result<DeductionType> {
+        let mut deduction_type = deduction_type.clone();
+        deduction_type.deduction_type_id = Some(deduction_type_id);
+
+        self.write_pay_item(
+            "DeductionType",
+            Some(deduction_type_id),
+            |current| {
+                PostPayItems {
+                    deduction_types: current.deduction_types.iter().map(Into::into).collect(),
+                    ..PostPayItems::default()
+                }
+                .with_deduction_type(deduction_type)
+            },
+            |_, updated| {
+                updated
+                    .deduction_types
+                    .into_iter()
+                    .find(|deduction| deduction.deduction_type_id == deduction_type_id)
+            },
+        )
+        .await
+    }
+
+    /// Create a leave type, keeping the existing ones
+    #[instrument(skip(self, leave_type))]
+    pub async fn create_leave_type(&self, leave_type: &PostLeaveType) -> Result<LeaveType> {
+        let mut leave_type = leave_type.clone();
+        leave_type.leave_type_id = None;
+
+        self.write_pay_item(
+            "LeaveType",
+            None,
+            |current| {
+                PostPayItems {
+                    leave_types: current.leave_types.iter().map(Into::into).collect(),
+                    ..PostPayItems::default()
+                }
+                .with_leave_type(leave_type)
+            },
+            |current, updated| {
+                updated
+                    .leave_types
+                    .into_iter()
+                    .find(|leave| current.leave_type(leave.leave_type_id).is_none())
+            },
+        )
+        .await
+    }
+
+    /// Update a leave type, keeping the others
+    #[instrument(skip(self, leave_type))]
+    pub async fn update_leave_type(
+        &self,
+        leave_type_id: Uuid,
+        leave_type: &PostLeaveType,
+    ) -> Result<LeaveType> {
+        let mut leave_type = leave_type.clone();
+        leave_type.leave_type_id = Some(leave_type_id);
+
+        self.write_pay_item(
+            "LeaveType",
+            Some(leave_type_id),
+            |current| {
+                PostPayItems {
+                    leave_types: current.leave_types.iter().map(Into::into).collect(),
+                    ..PostPayItems::default()
+                }
+                .with_leave_type(leave_type)
+            },
+            |_, updated| {
+                updated
+                    .leave_types
+                    .into_iter()
+                    .find(|leave| leave.leave_type_id == leave_type_id)
+            },
+        )
+        .await
+    }
+
+    /// Create a reimbursement type, keeping the existing ones
+    #[instrument(skip(self, reimbursement_type))]
+    pub async fn create_reimbursement_type(
+        &self,
+        reimbursement_type: &PostReimbursementType,
+    ) -> Result<ReimbursementType> {
+        let mut reimbursement_type = reimbursement_type.clone();
+        reimbursement_type.reimbursement_type_id = None;
+
+        self.write_pay_item(
+            "ReimbursementType",
+            None,
+            |current| {
+                PostPayItems {
+                    reimbursement_types: current
+                        .reimbursement_types
+                        .iter()
+                        .map(Into::into)
+                        .collect(),
+                    ..PostPayItems::default()
+                }
+                .with_reimbursement_type(reimbursement_type)
+            },
+            |current, updated| {
+                updated
+                    .reimbursement_types
+                    .into_iter()
+                    .find(|reimbursement| {
+                        current
+                            .reimbursement_type(reimbursement.reimbursement_type_id)
+                            .is_none()
+                    })
+            },
+        )
+        .await
+    }
+
+    /// Update a reimbursement type, keeping the others
+    #[instrument(skip(self, reimbursement_type))]
+    pub async fn update_reimbursement_type(
+        &self,
+        reimbursement_type_id: Uuid,
+        reimbursement_type: &PostReimbursementType,
+    ) -> Result<ReimbursementType> {
+        let mut reimbursement_type = reimbursement_type.clone();
+        reimbursement_type.reimbursement_type_id = Some(reimbursement_type_id);
+
+        self.write_pay_item(
+            "ReimbursementType",
+            Some(reimbursement_type_id),
+            |current| {
+                PostPayItems {
+                    reimbursement_types: current
+                        .reimbursement_types
+                        .iter()
+                        .map(Into::into)
+                        .collect(),
+                    ..PostPayItems::default()
+                }
+                .with_reimbursement_type(reimbursement_type)
+            },
+            |_, updated| {
+                updated
+                    .reimbursement_types
+                    .into_iter()
+                    .find(|reimbursement| {
+                        reimbursement.reimbursement_type_id == reimbursement_type_id
+                    })
+            },
+        )
+        .await
+    }
+
+    /// Post the request `build` makes from the current pay items, and return the item `find`
+    /// picks out of the response
+    async fn write_pay_item<T>(
+        &self,
+        entity: &str,
+        item_id: Option<Uuid>,
+        build: impl FnOnce(&PayItems) -> PostPayItems,
+        find: impl FnOnce(&PayItems, PayItems) -> Option<T>,
+    ) -> Result<T> {
+        let current = PayItems::get(self.client).await?;
+        let updated = PayItems::post(self.client, &build(&current)).await?;
+        find(&current, updated).ok_or_else(|| pay_item_not_found(entity, item_id))
+    }
+}
+
+/// Error for a pay items response that is missing the pay item just written
+fn pay_item_not_found(entity: &str, item_id: Option<Uuid>) -> Error {
+    Error::NotFound {
+        entity: entity.to_string(),
+        url: earnings_rates::ENDPOINT.to_string(),
+        status_code: StatusCode::NOT_FOUND,
+        response_body: Some(match item_id {
+            Some(id) => format!("{entity} with ID {id} not returned in response"),
+            None => format!("No new {entity} returned in response"),
+        }),
+        span_trace: SpanTrace::capture(),
+    }
+}
+
+/// API handler for payroll Settings endpoints
+#[derive(Debug)]
+pub struct PayrollSettingsApi<'a> {
+    client: &'a Client,
+}
+
+impl PayrollSettingsApi<'_> {
+    /// Retrieve the organisation's payroll accounts, tracking categories and days in the
+    /// payroll year
+    #[instrument(skip(self))]
+    pub async fn get(&self) -> Result<PayrollSettings> {
+        PayrollSettings::get(self.client).await
+    }
+}
+
+/// API handler for payroll Superfunds endpoints
+#[derive(Debug)]
+pub struct SuperfundsApi<'a> {
+    client: &'a Client,
+}
+
+impl SuperfundsApi<'_> {
+    /// Retrieve a list of superfunds
+    #[instrument(skip(self))]
+    pub async fn list(&self) -> Result<Vec<Superfund>> {
+        Superfund::list(self.client).await
+    }
+
+    /// Retrieve a superfund by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, super_fund_id: Uuid) -> Result<Superfund> {
+        Superfund::get(self.client, super_fund_id).await
+    }
+
+    /// Create a superfund
+    #[instrument(skip(self, superfund))]
+    pub async fn create(&self, superfund: &PostSuperfund) -> Result<Superfund> {
+        Superfund::create(self.client, superfund).await
+    }
+
+    /// Update a superfund
+    #[instrument(skip(self, superfund))]
+    pub async fn update(
+        &self,
+        super_fund_id: Uuid,
+        superfund: &PostSuperfund,
+    ) -> Result<Superfund> {
+        Superfund::update(self.client, super_fund_id, superfund).await
+    }
+}
+
+/// API handler for payroll Superfund Products endpoints
+#[derive(Debug)]
+pub struct SuperfundProductsApi<'a> {
+    client: &'a Client,
+}
+
+impl SuperfundProductsApi<'_> {
+    /// Look up regulated superfund products by ABN
+    #[instrument(skip(self))]
+    pub async fn by_abn(&self, abn: &str) -> Result<Vec<SuperfundProduct>> {
+        let parameters = superfund::ProductParameters {
+            abn: Some(abn.to_string()),
+            ..Default::default()
+        };
+        SuperfundProduct::lookup(self.client, &parameters).await
+    }
+
+    /// Look up a regulated superfund product by its unique superannuation identifier
+    #[instrument(skip(self))]
+    pub async fn by_usi(&self, usi: &str) -> Result<Vec<SuperfundProduct>> {
+        let parameters = superfund::ProductParameters {
+            usi: Some(usi.to_string()),
+            ..Default::default()
+        };
+        SuperfundProduct::lookup(self.client, &parameters).await
+    }
+}
+
+/// API handler for payroll Pay Runs endpoints
+#[derive(Debug)]
+pub struct PayRunsApi<'a> {
+    client: &'a Client,
+}
+
+impl PayRunsApi<'_> {
+    /// Retrieve a list of pay runs with optional filtering
+    #[instrument(skip(self, parameters))]
+    pub async fn list(&self, parameters: Option<pay_run::ListParameters>) -> Result<Vec<PayRun>> {
+        PayRun::list(self.client, parameters.as_ref()).await
+    }
+
+    /// List all pay runs without any filtering
+    #[instrument(skip(self))]
+    pub async fn list_all(&self) -> Result<Vec<PayRun>> {
+        self.list(None).await
+    }
+
+    /// Retrieve a single pay run by ID, including its payslip summaries
+    #[instrument(skip(self))]
+    pub async fn get(&self, pay_run_id: Uuid) -> Result<PayRun> {
+        PayRun::get(self.client, pay_run_id).await
+    }
+
+    /// Create a draft pay run for a payroll calendar
+    #[instrument(skip(self, pay_run))]
+    pub async fn create(&self, pay_run: &PostPayRun) -> Result<PayRun> {
+        PayRun::create(self.client, pay_run).await
+    }
+
+    /// Update a pay run
+    #[instrument(skip(self, pay_run))]
+    pub async fn update(&self, pay_run: &UpdatePayRun) -> Result<PayRun> {
+        PayRun::update(self.client, pay_run).await
+    }
+
+    /// Post a draft pay run
+    #[instrument(skip(self))]
+    pub async fn post(&self, pay_run_id: Uuid) -> Result<PayRun> {
+        PayRun::post(self.client, pay_run_id).await
+    }
+}
+
+/// API handler for payroll Payslip endpoints
+#[derive(Debug)]
+pub struct PayslipsApi<'a> {
+    client: &'a Client,
+}
+
+impl PayslipsApi<'_> {
+    /// Retrieve a payslip by ID, including all of its lines
+    #[instrument(skip(self))]
+    pub async fn get(&self, payslip_id: Uuid) -> Result<Payslip> {
+        Payslip::get(self.client, payslip_id).await
+    }
+
+    /// Replace earnings, deduction, leave, reimbursement, super or tax lines on a payslip
+    #[instrument(skip(self, payslip))]
+    pub async fn update(&self, payslip_id: Uuid, payslip: &UpdatePayslip) -> Result<Payslip> {
+        Payslip::update(self.client, payslip_id, payslip).await
+    }
+}
+
+/// API handler for AU payroll endpoints
+///
+/// Groups the AU handlers that are also available directly on [`Client`], mirroring the UK
+/// and NZ payroll APIs.
+#[derive(Debug)]
+pub struct PayrollAuApi<'a> {
+    client: &'a Client,
+}
+
+impl<'a> PayrollAuApi<'a> {
+    /// Access AU employees, see [`Client::employees`]
+    #[must_use]
+    pub fn employees(&self) -> EmployeesApi<'a> {
+        EmployeesApi {
+            client: self.client,
+        }
+    }
+
+    /// Access AU timesheets, see [`Client::timesheets`]
+    #[must_use]
+    pub fn timesheets(&self) -> TimesheetsApi<'a> {
+        TimesheetsApi {
+            client: self.client,
+        }
+    }
+
+    /// Access AU leave applications, see [`Client::leave_applications`]
+    #[must_use]
+    pub fn leave_applications(&self) -> LeaveApplicationsApi<'a> {
+        LeaveApplicationsApi {
+            client: self.client,
+        }
+    }
+
+    /// Access AU pay runs, see [`Client::pay_runs`]
+    #[must_use]
+    pub fn pay_runs(&self) -> PayRunsApi<'a> {
+        PayRunsApi {
+            client: self.client,
+        }
+    }
+}
+
+/// API handler for UK payroll endpoints
+#[cfg(feature = "payroll-uk")]
+#[derive(Debug)]
+pub struct PayrollUkApi<'a> {
+    client: &'a Client,
+}
+
+#[cfg(feature = "payroll-uk")]
+impl<'a> PayrollUkApi<'a> {
+    /// Access UK employees and their employment, tax, leave and pay templates
+    #[must_use]
+    pub fn employees(&self) -> PayrollUkEmployeesApi<'a> {
+        PayrollUkEmployeesApi {
+            client: self.client,
+        }
+    }
+
+    /// Access UK earnings rates, deductions, benefits, leave types and reimbursements
+    #[must_use]
+    pub fn pay_items(&self) -> PayrollUkPayItemsApi<'a> {
+        PayrollUkPayItemsApi {
+            client: self.client,
+        }
+    }
+
+    /// Access UK timesheets
+    #[must_use]
+    pub fn timesheets(&self) -> PayrollUkTimesheetsApi<'a> {
+        PayrollUkTimesheetsApi {
+            client: self.client,
+        }
+    }
+
+    /// Access UK pay runs and payslips
+    #[must_use]
+    pub fn pay_runs(&self) -> PayrollUkPayRunsApi<'a> {
+        PayrollUkPayRunsApi {
+            client: self.client,
+        }
+    }
+}
+
+/// API handler for UK payroll Employees endpoints
+#[cfg(feature = "payroll-uk")]
+#[derive(Debug)]
+pub struct PayrollUkEmployeesApi<'a> {
+    client: &'a Client,
+}
+
+#[cfg(feature = "payroll-uk")]
+impl PayrollUkEmployeesApi<'_> {
+    /// Retrieve all employees
+    #[instrument(skip(self))]
+    pub async fn list(&self) -> Result<Vec<uk::employee::Employee>> {
+        uk::employee::Employee::list(self.client).await
+    }
+
+    /// Retrieve an employee by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, employee_id: Uuid) -> Result<uk::employee::Employee> {
+        uk::employee::Employee::get(self.client, employee_id).await
+    }
+
+    /// Create an employee
+    #[instrument(skip(self, employee))]
+    pub async fn create(
+        &self,
+        employee: &uk::employee::PostEmployee,
+    ) -> Result<uk::employee::Employee> {
+        uk::employee::Employee::create(self.client, employee).await
+    }
+
+    /// Update an employee
+    #[instrument(skip(self, employee))]
+    pub async fn update(
+        &self,
+        employee_id: Uuid,
+        employee: &uk::employee::PostEmployee,
+    ) -> Result<uk::employee::Employee> {
+        uk::employee::Employee::update(self.client, employee_id, employee).await
+    }
+
+    /// Create the employment details that place an employee on a payroll calendar
+    #[instrument(skip(self, employment))]
+    pub async fn create_employment(
+        &self,
+        employee_id: Uuid,
+        employment: &uk::employee::Employment,
+    ) -> Result<uk::employee::Employment> {
+        uk::employee::Employment::create(self.client, employee_id, employment).await
+    }
+
+    /// Retrieve an employee's tax details
+    #[instrument(skip(self))]
+    pub async fn tax(&self, employee_id: Uuid) -> Result<uk::employee::EmployeeTax> {
+        uk::employee::EmployeeTax::get(self.client, employee_id).await
+    }
+
+    /// Retrieve an employee's leave
+    #[instrument(skip(self))]
+    pub async fn leave(&self, employee_id: Uuid) -> Result<Vec<uk::leave::EmployeeLeave>> {
+        uk::leave::EmployeeLeave::list(self.client, employee_id).await
+    }
+
+    /// Retrieve a single leave record of an employee
+    #[instrument(skip(self))]
+    pub async fn get_leave(
+        &self,
+        employee_id: Uuid,
+        leave_id: Uuid,
+    ) -> Result<uk::leave::EmployeeLeave> {
+        uk::leave::EmployeeLeave::get(self.client, employee_id, leave_id).await
+    }
+
+    /// Record leave for an employee
+    #[instrument(skip(self, leave))]
+    pub async fn create_leave(
+        &self,
+        employee_id: Uuid,
+        leave: &uk::leave::PostLeave,
+    ) -> Result<uk::leave::EmployeeLeave> {
+        uk::leave::EmployeeLeave::create(self.client, employee_id, leave).await
+    }
+
+    /// Update a leave record of an employee
+    #[instrument(skip(self, leave))]
+    pub async fn update_leave(
+        &self,
+        employee_id: Uuid,
+        leave_id: Uuid,
+        leave: &uk::leave::PostLeave,
+    ) -> Result<uk::leave::EmployeeLeave> {
+        uk::leave::EmployeeLeave::update(self.client, employee_id, leave_id, leave).await
+    }
+
+    /// Delete a leave record of an employee
+    #[instrument(skip(self))]
+    pub async fn delete_leave(&self, employee_id: Uuid, leave_id: Uuid) -> Result<()> {
+        uk::leave::EmployeeLeave::delete(self.client, employee_id, leave_id).await
+    }
+
+    /// Retrieve an employee's leave balances
+    #[instrument(skip(self))]
+    pub async fn leave_balances(&self, employee_id: Uuid) -> Result<Vec<uk::leave::LeaveBalance>> {
+        uk::leave::LeaveBalance::list(self.client, employee_id).await
+    }
+
+    /// Retrieve a summary of an employee's statutory leave
+    #[instrument(skip(self))]
+    pub async fn statutory_leave(
+        &self,
+        employee_id: Uuid,
+        active_only: bool,
+    ) -> Result<Vec<uk::statutory_leave::StatutoryLeaveSummary>> {
+        uk::statutory_leave::StatutoryLeaveSummary::list(self.client, employee_id, active_only)
+            .await
+    }
+
+    /// Retrieve a statutory sick leave record by ID
+    #[instrument(skip(self))]
+    pub async fn get_statutory_sick_leave(
+        &self,
+        statutory_sick_leave_id: Uuid,
+    ) -> Result<uk::statutory_leave::StatutorySickLeave> {
+        uk::statutory_leave::StatutorySickLeave::get(self.client, statutory_sick_leave_id).await
+    }
+
+    /// Record statutory sick leave for an employee
+    #[instrument(skip(self, sick_leave))]
+    pub async fn create_statutory_sick_leave(
+        &self,
+        sick_leave: &uk::statutory_leave::PostStatutorySickLeave,
+    ) -> Result<uk::statutory_leave::StatutorySickLeave> {
+        uk::statutory_leave::StatutorySickLeave::create(self.client, sick_leave).await
+    }
+
+    /// Retrieve an employee's pay template
+    #[instrument(skip(self))]
+    pub async fn pay_template(
+        &self,
+        employee_id: Uuid,
+    ) -> Result<uk::pay_template::EmployeePayTemplate> {
+        v2::pay_template::get(self.client, uk::REGION, employee_id).await
+    }
+
+    /// Add an earnings line to an employee's pay template
+    #[instrument(skip(self, earnings))]
+    pub async fn create_earnings_template(
+        &self,
+        employee_id: Uuid,
+        earnings: &uk::pay_template::EarningsTemplate,
+    ) -> Result<uk::pay_template::EarningsTemplate> {
+        v2::pay_template::create_earnings(self.client, uk::REGION, employee_id, earnings).await
+    }
+
+    /// Update an earnings line of an employee's pay template
+    #[instrument(skip(self, earnings))]
+    pub async fn update_earnings_template(
+        &self,
+        employee_id: Uuid,
+        pay_template_earning_id: Uuid,
+        earnings: &uk::pay_template::EarningsTemplate,
+    ) -> Result<uk::pay_template::EarningsTemplate> {
+        v2::pay_template::update_earnings(
+            self.client,
+            uk::REGION,
+            employee_id,
+            pay_template_earning_id,
+            earnings,
+        )
+        .await
+    }
+
+    /// Remove an earnings line from an employee's pay template
+    #[instrument(skip(self))]
+    pub async fn delete_earnings_template(
+        &self,
+        employee_id: Uuid,
+        pay_template_earning_id: Uuid,
+    ) -> Result<()> {
+        v2::pay_template::delete_earnings(
+            self.client,
+            uk::REGION,
+            employee_id,
+            pay_template_earning_id,
+        )
+        .await
     }
 }
 
-/// API handler for Quotes endpoints
+/// API handler for UK payroll pay item endpoints
+#[cfg(feature = "payroll-uk")]
 #[derive(Debug)]
-pub struct QuotesApi<'a> {
+pub struct PayrollUkPayItemsApi<'a> {
     client: &'a Client,
 }
 
-impl QuotesApi<'_> {
-    /// Retrieve a list of quotes with filters
-    #[instrument(skip(self, parameters))]
-    pub async fn list(&self, parameters: quote::ListParameters) -> Result<Vec<Quote>> {
-        quote::list(self.client, parameters).await
+#[cfg(feature = "payroll-uk")]
+impl PayrollUkPayItemsApi<'_> {
+    /// Retrieve all earnings rates
+    #[instrument(skip(self))]
+    pub async fn earnings_rates(&self) -> Result<Vec<uk::pay_items::EarningsRate>> {
+        uk::pay_items::EarningsRate::list(self.client).await
     }
 
-    /// Retrieve a list of all quotes without filtering
+    /// Retrieve an earnings rate by ID
     #[instrument(skip(self))]
-    pub async fn list_all(&self) -> Result<Vec<Quote>> {
-        quote::list_all(self.client).await
+    pub async fn earnings_rate(
+        &self,
+        earnings_rate_id: Uuid,
+    ) -> Result<uk::pay_items::EarningsRate> {
+        uk::pay_items::EarningsRate::get(self.client, earnings_rate_id).await
     }
 
-    /// Retrieve a single quote by ID
-    #[instrument(skip(self))]
-    pub async fn get(&self, quote_id: Uuid) -> Result<Quote> {
-        quote::get(self.client, quote_id).await
+    /// Create an earnings rate
+    #[instrument(skip(self, earnings_rate))]
+    pub async fn create_earnings_rate(
+        &self,
+        earnings_rate: &uk::pay_items::EarningsRate,
+    ) -> Result<uk::pay_items::EarningsRate> {
+        uk::pay_items::EarningsRate::create(self.client, earnings_rate).await
     }
 
-    /// Create a new quote
-    #[instrument(skip(self, quote))]
-    pub async fn create(&self, quote: &quote::QuoteBuilder) -> Result<Quote> {
-        quote::create(self.client, quote).await
+    /// Retrieve all deductions
+    #[instrument(skip(self))]
+    pub async fn deductions(&self) -> Result<Vec<uk::pay_items::Deduction>> {
+        uk::pay_items::Deduction::list(self.client).await
     }
 
-    /// Update or create a quote
-    #[instrument(skip(self, quote))]
-    pub async fn update_or_create(&self, quote: &quote::QuoteBuilder) -> Result<Quote> {
-        quote::update_or_create(self.client, quote).await
+    /// Retrieve a deduction by ID
+    #[instrument(skip(self))]
+    pub async fn deduction(&self, deduction_id: Uuid) -> Result<uk::pay_items::Deduction> {
+        uk::pay_items::Deduction::get(self.client, deduction_id).await
     }
 
-    /// Update a specific quote
-    #[instrument(skip(self, quote))]
-    pub async fn update(&self, quote_id: Uuid, quote: &quote::QuoteBuilder) -> Result<Quote> {
-        quote::update(self.client, quote_id, quote).await
+    /// Create a deduction
+    #[instrument(skip(self, deduction))]
+    pub async fn create_deduction(
+        &self,
+        deduction: &uk::pay_items::Deduction,
+    ) -> Result<uk::pay_items::Deduction> {
+        uk::pay_items::Deduction::create(self.client, deduction).await
     }
 
-    /// Get the history records for a quote
+    /// Retrieve all benefits
     #[instrument(skip(self))]
-    pub async fn get_history(&self, quote_id: Uuid) -> Result<Vec<quote::HistoryRecord>> {
-        quote::get_history(self.client, quote_id).await
+    pub async fn benefits(&self) -> Result<Vec<uk::pay_items::Benefit>> {
+        uk::pay_items::Benefit::list(self.client).await
     }
 
-    /// Create a history record for a quote
+    /// Retrieve a benefit by ID
     #[instrument(skip(self))]
-    pub async fn create_history(
+    pub async fn benefit(&self, benefit_id: Uuid) -> Result<uk::pay_items::Benefit> {
+        uk::pay_items::Benefit::get(self.client, benefit_id).await
+    }
+
+    /// Create a benefit
+    #[instrument(skip(self, benefit))]
+    pub async fn create_benefit(
         &self,
-        quote_id: Uuid,
-        details: &str,
-    ) -> Result<Vec<quote::HistoryRecord>> {
-        quote::create_history(self.client, quote_id, details).await
+        benefit: &uk::pay_items::Benefit,
+    ) -> Result<uk::pay_items::Benefit> {
+        uk::pay_items::Benefit::create(self.client, benefit).await
     }
 
-    /// Get a quote as PDF
+    /// Retrieve all leave types
     #[instrument(skip(self))]
-    pub async fn get_pdf(&self, quote_id: Uuid) -> Result<Vec<u8>> {
-        quote::get_pdf(self.client, quote_id).await
+    pub async fn leave_types(&self) -> Result<Vec<uk::pay_items::LeaveType>> {
+        uk::pay_items::LeaveType::list(self.client).await
     }
 
-    /// List all attachments for a quote
+    /// Retrieve a leave type by ID
     #[instrument(skip(self))]
-    pub async fn list_attachments(&self, quote_id: Uuid) -> Result<Vec<quote::Attachment>> {
-        quote::list_attachments(self.client, quote_id).await
+    pub async fn leave_type(&self, leave_type_id: Uuid) -> Result<uk::pay_items::LeaveType> {
+        uk::pay_items::LeaveType::get(self.client, leave_type_id).await
     }
 
-    /// Get a specific attachment by ID
-    #[instrument(skip(self))]
-    pub async fn get_attachment(&self, quote_id: Uuid, attachment_id: Uuid) -> Result<Vec<u8>> {
-        quote::get_attachment(self.client, quote_id, attachment_id).await
+    /// Create a leave type
+    #[instrument(skip(self, leave_type))]
+    pub async fn create_leave_type(
+        &self,
+        leave_type: &uk::pay_items::LeaveType,
+    ) -> Result<uk::pay_items::LeaveType> {
+        uk::pay_items::LeaveType::create(self.client, leave_type).await
     }
 
-    /// Get an attachment by filename
+    /// Retrieve all reimbursements
     #[instrument(skip(self))]
-    pub async fn get_attachment_by_filename(
-        &self,
-        quote_id: Uuid,
-        filename: &str,
-    ) -> Result<Vec<u8>> {
-        quote::get_attachment_by_filename(self.client, quote_id, filename).await
+    pub async fn reimbursements(&self) -> Result<Vec<uk::pay_items::Reimbursement>> {
+        uk::pay_items::Reimbursement::list(self.client).await
     }
 
-    /// Upload an attachment to a quote
-    #[instrument(skip(self, attachment_content))]
-    pub async fn upload_attachment(
-        &mut self,
-        quote_id: Uuid,
-        filename: &str,
-        attachment_content: &[u8],
-    ) -> Result<quote::Attachment> {
-        quote::upload_attachment(self.client, quote_id, filename, attachment_content).await
+    /// Retrieve a reimbursement by ID
+    #[instrument(skip(self))]
+    pub async fn reimbursement(
+        &self,
+        reimbursement_id: Uuid,
+    ) -> Result<uk::pay_items::Reimbursement> {
+        uk::pay_items::Reimbursement::get(self.client, reimbursement_id).await
     }
 
-    /// Update an existing attachment
-    #[instrument(skip(self, attachment_content))]
-    pub async fn update_attachment(
-        &mut self,
-        quote_id: Uuid,
-        filename: &str,
-        attachment_content: &[u8],
-    ) -> Result<quote::Attachment> {
-        quote::update_attachment(self.client, quote_id, filename, attachment_content).await
+    /// Create a reimbursement
+    #[instrument(skip(self, reimbursement))]
+    pub async fn create_reimbursement(
+        &self,
+        reimbursement: &uk::pay_items::Reimbursement,
+    ) -> Result<uk::pay_items::Reimbursement> {
+        uk::pay_items::Reimbursement::create(self.client, reimbursement).await
     }
 }
 
-/// API handler for Timesheets endpoints
+/// API handler for UK payroll Timesheets endpoints
+#[cfg(feature = "payroll-uk")]
 #[derive(Debug)]
-pub struct TimesheetsApi<'a> {
+pub struct PayrollUkTimesheetsApi<'a> {
     client: &'a Client,
 }
 
-impl TimesheetsApi<'_> {
-    /// Retrieve a list of timesheets with optional filtering
-    ///
-    /// # Parameters
-    ///
-    /// * `parameters` - Optional filter parameters
-    /// * `modified_after` - Optional ISO8601 timestamp (format: yyyy-mm-ddThh:mm:ss) to filter by modification date
-    #[instrument(skip(self, parameters, modified_after))]
+#[cfg(feature = "payroll-uk")]
+impl PayrollUkTimesheetsApi<'_> {
+    /// Retrieve timesheets matching the parameters
+    #[instrument(skip(self))]
     pub async fn list(
         &self,
-        parameters: Option<timesheet::ListParameters>,
-        modified_after: Option<String>,
-    ) -> Result<Vec<Timesheet>> {
-        Timesheet::list(self.client, parameters.as_ref(), modified_after).await
+        parameters: &uk::timesheet::ListParameters,
+    ) -> Result<Vec<uk::timesheet::Timesheet>> {
+        v2::timesheet::list(self.client, uk::REGION, parameters).await
     }
 
-    /// List all timesheets without any filtering
+    /// Retrieve a timesheet by ID
     #[instrument(skip(self))]
-    pub async fn list_all(&self) -> Result<Vec<Timesheet>> {
-        self.list(None::<timesheet::ListParameters>, None).await
+    pub async fn get(&self, timesheet_id: Uuid) -> Result<uk::timesheet::Timesheet> {
+        v2::timesheet::get(self.client, uk::REGION, timesheet_id).await
     }
 
-    /// Retrieve a single timesheet by ID
+    /// Create a timesheet
+    #[instrument(skip(self, timesheet))]
+    pub async fn create(
+        &self,
+        timesheet: &uk::timesheet::PostTimesheet,
+    ) -> Result<uk::timesheet::Timesheet> {
+        v2::timesheet::create(self.client, uk::REGION, timesheet).await
+    }
+
+    /// Delete a draft timesheet
     #[instrument(skip(self))]
-    pub async fn get(&self, timesheet_id: Uuid) -> Result<Timesheet> {
-        Timesheet::get(self.client, timesheet_id).await
+    pub async fn delete(&self, timesheet_id: Uuid) -> Result<()> {
+        v2::timesheet::delete(self.client, uk::REGION, timesheet_id).await
     }
 
-    /// Create a new timesheet
-    #[instrument(skip(self, timesheet))]
-    pub async fn create(&self, timesheet: &PostTimesheet) -> Result<Timesheet> {
-        Timesheet::post(self.client, timesheet).await
+    /// Approve a draft timesheet
+    #[instrument(skip(self))]
+    pub async fn approve(&self, timesheet_id: Uuid) -> Result<uk::timesheet::Timesheet> {
+        v2::timesheet::approve(self.client, uk::REGION, timesheet_id).await
     }
 
-    /// Update a timesheet
-    #[instrument(skip(self, timesheet))]
-    pub async fn update(&self, timesheet: &Timesheet) -> Result<Timesheet> {
-        Timesheet::update(self.client, timesheet).await
+    /// Revert an approved timesheet to draft
+    #[instrument(skip(self))]
+    pub async fn revert_to_draft(&self, timesheet_id: Uuid) -> Result<uk::timesheet::Timesheet> {
+        v2::timesheet::revert_to_draft(self.client, uk::REGION, timesheet_id).await
+    }
+
+    /// Add a line to a draft timesheet
+    #[instrument(skip(self, line))]
+    pub async fn create_line(
+        &self,
+        timesheet_id: Uuid,
+        line: &uk::timesheet::TimesheetLine,
+    ) -> Result<uk::timesheet::TimesheetLine> {
+        v2::timesheet::create_line(self.client, uk::REGION, timesheet_id, line).await
+    }
+
+    /// Update a line of a draft timesheet
+    #[instrument(skip(self, line))]
+    pub async fn update_line(
+        &self,
+        timesheet_id: Uuid,
+        timesheet_line_id: Uuid,
+        line: &uk::timesheet::TimesheetLine,
+    ) -> Result<uk::timesheet::TimesheetLine> {
+        v2::timesheet::update_line(
+            self.client,
+            uk::REGION,
+            timesheet_id,
+            timesheet_line_id,
+            line,
+        )
+        .await
+    }
+
+    /// Delete a line of a draft timesheet
+    #[instrument(skip(self))]
+    pub async fn delete_line(&self, timesheet_id: Uuid, timesheet_line_id: Uuid) -> Result<()> {
+        v2::timesheet::delete_line(self.client, uk::REGION, timesheet_id, timesheet_line_id).await
     }
 }
 
-/// API handler for Employees endpoints
+/// API handler for UK payroll Pay Runs and Payslips endpoints
+#[cfg(feature = "payroll-uk")]
 #[derive(Debug)]
-pub struct EmployeesApi<'a> {
+pub struct PayrollUkPayRunsApi<'a> {
     client: &'a Client,
 }
 
-impl EmployeesApi<'_> {
-    /// Retrieve a list of employees
+#[cfg(feature = "payroll-uk")]
+impl PayrollUkPayRunsApi<'_> {
+    /// Retrieve pay runs, optionally only those with the given status
     #[instrument(skip(self))]
-    pub async fn list(&self) -> Result<Vec<Employee>> {
-        let empty_vec: Vec<String> = Vec::new();
-        let response: employee::ListResponse =
-            self.client.get(employee::ENDPOINT, &empty_vec).await?;
-        Ok(response.employees)
+    pub async fn list(
+        &self,
+        status: Option<uk::pay_run::PayRunStatus>,
+    ) -> Result<Vec<uk::pay_run::PayRun>> {
+        v2::pay_run::list(self.client, uk::REGION, status).await
+    }
+
+    /// Retrieve a pay run by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, pay_run_id: Uuid) -> Result<uk::pay_run::PayRun> {
+        v2::pay_run::get(self.client, uk::REGION, pay_run_id).await
+    }
+
+    /// Update a draft pay run
+    #[instrument(skip(self, pay_run))]
+    pub async fn update(
+        &self,
+        pay_run_id: Uuid,
+        pay_run: &uk::pay_run::PostPayRun,
+    ) -> Result<uk::pay_run::PayRun> {
+        v2::pay_run::update(self.client, uk::REGION, pay_run_id, pay_run).await
+    }
+
+    /// Retrieve the payslips of a pay run
+    #[instrument(skip(self))]
+    pub async fn payslips(&self, pay_run_id: Uuid) -> Result<Vec<uk::payslip::Payslip>> {
+        v2::payslip::list(self.client, uk::REGION, pay_run_id).await
+    }
+
+    /// Retrieve a payslip by ID
+    #[instrument(skip(self))]
+    pub async fn payslip(&self, payslip_id: Uuid) -> Result<uk::payslip::Payslip> {
+        v2::payslip::get(self.client, uk::REGION, payslip_id).await
     }
 }
 
-/// API handler for Earnings Rates endpoints
+/// API handler for NZ payroll endpoints
+#[cfg(feature = "payroll-nz")]
 #[derive(Debug)]
-pub struct EarningsRatesApi<'a> {
+pub struct PayrollNzApi<'a> {
     client: &'a Client,
 }
 
-impl EarningsRatesApi<'_> {
-    /// Retrieve a list of earnings rates
-    #[instrument(skip(self))]
-    pub async fn list(&self) -> Result<Vec<EarningsRate>> {
-        #[derive(Deserialize)]
-        #[serde(rename_all = "PascalCase")]
-        struct PayItems {
-            earnings_rates: Vec<EarningsRate>,
+#[cfg(feature = "payroll-nz")]
+impl<'a> PayrollNzApi<'a> {
+    /// Access NZ employees and their employment, tax, opening balances, leave and pay templates
+    #[must_use]
+    pub fn employees(&self) -> PayrollNzEmployeesApi<'a> {
+        PayrollNzEmployeesApi {
+            client: self.client,
         }
+    }
 
-        #[derive(Deserialize)]
-        #[serde(rename_all = "PascalCase")]
-        struct ListResponse {
-            pay_items: PayItems,
+    /// Access NZ earnings rates, deductions, statutory deductions, superannuation, leave types
+    /// and reimbursements
+    #[must_use]
+    pub fn pay_items(&self) -> PayrollNzPayItemsApi<'a> {
+        PayrollNzPayItemsApi {
+            client: self.client,
         }
+    }
 
-        let empty_vec: Vec<String> = Vec::new();
-        let response: ListResponse = self
-            .client
-            .get(earnings_rates::ENDPOINT, &empty_vec)
-            .await?;
-        Ok(response.pay_items.earnings_rates)
+    /// Access NZ timesheets
+    #[must_use]
+    pub fn timesheets(&self) -> PayrollNzTimesheetsApi<'a> {
+        PayrollNzTimesheetsApi {
+            client: self.client,
+        }
+    }
+
+    /// Access NZ pay runs and payslips
+    #[must_use]
+    pub fn pay_runs(&self) -> PayrollNzPayRunsApi<'a> {
+        PayrollNzPayRunsApi {
+            client: self.client,
+        }
     }
 }
 
-/// API client for interacting with Xero Payroll Calendars
-///
-/// This API provides methods for listing, retrieving, and creating pay calendars.
-pub struct PayCalendarsApi<'a> {
+/// API handler for NZ payroll Employees endpoints
+#[cfg(feature = "payroll-nz")]
+#[derive(Debug)]
+pub struct PayrollNzEmployeesApi<'a> {
     client: &'a Client,
 }
 
-impl PayCalendarsApi<'_> {
-    /// Retrieve a list of pay calendars
-    ///
-    /// Returns all pay calendars defined in the Xero organization.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the API request fails.
+#[cfg(feature = "payroll-nz")]
+impl PayrollNzEmployeesApi<'_> {
+    /// Retrieve all employees
     #[instrument(skip(self))]
-    pub async fn list(&self) -> Result<Vec<PayCalendar>> {
-        let url = "https://api.xero.com/payroll.xro/1.0/PayrollCalendars";
-        let response: pay_calendar::PayCalendarResponse = self.client.get(url, &()).await?;
-        Ok(response.payroll_calendars)
+    pub async fn list(&self) -> Result<Vec<nz::employee::Employee>> {
+        nz::employee::Employee::list(self.client).await
     }
 
-    /// Get a pay calendar by ID
-    ///
-    /// Retrieves a specific pay calendar using its unique identifier.
-    ///
-    /// # Arguments
-    ///
-    /// * `pay_calendar_id` - The UUID of the pay calendar to retrieve
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the pay calendar is not found or if the API request fails.
+    /// Retrieve an employee by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, employee_id: Uuid) -> Result<nz::employee::Employee> {
+        nz::employee::Employee::get(self.client, employee_id).await
+    }
+
+    /// Create an employee
+    #[instrument(skip(self, employee))]
+    pub async fn create(
+        &self,
+        employee: &nz::employee::PostEmployee,
+    ) -> Result<nz::employee::Employee> {
+        nz::employee::Employee::create(self.client, employee).await
+    }
+
+    /// Update an employee
+    #[instrument(skip(self, employee))]
+    pub async fn update(
+        &self,
+        employee_id: Uuid,
+        employee: &nz::employee::PostEmployee,
+    ) -> Result<nz::employee::Employee> {
+        nz::employee::Employee::update(self.client, employee_id, employee).await
+    }
+
+    /// Create the employment details that place an employee on a payroll calendar
+    #[instrument(skip(self, employment))]
+    pub async fn create_employment(
+        &self,
+        employee_id: Uuid,
+        employment: &nz::employee::Employment,
+    ) -> Result<nz::employee::Employment> {
+        nz::employee::Employment::create(self.client, employee_id, employment).await
+    }
+
+    /// Retrieve an employee's tax and KiwiSaver details
+    #[instrument(skip(self))]
+    pub async fn tax(&self, employee_id: Uuid) -> Result<nz::employee::EmployeeTax> {
+        nz::employee::EmployeeTax::get(self.client, employee_id).await
+    }
+
+    /// Update an employee's tax and KiwiSaver details
+    #[instrument(skip(self, tax))]
+    pub async fn update_tax(
+        &self,
+        employee_id: Uuid,
+        tax: &nz::employee::EmployeeTax,
+    ) -> Result<nz::employee::EmployeeTax> {
+        nz::employee::EmployeeTax::update(self.client, employee_id, tax).await
+    }
+
+    /// Retrieve an employee's opening balances
+    #[instrument(skip(self))]
+    pub async fn opening_balances(
+        &self,
+        employee_id: Uuid,
+    ) -> Result<Vec<nz::employee::OpeningBalance>> {
+        nz::employee::OpeningBalance::list(self.client, employee_id).await
+    }
+
+    /// Record an employee's opening balances
+    #[instrument(skip(self, opening_balances))]
+    pub async fn create_opening_balances(
+        &self,
+        employee_id: Uuid,
+        opening_balances: &[nz::employee::OpeningBalance],
+    ) -> Result<Vec<nz::employee::OpeningBalance>> {
+        nz::employee::OpeningBalance::create(self.client, employee_id, opening_balances).await
+    }
+
+    /// Set up an employee's holiday pay and sick leave entitlements
+    #[instrument(skip(self, setup))]
+    pub async fn set_up_leave(
+        &self,
+        employee_id: Uuid,
+        setup: &nz::leave::LeaveSetup,
+    ) -> Result<nz::leave::LeaveSetup> {
+        nz::leave::LeaveSetup::create(self.client, employee_id, setup).await
+    }
+
+    /// Retrieve an employee's leave
     #[instrument(skip(self))]
-    pub async fn get(&self, pay_calendar_id: Uuid) -> Result<PayCalendar> {
-        let url =
-            format!("https://api.xero.com/payroll.xro/1.0/PayrollCalendars/{pay_calendar_id}");
-        let response: pay_calendar::PayCalendarResponse = self.client.get(&url, &()).await?;
+    pub async fn leave(&self, employee_id: Uuid) -> Result<Vec<nz::leave::EmployeeLeave>> {
+        nz::leave::EmployeeLeave::list(self.client, employee_id).await
+    }
 
-        if response.payroll_calendars.is_empty() {
-            return Err(Error::NotFound {
-                entity: "PayCalendar".to_string(),
-                url,
-                status_code: StatusCode::NOT_FOUND,
-                response_body: Some(format!("Pay Calendar with ID {pay_calendar_id} not found")),
-                span_trace: SpanTrace::capture(),
-            });
-        }
+    /// Record leave for an employee
+    #[instrument(skip(self, leave))]
+    pub async fn create_leave(
+        &self,
+        employee_id: Uuid,
+        leave: &nz::leave::PostLeave,
+    ) -> Result<nz::leave::EmployeeLeave> {
+        nz::leave::EmployeeLeave::create(self.client, employee_id, leave).await
+    }
 
-        Ok(response.payroll_calendars.into_iter().next().unwrap())
+    /// Update a leave record of an employee
+    #[instrument(skip(self, leave))]
+    pub async fn update_leave(
+        &self,
+        employee_id: Uuid,
+        leave_id: Uuid,
+        leave: &nz::leave::PostLeave,
+    ) -> Result<nz::leave::EmployeeLeave> {
+        nz::leave::EmployeeLeave::update(self.client, employee_id, leave_id, leave).await
     }
 
-    /// Create a new pay calendar
-    ///
-    /// Creates a new pay calendar with the specified details.
-    ///
-    /// # Arguments
-    ///
-    /// * `pay_calendar` - The pay calendar details to create
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the API request fails or if the response doesn't contain the created pay calendar.
-    #[instrument(skip(self, pay_calendar))]
-    pub async fn create(
-        &mut self,
-        pay_calendar: &pay_calendar::CreatePayCalendar,
-    ) -> Result<PayCalendar> {
-        let url = "https://api.xero.com/payroll.xro/1.0/PayrollCalendars";
+    /// Delete a leave record of an employee
+    #[instrument(skip(self))]
+    pub async fn delete_leave(&self, employee_id: Uuid, leave_id: Uuid) -> Result<()> {
+        nz::leave::EmployeeLeave::delete(self.client, employee_id, leave_id).await
+    }
 
-        // Create a vector with a single pay calendar
-        let request = vec![pay_calendar.clone()];
+    /// Retrieve an employee's leave balances
+    #[instrument(skip(self))]
+    pub async fn leave_balances(&self, employee_id: Uuid) -> Result<Vec<nz::leave::LeaveBalance>> {
+        nz::leave::LeaveBalance::list(self.client, employee_id).await
+    }
 
-        let response: pay_calendar::PayCalendarResponse = self.client.post(url, &request).await?;
+    /// Retrieve an employee's pay template
+    #[instrument(skip(self))]
+    pub async fn pay_template(
+        &self,
+        employee_id: Uuid,
+    ) -> Result<nz::pay_template::EmployeePayTemplate> {
+        v2::pay_template::get(self.client, nz::REGION, employee_id).await
+    }
 
-        if response.payroll_calendars.is_empty() {
-            return Err(Error::NotFound {
-                entity: "PayCalendar".to_string(),
-                url: url.to_string(),
-                status_code: StatusCode::OK,
-                response_body: Some("No pay calendar was returned after creation".to_string()),
-                span_trace: SpanTrace::capture(),
-            });
-        }
+    /// Add an earnings line to an employee's pay template
+    #[instrument(skip(self, earnings))]
+    pub async fn create_earnings_template(
+        &self,
+        employee_id: Uuid,
+        earnings: &nz::pay_template::EarningsTemplate,
+    ) -> Result<nz::pay_template::EarningsTemplate> {
+        v2::pay_template::create_earnings(self.client, nz::REGION, employee_id, earnings).await
+    }
 
-        Ok(response.payroll_calendars.into_iter().next().unwrap())
+    /// Update an earnings line of an employee's pay template
+    #[instrument(skip(self, earnings))]
+    pub async fn update_earnings_template(
+        &self,
+        employee_id: Uuid,
+        pay_template_earning_id: Uuid,
+        earnings: &nz::pay_template::EarningsTemplate,
+    ) -> Result<nz::pay_template::EarningsTemplate> {
+        v2::pay_template::update_earnings(
+            self.client,
+            nz::REGION,
+            employee_id,
+            pay_template_earning_id,
+            earnings,
+        )
+        .await
+    }
+
+    /// Remove an earnings line from an employee's pay template
+    #[instrument(skip(self))]
+    pub async fn delete_earnings_template(
+        &self,
+        employee_id: Uuid,
+        pay_template_earning_id: Uuid,
+    ) -> Result<()> {
+        v2::pay_template::delete_earnings(
+            self.client,
+            nz::REGION,
+            employee_id,
+            pay_template_earning_id,
+        )
+        .await
     }
 }
 
-/// API handler for Items endpoints
+/// API handler for NZ payroll pay item endpoints
+#[cfg(feature = "payroll-nz")]
 #[derive(Debug)]
-pub struct ItemsApi<'a> {
+pub struct PayrollNzPayItemsApi<'a> {
     client: &'a Client,
 }
 
-impl ItemsApi<'_> {
-    /// Retrieve a list of items with optional filtering
-    #[instrument(skip(self, parameters))]
-    pub async fn list(&self, parameters: item::ListParameters) -> Result<Vec<Item>> {
-        item::list(self.client, parameters).await
+#[cfg(feature = "payroll-nz")]
+impl PayrollNzPayItemsApi<'_> {
+    /// Retrieve all earnings rates
+    #[instrument(skip(self))]
+    pub async fn earnings_rates(&self) -> Result<Vec<nz::pay_items::EarningsRate>> {
+        nz::pay_items::EarningsRate::list(self.client).await
     }
 
-    /// List all items without any filtering
+    /// Retrieve an earnings rate by ID
     #[instrument(skip(self))]
-    pub async fn list_all(&self) -> Result<Vec<Item>> {
-        item::list_all(self.client).await
+    pub async fn earnings_rate(
+        &self,
+        earnings_rate_id: Uuid,
+    ) -> Result<nz::pay_items::EarningsRate> {
+        nz::pay_items::EarningsRate::get(self.client, earnings_rate_id).await
     }
 
-    /// Retrieve a single item by ID
+    /// Create an earnings rate
+    #[instrument(skip(self, earnings_rate))]
+    pub async fn create_earnings_rate(
+        &self,
+        earnings_rate: &nz::pay_items::EarningsRate,
+    ) -> Result<nz::pay_items::EarningsRate> {
+        nz::pay_items::EarningsRate::create(self.client, earnings_rate).await
+    }
+
+    /// Retrieve all deductions
     #[instrument(skip(self))]
-    pub async fn get(&self, item_id: Uuid) -> Result<Item> {
-        item::get(self.client, item_id).await
+    pub async fn deductions(&self) -> Result<Vec<nz::pay_items::Deduction>> {
+        nz::pay_items::Deduction::list(self.client).await
     }
 
-    /// Retrieve a single item by code
+    /// Retrieve a deduction by ID
     #[instrument(skip(self))]
-    pub async fn get_by_code(&self, code: &str) -> Result<Item> {
-        item::get_by_code(self.client, code).await
+    pub async fn deduction(&self, deduction_id: Uuid) -> Result<nz::pay_items::Deduction> {
+        nz::pay_items::Deduction::get(self.client, deduction_id).await
     }
 
-    /// Create a single item
-    #[instrument(skip(self, item))]
-    pub async fn create(&self, item: &item::Builder) -> Result<Item> {
-        item::create_single(self.client, item).await
+    /// Create a deduction
+    #[instrument(skip(self, deduction))]
+    pub async fn create_deduction(
+        &self,
+        deduction: &nz::pay_items::Deduction,
+    ) -> Result<nz::pay_items::Deduction> {
+        nz::pay_items::Deduction::create(self.client, deduction).await
     }
 
-    /// Create multiple items
-    #[instrument(skip(self, items))]
-    pub async fn create_multiple(&self, items: &[item::Builder]) -> Result<Vec<Item>> {
-        item::create(self.client, items).await
+    /// Retrieve all statutory deductions
+    #[instrument(skip(self))]
+    pub async fn statutory_deductions(&self) -> Result<Vec<nz::pay_items::StatutoryDeduction>> {
+        nz::pay_items::StatutoryDeduction::list(self.client).await
     }
 
-    /// Update or create a single item
-    #[instrument(skip(self, item))]
-    pub async fn update_or_create(&self, item: &item::Builder) -> Result<Item> {
-        let items = item::update_or_create(self.client, &[item.clone()]).await?;
-        items.into_iter().next().ok_or(Error::NotFound {
-            entity: "Item".to_string(),
-            url: item::ENDPOINT.to_string(),
-            status_code: reqwest::StatusCode::NOT_FOUND,
-            response_body: Some("No item returned in response".to_string()),
-            span_trace: SpanTrace::capture(),
-        })
+    /// Retrieve a statutory deduction by ID
+    #[instrument(skip(self))]
+    pub async fn statutory_deduction(
+        &self,
+        statutory_deduction_id: Uuid,
+    ) -> Result<nz::pay_items::StatutoryDeduction> {
+        nz::pay_items::StatutoryDeduction::get(self.client, statutory_deduction_id).await
     }
 
-    /// Update or create multiple items
-    #[instrument(skip(self, items))]
-    pub async fn update_or_create_multiple(
-        &mut self,
-        items: &[item::Builder],
-    ) -> Result<Vec<Item>> {
-        item::update_or_create(self.client, items).await
+    /// Retrieve all superannuation schemes
+    #[instrument(skip(self))]
+    pub async fn superannuations(&self) -> Result<Vec<nz::pay_items::Superannuation>> {
+        nz::pay_items::Superannuation::list(self.client).await
     }
 
-    /// Update a specific item
-    #[instrument(skip(self, item))]
-    pub async fn update(&self, item_id: Uuid, item: &item::Builder) -> Result<Item> {
-        item::update(self.client, item_id, item).await
+    /// Retrieve a superannuation scheme by ID
+    #[instrument(skip(self))]
+    pub async fn superannuation(
+        &self,
+        superannuation_id: Uuid,
+    ) -> Result<nz::pay_items::Superannuation> {
+        nz::pay_items::Superannuation::get(self.client, superannuation_id).await
     }
 
-    /// Delete a specific item
+    /// Create a superannuation scheme
+    #[instrument(skip(self, superannuation))]
+    pub async fn create_superannuation(
+        &self,
+        superannuation: &nz::pay_items::Superannuation,
+    ) -> Result<nz::pay_items::Superannuation> {
+        nz::pay_items::Superannuation::create(self.client, superannuation).await
+    }
+
+    /// Retrieve all leave types
     #[instrument(skip(self))]
-    pub async fn delete(&self, item_id: Uuid) -> Result<()> {
-        item::delete(self.client, item_id).await
+    pub async fn leave_types(&self) -> Result<Vec<nz::pay_items::LeaveType>> {
+        nz::pay_items::LeaveType::list(self.client).await
     }
 
-    /// Get the history for an item
+    /// Retrieve a leave type by ID
     #[instrument(skip(self))]
-    pub async fn get_history(&self, item_id: Uuid) -> Result<Vec<item::HistoryRecord>> {
-        item::get_history(self.client, item_id).await
+    pub async fn leave_type(&self, leave_type_id: Uuid) -> Result<nz::pay_items::LeaveType> {
+        nz::pay_items::LeaveType::get(self.client, leave_type_id).await
     }
 
-    /// Create a history record for an item
+    /// Create a leave type
+    #[instrument(skip(self, leave_type))]
+    pub async fn create_leave_type(
+        &self,
+        leave_type: &nz::pay_items::LeaveType,
+    ) -> Result<nz::pay_items::LeaveType> {
+        nz::pay_items::LeaveType::create(self.client, leave_type).await
+    }
+
+    /// Retrieve all reimbursements
     #[instrument(skip(self))]
-    pub async fn create_history(
+    pub async fn reimbursements(&self) -> Result<Vec<nz::pay_items::Reimbursement>> {
+        nz::pay_items::Reimbursement::list(self.client).await
+    }
+
+    /// Retrieve a reimbursement by ID
+    #[instrument(skip(self))]
+    pub async fn reimbursement(
         &self,
-        item_id: Uuid,
-        details: &str,
-    ) -> Result<Vec<item::HistoryRecord>> {
-        item::create_history(self.client, item_id, details).await
+        reimbursement_id: Uuid,
+    ) -> Result<nz::pay_items::Reimbursement> {
+        nz::pay_items::Reimbursement::get(self.client, reimbursement_id).await
+    }
+
+    /// Create a reimbursement
+    #[instrument(skip(self, reimbursement))]
+    pub async fn create_reimbursement(
+        &self,
+        reimbursement: &nz::pay_items::Reimbursement,
+    ) -> Result<nz::pay_items::Reimbursement> {
+        nz::pay_items::Reimbursement::create(self.client, reimbursement).await
     }
 }
 
-/// API handler for Leave Applications endpoints
+/// API handler for NZ payroll Timesheets endpoints
+#[cfg(feature = "payroll-nz")]
 #[derive(Debug)]
-pub struct LeaveApplicationsApi<'a> {
+pub struct PayrollNzTimesheetsApi<'a> {
     client: &'a Client,
 }
 
-impl LeaveApplicationsApi<'_> {
-    /// List approved leave applications (v1 endpoint)
-    ///
-    /// This endpoint only returns leave applications that have been approved.
-    /// Use `list_v2` to get all leave including pending and rejected.
-    ///
-    /// # Parameters
-    ///
-    /// * `parameters` - Optional filter parameters
-    /// * `modified_after` - Optional ISO8601 timestamp (format: yyyy-mm-ddThh:mm:ss) to filter by modification date
-    #[instrument(skip(self, parameters, modified_after))]
+#[cfg(feature = "payroll-nz")]
+impl PayrollNzTimesheetsApi<'_> {
+    /// Retrieve timesheets matching the parameters
+    #[instrument(skip(self))]
     pub async fn list(
         &self,
-        parameters: Option<leave_application::ListParameters>,
-        modified_after: Option<String>,
-    ) -> Result<Vec<LeaveApplication>> {
-        LeaveApplication::list(self.client, parameters.as_ref(), modified_after).await
+        parameters: &nz::timesheet::ListParameters,
+    ) -> Result<Vec<nz::timesheet::Timesheet>> {
+        v2::timesheet::list(self.client, nz::REGION, parameters).await
     }
 
-    /// List all leave applications (v2 endpoint)
-    ///
-    /// This endpoint returns leave with all statuses: SCHEDULED, PROCESSED,
-    /// REQUESTED (awaiting approval), and REJECTED.
-    ///
-    /// # Parameters
-    ///
-    /// * `parameters` - Optional filter parameters
-    /// * `modified_after` - Optional ISO8601 timestamp to filter by modification date
-    #[instrument(skip(self, parameters, modified_after))]
-    pub async fn list_v2(
+    /// Retrieve a timesheet by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, timesheet_id: Uuid) -> Result<nz::timesheet::Timesheet> {
+        v2::timesheet::get(self.client, nz::REGION, timesheet_id).await
+    }
+
+    /// Create a timesheet
+    #[instrument(skip(self, timesheet))]
+    pub async fn create(
         &self,
-        parameters: Option<leave_application::ListParameters>,
-        modified_after: Option<String>,
-    ) -> Result<Vec<LeaveApplication>> {
-        LeaveApplication::list_v2(self.client, parameters.as_ref(), modified_after).await
+        timesheet: &nz::timesheet::PostTimesheet,
+    ) -> Result<nz::timesheet::Timesheet> {
+        v2::timesheet::create(self.client, nz::REGION, timesheet).await
     }
 
-    /// List all approved leave without filtering
+    /// Delete a draft timesheet
     #[instrument(skip(self))]
-    pub async fn list_all(&self) -> Result<Vec<LeaveApplication>> {
-        self.list(None, None).await
+    pub async fn delete(&self, timesheet_id: Uuid) -> Result<()> {
+        v2::timesheet::delete(self.client, nz::REGION, timesheet_id).await
     }
 
-    /// Retrieve a single leave application by ID
+    /// Approve a draft timesheet
     #[instrument(skip(self))]
-    pub async fn get(&self, leave_application_id: Uuid) -> Result<LeaveApplication> {
-        LeaveApplication::get(self.client, leave_application_id).await
+    pub async fn approve(&self, timesheet_id: Uuid) -> Result<nz::timesheet::Timesheet> {
+        v2::timesheet::approve(self.client, nz::REGION, timesheet_id).await
     }
 
-    /// Create a new leave application
-    #[instrument(skip(self, leave_application))]
-    pub async fn create(
-        &self,
-        leave_application: &PostLeaveApplication,
-    ) -> Result<LeaveApplication> {
-        LeaveApplication::post(self.client, leave_application).await
+    /// Revert an approved timesheet to draft
+    #[instrument(skip(self))]
+    pub async fn revert_to_draft(&self, timesheet_id: Uuid) -> Result<nz::timesheet::Timesheet> {
+        v2::timesheet::revert_to_draft(self.client, nz::REGION, timesheet_id).await
     }
 
-    /// Update an existing leave application
-    #[instrument(skip(self, leave_application))]
-    pub async fn update(&self, leave_application: &LeaveApplication) -> Result<LeaveApplication> {
-        LeaveApplication::update(self.client, leave_application).await
+    /// Add a line to a draft timesheet
+    #[instrument(skip(self, line))]
+    pub async fn create_line(
+        &self,
+        timesheet_id: Uuid,
+        line: &nz::timesheet::TimesheetLine,
+    ) -> Result<nz::timesheet::TimesheetLine> {
+        v2::timesheet::create_line(self.client, nz::REGION, timesheet_id, line).await
     }
 
-    /// Approve a leave application that is in REQUESTED status
-    #[instrument(skip(self))]
-    pub async fn approve(&self, leave_application_id: Uuid) -> Result<LeaveApplication> {
-        LeaveApplication::approve(self.client, leave_application_id).await
+    /// Update a line of a draft timesheet
+    #[instrument(skip(self, line))]
+    pub async fn update_line(
+        &self,
+        timesheet_id: Uuid,
+        timesheet_line_id: Uuid,
+        line: &nz::timesheet::TimesheetLine,
+    ) -> Result<nz::timesheet::TimesheetLine> {
+        v2::timesheet::update_line(
+            self.client,
+            nz::REGION,
+            timesheet_id,
+            timesheet_line_id,
+            line,
+        )
+        .await
     }
 
-    /// Reject a leave application that is in REQUESTED status
+    /// Delete a line of a draft timesheet
     #[instrument(skip(self))]
-    pub async fn reject(&self, leave_application_id: Uuid) -> Result<LeaveApplication> {
-        LeaveApplication::reject(self.client, leave_application_id).await
+    pub async fn delete_line(&self, timesheet_id: Uuid, timesheet_line_id: Uuid) -> Result<()> {
+        v2::timesheet::delete_line(self.client, nz::REGION, timesheet_id, timesheet_line_id).await
     }
 }
 
-/// API handler for Leave Types endpoints
+/// API handler for NZ payroll Pay Runs and Payslips endpoints
+#[cfg(feature = "payroll-nz")]
 #[derive(Debug)]
-pub struct LeaveTypesApi<'a> {
+pub struct PayrollNzPayRunsApi<'a> {
     client: &'a Client,
 }
 
-impl LeaveTypesApi<'_> {
-    /// Retrieve a list of leave types
-    ///
-    /// Leave types are retrieved from the PayItems endpoint.
+#[cfg(feature = "payroll-nz")]
+impl PayrollNzPayRunsApi<'_> {
+    /// Retrieve pay runs, optionally only those with the given status
     #[instrument(skip(self))]
-    pub async fn list(&self) -> Result<Vec<LeaveType>> {
-        #[derive(Deserialize)]
-        #[serde(rename_all = "PascalCase")]
-        struct PayItems {
-            #[serde(default)]
-            leave_types: Vec<LeaveType>,
-        }
+    pub async fn list(
+        &self,
+        status: Option<nz::pay_run::PayRunStatus>,
+    ) -> Result<Vec<nz::pay_run::PayRun>> {
+        v2::pay_run::list(self.client, nz::REGION, status).await
+    }
 
-        #[derive(Deserialize)]
-        #[serde(rename_all = "PascalCase")]
-        struct ListResponse {
-            pay_items: PayItems,
-        }
+    /// Retrieve a pay run by ID
+    #[instrument(skip(self))]
+    pub async fn get(&self, pay_run_id: Uuid) -> Result<nz::pay_run::PayRun> {
+        v2::pay_run::get(self.client, nz::REGION, pay_run_id).await
+    }
 
-        let empty_vec: Vec<String> = Vec::new();
-        let response: ListResponse = self
-            .client
-            .get(earnings_rates::ENDPOINT, &empty_vec)
-            .await?;
-        Ok(response.pay_items.leave_types)
+    /// Create a pay run
+    #[instrument(skip(self, pay_run))]
+    pub async fn create(&self, pay_run: &nz::pay_run::PostPayRun) -> Result<nz::pay_run::PayRun> {
+        v2::pay_run::create(self.client, nz::REGION, pay_run).await
+    }
+
+    /// Update a draft pay run
+    #[instrument(skip(self, pay_run))]
+    pub async fn update(
+        &self,
+        pay_run_id: Uuid,
+        pay_run: &nz::pay_run::PostPayRun,
+    ) -> Result<nz::pay_run::PayRun> {
+        v2::pay_run::update(self.client, nz::REGION, pay_run_id, pay_run).await
+    }
+
+    /// Retrieve the payslips of a pay run
+    #[instrument(skip(self))]
+    pub async fn payslips(&self, pay_run_id: Uuid) -> Result<Vec<nz::payslip::Payslip>> {
+        v2::payslip::list(self.client, nz::REGION, pay_run_id).await
+    }
+
+    /// Retrieve a payslip by ID
+    #[instrument(skip(self))]
+    pub async fn payslip(&self, payslip_id: Uuid) -> Result<nz::payslip::Payslip> {
+        v2::payslip::get(self.client, nz::REGION, payslip_id).await
     }
 }
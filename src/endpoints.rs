@@ -17,6 +17,7 @@ pub enum XeroEndpoint {
     Account(Uuid),
     Contacts,
     Contact(Uuid),
+    Currencies,
     Invoices,
     Invoice(Uuid),
     Items,
@@ -52,6 +53,7 @@ impl XeroEndpoint {
                     .join(&format!("Contacts/{id}"))
                     .map_err(|_| Error::InvalidEndpoint);
             }
+            Self::Currencies => "Currencies",
             Self::Invoices => "Invoices",
             Self::Invoice(id) => {
                 return base
@@ -5,6 +5,7 @@ use uuid::Uuid;
 
 use crate::{
     Client,
+    currency::CurrencyCode,
     endpoints::XeroEndpoint,
     entities::{EntityEndpoint, MutationResponse, endpoint_utils},
     error::{Error, Result},
@@ -138,7 +139,7 @@ pub struct Account {
 
     /// Currency code for the account
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency_code: Option<String>,
+    pub currency_code: Option<CurrencyCode>,
 
     /// Reporting code
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -309,7 +310,7 @@ pub struct Builder {
 
     /// Currency code for the account
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency_code: Option<String>,
+    pub currency_code: Option<CurrencyCode>,
 
     /// Account ID (for updates)
     #[serde(rename = "AccountID", skip_serializing_if = "Option::is_none")]
@@ -383,8 +384,8 @@ impl Builder {
 
     /// Set the currency code
     #[must_use]
-    pub fn with_currency_code(mut self, currency: impl Into<String>) -> Self {
-        self.currency_code = Some(currency.into());
+    pub fn with_currency_code(mut self, currency: CurrencyCode) -> Self {
+        self.currency_code = Some(currency);
         self
     }
 
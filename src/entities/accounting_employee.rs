@@ -0,0 +1,278 @@
+//! Employees of the accounting API (`/api.xro/2.0/Employees`)
+//!
+//! These are the lightweight contacts used when paying employees through bank transactions,
+//! expense claims and receipts. They are unrelated to the employees of the payroll API, see
+//! [`crate::payroll::employee::Employee`].
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    Client,
+    endpoints::XeroEndpoint,
+    entities::{EntityEndpoint, MutationResponse, endpoint_utils},
+    error::{Error, Result},
+    utils::date_format::xero_datetime_format_option,
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/Employees/";
+
+/// Status of an accounting employee
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    Active,
+    Archived,
+    Deleted,
+}
+
+/// A link to an external resource for an employee, e.g. their profile in another system
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExternalLink {
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// An employee in the accounting API
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccountingEmployee {
+    #[serde(rename = "EmployeeID")]
+    pub employee_id: Uuid,
+    #[serde(default)]
+    pub status: Option<Status>,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub last_name: Option<String>,
+    #[serde(default)]
+    pub external_link: Option<ExternalLink>,
+    #[serde(
+        rename = "UpdatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+}
+
+impl AccountingEmployee {
+    /// The employee's full name
+    #[must_use]
+    pub fn full_name(&self) -> String {
+        [self.first_name.as_deref(), self.last_name.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ListResponse {
+    pub employees: Vec<AccountingEmployee>,
+}
+
+impl From<ListResponse> for Vec<AccountingEmployee> {
+    fn from(response: ListResponse) -> Self {
+        response.employees
+    }
+}
+
+/// Parameters for filtering accounting employee list results
+#[derive(Debug, Serialize, Default)]
+pub struct ListParameters {
+    /// Filter by any element
+    #[serde(rename = "where", skip_serializing_if = "Option::is_none")]
+    pub r#where: Option<String>,
+
+    /// Order by any element
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+
+    /// Only return employees modified since this timestamp (sent as the
+    /// `If-Modified-Since` header rather than a query parameter)
+    #[serde(skip)]
+    pub if_modified_since: Option<OffsetDateTime>,
+}
+
+impl ListParameters {
+    /// Create a new builder for `ListParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Set the where filter
+    #[must_use]
+    pub fn with_where(mut self, filter: impl Into<String>) -> Self {
+        self.r#where = Some(filter.into());
+        self
+    }
+
+    /// Set the order clause
+    #[must_use]
+    pub fn with_order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
+    /// Only return employees modified since the given timestamp
+    #[must_use]
+    pub fn with_if_modified_since(mut self, modified_since: OffsetDateTime) -> Self {
+        self.if_modified_since = Some(modified_since);
+        self
+    }
+}
+
+/// Builder for creating and updating accounting employees
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct Builder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_link: Option<ExternalLink>,
+}
+
+impl Builder {
+    /// Create a builder for a new employee
+    #[must_use]
+    pub fn new(first_name: impl Into<String>, last_name: impl Into<String>) -> Self {
+        Self {
+            first_name: Some(first_name.into()),
+            last_name: Some(last_name.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the employee's status
+    #[must_use]
+    pub fn with_status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Link the employee to an external resource
+    #[must_use]
+    pub fn with_external_link(
+        mut self,
+        url: impl Into<String>,
+        description: Option<String>,
+    ) -> Self {
+        self.external_link = Some(ExternalLink {
+            url: url.into(),
+            description,
+        });
+        self
+    }
+}
+
+/// Request wrapper for accounting employees
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct EmployeeWrapper<'a> {
+    pub employees: Vec<&'a Builder>,
+}
+
+impl EntityEndpoint<AccountingEmployee, ListParameters> for AccountingEmployee {
+    fn endpoint() -> &'static str {
+        ENDPOINT
+    }
+
+    async fn get(client: &Client, id: Uuid) -> Result<AccountingEmployee> {
+        endpoint_utils::get::<AccountingEmployee, ListResponse>(client, ENDPOINT, id, "Employee")
+            .await
+    }
+
+    async fn list(client: &Client, params: ListParameters) -> Result<Vec<AccountingEmployee>> {
+        endpoint_utils::list::<AccountingEmployee, ListResponse, _>(client, ENDPOINT, &params).await
+    }
+}
+
+/// Retrieve a list of accounting employees with filtering
+#[instrument(skip(client))]
+pub async fn list(client: &Client, params: ListParameters) -> Result<Vec<AccountingEmployee>> {
+    let response: ListResponse = client
+        .get_endpoint_with_modified_since(
+            XeroEndpoint::Custom(vec!["Employees".to_string()]),
+            &params,
+            params.if_modified_since,
+        )
+        .await?;
+    Ok(response.employees)
+}
+
+/// Retrieve a list of all accounting employees without filtering
+#[instrument(skip(client))]
+pub async fn list_all(client: &Client) -> Result<Vec<AccountingEmployee>> {
+    list(client, ListParameters::default()).await
+}
+
+/// Retrieve a single accounting employee by ID
+#[instrument(skip(client))]
+pub async fn get(client: &Client, employee_id: Uuid) -> Result<AccountingEmployee> {
+    AccountingEmployee::get(client, employee_id).await
+}
+
+/// Create an accounting employee
+#[instrument(skip(client, employee))]
+pub async fn create(client: &Client, employee: &Builder) -> Result<AccountingEmployee> {
+    let wrapper = EmployeeWrapper {
+        employees: vec![employee],
+    };
+
+    let response: MutationResponse = client
+        .put_endpoint(
+            XeroEndpoint::Custom(vec!["Employees".to_string()]),
+            &wrapper,
+        )
+        .await?;
+
+    response
+        .data
+        .get_employees()
+        .and_then(|employees| employees.into_iter().next())
+        .ok_or(Error::NotFound {
+            entity: "Employee".to_string(),
+            url: ENDPOINT.to_string(),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No employee returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Update an accounting employee
+#[instrument(skip(client, employee))]
+pub async fn update(
+    client: &Client,
+    employee_id: Uuid,
+    employee: &Builder,
+) -> Result<AccountingEmployee> {
+    let wrapper = EmployeeWrapper {
+        employees: vec![employee],
+    };
+
+    let endpoint = XeroEndpoint::Custom(vec!["Employees".to_string(), employee_id.to_string()]);
+    let response: MutationResponse = client.post_endpoint(endpoint, &wrapper).await?;
+
+    response
+        .data
+        .get_employees()
+        .and_then(|employees| employees.into_iter().next())
+        .ok_or(Error::NotFound {
+            entity: "Employee".to_string(),
+            url: format!("{ENDPOINT}{employee_id}"),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No employee returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
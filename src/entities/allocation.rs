@@ -0,0 +1,81 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+use crate::utils::date_format::xero_date_format_option;
+
+/// Reference to the invoice an allocation is applied to
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InvoiceReference {
+    #[serde(rename = "InvoiceID")]
+    pub invoice_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invoice_number: Option<String>,
+}
+
+/// Part of a credit (prepayment, overpayment or credit note) applied to an invoice
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Allocation {
+    #[serde(rename = "AllocationID", default)]
+    pub allocation_id: Option<Uuid>,
+    #[serde(default)]
+    pub invoice: Option<InvoiceReference>,
+    pub amount: Decimal,
+    #[serde(default, with = "xero_date_format_option")]
+    pub date: Option<Date>,
+    #[serde(default)]
+    pub is_deleted: Option<bool>,
+}
+
+/// Builder for allocating a credit to an invoice
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Builder {
+    pub invoice: InvoiceReference,
+    pub amount: Decimal,
+    #[serde(
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub date: Option<Date>,
+}
+
+impl Builder {
+    /// Allocate `amount` to the invoice with the given ID
+    #[must_use]
+    pub fn new(invoice_id: Uuid, amount: Decimal) -> Self {
+        Self {
+            invoice: InvoiceReference {
+                invoice_id,
+                invoice_number: None,
+            },
+            amount,
+            date: None,
+        }
+    }
+
+    /// Set the date of the allocation (defaults to today)
+    #[must_use]
+    pub fn with_date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+}
+
+/// Request wrapper for allocations
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct AllocationWrapper<'a> {
+    pub allocations: &'a [Builder],
+}
+
+/// Response wrapper for allocations
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct AllocationsResponse {
+    #[serde(default)]
+    pub allocations: Vec<Allocation>,
+}
@@ -0,0 +1,575 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    Client,
+    allocation::InvoiceReference,
+    endpoints::XeroEndpoint,
+    entities::{EntityEndpoint, endpoint_utils},
+    error::{Error, Result},
+    payment::AccountIdentifier,
+    utils::date_format::{xero_date_format, xero_date_format_option, xero_datetime_format_option},
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/BatchPayments/";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Type {
+    /// A batch of payments made to suppliers
+    #[serde(rename = "PAYBATCH")]
+    PayBatch,
+    /// A batch of payments received from customers
+    #[serde(rename = "RECBATCH")]
+    ReceiveBatch,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    Authorised,
+    Deleted,
+}
+
+/// The bank account a batch payment was made from
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccountReference {
+    #[serde(rename = "AccountID", default)]
+    pub account_id: Option<Uuid>,
+    #[serde(default)]
+    pub code: Option<String>,
+}
+
+/// A single invoice payment within a batch payment
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Payment {
+    #[serde(rename = "PaymentID", default)]
+    pub payment_id: Option<Uuid>,
+    #[serde(default)]
+    pub invoice: Option<InvoiceReference>,
+    pub amount: Decimal,
+    #[serde(default)]
+    pub bank_account_number: Option<String>,
+    #[serde(default)]
+    pub particulars: Option<String>,
+    #[serde(default)]
+    pub code: Option<String>,
+    #[serde(default)]
+    pub reference: Option<String>,
+    #[serde(default)]
+    pub details: Option<String>,
+}
+
+/// Several invoice payments made from or to one bank account as a single transaction
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BatchPayment {
+    #[serde(rename = "BatchPaymentID")]
+    pub batch_payment_id: Uuid,
+    #[serde(default)]
+    pub account: Option<AccountReference>,
+    #[serde(default)]
+    pub reference: Option<String>,
+    #[serde(default)]
+    pub particulars: Option<String>,
+    #[serde(default)]
+    pub code: Option<String>,
+    #[serde(default)]
+    pub details: Option<String>,
+    #[serde(default)]
+    pub narrative: Option<String>,
+    #[serde(rename = "DateString", default, with = "xero_date_format_option")]
+    pub date: Option<Date>,
+    pub r#type: Type,
+    pub status: Status,
+    pub total_amount: Decimal,
+    #[serde(default)]
+    pub is_reconciled: Option<bool>,
+    #[serde(
+        rename = "UpdatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub payments: Vec<Payment>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ListResponse {
+    #[serde(default)]
+    pub batch_payments: Vec<BatchPayment>,
+}
+
+impl From<ListResponse> for Vec<BatchPayment> {
+    fn from(response: ListResponse) -> Self {
+        response.batch_payments
+    }
+}
+
+/// Parameters for filtering batch payment list results
+#[derive(Debug, Serialize, Default)]
+pub struct ListParameters {
+    /// Filter by any element
+    #[serde(rename = "where", skip_serializing_if = "Option::is_none")]
+    pub r#where: Option<String>,
+
+    /// Order by any element
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+}
+
+impl ListParameters {
+    /// Create a new builder for `ListParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Set the where filter
+    #[must_use]
+    pub fn with_where(mut self, filter: impl Into<String>) -> Self {
+        self.r#where = Some(filter.into());
+        self
+    }
+
+    /// Set the order clause
+    #[must_use]
+    pub fn with_order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+}
+
+/// A payment of a single invoice within a new batch payment
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PaymentBuilder {
+    pub invoice: InvoiceReference,
+    pub amount: Decimal,
+    /// The supplier's bank account number, used on the remittance for pay batches
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_account_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub particulars: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+impl PaymentBuilder {
+    /// Pay `amount` towards the invoice with the given ID
+    #[must_use]
+    pub fn new(invoice_id: Uuid, amount: Decimal) -> Self {
+        Self {
+            invoice: InvoiceReference {
+                invoice_id,
+                invoice_number: None,
+            },
+            amount,
+            bank_account_number: None,
+            particulars: None,
+            code: None,
+            reference: None,
+            details: None,
+        }
+    }
+
+    /// Set the payee's bank account number
+    #[must_use]
+    pub fn with_bank_account_number(mut self, bank_account_number: impl Into<String>) -> Self {
+        self.bank_account_number = Some(bank_account_number.into());
+        self
+    }
+
+    /// Set the particulars shown on the payee's bank statement (NZ only)
+    #[must_use]
+    pub fn with_particulars(mut self, particulars: impl Into<String>) -> Self {
+        self.particulars = Some(particulars.into());
+        self
+    }
+
+    /// Set the code shown on the payee's bank statement (NZ only)
+    #[must_use]
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Set the reference shown on the payee's bank statement
+    #[must_use]
+    pub fn with_reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the details shown on the payee's bank statement
+    #[must_use]
+    pub fn with_details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+}
+
+/// Builder for creating batch payments
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Builder {
+    /// The bank account the payments are made from
+    pub account: AccountIdentifier,
+    #[serde(with = "xero_date_format")]
+    pub date: Date,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub particulars: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// The text shown on the bank statement of the paying account
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub narrative: Option<String>,
+    pub payments: Vec<PaymentBuilder>,
+    /// The total the payments are expected to add up to, checked before the batch is sent
+    #[serde(skip)]
+    pub expected_total: Option<Decimal>,
+}
+
+impl Builder {
+    /// Create a new batch payment from `account` on `date`
+    #[must_use]
+    pub fn new(account: AccountIdentifier, date: Date) -> Self {
+        Self {
+            account,
+            date,
+            reference: None,
+            particulars: None,
+            code: None,
+            details: None,
+            narrative: None,
+            payments: Vec::new(),
+            expected_total: None,
+        }
+    }
+
+    /// Pay `amount` towards the invoice with the given ID
+    #[must_use]
+    pub fn with_payment(self, invoice_id: Uuid, amount: Decimal) -> Self {
+        self.with_payment_line(PaymentBuilder::new(invoice_id, amount))
+    }
+
+    /// Add a payment with remittance details
+    #[must_use]
+    pub fn with_payment_line(mut self, payment: PaymentBuilder) -> Self {
+        self.payments.push(payment);
+        self
+    }
+
+    /// Set the reference
+    #[must_use]
+    pub fn with_reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the particulars (NZ only)
+    #[must_use]
+    pub fn with_particulars(mut self, particulars: impl Into<String>) -> Self {
+        self.particulars = Some(particulars.into());
+        self
+    }
+
+    /// Set the code (NZ only)
+    #[must_use]
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Set the details
+    #[must_use]
+    pub fn with_details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    /// Set the narrative shown on the paying account's bank statement
+    #[must_use]
+    pub fn with_narrative(mut self, narrative: impl Into<String>) -> Self {
+        self.narrative = Some(narrative.into());
+        self
+    }
+
+    /// Require the payments to add up to `total` before the batch is created
+    #[must_use]
+    pub fn with_expected_total(mut self, total: Decimal) -> Self {
+        self.expected_total = Some(total);
+        self
+    }
+
+    /// The sum of all payment amounts in the batch
+    #[must_use]
+    pub fn total(&self) -> Decimal {
+        self.payments.iter().map(|payment| payment.amount).sum()
+    }
+
+    /// Whether the payments add up to the expected total, if one is set
+    #[must_use]
+    pub fn total_matches(&self) -> bool {
+        self.expected_total
+            .is_none_or(|expected| expected == self.total())
+    }
+
+    /// Check the batch before it is sent to Xero
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationError::Empty` if there are no payments,
+    /// `ValidationError::InvalidAmount` for the first payment that is zero or negative, and
+    /// `ValidationError::TotalMismatch` if an expected total is set and the payments do not
+    /// add up to it.
+    pub fn validate(&self) -> std::result::Result<(), ValidationError> {
+        if self.payments.is_empty() {
+            return Err(ValidationError::Empty);
+        }
+
+        if let Some(payment) = self
+            .payments
+            .iter()
+            .find(|payment| payment.amount <= Decimal::ZERO)
+        {
+            return Err(ValidationError::InvalidAmount {
+                invoice_id: payment.invoice.invoice_id,
+                amount: payment.amount,
+            });
+        }
+
+        if let Some(expected) = self.expected_total {
+            let actual = self.total();
+            if actual != expected {
+                return Err(ValidationError::TotalMismatch { expected, actual });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Why a batch payment was rejected before it was sent to Xero
+///
+/// Converts into the matching [`Error`] variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The batch has no payments
+    Empty,
+    /// A payment is zero or negative
+    InvalidAmount { invoice_id: Uuid, amount: Decimal },
+    /// The payments do not add up to the expected total
+    TotalMismatch { expected: Decimal, actual: Decimal },
+}
+
+impl From<ValidationError> for Error {
+    fn from(error: ValidationError) -> Self {
+        match error {
+            ValidationError::Empty => Error::BatchPaymentEmpty,
+            ValidationError::InvalidAmount { invoice_id, amount } => {
+                Error::InvalidBatchPaymentAmount { invoice_id, amount }
+            }
+            ValidationError::TotalMismatch { expected, actual } => {
+                Error::BatchPaymentTotalMismatch { expected, actual }
+            }
+        }
+    }
+}
+
+/// Request wrapper for batch payments
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct BatchPaymentWrapper<'a> {
+    pub batch_payments: Vec<&'a Builder>,
+}
+
+/// Request body for deleting a batch payment
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct DeleteRequest {
+    #[serde(rename = "BatchPaymentID")]
+    pub batch_payment_id: Uuid,
+    pub status: Status,
+}
+
+/// History record for a batch payment
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecord {
+    /// The details of the history record
+    pub details: String,
+
+    /// The date and time of the history record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_utc: Option<String>,
+
+    /// The user who created the history record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+
+    /// The changes made
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<String>,
+}
+
+/// Wrapper for history records response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecords {
+    pub history_records: Vec<HistoryRecord>,
+}
+
+/// Wrapper for posting history records
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecordsRequest {
+    pub history_records: Vec<HistoryRecord>,
+}
+
+impl EntityEndpoint<BatchPayment, ListParameters> for BatchPayment {
+    fn endpoint() -> &'static str {
+        ENDPOINT
+    }
+
+    async fn get(client: &Client, id: Uuid) -> Result<BatchPayment> {
+        endpoint_utils::get::<BatchPayment, ListResponse>(client, ENDPOINT, id, "BatchPayment")
+            .await
+    }
+
+    async fn list(client: &Client, params: ListParameters) -> Result<Vec<BatchPayment>> {
+        endpoint_utils::list::<BatchPayment, ListResponse, _>(client, ENDPOINT, &params).await
+    }
+}
+
+/// Retrieve a list of batch payments with filtering
+#[instrument(skip(client))]
+pub async fn list(client: &Client, params: ListParameters) -> Result<Vec<BatchPayment>> {
+    BatchPayment::list(client, params).await
+}
+
+/// Retrieve a list of all batch payments without filtering
+#[instrument(skip(client))]
+pub async fn list_all(client: &Client) -> Result<Vec<BatchPayment>> {
+    list(client, ListParameters::default()).await
+}
+
+/// Retrieve a single batch payment by ID
+#[instrument(skip(client))]
+pub async fn get(client: &Client, batch_payment_id: Uuid) -> Result<BatchPayment> {
+    BatchPayment::get(client, batch_payment_id).await
+}
+
+/// Create a batch payment
+///
+/// The batch is checked with [`Builder::validate`] before anything is sent to Xero, so an
+/// empty batch, a payment that is not positive or a total that does not match the expected
+/// total is rejected without calling the API.
+#[instrument(skip(client, batch_payment))]
+pub async fn create(client: &Client, batch_payment: &Builder) -> Result<BatchPayment> {
+    batch_payment.validate()?;
+
+    let wrapper = BatchPaymentWrapper {
+        batch_payments: vec![batch_payment],
+    };
+    let response: ListResponse = client
+        .put_endpoint(
+            XeroEndpoint::Custom(vec!["BatchPayments".to_string()]),
+            &wrapper,
+        )
+        .await?;
+
+    response
+        .batch_payments
+        .into_iter()
+        .next()
+        .ok_or(Error::NotFound {
+            entity: "BatchPayment".to_string(),
+            url: ENDPOINT.to_string(),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No batch payment returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Delete a batch payment
+///
+/// The payments on the invoices in the batch are deleted with it. Reconciled batch
+/// payments cannot be deleted.
+#[instrument(skip(client))]
+pub async fn delete(client: &Client, batch_payment_id: Uuid) -> Result<BatchPayment> {
+    let request = DeleteRequest {
+        batch_payment_id,
+        status: Status::Deleted,
+    };
+    let response: ListResponse = client
+        .post_endpoint(
+            XeroEndpoint::Custom(vec!["BatchPayments".to_string()]),
+            &request,
+        )
+        .await?;
+
+    response
+        .batch_payments
+        .into_iter()
+        .next()
+        .ok_or(Error::NotFound {
+            entity: "BatchPayment".to_string(),
+            url: format!("{ENDPOINT}{batch_payment_id}"),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No batch payment returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Get history records for a batch payment
+#[instrument(skip(client))]
+pub async fn get_history(client: &Client, batch_payment_id: Uuid) -> Result<Vec<HistoryRecord>> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "BatchPayments".to_string(),
+        batch_payment_id.to_string(),
+        "History".to_string(),
+    ]);
+    let response: HistoryRecords = client.get_endpoint(endpoint, &()).await?;
+    Ok(response.history_records)
+}
+
+/// Create a history record for a batch payment
+#[instrument(skip(client))]
+pub async fn create_history(
+    client: &Client,
+    batch_payment_id: Uuid,
+    details: &str,
+) -> Result<Vec<HistoryRecord>> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "BatchPayments".to_string(),
+        batch_payment_id.to_string(),
+        "History".to_string(),
+    ]);
+
+    let request = HistoryRecordsRequest {
+        history_records: vec![HistoryRecord {
+            details: details.to_string(),
+            date_utc: None,
+            user: None,
+            changes: None,
+        }],
+    };
+
+    let response: HistoryRecords = client.put_endpoint(endpoint, &request).await?;
+    Ok(response.history_records)
+}
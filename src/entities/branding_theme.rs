@@ -0,0 +1,222 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    Client,
+    endpoints::XeroEndpoint,
+    entities::{EntityEndpoint, endpoint_utils},
+    error::{Error, Result},
+    utils::date_format::xero_datetime_format_option,
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/BrandingThemes/";
+
+/// A branding theme controls the layout and appearance of sales documents
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BrandingTheme {
+    /// Xero identifier
+    #[serde(rename = "BrandingThemeID")]
+    pub branding_theme_id: Uuid,
+
+    /// Name of the branding theme
+    pub name: String,
+
+    /// The location of the image file used as the logo on this theme
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+
+    /// Type of the theme (always "INVOICE")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+
+    /// Ranked order of the theme. The default theme has a value of 0
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<i32>,
+
+    /// When the theme was created
+    #[serde(
+        rename = "CreatedDateUTC",
+        default,
+        with = "xero_datetime_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub created_date_utc: Option<OffsetDateTime>,
+}
+
+impl BrandingTheme {
+    /// Whether this is the organisation's default branding theme
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self.sort_order == Some(0)
+    }
+}
+
+/// A payment service that adds a "Pay Now" link to online invoices
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PaymentService {
+    /// Xero identifier
+    #[serde(rename = "PaymentServiceID", skip_serializing_if = "Option::is_none")]
+    pub payment_service_id: Option<Uuid>,
+
+    /// Name of the payment service
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_service_name: Option<String>,
+
+    /// The custom payment URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_service_url: Option<String>,
+
+    /// The text displayed on the Pay Now button in Xero Online Invoicing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pay_now_text: Option<String>,
+
+    /// Type of the payment service (always "CUSTOM" for services created via the API)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_service_type: Option<String>,
+}
+
+impl PaymentService {
+    /// Reference an existing payment service by ID, e.g. to attach it to a theme
+    #[must_use]
+    pub fn from_id(payment_service_id: Uuid) -> Self {
+        Self {
+            payment_service_id: Some(payment_service_id),
+            ..Default::default()
+        }
+    }
+
+    /// Describe a new custom payment service
+    #[must_use]
+    pub fn new(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            payment_service_name: Some(name.into()),
+            payment_service_url: Some(url.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Set the text displayed on the Pay Now button
+    #[must_use]
+    pub fn with_pay_now_text(mut self, text: impl Into<String>) -> Self {
+        self.pay_now_text = Some(text.into());
+        self
+    }
+}
+
+/// Response wrapper for listing branding themes
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ListResponse {
+    pub branding_themes: Vec<BrandingTheme>,
+}
+
+impl From<ListResponse> for Vec<BrandingTheme> {
+    fn from(response: ListResponse) -> Self {
+        response.branding_themes
+    }
+}
+
+/// Response wrapper for payment services
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct PaymentServicesResponse {
+    #[serde(default)]
+    pub payment_services: Vec<PaymentService>,
+}
+
+/// Request wrapper for payment services
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct PaymentServiceWrapper<'a> {
+    pub payment_services: Vec<&'a PaymentService>,
+}
+
+impl EntityEndpoint<BrandingTheme, ()> for BrandingTheme {
+    fn endpoint() -> &'static str {
+        ENDPOINT
+    }
+
+    async fn get(client: &Client, id: Uuid) -> Result<BrandingTheme> {
+        endpoint_utils::get::<BrandingTheme, ListResponse>(client, ENDPOINT, id, "BrandingTheme")
+            .await
+    }
+
+    async fn list(client: &Client, params: ()) -> Result<Vec<BrandingTheme>> {
+        endpoint_utils::list::<BrandingTheme, ListResponse, ()>(client, ENDPOINT, &params).await
+    }
+}
+
+/// List all branding themes
+pub async fn list(client: &Client) -> Result<Vec<BrandingTheme>> {
+    BrandingTheme::list(client, ()).await
+}
+
+/// Get a single branding theme by ID
+pub async fn get(client: &Client, branding_theme_id: Uuid) -> Result<BrandingTheme> {
+    BrandingTheme::get(client, branding_theme_id).await
+}
+
+/// Find a branding theme by name
+///
+/// The Xero API does not support filtering branding themes, so this lists all themes and
+/// matches the name case-insensitively, ignoring surrounding whitespace.
+pub async fn get_by_name(client: &Client, name: &str) -> Result<BrandingTheme> {
+    let themes = list(client).await?;
+    find_by_name(themes, name).ok_or(Error::NotFound {
+        entity: "BrandingTheme".to_string(),
+        url: ENDPOINT.to_string(),
+        status_code: reqwest::StatusCode::NOT_FOUND,
+        response_body: Some(format!("Branding theme with name {name} not found")),
+        span_trace: SpanTrace::capture(),
+    })
+}
+
+/// Pick the theme matching `name` out of a list of themes
+///
+/// Names are compared case-insensitively, ignoring surrounding whitespace.
+#[must_use]
+pub fn find_by_name(
+    themes: impl IntoIterator<Item = BrandingTheme>,
+    name: &str,
+) -> Option<BrandingTheme> {
+    let name = name.trim();
+    themes
+        .into_iter()
+        .find(|theme| theme.name.trim().eq_ignore_ascii_case(name))
+}
+
+/// List the payment services attached to a branding theme
+///
+/// Requires the `paymentservices` scope.
+pub async fn list_payment_services(
+    client: &Client,
+    branding_theme_id: Uuid,
+) -> Result<Vec<PaymentService>> {
+    let endpoint = XeroEndpoint::Custom(vec![format!(
+        "BrandingThemes/{branding_theme_id}/PaymentServices"
+    )]);
+    let response: PaymentServicesResponse = client.get_endpoint(endpoint, &()).await?;
+    Ok(response.payment_services)
+}
+
+/// Attach a payment service to a branding theme
+///
+/// Requires the `paymentservices` scope.
+pub async fn attach_payment_service(
+    client: &Client,
+    branding_theme_id: Uuid,
+    payment_service: &PaymentService,
+) -> Result<Vec<PaymentService>> {
+    let endpoint = XeroEndpoint::Custom(vec![format!(
+        "BrandingThemes/{branding_theme_id}/PaymentServices"
+    )]);
+    let wrapper = PaymentServiceWrapper {
+        payment_services: vec![payment_service],
+    };
+    let response: PaymentServicesResponse = client.post_endpoint(endpoint, &wrapper).await?;
+    Ok(response.payment_services)
+}
@@ -0,0 +1,344 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use time::{Date, Month, OffsetDateTime};
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    Client,
+    endpoints::XeroEndpoint,
+    entities::{EntityEndpoint, endpoint_utils},
+    error::{Error, Result},
+    utils::date_format::{xero_date_format_option, xero_datetime_format_option},
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/Budgets/";
+
+/// The type of a budget
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Type {
+    /// The organisation's overall budget
+    Overall,
+    /// A budget for one or more tracking options
+    Tracking,
+}
+
+/// A calendar month of a budget, sent by Xero as `YYYY-MM`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Period {
+    pub year: i32,
+    pub month: Month,
+}
+
+impl Period {
+    /// Create a period for the given month
+    #[must_use]
+    pub fn new(year: i32, month: Month) -> Self {
+        Self { year, month }
+    }
+
+    /// The period containing `date`
+    #[must_use]
+    pub fn containing(date: Date) -> Self {
+        Self::new(date.year(), date.month())
+    }
+
+    /// The first day of the period
+    #[must_use]
+    pub fn start_date(self) -> Option<Date> {
+        Date::from_calendar_date(self.year, self.month, 1).ok()
+    }
+
+    /// Whether `date` falls in the period
+    #[must_use]
+    pub fn contains(self, date: Date) -> bool {
+        Self::containing(date) == self
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}", self.year, u8::from(self.month))
+    }
+}
+
+impl FromStr for Period {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || format!("Invalid budget period: {s}");
+        let (year, month) = s.split_once('-').ok_or_else(invalid)?;
+        let year = year.parse::<i32>().map_err(|_| invalid())?;
+        let month = month
+            .parse::<u8>()
+            .ok()
+            .and_then(|month| Month::try_from(month).ok())
+            .ok_or_else(invalid)?;
+        Ok(Self::new(year, month))
+    }
+}
+
+impl Serialize for Period {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Period {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// The budgeted amount for one account in one period
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BudgetBalance {
+    pub period: Period,
+    #[serde(default)]
+    pub amount: Decimal,
+    #[serde(default)]
+    pub unit_amount: Option<Decimal>,
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+/// The budgeted amounts for one account
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BudgetLine {
+    #[serde(rename = "AccountID")]
+    pub account_id: Uuid,
+    #[serde(default)]
+    pub account_code: Option<String>,
+    #[serde(default)]
+    pub budget_balances: Vec<BudgetBalance>,
+}
+
+impl BudgetLine {
+    /// The budgeted amount for a period, if one is set
+    #[must_use]
+    pub fn amount_for(&self, period: Period) -> Option<Decimal> {
+        self.budget_balances
+            .iter()
+            .find(|balance| balance.period == period)
+            .map(|balance| balance.amount)
+    }
+
+    /// The total budgeted amount across all periods
+    #[must_use]
+    pub fn total(&self) -> Decimal {
+        self.budget_balances
+            .iter()
+            .map(|balance| balance.amount)
+            .sum()
+    }
+}
+
+/// A tracking option a budget applies to
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Tracking {
+    #[serde(rename = "TrackingCategoryID")]
+    pub tracking_category_id: Uuid,
+    #[serde(rename = "TrackingOptionID", default)]
+    pub tracking_option_id: Option<Uuid>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub option: Option<String>,
+}
+
+/// A budget, either the overall budget or one broken down by tracking category
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Budget {
+    #[serde(rename = "BudgetID")]
+    pub budget_id: Uuid,
+    pub r#type: Type,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(
+        rename = "UpdatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+    /// Budgeted amounts per account. Only returned when fetching a single budget
+    #[serde(default)]
+    pub budget_lines: Vec<BudgetLine>,
+    #[serde(default)]
+    pub tracking: Vec<Tracking>,
+}
+
+impl Budget {
+    /// The budget line for an account
+    #[must_use]
+    pub fn line_for_account(&self, account_id: Uuid) -> Option<&BudgetLine> {
+        self.budget_lines
+            .iter()
+            .find(|line| line.account_id == account_id)
+    }
+
+    /// The budgeted amount for an account in a period
+    #[must_use]
+    pub fn amount_for(&self, account_id: Uuid, period: Period) -> Option<Decimal> {
+        self.line_for_account(account_id)
+            .and_then(|line| line.amount_for(period))
+    }
+
+    /// Whether the budget applies to the given tracking option
+    #[must_use]
+    pub fn applies_to_tracking_option(&self, tracking_option_id: Uuid) -> bool {
+        self.tracking
+            .iter()
+            .any(|tracking| tracking.tracking_option_id == Some(tracking_option_id))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ListResponse {
+    #[serde(default)]
+    pub budgets: Vec<Budget>,
+}
+
+impl From<ListResponse> for Vec<Budget> {
+    fn from(response: ListResponse) -> Self {
+        response.budgets
+    }
+}
+
+/// Parameters for filtering budget list results
+#[derive(Debug, Serialize, Default)]
+pub struct ListParameters {
+    /// Filter by a comma-separated list of budget IDs
+    #[serde(rename = "IDs", skip_serializing_if = "Option::is_none")]
+    pub ids: Option<String>,
+
+    /// Start of the date range for budget balances
+    #[serde(
+        rename = "DateFrom",
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub date_from: Option<Date>,
+
+    /// End of the date range for budget balances
+    #[serde(
+        rename = "DateTo",
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub date_to: Option<Date>,
+}
+
+impl ListParameters {
+    /// Create a new builder for `ListParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Only return the given budgets
+    #[must_use]
+    pub fn with_ids(mut self, ids: impl IntoIterator<Item = Uuid>) -> Self {
+        let ids_str = ids
+            .into_iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        self.ids = Some(ids_str);
+        self
+    }
+
+    /// Only include budget balances between `date_from` and `date_to`
+    #[must_use]
+    pub fn with_date_range(mut self, date_from: Date, date_to: Date) -> Self {
+        self.date_from = Some(date_from);
+        self.date_to = Some(date_to);
+        self
+    }
+}
+
+/// Parameters for retrieving a single budget
+#[derive(Debug, Serialize, Default)]
+pub struct GetParameters {
+    /// Start of the date range for budget balances
+    #[serde(
+        rename = "DateFrom",
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub date_from: Option<Date>,
+
+    /// End of the date range for budget balances
+    #[serde(
+        rename = "DateTo",
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub date_to: Option<Date>,
+}
+
+impl GetParameters {
+    /// Create a new builder for `GetParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Only include budget balances between `date_from` and `date_to`
+    #[must_use]
+    pub fn with_date_range(mut self, date_from: Date, date_to: Date) -> Self {
+        self.date_from = Some(date_from);
+        self.date_to = Some(date_to);
+        self
+    }
+}
+
+impl EntityEndpoint<Budget, ListParameters> for Budget {
+    fn endpoint() -> &'static str {
+        ENDPOINT
+    }
+
+    async fn get(client: &Client, id: Uuid) -> Result<Budget> {
+        endpoint_utils::get::<Budget, ListResponse>(client, ENDPOINT, id, "Budget").await
+    }
+
+    async fn list(client: &Client, params: ListParameters) -> Result<Vec<Budget>> {
+        endpoint_utils::list::<Budget, ListResponse, _>(client, ENDPOINT, &params).await
+    }
+}
+
+/// Retrieve a list of budgets with filtering
+#[instrument(skip(client))]
+pub async fn list(client: &Client, params: ListParameters) -> Result<Vec<Budget>> {
+    Budget::list(client, params).await
+}
+
+/// Retrieve a list of all budgets without filtering
+#[instrument(skip(client))]
+pub async fn list_all(client: &Client) -> Result<Vec<Budget>> {
+    list(client, ListParameters::default()).await
+}
+
+/// Retrieve a single budget by ID, including its budget lines
+#[instrument(skip(client))]
+pub async fn get(client: &Client, budget_id: Uuid, params: GetParameters) -> Result<Budget> {
+    let endpoint = XeroEndpoint::Custom(vec!["Budgets".to_string(), budget_id.to_string()]);
+    let response: ListResponse = client.get_endpoint(endpoint, &params).await?;
+
+    response.budgets.into_iter().next().ok_or(Error::NotFound {
+        entity: "Budget".to_string(),
+        url: format!("{ENDPOINT}{budget_id}"),
+        status_code: reqwest::StatusCode::NOT_FOUND,
+        response_body: Some(format!("Budget with ID {budget_id} not found")),
+        span_trace: SpanTrace::capture(),
+    })
+}
@@ -1,8 +1,24 @@
+use std::{ffi::OsStr, path::Path};
+
+use rust_decimal::Decimal;
 use serde::ser::SerializeMap;
 use serde::{Deserialize, Serialize, Serializer};
+use time::OffsetDateTime;
+use tracing_error::SpanTrace;
 use uuid::Uuid;
 
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+use crate::{
+    Client,
+    contact_group::ContactGroup,
+    currency::CurrencyCode,
+    endpoints::XeroEndpoint,
+    entities::MutationResponse,
+    error::{Error, Result},
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/Contacts/";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Status {
     Active,
@@ -22,7 +38,7 @@ pub enum ContactIdentifier {
 }
 
 impl Serialize for ContactIdentifier {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
@@ -57,15 +73,374 @@ pub struct Contact {
     pub skype_user_name: Option<String>,
     pub bank_account_details: Option<String>,
     pub tax_number: Option<String>,
-    /*pub accounts_receivable_tax_type: TaxType,
-    pub accounts_payable_tax_type: TaxType,
+    pub accounts_receivable_tax_type: Option<String>,
+    pub accounts_payable_tax_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub addresses: Vec<Address>,
-    pub phones: Vec<Phone>,*/
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub phones: Vec<Phone>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contact_persons: Vec<ContactPerson>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contact_groups: Vec<ContactGroup>,
     pub is_supplier: Option<bool>,
     pub is_customer: Option<bool>,
-    pub default_currency: Option<String>,
+    pub default_currency: Option<CurrencyCode>,
     #[serde(rename = "UpdatedDateUTC")]
     pub updated_date_utc: Option<String>,
+    pub payment_terms: Option<PaymentTerms>,
+    /// Outstanding and overdue amounts. Only returned when a single contact is retrieved
+    /// or when listing without `summaryOnly`
+    pub balances: Option<Balances>,
+    pub batch_payments: Option<BatchPaymentDetails>,
+    #[serde(default)]
+    pub has_attachments: bool,
+}
+
+/// The kind of address held against a contact
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AddressType {
+    /// Postal address
+    Pobox,
+    /// Physical address
+    Street,
+    /// Delivery address (only used on purchase orders)
+    Delivery,
+}
+
+/// A postal or physical address of a contact
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Address {
+    pub address_type: AddressType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line3: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line4: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attention_to: Option<String>,
+}
+
+impl Address {
+    /// Create an empty address of the given type
+    #[must_use]
+    pub fn new(address_type: AddressType) -> Self {
+        Self {
+            address_type,
+            address_line1: None,
+            address_line2: None,
+            address_line3: None,
+            address_line4: None,
+            city: None,
+            region: None,
+            postal_code: None,
+            country: None,
+            attention_to: None,
+        }
+    }
+
+    /// Set the address lines (up to four, extra lines are ignored)
+    #[must_use]
+    pub fn with_lines<I, S>(mut self, lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut lines = lines.into_iter().map(Into::into);
+        self.address_line1 = lines.next();
+        self.address_line2 = lines.next();
+        self.address_line3 = lines.next();
+        self.address_line4 = lines.next();
+        self
+    }
+
+    /// Set the city
+    #[must_use]
+    pub fn with_city(mut self, city: impl Into<String>) -> Self {
+        self.city = Some(city.into());
+        self
+    }
+
+    /// Set the region (state, county, etc.)
+    #[must_use]
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Set the postal code
+    #[must_use]
+    pub fn with_postal_code(mut self, postal_code: impl Into<String>) -> Self {
+        self.postal_code = Some(postal_code.into());
+        self
+    }
+
+    /// Set the country
+    #[must_use]
+    pub fn with_country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    /// Set the attention to line
+    #[must_use]
+    pub fn with_attention_to(mut self, attention_to: impl Into<String>) -> Self {
+        self.attention_to = Some(attention_to.into());
+        self
+    }
+}
+
+/// The kind of phone number held against a contact
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PhoneType {
+    Default,
+    Ddi,
+    Mobile,
+    Fax,
+    Office,
+}
+
+/// A phone number of a contact
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Phone {
+    pub phone_type: PhoneType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_area_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_country_code: Option<String>,
+}
+
+impl Phone {
+    /// Create a phone number of the given type
+    #[must_use]
+    pub fn new(phone_type: PhoneType, number: impl Into<String>) -> Self {
+        Self {
+            phone_type,
+            phone_number: Some(number.into()),
+            phone_area_code: None,
+            phone_country_code: None,
+        }
+    }
+
+    /// Set the area code
+    #[must_use]
+    pub fn with_area_code(mut self, area_code: impl Into<String>) -> Self {
+        self.phone_area_code = Some(area_code.into());
+        self
+    }
+
+    /// Set the country code
+    #[must_use]
+    pub fn with_country_code(mut self, country_code: impl Into<String>) -> Self {
+        self.phone_country_code = Some(country_code.into());
+        self
+    }
+}
+
+/// An additional person at a contact (Xero allows up to five)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactPerson {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_in_emails: Option<bool>,
+}
+
+impl ContactPerson {
+    /// Create a contact person
+    #[must_use]
+    pub fn new(
+        first_name: impl Into<String>,
+        last_name: impl Into<String>,
+        email_address: impl Into<String>,
+    ) -> Self {
+        Self {
+            first_name: Some(first_name.into()),
+            last_name: Some(last_name.into()),
+            email_address: Some(email_address.into()),
+            include_in_emails: None,
+        }
+    }
+
+    /// Set whether this person is included on emails sent to the contact
+    #[must_use]
+    pub fn with_include_in_emails(mut self, include: bool) -> Self {
+        self.include_in_emails = Some(include);
+        self
+    }
+}
+
+/// How the due date of a bill or invoice is calculated
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PaymentTermType {
+    /// N days after the document date
+    DaysAfterBillDate,
+    /// N days after the end of the document's month
+    DaysAfterBillMonth,
+    /// Day N of the document's month
+    OfCurrentMonth,
+    /// Day N of the month following the document date
+    OfFollowingMonth,
+}
+
+/// A single payment term
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PaymentTerm {
+    pub day: u32,
+    pub r#type: PaymentTermType,
+}
+
+impl PaymentTerm {
+    #[must_use]
+    pub fn new(day: u32, r#type: PaymentTermType) -> Self {
+        Self { day, r#type }
+    }
+}
+
+/// Default payment terms for bills and sales invoices of a contact
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PaymentTerms {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bills: Option<PaymentTerm>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sales: Option<PaymentTerm>,
+}
+
+/// Outstanding and overdue amounts for one side of the ledger
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Balance {
+    #[serde(default)]
+    pub outstanding: Decimal,
+    #[serde(default)]
+    pub overdue: Decimal,
+}
+
+/// Raw receivable (sales invoices) and payable (bills) balances of a contact.
+///
+/// Amounts are in the currency of the underlying documents and are not converted
+/// to the organisation's base currency.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Balances {
+    #[serde(default)]
+    pub accounts_receivable: Balance,
+    #[serde(default)]
+    pub accounts_payable: Balance,
+}
+
+/// Bank details used when the contact is paid with a batch payment
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BatchPaymentDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_account_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_account_name: Option<String>,
+    /// (Non-NZ only) Reference sent to the bank, maximum 18 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// (NZ only) Code shown on the statement, maximum 12 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// (NZ only) Reference shown on the statement, maximum 12 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+}
+
+/// Construction Industry Scheme (UK) settings of a contact
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CisSetting {
+    /// Whether the contact is a CIS subcontractor
+    #[serde(rename = "CISEnabled")]
+    pub cis_enabled: bool,
+    /// CIS deduction rate. Only returned when the contact is CIS enabled
+    #[serde(rename = "Rate", default)]
+    pub rate: Option<Decimal>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct CisSettingsResponse {
+    #[serde(rename = "CISSettings", default)]
+    pub cis_settings: Vec<CisSetting>,
+}
+
+/// History record for a contact
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecord {
+    /// The details of the history record
+    pub details: String,
+
+    /// The date and time of the history record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_utc: Option<String>,
+
+    /// The user who created the history record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+
+    /// The changes made
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<String>,
+}
+
+/// Wrapper for history records response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecords {
+    pub history_records: Vec<HistoryRecord>,
+}
+
+/// Wrapper for posting history records
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecordsRequest {
+    pub history_records: Vec<HistoryRecord>,
+}
+
+/// Attachment details for a contact
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Attachment {
+    #[serde(rename = "AttachmentID")]
+    pub attachment_id: Uuid,
+    pub file_name: String,
+    pub url: String,
+    pub mime_type: String,
+    pub content_length: i64,
+}
+
+/// Attachments response wrapper
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Attachments {
+    pub attachments: Vec<Attachment>,
 }
 
 #[derive(Deserialize)]
@@ -73,3 +448,643 @@ pub struct Contact {
 pub(crate) struct ListResponse {
     pub contacts: Vec<Contact>,
 }
+
+impl From<ListResponse> for Vec<Contact> {
+    fn from(response: ListResponse) -> Self {
+        response.contacts
+    }
+}
+
+/// Parameters for filtering contact list results
+#[derive(Debug, Serialize, Default)]
+pub struct ListParameters {
+    /// Filter by any element
+    #[serde(rename = "where", skip_serializing_if = "Option::is_none")]
+    pub r#where: Option<String>,
+
+    /// Order by any element
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+
+    /// Pagination parameter (1-based)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i32>,
+
+    /// Number of records per page (maximum 1000)
+    #[serde(rename = "pageSize", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i32>,
+
+    /// Filter by a comma-separated list of contact IDs
+    #[serde(rename = "IDs", skip_serializing_if = "Option::is_none")]
+    pub ids: Option<String>,
+
+    /// Search name, first name, last name, contact number and email address
+    #[serde(rename = "searchTerm", skip_serializing_if = "Option::is_none")]
+    pub search_term: Option<String>,
+
+    /// Include archived contacts
+    #[serde(rename = "includeArchived", skip_serializing_if = "Option::is_none")]
+    pub include_archived: Option<bool>,
+
+    /// Return a lightweight version of each contact, without addresses, phones, etc.
+    #[serde(rename = "summaryOnly", skip_serializing_if = "Option::is_none")]
+    pub summary_only: Option<bool>,
+
+    /// Only return contacts modified since this timestamp (sent as the
+    /// `If-Modified-Since` header rather than a query parameter)
+    #[serde(skip)]
+    pub if_modified_since: Option<OffsetDateTime>,
+}
+
+impl ListParameters {
+    /// Create a new builder for `ListParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Set the where filter
+    #[must_use]
+    pub fn with_where(mut self, filter: impl Into<String>) -> Self {
+        self.r#where = Some(filter.into());
+        self
+    }
+
+    /// Set the order clause
+    #[must_use]
+    pub fn with_order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
+    /// Set the page number
+    #[must_use]
+    pub fn with_page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Set the page size
+    #[must_use]
+    pub fn with_page_size(mut self, page_size: i32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set the ids filter with a list of contact IDs
+    #[must_use]
+    pub fn with_ids(mut self, ids: impl IntoIterator<Item = Uuid>) -> Self {
+        let ids_str = ids
+            .into_iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        self.ids = Some(ids_str);
+        self
+    }
+
+    /// Set the search term
+    #[must_use]
+    pub fn with_search_term(mut self, search_term: impl Into<String>) -> Self {
+        self.search_term = Some(search_term.into());
+        self
+    }
+
+    /// Set the `include_archived` filter
+    #[must_use]
+    pub fn with_include_archived(mut self, include: bool) -> Self {
+        self.include_archived = Some(include);
+        self
+    }
+
+    /// Set the `summary_only` flag
+    #[must_use]
+    pub fn with_summary_only(mut self, summary_only: bool) -> Self {
+        self.summary_only = Some(summary_only);
+        self
+    }
+
+    /// Filter by exact name
+    ///
+    /// `Name`, `EmailAddress` and `AccountNumber` are indexed by Xero, so these filters
+    /// are much faster than a `searchTerm` or a general where clause.
+    #[must_use]
+    pub fn with_name(self, name: &str) -> Self {
+        self.with_field_equals("Name", name)
+    }
+
+    /// Filter by exact email address
+    #[must_use]
+    pub fn with_email_address(self, email_address: &str) -> Self {
+        self.with_field_equals("EmailAddress", email_address)
+    }
+
+    /// Filter by exact account number
+    #[must_use]
+    pub fn with_account_number(self, account_number: &str) -> Self {
+        self.with_field_equals("AccountNumber", account_number)
+    }
+
+    fn with_field_equals(self, field: &str, value: &str) -> Self {
+        let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+        self.with_where(format!("{field}==\"{escaped}\""))
+    }
+
+    /// Only return contacts modified since the given timestamp
+    #[must_use]
+    pub fn with_if_modified_since(mut self, modified_since: OffsetDateTime) -> Self {
+        self.if_modified_since = Some(modified_since);
+        self
+    }
+}
+
+/// List contacts with optional parameters
+pub async fn list(client: &Client, params: ListParameters) -> Result<Vec<Contact>> {
+    let response: ListResponse = client
+        .get_endpoint_with_modified_since(XeroEndpoint::Contacts, &params, params.if_modified_since)
+        .await?;
+    Ok(response.contacts)
+}
+
+/// List all contacts without any filtering
+pub async fn list_all(client: &Client) -> Result<Vec<Contact>> {
+    list(client, ListParameters::default()).await
+}
+
+/// Return the first contact matching the parameters, if any
+async fn find_first(client: &Client, params: ListParameters) -> Result<Option<Contact>> {
+    Ok(list(client, params.with_page(1)).await?.into_iter().next())
+}
+
+/// Find a contact by its exact name
+pub async fn find_by_name(client: &Client, name: &str) -> Result<Option<Contact>> {
+    find_first(client, ListParameters::builder().with_name(name)).await
+}
+
+/// Find a contact by its exact email address
+pub async fn find_by_email(client: &Client, email_address: &str) -> Result<Option<Contact>> {
+    find_first(
+        client,
+        ListParameters::builder().with_email_address(email_address),
+    )
+    .await
+}
+
+/// Find a contact by its exact account number
+pub async fn find_by_account_number(
+    client: &Client,
+    account_number: &str,
+) -> Result<Option<Contact>> {
+    find_first(
+        client,
+        ListParameters::builder().with_account_number(account_number),
+    )
+    .await
+}
+
+/// Builder for creating and updating contacts
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct Builder {
+    /// Full name of the contact. Must be unique within the organisation
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub name: String,
+
+    /// Identifier from an external system
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_number: Option<String>,
+
+    /// User defined account number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+
+    /// Status of the contact
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_status: Option<Status>,
+
+    /// First name of the primary person
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+
+    /// Last name of the primary person
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+
+    /// Email address of the primary person
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+
+    /// Bank account number of the contact
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_account_details: Option<String>,
+
+    /// Tax number (ABN, GST number, VAT number, etc.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_number: Option<String>,
+
+    /// Default tax type used for sales to the contact
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accounts_receivable_tax_type: Option<String>,
+
+    /// Default tax type used for purchases from the contact
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accounts_payable_tax_type: Option<String>,
+
+    /// Postal and physical addresses
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub addresses: Vec<Address>,
+
+    /// Phone numbers
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub phones: Vec<Phone>,
+
+    /// Additional people at the contact
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub contact_persons: Vec<ContactPerson>,
+
+    /// Default payment terms for bills and sales invoices
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_terms: Option<PaymentTerms>,
+
+    /// Default currency for documents raised against the contact
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_currency: Option<CurrencyCode>,
+
+    /// Bank details used for batch payments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_payments: Option<BatchPaymentDetails>,
+
+    /// Contact ID (for updates)
+    #[serde(rename = "ContactID", skip_serializing_if = "Option::is_none")]
+    pub contact_id: Option<Uuid>,
+}
+
+impl Builder {
+    /// Create a new contact builder
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the contact number
+    #[must_use]
+    pub fn with_contact_number(mut self, contact_number: impl Into<String>) -> Self {
+        self.contact_number = Some(contact_number.into());
+        self
+    }
+
+    /// Set the account number
+    #[must_use]
+    pub fn with_account_number(mut self, account_number: impl Into<String>) -> Self {
+        self.account_number = Some(account_number.into());
+        self
+    }
+
+    /// Set the contact status
+    #[must_use]
+    pub fn with_contact_status(mut self, status: Status) -> Self {
+        self.contact_status = Some(status);
+        self
+    }
+
+    /// Set the first name of the primary person
+    #[must_use]
+    pub fn with_first_name(mut self, first_name: impl Into<String>) -> Self {
+        self.first_name = Some(first_name.into());
+        self
+    }
+
+    /// Set the last name of the primary person
+    #[must_use]
+    pub fn with_last_name(mut self, last_name: impl Into<String>) -> Self {
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    /// Set the email address of the primary person
+    #[must_use]
+    pub fn with_email_address(mut self, email_address: impl Into<String>) -> Self {
+        self.email_address = Some(email_address.into());
+        self
+    }
+
+    /// Set the bank account details
+    #[must_use]
+    pub fn with_bank_account_details(mut self, details: impl Into<String>) -> Self {
+        self.bank_account_details = Some(details.into());
+        self
+    }
+
+    /// Set the tax number
+    #[must_use]
+    pub fn with_tax_number(mut self, tax_number: impl Into<String>) -> Self {
+        self.tax_number = Some(tax_number.into());
+        self
+    }
+
+    /// Set the default tax type for sales
+    #[must_use]
+    pub fn with_accounts_receivable_tax_type(mut self, tax_type: impl Into<String>) -> Self {
+        self.accounts_receivable_tax_type = Some(tax_type.into());
+        self
+    }
+
+    /// Set the default tax type for purchases
+    #[must_use]
+    pub fn with_accounts_payable_tax_type(mut self, tax_type: impl Into<String>) -> Self {
+        self.accounts_payable_tax_type = Some(tax_type.into());
+        self
+    }
+
+    /// Add an address
+    #[must_use]
+    pub fn with_address(mut self, address: Address) -> Self {
+        self.addresses.push(address);
+        self
+    }
+
+    /// Add a phone number
+    #[must_use]
+    pub fn with_phone(mut self, phone: Phone) -> Self {
+        self.phones.push(phone);
+        self
+    }
+
+    /// Add a contact person
+    #[must_use]
+    pub fn with_contact_person(mut self, person: ContactPerson) -> Self {
+        self.contact_persons.push(person);
+        self
+    }
+
+    /// Set the default payment terms
+    #[must_use]
+    pub fn with_payment_terms(mut self, payment_terms: PaymentTerms) -> Self {
+        self.payment_terms = Some(payment_terms);
+        self
+    }
+
+    /// Set the default currency
+    #[must_use]
+    pub fn with_default_currency(mut self, currency: CurrencyCode) -> Self {
+        self.default_currency = Some(currency);
+        self
+    }
+
+    /// Set the batch payment bank details
+    #[must_use]
+    pub fn with_batch_payments(mut self, batch_payments: BatchPaymentDetails) -> Self {
+        self.batch_payments = Some(batch_payments);
+        self
+    }
+
+    /// Set the contact ID (for updates)
+    #[must_use]
+    pub fn with_contact_id(mut self, id: Uuid) -> Self {
+        self.contact_id = Some(id);
+        self
+    }
+}
+
+/// Request wrapper for contacts
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ContactWrapper<'a> {
+    pub contacts: Vec<&'a Builder>,
+}
+
+/// Create one or more contacts
+pub async fn create(client: &Client, contacts: &[Builder]) -> Result<Vec<Contact>> {
+    let wrapper = ContactWrapper {
+        contacts: contacts.iter().collect(),
+    };
+
+    let response: MutationResponse = client
+        .put_endpoint(XeroEndpoint::Contacts, &wrapper)
+        .await?;
+
+    response.data.get_contacts().ok_or(Error::NotFound {
+        entity: "Contact".to_string(),
+        url: ENDPOINT.to_string(),
+        status_code: reqwest::StatusCode::NOT_FOUND,
+        response_body: Some("No contacts returned in response".to_string()),
+        span_trace: SpanTrace::capture(),
+    })
+}
+
+/// Create a single contact
+pub async fn create_single(client: &Client, contact: &Builder) -> Result<Contact> {
+    let contacts = create(client, std::slice::from_ref(contact)).await?;
+    contacts.into_iter().next().ok_or(Error::NotFound {
+        entity: "Contact".to_string(),
+        url: ENDPOINT.to_string(),
+        status_code: reqwest::StatusCode::NOT_FOUND,
+        response_body: Some("No contact returned in response".to_string()),
+        span_trace: SpanTrace::capture(),
+    })
+}
+
+/// Update or create one or more contacts
+///
+/// Contacts with a `contact_id` are updated, others are created.
+pub async fn update_or_create(client: &Client, contacts: &[Builder]) -> Result<Vec<Contact>> {
+    let wrapper = ContactWrapper {
+        contacts: contacts.iter().collect(),
+    };
+
+    let response: MutationResponse = client
+        .post_endpoint(XeroEndpoint::Contacts, &wrapper)
+        .await?;
+
+    response.data.get_contacts().ok_or(Error::NotFound {
+        entity: "Contact".to_string(),
+        url: ENDPOINT.to_string(),
+        status_code: reqwest::StatusCode::NOT_FOUND,
+        response_body: Some("No contacts returned in response".to_string()),
+        span_trace: SpanTrace::capture(),
+    })
+}
+
+/// Update a specific contact
+pub async fn update(client: &Client, contact_id: Uuid, contact: &Builder) -> Result<Contact> {
+    let mut contact_with_id = contact.clone();
+    contact_with_id.contact_id = Some(contact_id);
+
+    let wrapper = ContactWrapper {
+        contacts: vec![&contact_with_id],
+    };
+
+    let response: MutationResponse = client
+        .post_endpoint(XeroEndpoint::Contact(contact_id), &wrapper)
+        .await?;
+
+    response
+        .data
+        .get_contacts()
+        .and_then(|contacts| contacts.into_iter().next())
+        .ok_or(Error::NotFound {
+            entity: "Contact".to_string(),
+            url: format!("{ENDPOINT}{contact_id}"),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No contact returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Archive a contact
+///
+/// Xero does not allow contacts to be deleted; archiving hides them from lists
+/// and prevents new transactions from being raised against them.
+pub async fn archive(client: &Client, contact_id: Uuid) -> Result<Contact> {
+    let builder = Builder::default().with_contact_status(Status::Archived);
+    update(client, contact_id, &builder).await
+}
+
+/// Get history records for a contact
+pub async fn get_history(client: &Client, contact_id: Uuid) -> Result<Vec<HistoryRecord>> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "Contacts".to_string(),
+        contact_id.to_string(),
+        "History".to_string(),
+    ]);
+    let response: HistoryRecords = client.get_endpoint(endpoint, &()).await?;
+    Ok(response.history_records)
+}
+
+/// Create a history record for a specific contact
+#[instrument(skip(client))]
+pub async fn create_history(
+    client: &Client,
+    contact_id: Uuid,
+    details: &str,
+) -> Result<Vec<HistoryRecord>> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "Contacts".to_string(),
+        contact_id.to_string(),
+        "History".to_string(),
+    ]);
+
+    let request = HistoryRecordsRequest {
+        history_records: vec![HistoryRecord {
+            details: details.to_string(),
+            date_utc: None,
+            user: None,
+            changes: None,
+        }],
+    };
+
+    let response: HistoryRecords = client.put_endpoint(endpoint, &request).await?;
+    Ok(response.history_records)
+}
+
+/// List attachments for a contact
+pub async fn list_attachments(client: &Client, contact_id: Uuid) -> Result<Vec<Attachment>> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "Contacts".to_string(),
+        contact_id.to_string(),
+        "Attachments".to_string(),
+    ]);
+    let response: Attachments = client.get_endpoint(endpoint, &()).await?;
+    Ok(response.attachments)
+}
+
+/// Upload an attachment to a contact
+#[instrument(skip(client, attachment_content))]
+pub async fn upload_attachment(
+    client: &Client,
+    contact_id: Uuid,
+    filename: &str,
+    attachment_content: &[u8],
+) -> Result<Attachment> {
+    const MAX_ATTACHMENT_SIZE: usize = 25 * 1024 * 1024; // 25 MB
+
+    if filename.is_empty() {
+        return Err(Error::InvalidFilename);
+    }
+
+    let ext = Path::new(filename).extension().and_then(OsStr::to_str);
+
+    let content_type = match ext {
+        Some("pdf") => "application/pdf",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("txt") => "text/plain",
+        Some("csv") => "text/csv",
+        _ => "application/octet-stream",
+    };
+
+    if attachment_content.len() > MAX_ATTACHMENT_SIZE {
+        return Err(Error::AttachmentTooLarge);
+    }
+
+    let endpoint = XeroEndpoint::Custom(vec![
+        "Contacts".to_string(),
+        contact_id.to_string(),
+        "Attachments".to_string(),
+        filename.to_string(),
+    ]);
+
+    let url = endpoint.to_url()?;
+    let response = client
+        .build_request(reqwest::Method::PUT, url)
+        .await
+        .header(reqwest::header::CONTENT_TYPE, content_type)
+        .header(reqwest::header::CONTENT_LENGTH, attachment_content.len())
+        .body(attachment_content.to_vec())
+        .send()
+        .await?;
+
+    let status = response.status();
+
+    if status.is_success() {
+        let attachments: Attachments = response.json().await?;
+        attachments
+            .attachments
+            .into_iter()
+            .next()
+            .ok_or(Error::NotFound {
+                entity: "Contact Attachment".to_string(),
+                url: endpoint.to_string(),
+                status_code: status,
+                response_body: Some("No attachment was returned after upload".to_string()),
+                span_trace: SpanTrace::capture(),
+            })
+    } else {
+        Err(Error::NotFound {
+            entity: "Contact Attachment".to_string(),
+            url: endpoint.to_string(),
+            status_code: status,
+            response_body: Some(format!(
+                "Failed to upload attachment for contact with ID {contact_id}"
+            )),
+            span_trace: SpanTrace::capture(),
+        })
+    }
+}
+
+/// Get the Construction Industry Scheme (UK) settings of a contact
+pub async fn get_cis_settings(client: &Client, contact_id: Uuid) -> Result<CisSetting> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "Contacts".to_string(),
+        contact_id.to_string(),
+        "CISSettings".to_string(),
+    ]);
+    let response: CisSettingsResponse = client.get_endpoint(endpoint.clone(), &()).await?;
+    response
+        .cis_settings
+        .into_iter()
+        .next()
+        .ok_or(Error::NotFound {
+            entity: "CISSetting".to_string(),
+            url: endpoint.to_string(),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some(format!(
+                "No CIS settings returned for contact with ID {contact_id}"
+            )),
+            span_trace: SpanTrace::capture(),
+        })
+}
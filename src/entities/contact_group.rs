@@ -0,0 +1,262 @@
+use serde::{Deserialize, Serialize};
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    Client,
+    contact::{Contact, ContactIdentifier},
+    endpoints::XeroEndpoint,
+    entities::{EntityEndpoint, MutationResponse, endpoint_utils},
+    error::{Error, Result},
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/ContactGroups/";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    Active,
+    Deleted,
+}
+
+/// A named group of contacts
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContactGroup {
+    #[serde(rename = "ContactGroupID")]
+    pub contact_group_id: Uuid,
+    pub name: String,
+    pub status: Option<Status>,
+    /// Members of the group. Only populated when a single group is retrieved by ID
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contacts: Vec<Contact>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ListResponse {
+    pub contact_groups: Vec<ContactGroup>,
+}
+
+impl From<ListResponse> for Vec<ContactGroup> {
+    fn from(response: ListResponse) -> Self {
+        response.contact_groups
+    }
+}
+
+/// Parameters for filtering contact group list results
+#[derive(Debug, Serialize, Default)]
+pub struct ListParameters {
+    /// Filter by any element
+    #[serde(rename = "where", skip_serializing_if = "Option::is_none")]
+    pub r#where: Option<String>,
+
+    /// Order by any element
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+}
+
+impl ListParameters {
+    /// Create a new builder for `ListParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Set the where filter
+    #[must_use]
+    pub fn with_where(mut self, filter: impl Into<String>) -> Self {
+        self.r#where = Some(filter.into());
+        self
+    }
+
+    /// Set the order clause
+    #[must_use]
+    pub fn with_order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+}
+
+/// Builder for creating and updating contact groups
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct Builder {
+    /// Name of the group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Status of the group. Set to `Deleted` to delete it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+}
+
+impl Builder {
+    /// Create a new contact group builder
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            status: None,
+        }
+    }
+
+    /// Set the status
+    #[must_use]
+    pub fn with_status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// Request wrapper for contact groups
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ContactGroupWrapper<'a> {
+    pub contact_groups: Vec<&'a Builder>,
+}
+
+/// Request wrapper for contact group members
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ContactsWrapper {
+    pub contacts: Vec<ContactIdentifier>,
+}
+
+impl EntityEndpoint<ContactGroup, ListParameters> for ContactGroup {
+    fn endpoint() -> &'static str {
+        ENDPOINT
+    }
+
+    async fn get(client: &Client, id: Uuid) -> Result<ContactGroup> {
+        endpoint_utils::get::<ContactGroup, ListResponse>(client, ENDPOINT, id, "ContactGroup")
+            .await
+    }
+
+    async fn list(client: &Client, params: ListParameters) -> Result<Vec<ContactGroup>> {
+        endpoint_utils::list::<ContactGroup, ListResponse, ListParameters>(
+            client, ENDPOINT, &params,
+        )
+        .await
+    }
+}
+
+/// List contact groups with optional parameters
+///
+/// Only groups with an `ACTIVE` status are returned.
+pub async fn list(client: &Client, params: ListParameters) -> Result<Vec<ContactGroup>> {
+    ContactGroup::list(client, params).await
+}
+
+/// List all active contact groups
+pub async fn list_all(client: &Client) -> Result<Vec<ContactGroup>> {
+    list(client, ListParameters::default()).await
+}
+
+/// Get a single contact group, including its contacts
+pub async fn get(client: &Client, contact_group_id: Uuid) -> Result<ContactGroup> {
+    ContactGroup::get(client, contact_group_id).await
+}
+
+/// Create a new contact group
+pub async fn create(client: &Client, name: &str) -> Result<ContactGroup> {
+    let builder = Builder::new(name);
+    let wrapper = ContactGroupWrapper {
+        contact_groups: vec![&builder],
+    };
+
+    let response: MutationResponse = client
+        .put_endpoint(
+            XeroEndpoint::Custom(vec!["ContactGroups".to_string()]),
+            &wrapper,
+        )
+        .await?;
+
+    response
+        .data
+        .get_contact_groups()
+        .and_then(|groups| groups.into_iter().next())
+        .ok_or(Error::NotFound {
+            entity: "ContactGroup".to_string(),
+            url: ENDPOINT.to_string(),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No contact group returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Update a contact group
+pub async fn update(
+    client: &Client,
+    contact_group_id: Uuid,
+    contact_group: &Builder,
+) -> Result<ContactGroup> {
+    let wrapper = ContactGroupWrapper {
+        contact_groups: vec![contact_group],
+    };
+
+    let endpoint = XeroEndpoint::Custom(vec![format!("ContactGroups/{contact_group_id}")]);
+    let response: MutationResponse = client.post_endpoint(endpoint, &wrapper).await?;
+
+    response
+        .data
+        .get_contact_groups()
+        .and_then(|groups| groups.into_iter().next())
+        .ok_or(Error::NotFound {
+            entity: "ContactGroup".to_string(),
+            url: format!("{ENDPOINT}{contact_group_id}"),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No contact group returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Rename a contact group
+pub async fn rename(client: &Client, contact_group_id: Uuid, name: &str) -> Result<ContactGroup> {
+    update(client, contact_group_id, &Builder::new(name)).await
+}
+
+/// Delete a contact group
+///
+/// Xero deletes groups by setting their status to `DELETED`; the contacts themselves
+/// are not affected.
+pub async fn delete(client: &Client, contact_group_id: Uuid) -> Result<ContactGroup> {
+    let builder = Builder::default().with_status(Status::Deleted);
+    update(client, contact_group_id, &builder).await
+}
+
+/// Add contacts to a contact group
+pub async fn add_contacts(
+    client: &Client,
+    contact_group_id: Uuid,
+    contact_ids: &[Uuid],
+) -> Result<()> {
+    let wrapper = ContactsWrapper {
+        contacts: contact_ids
+            .iter()
+            .map(|id| ContactIdentifier::ID(*id))
+            .collect(),
+    };
+
+    let endpoint = XeroEndpoint::Custom(vec![format!("ContactGroups/{contact_group_id}/Contacts")]);
+    let _: serde_json::Value = client.put_endpoint(endpoint, &wrapper).await?;
+    Ok(())
+}
+
+/// Remove a single contact from a contact group
+pub async fn remove_contact(
+    client: &Client,
+    contact_group_id: Uuid,
+    contact_id: Uuid,
+) -> Result<()> {
+    let endpoint = XeroEndpoint::Custom(vec![format!(
+        "ContactGroups/{contact_group_id}/Contacts/{contact_id}"
+    )]);
+    client.delete_endpoint(endpoint).await
+}
+
+/// Remove all contacts from a contact group
+pub async fn remove_all_contacts(client: &Client, contact_group_id: Uuid) -> Result<()> {
+    let endpoint = XeroEndpoint::Custom(vec![format!("ContactGroups/{contact_group_id}/Contacts")]);
+    client.delete_endpoint(endpoint).await
+}
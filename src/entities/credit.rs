@@ -0,0 +1,291 @@
+//! Requests shared by overpayments and prepayments
+//!
+//! Both are credits held against a contact, and Xero lists, allocates, refunds and records
+//! the history of them the same way under their own collection name. Each entity module
+//! describes itself with a [`Credit`] and calls the functions here.
+
+use serde::{Deserialize, Serialize};
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    Client, UnitDp,
+    allocation::{self, Allocation, AllocationWrapper, AllocationsResponse},
+    endpoints::XeroEndpoint,
+    entities::EntityEndpoint,
+    error::{Error, Result},
+    invoice::Payment,
+    payment::{PaymentWrapper, PaymentsResponse, Refund, RefundRequest},
+    utils::serde_helpers::variant_name,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    Authorised,
+    Paid,
+    Voided,
+}
+
+/// Parameters for filtering overpayment and prepayment list results
+#[derive(Debug, Serialize, Default)]
+pub struct ListParameters {
+    /// Filter by any element
+    #[serde(rename = "where", skip_serializing_if = "Option::is_none")]
+    pub r#where: Option<String>,
+
+    /// Order by any element
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+
+    /// Page number, up to 100 credits are returned per page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// Number of records per page
+    #[serde(rename = "pageSize", skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<u32>,
+
+    /// Unit price decimal places for line item amounts.
+    ///
+    /// If not set, the client's `default_unitdp` will be used automatically.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unitdp: Option<UnitDp>,
+}
+
+impl ListParameters {
+    /// Create a new builder for `ListParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Set the where filter
+    #[must_use]
+    pub fn with_where(mut self, filter: impl Into<String>) -> Self {
+        self.r#where = Some(filter.into());
+        self
+    }
+
+    /// Only return credits with the given status
+    #[must_use]
+    pub fn with_status(self, status: Status) -> Self {
+        self.with_where(format!("Status==\"{}\"", variant_name(&status)))
+    }
+
+    /// Set the order clause
+    #[must_use]
+    pub fn with_order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
+    /// Set the page number
+    #[must_use]
+    pub fn with_page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Set the number of records per page
+    #[must_use]
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set the unit decimal places
+    #[must_use]
+    pub fn with_unitdp(mut self, unitdp: UnitDp) -> Self {
+        self.unitdp = Some(unitdp);
+        self
+    }
+}
+
+/// History record for an overpayment or prepayment
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecord {
+    /// The details of the history record
+    pub details: String,
+
+    /// The date and time of the history record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_utc: Option<String>,
+
+    /// The user who created the history record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+
+    /// The changes made
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<String>,
+}
+
+/// Wrapper for history records response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecords {
+    pub history_records: Vec<HistoryRecord>,
+}
+
+/// Wrapper for posting history records
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecordsRequest {
+    pub history_records: Vec<HistoryRecord>,
+}
+
+/// The kind of credit a request is made against
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Credit {
+    /// Entity name, e.g. `Overpayment`
+    pub name: &'static str,
+    /// Collection name used in the endpoint path, e.g. `Overpayments`
+    pub collection: &'static str,
+}
+
+impl Credit {
+    fn endpoint(self, credit_id: Uuid, path: &[String]) -> XeroEndpoint {
+        let mut segments = vec![self.collection.to_string(), credit_id.to_string()];
+        segments.extend_from_slice(path);
+        XeroEndpoint::Custom(segments)
+    }
+
+    /// The credit a refund is made against, e.g. `{"Overpayment": {"OverpaymentID": ..}}`
+    fn refund_target(self, credit_id: Uuid) -> serde_json::Value {
+        serde_json::json!({ self.name: { format!("{}ID", self.name): credit_id } })
+    }
+}
+
+/// List credits of type `T`, using the client's `default_unitdp` when none is set
+pub(crate) async fn list<T>(client: &Client, mut params: ListParameters) -> Result<Vec<T>>
+where
+    T: EntityEndpoint<T, ListParameters>,
+{
+    if params.unitdp.is_none() {
+        params.unitdp = client.default_unitdp();
+    }
+    T::list(client, params).await
+}
+
+/// Allocate part of a credit to one or more invoices
+pub(crate) async fn allocate(
+    client: &Client,
+    credit: Credit,
+    credit_id: Uuid,
+    allocations: &[allocation::Builder],
+) -> Result<Vec<Allocation>> {
+    let endpoint = credit.endpoint(credit_id, &["Allocations".to_string()]);
+    let wrapper = AllocationWrapper { allocations };
+    let response: AllocationsResponse = client.put_endpoint(endpoint, &wrapper).await?;
+    Ok(response.allocations)
+}
+
+/// Delete an allocation, returning the allocated amount to the credit
+pub(crate) async fn delete_allocation(
+    client: &Client,
+    credit: Credit,
+    credit_id: Uuid,
+    allocation_id: Uuid,
+) -> Result<()> {
+    let endpoint = credit.endpoint(
+        credit_id,
+        &["Allocations".to_string(), allocation_id.to_string()],
+    );
+    client.delete_endpoint(endpoint).await
+}
+
+/// Refund the remaining credit as a cash payment
+pub(crate) async fn refund(
+    client: &Client,
+    credit: Credit,
+    credit_id: Uuid,
+    refund: &Refund,
+) -> Result<Payment> {
+    let wrapper = PaymentWrapper {
+        payments: vec![RefundRequest {
+            target: credit.refund_target(credit_id),
+            refund,
+        }],
+    };
+    let response: PaymentsResponse = client
+        .put_endpoint(XeroEndpoint::Custom(vec!["Payments".to_string()]), &wrapper)
+        .await?;
+
+    response.payments.into_iter().next().ok_or(Error::NotFound {
+        entity: "Payment".to_string(),
+        url: crate::payment::ENDPOINT.to_string(),
+        status_code: reqwest::StatusCode::NOT_FOUND,
+        response_body: Some("No payment returned in response".to_string()),
+        span_trace: SpanTrace::capture(),
+    })
+}
+
+/// Get the history records of a credit
+pub(crate) async fn get_history(
+    client: &Client,
+    credit: Credit,
+    credit_id: Uuid,
+) -> Result<Vec<HistoryRecord>> {
+    let endpoint = credit.endpoint(credit_id, &["History".to_string()]);
+    let response: HistoryRecords = client.get_endpoint(endpoint, &()).await?;
+    Ok(response.history_records)
+}
+
+/// Add a history record to a credit
+pub(crate) async fn create_history(
+    client: &Client,
+    credit: Credit,
+    credit_id: Uuid,
+    details: &str,
+) -> Result<Vec<HistoryRecord>> {
+    let endpoint = credit.endpoint(credit_id, &["History".to_string()]);
+
+    let request = HistoryRecordsRequest {
+        history_records: vec![HistoryRecord {
+            details: details.to_string(),
+            date_utc: None,
+            user: None,
+            changes: None,
+        }],
+    };
+
+    let response: HistoryRecords = client.put_endpoint(endpoint, &request).await?;
+    Ok(response.history_records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::payment::AccountIdentifier;
+    use rust_decimal_macros::dec;
+    use time::macros::date;
+
+    #[test]
+    fn refund_request_names_the_credit() {
+        let credit = Credit {
+            name: "Prepayment",
+            collection: "Prepayments",
+        };
+        let refund = Refund::new(
+            AccountIdentifier::Code("090".to_string()),
+            date!(2024 - 03 - 12),
+            dec!(80.00),
+        );
+        let request = RefundRequest {
+            target: credit.refund_target(Uuid::nil()),
+            refund: &refund,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&request).expect("serialization should succeed"),
+            serde_json::json!({
+                "Prepayment": { "PrepaymentID": "00000000-0000-0000-0000-000000000000" },
+                "Account": { "Code": "090" },
+                "Date": "2024-03-12",
+                "Amount": "80.00"
+            })
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use std::{borrow::Cow, fmt, str::FromStr};
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use tracing_error::SpanTrace;
+
+use crate::{
+    Client,
+    endpoints::XeroEndpoint,
+    entities::MutationResponse,
+    error::{Error, Result},
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/Currencies/";
+
+/// An ISO 4217 currency code (e.g. `AUD`, `NZD`, `USD`).
+///
+/// Codes parsed with [`FromStr`] are validated to be three ASCII letters and are
+/// normalised to uppercase. Codes deserialized from Xero responses are accepted
+/// as-is, so an unexpected value never breaks deserialization of a document.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CurrencyCode(Cow<'static, str>);
+
+impl CurrencyCode {
+    pub const AUD: Self = Self(Cow::Borrowed("AUD"));
+    pub const CAD: Self = Self(Cow::Borrowed("CAD"));
+    pub const CHF: Self = Self(Cow::Borrowed("CHF"));
+    pub const CNY: Self = Self(Cow::Borrowed("CNY"));
+    pub const EUR: Self = Self(Cow::Borrowed("EUR"));
+    pub const GBP: Self = Self(Cow::Borrowed("GBP"));
+    pub const HKD: Self = Self(Cow::Borrowed("HKD"));
+    pub const JPY: Self = Self(Cow::Borrowed("JPY"));
+    pub const NZD: Self = Self(Cow::Borrowed("NZD"));
+    pub const SGD: Self = Self(Cow::Borrowed("SGD"));
+    pub const USD: Self = Self(Cow::Borrowed("USD"));
+    pub const ZAR: Self = Self(Cow::Borrowed("ZAR"));
+
+    /// The three letter code as a string slice
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for CurrencyCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let code = s.trim();
+        if code.len() == 3 && code.chars().all(|c| c.is_ascii_alphabetic()) {
+            Ok(Self(Cow::Owned(code.to_ascii_uppercase())))
+        } else {
+            Err(Error::InvalidCurrencyCode(s.to_string()))
+        }
+    }
+}
+
+impl TryFrom<&str> for CurrencyCode {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for CurrencyCode {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Convert an amount in a document's currency into the organisation's base currency.
+///
+/// Xero expresses `CurrencyRate` as units of the foreign currency per one unit of the
+/// base currency, so the base amount is `amount / currency_rate`. A missing or zero
+/// rate is treated as `1` (the document is already in the base currency). The result
+/// is rounded to two decimal places, matching how Xero reports base currency totals.
+#[must_use]
+pub fn to_base_currency(amount: Decimal, currency_rate: Option<Decimal>) -> Decimal {
+    match currency_rate {
+        Some(rate) if !rate.is_zero() => {
+            (amount / rate).round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero)
+        }
+        _ => amount,
+    }
+}
+
+/// A currency enabled for the organisation
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Currency {
+    /// The ISO 4217 currency code
+    pub code: CurrencyCode,
+
+    /// Name of the currency (e.g. "United States Dollar")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Response wrapper for listing currencies
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ListResponse {
+    pub currencies: Vec<Currency>,
+}
+
+impl From<ListResponse> for Vec<Currency> {
+    fn from(response: ListResponse) -> Self {
+        response.currencies
+    }
+}
+
+/// Parameters for listing currencies
+#[derive(Debug, Serialize, Default)]
+pub struct ListParameters {
+    /// Filter by any element
+    #[serde(rename = "where", skip_serializing_if = "Option::is_none")]
+    pub r#where: Option<String>,
+
+    /// Order by any element
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+}
+
+impl ListParameters {
+    /// Create a new builder for `ListParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Set the where filter
+    #[must_use]
+    pub fn with_where(mut self, filter: impl Into<String>) -> Self {
+        self.r#where = Some(filter.into());
+        self
+    }
+
+    /// Set the order clause
+    #[must_use]
+    pub fn with_order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
+    /// Filter by currency code
+    #[must_use]
+    pub fn with_code(self, code: &CurrencyCode) -> Self {
+        self.with_where(format!("Code==\"{code}\""))
+    }
+}
+
+/// List currencies with optional parameters
+pub async fn list(client: &Client, params: ListParameters) -> Result<Vec<Currency>> {
+    let response: ListResponse = client
+        .get_endpoint(XeroEndpoint::Currencies, &params)
+        .await?;
+    Ok(response.currencies)
+}
+
+/// List all currencies without any filtering
+pub async fn list_all(client: &Client) -> Result<Vec<Currency>> {
+    list(client, ListParameters::default()).await
+}
+
+/// Enable a new currency for the organisation
+pub async fn create(client: &Client, currency: &Currency) -> Result<Currency> {
+    let response: MutationResponse = client
+        .put_endpoint(XeroEndpoint::Currencies, currency)
+        .await?;
+
+    response
+        .data
+        .get_currencies()
+        .and_then(|currencies| currencies.into_iter().next())
+        .ok_or(Error::NotFound {
+            entity: "Currency".to_string(),
+            url: ENDPOINT.to_string(),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No currency returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
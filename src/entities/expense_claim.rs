@@ -0,0 +1,403 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    Client,
+    endpoints::XeroEndpoint,
+    entities::{EntityEndpoint, MutationResponse, endpoint_utils},
+    error::{Error, Result},
+    invoice::Payment,
+    receipt::Receipt,
+    user::{User, UserReference},
+    utils::{
+        date_format::{xero_date_format_option, xero_datetime_format_option},
+        serde_helpers::variant_name,
+    },
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/ExpenseClaims/";
+
+/// Status of an expense claim
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    Submitted,
+    Authorised,
+    Paid,
+    Voided,
+    Deleted,
+}
+
+impl Status {
+    /// Whether a claim in this status can be moved to `next` through the API
+    ///
+    /// Submitted claims can be authorised or voided, and authorised claims can be voided.
+    /// Claims become `Paid` by applying payments rather than by changing their status.
+    #[must_use]
+    pub fn can_transition_to(self, next: Status) -> bool {
+        matches!(
+            (self, next),
+            (Status::Submitted, Status::Authorised | Status::Voided)
+                | (Status::Authorised, Status::Voided)
+        )
+    }
+}
+
+/// A claim by a user for reimbursement of one or more receipts
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExpenseClaim {
+    #[serde(rename = "ExpenseClaimID")]
+    pub expense_claim_id: Uuid,
+    pub status: Status,
+    #[serde(default)]
+    pub user: Option<User>,
+    #[serde(default)]
+    pub receipts: Vec<Receipt>,
+    #[serde(default)]
+    pub payments: Vec<Payment>,
+    #[serde(
+        rename = "UpdatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub total: Decimal,
+    #[serde(default)]
+    pub amount_due: Decimal,
+    #[serde(default)]
+    pub amount_paid: Decimal,
+    #[serde(default, with = "xero_date_format_option")]
+    pub payment_due_date: Option<Date>,
+    /// The date the claim is reported against in the accounts
+    #[serde(default, with = "xero_date_format_option")]
+    pub reporting_date: Option<Date>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ListResponse {
+    pub expense_claims: Vec<ExpenseClaim>,
+}
+
+impl From<ListResponse> for Vec<ExpenseClaim> {
+    fn from(response: ListResponse) -> Self {
+        response.expense_claims
+    }
+}
+
+/// Parameters for filtering expense claim list results
+#[derive(Debug, Serialize, Default)]
+pub struct ListParameters {
+    /// Filter by any element
+    #[serde(rename = "where", skip_serializing_if = "Option::is_none")]
+    pub r#where: Option<String>,
+
+    /// Order by any element
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+}
+
+impl ListParameters {
+    /// Create a new builder for `ListParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Set the where filter
+    #[must_use]
+    pub fn with_where(mut self, filter: impl Into<String>) -> Self {
+        self.r#where = Some(filter.into());
+        self
+    }
+
+    /// Set the order clause
+    #[must_use]
+    pub fn with_order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+}
+
+/// Reference to a receipt included in an expense claim
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct ReceiptReference {
+    #[serde(rename = "ReceiptID")]
+    pub receipt_id: Uuid,
+}
+
+/// Builder for submitting expense claims
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Builder {
+    /// The user claiming the expenses. Must be the user the receipts belong to
+    pub user: UserReference,
+    pub receipts: Vec<ReceiptReference>,
+}
+
+impl Builder {
+    /// Create a claim for the given draft receipts
+    #[must_use]
+    pub fn new(
+        user: impl Into<UserReference>,
+        receipt_ids: impl IntoIterator<Item = Uuid>,
+    ) -> Self {
+        Self {
+            user: user.into(),
+            receipts: receipt_ids
+                .into_iter()
+                .map(|receipt_id| ReceiptReference { receipt_id })
+                .collect(),
+        }
+    }
+
+    /// Add a receipt to the claim
+    #[must_use]
+    pub fn with_receipt(mut self, receipt_id: Uuid) -> Self {
+        self.receipts.push(ReceiptReference { receipt_id });
+        self
+    }
+}
+
+/// Request wrapper for expense claims
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ExpenseClaimWrapper<'a> {
+    pub expense_claims: Vec<&'a Builder>,
+}
+
+/// Request body for changing the status of an expense claim
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct StatusUpdate {
+    #[serde(rename = "ExpenseClaimID")]
+    pub expense_claim_id: Uuid,
+    pub status: Status,
+}
+
+/// Request wrapper for expense claim status changes
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct StatusUpdateWrapper {
+    pub expense_claims: Vec<StatusUpdate>,
+}
+
+/// History record for an expense claim
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecord {
+    /// The details of the history record
+    pub details: String,
+
+    /// The date and time of the history record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_utc: Option<String>,
+
+    /// The user who created the history record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+
+    /// The changes made
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<String>,
+}
+
+/// Wrapper for history records response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecords {
+    pub history_records: Vec<HistoryRecord>,
+}
+
+/// Wrapper for posting history records
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecordsRequest {
+    pub history_records: Vec<HistoryRecord>,
+}
+
+impl EntityEndpoint<ExpenseClaim, ListParameters> for ExpenseClaim {
+    fn endpoint() -> &'static str {
+        ENDPOINT
+    }
+
+    async fn get(client: &Client, id: Uuid) -> Result<ExpenseClaim> {
+        endpoint_utils::get::<ExpenseClaim, ListResponse>(client, ENDPOINT, id, "ExpenseClaim")
+            .await
+    }
+
+    async fn list(client: &Client, params: ListParameters) -> Result<Vec<ExpenseClaim>> {
+        endpoint_utils::list::<ExpenseClaim, ListResponse, _>(client, ENDPOINT, &params).await
+    }
+}
+
+/// Retrieve a list of expense claims with filtering
+#[instrument(skip(client))]
+pub async fn list(client: &Client, params: ListParameters) -> Result<Vec<ExpenseClaim>> {
+    ExpenseClaim::list(client, params).await
+}
+
+/// Retrieve a list of all expense claims without filtering
+#[instrument(skip(client))]
+pub async fn list_all(client: &Client) -> Result<Vec<ExpenseClaim>> {
+    list(client, ListParameters::default()).await
+}
+
+/// Retrieve a single expense claim by ID
+#[instrument(skip(client))]
+pub async fn get(client: &Client, expense_claim_id: Uuid) -> Result<ExpenseClaim> {
+    ExpenseClaim::get(client, expense_claim_id).await
+}
+
+/// Submit an expense claim for one or more draft receipts
+#[instrument(skip(client, expense_claim))]
+pub async fn create(client: &Client, expense_claim: &Builder) -> Result<ExpenseClaim> {
+    let wrapper = ExpenseClaimWrapper {
+        expense_claims: vec![expense_claim],
+    };
+
+    let response: MutationResponse = client
+        .put_endpoint(
+            XeroEndpoint::Custom(vec!["ExpenseClaims".to_string()]),
+            &wrapper,
+        )
+        .await?;
+
+    response
+        .data
+        .get_expense_claims()
+        .and_then(|claims| claims.into_iter().next())
+        .ok_or(Error::NotFound {
+            entity: "ExpenseClaim".to_string(),
+            url: ENDPOINT.to_string(),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No expense claim returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Change the status of an expense claim
+///
+/// The claim is fetched first, and [`Error::InvalidStatusTransition`] is returned without
+/// writing anything unless its current status can move to `status`; see
+/// [`Status::can_transition_to`] for the transitions Xero allows.
+#[instrument(skip(client))]
+pub async fn update_status(
+    client: &Client,
+    expense_claim_id: Uuid,
+    status: Status,
+) -> Result<ExpenseClaim> {
+    let current = ExpenseClaim::get(client, expense_claim_id).await?;
+    if !current.status.can_transition_to(status) {
+        return Err(Error::InvalidStatusTransition {
+            entity: "ExpenseClaim".to_string(),
+            from: variant_name(&current.status),
+            to: variant_name(&status),
+        });
+    }
+
+    let wrapper = StatusUpdateWrapper {
+        expense_claims: vec![StatusUpdate {
+            expense_claim_id,
+            status,
+        }],
+    };
+
+    let endpoint = XeroEndpoint::Custom(vec![
+        "ExpenseClaims".to_string(),
+        expense_claim_id.to_string(),
+    ]);
+    let response: MutationResponse = client.post_endpoint(endpoint, &wrapper).await?;
+
+    response
+        .data
+        .get_expense_claims()
+        .and_then(|claims| claims.into_iter().next())
+        .ok_or(Error::NotFound {
+            entity: "ExpenseClaim".to_string(),
+            url: format!("{ENDPOINT}{expense_claim_id}"),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No expense claim returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Authorise a submitted expense claim
+pub async fn authorise(client: &Client, expense_claim_id: Uuid) -> Result<ExpenseClaim> {
+    update_status(client, expense_claim_id, Status::Authorised).await
+}
+
+/// Void an expense claim
+pub async fn void(client: &Client, expense_claim_id: Uuid) -> Result<ExpenseClaim> {
+    update_status(client, expense_claim_id, Status::Voided).await
+}
+
+/// Get history records for an expense claim
+#[instrument(skip(client))]
+pub async fn get_history(client: &Client, expense_claim_id: Uuid) -> Result<Vec<HistoryRecord>> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "ExpenseClaims".to_string(),
+        expense_claim_id.to_string(),
+        "History".to_string(),
+    ]);
+    let response: HistoryRecords = client.get_endpoint(endpoint, &()).await?;
+    Ok(response.history_records)
+}
+
+/// Create a history record for an expense claim
+#[instrument(skip(client))]
+pub async fn create_history(
+    client: &Client,
+    expense_claim_id: Uuid,
+    details: &str,
+) -> Result<Vec<HistoryRecord>> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "ExpenseClaims".to_string(),
+        expense_claim_id.to_string(),
+        "History".to_string(),
+    ]);
+
+    let request = HistoryRecordsRequest {
+        history_records: vec![HistoryRecord {
+            details: details.to_string(),
+            date_utc: None,
+            user: None,
+            changes: None,
+        }],
+    };
+
+    let response: HistoryRecords = client.put_endpoint(endpoint, &request).await?;
+    Ok(response.history_records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_update_includes_the_claim_id() {
+        let expense_claim_id = Uuid::parse_str("b2bb8a39-66b7-4a74-a2a4-1b7e5a1f0e3c").unwrap();
+        let wrapper = StatusUpdateWrapper {
+            expense_claims: vec![StatusUpdate {
+                expense_claim_id,
+                status: Status::Authorised,
+            }],
+        };
+
+        let json = serde_json::to_value(&wrapper).expect("serialization should succeed");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "ExpenseClaims": [{
+                    "ExpenseClaimID": "b2bb8a39-66b7-4a74-a2a4-1b7e5a1f0e3c",
+                    "Status": "AUTHORISED",
+                }]
+            })
+        );
+    }
+}
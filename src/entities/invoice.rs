@@ -11,10 +11,12 @@ use uuid::Uuid;
 use crate::{
     Client, UnitDp,
     contact::{Contact, ContactIdentifier},
+    currency::{self, CurrencyCode},
     endpoints::XeroEndpoint,
     entities::{EntityEndpoint, MutationResponse, endpoint_utils},
     error::{Error, Result},
     line_item::{LineAmountType, LineItem},
+    overpayment, prepayment,
     utils::date_format::{xero_date_format, xero_date_format_option, xero_datetime_format},
 };
 
@@ -81,9 +83,9 @@ pub struct Prepayment {
     pub prepayment_number: Option<String>,
     pub reference: Option<String>,
     pub applied_amount: Decimal,
-    pub currency_code: String,
+    pub currency_code: CurrencyCode,
     pub currency_rate: Option<Decimal>,
-    pub status: String,
+    pub status: prepayment::Status,
     pub sub_total: Decimal,
     pub total_tax: Decimal,
     pub total: Decimal,
@@ -98,9 +100,9 @@ pub struct Overpayment {
     pub overpayment_number: Option<String>,
     pub reference: Option<String>,
     pub applied_amount: Decimal,
-    pub currency_code: String,
+    pub currency_code: CurrencyCode,
     pub currency_rate: Option<Decimal>,
-    pub status: String,
+    pub status: overpayment::Status,
     pub sub_total: Decimal,
     pub total_tax: Decimal,
     pub total: Decimal,
@@ -125,7 +127,7 @@ pub struct Invoice {
     pub total_discount: Option<Decimal>,
     #[serde(rename = "UpdatedDateUTC", with = "xero_datetime_format")]
     pub updated_date_utc: OffsetDateTime,
-    pub currency_code: String,
+    pub currency_code: CurrencyCode,
     pub currency_rate: Option<Decimal>,
     #[serde(rename = "InvoiceID")]
     pub invoice_id: Uuid,
@@ -191,6 +193,36 @@ impl Invoice {
             _ => None,
         }
     }
+
+    /// Sub total converted into the organisation's base currency
+    #[must_use]
+    pub fn sub_total_in_base_currency(&self) -> Decimal {
+        currency::to_base_currency(self.sub_total, self.currency_rate)
+    }
+
+    /// Total tax converted into the organisation's base currency
+    #[must_use]
+    pub fn total_tax_in_base_currency(&self) -> Decimal {
+        currency::to_base_currency(self.total_tax, self.currency_rate)
+    }
+
+    /// Total converted into the organisation's base currency
+    #[must_use]
+    pub fn total_in_base_currency(&self) -> Decimal {
+        currency::to_base_currency(self.total, self.currency_rate)
+    }
+
+    /// Amount due converted into the organisation's base currency
+    #[must_use]
+    pub fn amount_due_in_base_currency(&self) -> Decimal {
+        currency::to_base_currency(self.amount_due, self.currency_rate)
+    }
+
+    /// Amount paid converted into the organisation's base currency
+    #[must_use]
+    pub fn amount_paid_in_base_currency(&self) -> Decimal {
+        currency::to_base_currency(self.amount_paid, self.currency_rate)
+    }
 }
 
 #[derive(Deserialize)]
@@ -421,7 +453,7 @@ pub struct Builder {
     #[serde(rename = "BrandingThemeID")]
     pub branding_theme_id: Option<Uuid>,
     pub url: Option<Url>,
-    pub currency_code: Option<String>,
+    pub currency_code: Option<CurrencyCode>,
     pub currency_rate: Option<Decimal>,
     pub status: Option<Status>,
     pub sent_to_contact: Option<bool>,
@@ -0,0 +1,335 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    Client,
+    endpoints::XeroEndpoint,
+    entities::{EntityEndpoint, endpoint_utils},
+    error::{Error, Result},
+    line_item::LineItem,
+    utils::date_format::xero_datetime_format_option,
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/LinkedTransactions/";
+
+/// Status of a linked transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    /// The source transaction is approved but not yet billed to the customer
+    Approved,
+    /// The source transaction is still a draft
+    Draft,
+    /// Linked to a draft sales invoice
+    OnDraft,
+    /// Linked to an approved sales invoice
+    Billed,
+    /// The source transaction has been voided
+    Voided,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Type {
+    BillableExpense,
+}
+
+/// The type of transaction a billable expense originates from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SourceTransactionType {
+    /// A bill (ACCPAY invoice)
+    AccPay,
+    /// A spend money bank transaction
+    Spend,
+}
+
+/// A billable expense linking a purchase line item to the customer it is re-billed to
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LinkedTransaction {
+    #[serde(rename = "LinkedTransactionID")]
+    pub linked_transaction_id: Uuid,
+    /// The bill or spend money transaction the expense comes from
+    #[serde(rename = "SourceTransactionID")]
+    pub source_transaction_id: Uuid,
+    /// The `LineItem::id` of the expense on the source transaction
+    #[serde(rename = "SourceLineItemID")]
+    pub source_line_item_id: Uuid,
+    /// The customer the expense is billable to
+    #[serde(rename = "ContactID", default)]
+    pub contact_id: Option<Uuid>,
+    /// The sales invoice the expense has been billed on
+    #[serde(rename = "TargetTransactionID", default)]
+    pub target_transaction_id: Option<Uuid>,
+    /// The `LineItem::id` on the sales invoice
+    #[serde(rename = "TargetLineItemID", default)]
+    pub target_line_item_id: Option<Uuid>,
+    pub status: Status,
+    #[serde(default)]
+    pub r#type: Option<Type>,
+    #[serde(default)]
+    pub source_transaction_type_code: Option<SourceTransactionType>,
+    #[serde(
+        rename = "UpdatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ListResponse {
+    #[serde(default)]
+    pub linked_transactions: Vec<LinkedTransaction>,
+}
+
+impl From<ListResponse> for Vec<LinkedTransaction> {
+    fn from(response: ListResponse) -> Self {
+        response.linked_transactions
+    }
+}
+
+/// Parameters for filtering linked transaction list results
+#[derive(Debug, Serialize, Default)]
+pub struct ListParameters {
+    /// Page number, up to 100 linked transactions are returned per page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// Only return links from this bill or spend money transaction
+    #[serde(
+        rename = "SourceTransactionID",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub source_transaction_id: Option<Uuid>,
+
+    /// Only return links to this sales invoice
+    #[serde(
+        rename = "TargetTransactionID",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub target_transaction_id: Option<Uuid>,
+
+    /// Only return links billable to this customer
+    #[serde(rename = "ContactID", skip_serializing_if = "Option::is_none")]
+    pub contact_id: Option<Uuid>,
+
+    /// Only return links with this status
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+}
+
+impl ListParameters {
+    /// Create a new builder for `ListParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Set the page number
+    #[must_use]
+    pub fn with_page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Filter by source transaction
+    #[must_use]
+    pub fn with_source_transaction_id(mut self, source_transaction_id: Uuid) -> Self {
+        self.source_transaction_id = Some(source_transaction_id);
+        self
+    }
+
+    /// Filter by target sales invoice
+    #[must_use]
+    pub fn with_target_transaction_id(mut self, target_transaction_id: Uuid) -> Self {
+        self.target_transaction_id = Some(target_transaction_id);
+        self
+    }
+
+    /// Filter by customer
+    #[must_use]
+    pub fn with_contact_id(mut self, contact_id: Uuid) -> Self {
+        self.contact_id = Some(contact_id);
+        self
+    }
+
+    /// Filter by status
+    #[must_use]
+    pub fn with_status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// Builder for creating and updating linked transactions
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct Builder {
+    #[serde(
+        rename = "SourceTransactionID",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub source_transaction_id: Option<Uuid>,
+    #[serde(rename = "SourceLineItemID", skip_serializing_if = "Option::is_none")]
+    pub source_line_item_id: Option<Uuid>,
+    #[serde(rename = "ContactID", skip_serializing_if = "Option::is_none")]
+    pub contact_id: Option<Uuid>,
+    #[serde(
+        rename = "TargetTransactionID",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub target_transaction_id: Option<Uuid>,
+    #[serde(rename = "TargetLineItemID", skip_serializing_if = "Option::is_none")]
+    pub target_line_item_id: Option<Uuid>,
+}
+
+impl Builder {
+    /// Mark a line item on a bill or spend money transaction as a billable expense
+    #[must_use]
+    pub fn new(source_transaction_id: Uuid, source_line_item_id: Uuid) -> Self {
+        Self {
+            source_transaction_id: Some(source_transaction_id),
+            source_line_item_id: Some(source_line_item_id),
+            ..Self::default()
+        }
+    }
+
+    /// Mark `line_item` on the given bill or spend money transaction as a billable expense
+    #[must_use]
+    pub fn from_line_item(source_transaction_id: Uuid, line_item: &LineItem) -> Self {
+        Self::new(source_transaction_id, line_item.id)
+    }
+
+    /// Make the expense billable to a customer
+    #[must_use]
+    pub fn with_contact_id(mut self, contact_id: Uuid) -> Self {
+        self.contact_id = Some(contact_id);
+        self
+    }
+
+    /// Bill the expense on a line item of an ACCREC invoice
+    #[must_use]
+    pub fn with_target(mut self, target_transaction_id: Uuid, target_line_item_id: Uuid) -> Self {
+        self.target_transaction_id = Some(target_transaction_id);
+        self.target_line_item_id = Some(target_line_item_id);
+        self
+    }
+}
+
+impl EntityEndpoint<LinkedTransaction, ListParameters> for LinkedTransaction {
+    fn endpoint() -> &'static str {
+        ENDPOINT
+    }
+
+    async fn get(client: &Client, id: Uuid) -> Result<LinkedTransaction> {
+        endpoint_utils::get::<LinkedTransaction, ListResponse>(
+            client,
+            ENDPOINT,
+            id,
+            "LinkedTransaction",
+        )
+        .await
+    }
+
+    async fn list(client: &Client, params: ListParameters) -> Result<Vec<LinkedTransaction>> {
+        endpoint_utils::list::<LinkedTransaction, ListResponse, _>(client, ENDPOINT, &params).await
+    }
+}
+
+/// Retrieve a list of linked transactions with filtering
+#[instrument(skip(client))]
+pub async fn list(client: &Client, params: ListParameters) -> Result<Vec<LinkedTransaction>> {
+    LinkedTransaction::list(client, params).await
+}
+
+/// Retrieve a list of all linked transactions without filtering
+#[instrument(skip(client))]
+pub async fn list_all(client: &Client) -> Result<Vec<LinkedTransaction>> {
+    list(client, ListParameters::default()).await
+}
+
+/// Retrieve a single linked transaction by ID
+#[instrument(skip(client))]
+pub async fn get(client: &Client, linked_transaction_id: Uuid) -> Result<LinkedTransaction> {
+    LinkedTransaction::get(client, linked_transaction_id).await
+}
+
+/// Create a linked transaction
+///
+/// The source must be an approved or draft ACCPAY invoice or spend money transaction, and
+/// either a contact or a target ACCREC invoice line item must be set.
+#[instrument(skip(client, linked_transaction))]
+pub async fn create(client: &Client, linked_transaction: &Builder) -> Result<LinkedTransaction> {
+    let response: ListResponse = client
+        .put_endpoint(
+            XeroEndpoint::Custom(vec!["LinkedTransactions".to_string()]),
+            linked_transaction,
+        )
+        .await?;
+
+    response
+        .linked_transactions
+        .into_iter()
+        .next()
+        .ok_or(Error::NotFound {
+            entity: "LinkedTransaction".to_string(),
+            url: ENDPOINT.to_string(),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No linked transaction returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Update a linked transaction, e.g. to bill it on a sales invoice
+#[instrument(skip(client, linked_transaction))]
+pub async fn update(
+    client: &Client,
+    linked_transaction_id: Uuid,
+    linked_transaction: &Builder,
+) -> Result<LinkedTransaction> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "LinkedTransactions".to_string(),
+        linked_transaction_id.to_string(),
+    ]);
+    let response: ListResponse = client.post_endpoint(endpoint, linked_transaction).await?;
+
+    response
+        .linked_transactions
+        .into_iter()
+        .next()
+        .ok_or(Error::NotFound {
+            entity: "LinkedTransaction".to_string(),
+            url: format!("{ENDPOINT}{linked_transaction_id}"),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No linked transaction returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Bill an existing linked transaction on a line item of an ACCREC invoice
+pub async fn link_to_invoice(
+    client: &Client,
+    linked_transaction_id: Uuid,
+    invoice_id: Uuid,
+    line_item_id: Uuid,
+) -> Result<LinkedTransaction> {
+    let builder = Builder::default().with_target(invoice_id, line_item_id);
+    update(client, linked_transaction_id, &builder).await
+}
+
+/// Delete a linked transaction
+///
+/// Only links that have not been billed on an approved sales invoice can be deleted.
+#[instrument(skip(client))]
+pub async fn delete(client: &Client, linked_transaction_id: Uuid) -> Result<()> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "LinkedTransactions".to_string(),
+        linked_transaction_id.to_string(),
+    ]);
+    client.delete_endpoint(endpoint).await
+}
@@ -2,19 +2,40 @@ use serde::Deserialize;
 use uuid::Uuid;
 
 use self::{
-    account::Account, contact::Contact, invoice::Invoice, item::Item,
-    purchase_order::PurchaseOrder, quote::Quote, timesheet::Timesheet,
+    account::Account, accounting_employee::AccountingEmployee, contact::Contact,
+    contact_group::ContactGroup, currency::Currency, expense_claim::ExpenseClaim, invoice::Invoice,
+    item::Item, purchase_order::PurchaseOrder, quote::Quote, receipt::Receipt,
+    repeating_invoice::RepeatingInvoice, timesheet::Timesheet,
 };
 
 pub mod account;
+pub mod accounting_employee;
+pub mod allocation;
+pub mod batch_payment;
+pub mod branding_theme;
+pub mod budget;
 pub mod connection;
 pub mod contact;
+pub mod contact_group;
+pub mod credit;
+pub mod currency;
+pub mod expense_claim;
 pub mod invoice;
 pub mod item;
 pub mod line_item;
+pub mod linked_transaction;
+pub mod organisation;
+pub mod overpayment;
+pub mod payment;
+pub mod prepayment;
 pub mod purchase_order;
 pub mod quote;
+pub mod receipt;
+pub mod repeating_invoice;
+pub mod reports;
+pub mod setup;
 pub mod timesheet;
+pub mod user;
 
 #[derive(Clone, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -26,6 +47,12 @@ pub enum Data {
     Quotes(Vec<Quote>),
     Timesheets(Vec<Timesheet>),
     Items(Vec<Item>),
+    Currencies(Vec<Currency>),
+    ContactGroups(Vec<ContactGroup>),
+    RepeatingInvoices(Vec<RepeatingInvoice>),
+    Receipts(Vec<Receipt>),
+    ExpenseClaims(Vec<ExpenseClaim>),
+    Employees(Vec<AccountingEmployee>),
 }
 
 impl Data {
@@ -91,6 +118,60 @@ impl Data {
             None
         }
     }
+
+    #[must_use]
+    pub fn get_currencies(self) -> Option<Vec<Currency>> {
+        if let Self::Currencies(currencies) = self {
+            Some(currencies)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn get_contact_groups(self) -> Option<Vec<ContactGroup>> {
+        if let Self::ContactGroups(contact_groups) = self {
+            Some(contact_groups)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn get_repeating_invoices(self) -> Option<Vec<RepeatingInvoice>> {
+        if let Self::RepeatingInvoices(repeating_invoices) = self {
+            Some(repeating_invoices)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn get_receipts(self) -> Option<Vec<Receipt>> {
+        if let Self::Receipts(receipts) = self {
+            Some(receipts)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn get_expense_claims(self) -> Option<Vec<ExpenseClaim>> {
+        if let Self::ExpenseClaims(expense_claims) = self {
+            Some(expense_claims)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn get_employees(self) -> Option<Vec<AccountingEmployee>> {
+        if let Self::Employees(employees) = self {
+            Some(employees)
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Clone, Deserialize)]
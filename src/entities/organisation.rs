@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    Client,
+    endpoints::XeroEndpoint,
+    entities::currency::CurrencyCode,
+    error::{Error, Result},
+    payroll::region::PayrollRegion,
+    utils::date_format::xero_datetime_format_option,
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/Organisation";
+
+/// The legal structure of an organisation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrganisationType {
+    AccountingPractice,
+    Company,
+    Charity,
+    ClubOrSociety,
+    Individual,
+    LookThroughCompany,
+    NotForProfit,
+    Partnership,
+    SCorporation,
+    SelfManagedSuperannuationFund,
+    SoleTrader,
+    SuperannuationFund,
+    Trust,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The Xero organisation the client is connected to
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Organisation {
+    #[serde(rename = "OrganisationID")]
+    pub organisation_id: Uuid,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub legal_name: Option<String>,
+    #[serde(default)]
+    pub short_code: Option<String>,
+    /// Whether the organisation is registered with a local tax authority
+    #[serde(default)]
+    pub pays_tax: Option<bool>,
+    /// The Xero edition, e.g. "AU", "NZ", "UK", "US" or "GLOBAL"
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub organisation_type: Option<OrganisationType>,
+    #[serde(default)]
+    pub base_currency: Option<CurrencyCode>,
+    /// ISO 3166 two letter country code, e.g. "AU", "GB" or "NZ"
+    #[serde(default)]
+    pub country_code: Option<String>,
+    #[serde(default)]
+    pub is_demo_company: Option<bool>,
+    /// "ACTIVE" when the organisation can be used through the API
+    #[serde(default)]
+    pub organisation_status: Option<String>,
+    #[serde(default)]
+    pub registration_number: Option<String>,
+    #[serde(default)]
+    pub tax_number: Option<String>,
+    #[serde(default)]
+    pub financial_year_end_day: Option<u8>,
+    #[serde(default)]
+    pub financial_year_end_month: Option<u8>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(
+        rename = "CreatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub created_date_utc: Option<OffsetDateTime>,
+}
+
+impl Organisation {
+    /// The payroll region of the organisation, if Xero offers payroll in its country
+    #[must_use]
+    pub fn payroll_region(&self) -> Option<PayrollRegion> {
+        self.country_code
+            .as_deref()
+            .and_then(PayrollRegion::from_country_code)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ListResponse {
+    pub organisations: Vec<Organisation>,
+}
+
+/// Retrieve the organisation the client is connected to
+#[instrument(skip(client))]
+pub async fn get(client: &Client) -> Result<Organisation> {
+    let response: ListResponse = client
+        .get_endpoint(XeroEndpoint::Custom(vec!["Organisation".to_string()]), &())
+        .await?;
+
+    response
+        .organisations
+        .into_iter()
+        .next()
+        .ok_or(Error::NotFound {
+            entity: "Organisation".to_string(),
+            url: ENDPOINT.to_string(),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No organisation returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
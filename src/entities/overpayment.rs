@@ -0,0 +1,168 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+pub use crate::credit::{HistoryRecord, ListParameters, Status};
+use crate::{
+    Client,
+    allocation::{self, Allocation},
+    contact::Contact,
+    credit::{self, Credit},
+    currency::CurrencyCode,
+    entities::{EntityEndpoint, endpoint_utils},
+    error::Result,
+    invoice::Payment,
+    line_item::{LineAmountType, LineItem},
+    payment::Refund,
+    utils::date_format::{xero_date_format_option, xero_datetime_format_option},
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/Overpayments/";
+
+const CREDIT: Credit = Credit {
+    name: "Overpayment",
+    collection: "Overpayments",
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Type {
+    /// An overpayment received from a customer
+    #[serde(rename = "RECEIVE-OVERPAYMENT")]
+    Receive,
+    /// An overpayment made to a supplier
+    #[serde(rename = "SPEND-OVERPAYMENT")]
+    Spend,
+    #[serde(rename = "AROVERPAYMENT")]
+    AccountsReceivable,
+    #[serde(rename = "APOVERPAYMENT")]
+    AccountsPayable,
+}
+
+/// Money paid in excess of an invoice total, held as credit against the contact
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Overpayment {
+    #[serde(rename = "OverpaymentID")]
+    pub overpayment_id: Uuid,
+    pub r#type: Type,
+    pub contact: Contact,
+    #[serde(rename = "DateString", default, with = "xero_date_format_option")]
+    pub date: Option<Date>,
+    pub status: Status,
+    #[serde(default)]
+    pub line_amount_types: Option<LineAmountType>,
+    #[serde(default)]
+    pub line_items: Vec<LineItem>,
+    pub sub_total: Decimal,
+    pub total_tax: Decimal,
+    pub total: Decimal,
+    #[serde(
+        rename = "UpdatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub currency_code: Option<CurrencyCode>,
+    #[serde(default)]
+    pub currency_rate: Option<Decimal>,
+    /// The amount of the overpayment still available to allocate or refund
+    #[serde(default)]
+    pub remaining_credit: Option<Decimal>,
+    #[serde(default)]
+    pub allocations: Vec<Allocation>,
+    #[serde(default)]
+    pub applied_amount: Option<Decimal>,
+    /// Cash refunds made against the overpayment
+    #[serde(default)]
+    pub payments: Vec<Payment>,
+    #[serde(default)]
+    pub has_attachments: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ListResponse {
+    pub overpayments: Vec<Overpayment>,
+}
+
+impl From<ListResponse> for Vec<Overpayment> {
+    fn from(response: ListResponse) -> Self {
+        response.overpayments
+    }
+}
+
+impl EntityEndpoint<Overpayment, ListParameters> for Overpayment {
+    fn endpoint() -> &'static str {
+        ENDPOINT
+    }
+
+    async fn get(client: &Client, id: Uuid) -> Result<Overpayment> {
+        endpoint_utils::get::<Overpayment, ListResponse>(client, ENDPOINT, id, "Overpayment").await
+    }
+
+    async fn list(client: &Client, params: ListParameters) -> Result<Vec<Overpayment>> {
+        endpoint_utils::list::<Overpayment, ListResponse, _>(client, ENDPOINT, &params).await
+    }
+}
+
+/// Retrieve a list of overpayments with filtering
+#[instrument(skip(client))]
+pub async fn list(client: &Client, params: ListParameters) -> Result<Vec<Overpayment>> {
+    credit::list(client, params).await
+}
+
+/// Retrieve a list of all overpayments without filtering
+#[instrument(skip(client))]
+pub async fn list_all(client: &Client) -> Result<Vec<Overpayment>> {
+    list(client, ListParameters::default()).await
+}
+
+/// Retrieve a single overpayment by ID
+#[instrument(skip(client))]
+pub async fn get(client: &Client, overpayment_id: Uuid) -> Result<Overpayment> {
+    Overpayment::get(client, overpayment_id).await
+}
+
+/// Allocate part of an overpayment to one or more invoices
+#[instrument(skip(client, allocations))]
+pub async fn allocate(
+    client: &Client,
+    overpayment_id: Uuid,
+    allocations: &[allocation::Builder],
+) -> Result<Vec<Allocation>> {
+    credit::allocate(client, CREDIT, overpayment_id, allocations).await
+}
+
+/// Delete an allocation, returning the allocated amount to the overpayment's credit
+#[instrument(skip(client))]
+pub async fn delete_allocation(
+    client: &Client,
+    overpayment_id: Uuid,
+    allocation_id: Uuid,
+) -> Result<()> {
+    credit::delete_allocation(client, CREDIT, overpayment_id, allocation_id).await
+}
+
+/// Refund the remaining credit on an overpayment as a cash payment
+#[instrument(skip(client, refund))]
+pub async fn refund(client: &Client, overpayment_id: Uuid, refund: &Refund) -> Result<Payment> {
+    credit::refund(client, CREDIT, overpayment_id, refund).await
+}
+
+/// Get history records for an overpayment
+#[instrument(skip(client))]
+pub async fn get_history(client: &Client, overpayment_id: Uuid) -> Result<Vec<HistoryRecord>> {
+    credit::get_history(client, CREDIT, overpayment_id).await
+}
+
+/// Create a history record for an overpayment
+#[instrument(skip(client))]
+pub async fn create_history(
+    client: &Client,
+    overpayment_id: Uuid,
+    details: &str,
+) -> Result<Vec<HistoryRecord>> {
+    credit::create_history(client, CREDIT, overpayment_id, details).await
+}
@@ -0,0 +1,106 @@
+use rust_decimal::Decimal;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
+use time::Date;
+use uuid::Uuid;
+
+use crate::{invoice::Payment, utils::date_format::xero_date_format};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/Payments/";
+
+/// An account identifier used for referencing the bank account a payment is made
+/// from or to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountIdentifier {
+    /// Identify the account by its Xero ID
+    ID(Uuid),
+    /// Identify the account by its code
+    Code(String),
+}
+
+impl Serialize for AccountIdentifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        match self {
+            AccountIdentifier::ID(id) => {
+                map.serialize_entry("AccountID", id)?;
+            }
+            AccountIdentifier::Code(code) => {
+                map.serialize_entry("Code", code)?;
+            }
+        }
+        map.end()
+    }
+}
+
+/// A cash refund of the unallocated credit on a prepayment or overpayment
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Refund {
+    /// The bank account the refund is paid from (or into, for supplier credits)
+    pub account: AccountIdentifier,
+    #[serde(with = "xero_date_format")]
+    pub date: Date,
+    pub amount: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// Exchange rate, required for foreign currency credits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_rate: Option<Decimal>,
+}
+
+impl Refund {
+    /// Create a refund of `amount` paid from `account` on `date`
+    #[must_use]
+    pub fn new(account: AccountIdentifier, date: Date, amount: Decimal) -> Self {
+        Self {
+            account,
+            date,
+            amount,
+            reference: None,
+            currency_rate: None,
+        }
+    }
+
+    /// Set the payment reference
+    #[must_use]
+    pub fn with_reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the exchange rate
+    #[must_use]
+    pub fn with_currency_rate(mut self, rate: Decimal) -> Self {
+        self.currency_rate = Some(rate);
+        self
+    }
+}
+
+/// A refund targeted at a specific credit, as sent to `/Payments`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct RefundRequest<'a, T: Serialize> {
+    #[serde(flatten)]
+    pub target: T,
+    #[serde(flatten)]
+    pub refund: &'a Refund,
+}
+
+/// Request wrapper for payments
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct PaymentWrapper<T: Serialize> {
+    pub payments: Vec<T>,
+}
+
+/// Response wrapper for payments
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct PaymentsResponse {
+    #[serde(default)]
+    pub payments: Vec<Payment>,
+}
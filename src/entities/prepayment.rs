@@ -0,0 +1,170 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+pub use crate::credit::{HistoryRecord, ListParameters, Status};
+use crate::{
+    Client,
+    allocation::{self, Allocation},
+    contact::Contact,
+    credit::{self, Credit},
+    currency::CurrencyCode,
+    entities::{EntityEndpoint, endpoint_utils},
+    error::Result,
+    invoice::Payment,
+    line_item::{LineAmountType, LineItem},
+    payment::Refund,
+    utils::date_format::{xero_date_format_option, xero_datetime_format_option},
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/Prepayments/";
+
+const CREDIT: Credit = Credit {
+    name: "Prepayment",
+    collection: "Prepayments",
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Type {
+    /// A prepayment received from a customer
+    #[serde(rename = "RECEIVE-PREPAYMENT")]
+    Receive,
+    /// A prepayment made to a supplier
+    #[serde(rename = "SPEND-PREPAYMENT")]
+    Spend,
+    #[serde(rename = "ARPREPAYMENT")]
+    AccountsReceivable,
+    #[serde(rename = "APPREPAYMENT")]
+    AccountsPayable,
+}
+
+/// Money paid before an invoice is raised, held as credit against the contact
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Prepayment {
+    #[serde(rename = "PrepaymentID")]
+    pub prepayment_id: Uuid,
+    pub r#type: Type,
+    pub contact: Contact,
+    #[serde(rename = "DateString", default, with = "xero_date_format_option")]
+    pub date: Option<Date>,
+    pub status: Status,
+    #[serde(default)]
+    pub reference: Option<String>,
+    #[serde(default)]
+    pub line_amount_types: Option<LineAmountType>,
+    #[serde(default)]
+    pub line_items: Vec<LineItem>,
+    pub sub_total: Decimal,
+    pub total_tax: Decimal,
+    pub total: Decimal,
+    #[serde(
+        rename = "UpdatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub currency_code: Option<CurrencyCode>,
+    #[serde(default)]
+    pub currency_rate: Option<Decimal>,
+    /// The amount of the prepayment still available to allocate or refund
+    #[serde(default)]
+    pub remaining_credit: Option<Decimal>,
+    #[serde(default)]
+    pub allocations: Vec<Allocation>,
+    #[serde(default)]
+    pub applied_amount: Option<Decimal>,
+    /// Cash refunds made against the prepayment
+    #[serde(default)]
+    pub payments: Vec<Payment>,
+    #[serde(default)]
+    pub has_attachments: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ListResponse {
+    pub prepayments: Vec<Prepayment>,
+}
+
+impl From<ListResponse> for Vec<Prepayment> {
+    fn from(response: ListResponse) -> Self {
+        response.prepayments
+    }
+}
+
+impl EntityEndpoint<Prepayment, ListParameters> for Prepayment {
+    fn endpoint() -> &'static str {
+        ENDPOINT
+    }
+
+    async fn get(client: &Client, id: Uuid) -> Result<Prepayment> {
+        endpoint_utils::get::<Prepayment, ListResponse>(client, ENDPOINT, id, "Prepayment").await
+    }
+
+    async fn list(client: &Client, params: ListParameters) -> Result<Vec<Prepayment>> {
+        endpoint_utils::list::<Prepayment, ListResponse, _>(client, ENDPOINT, &params).await
+    }
+}
+
+/// Retrieve a list of prepayments with filtering
+#[instrument(skip(client))]
+pub async fn list(client: &Client, params: ListParameters) -> Result<Vec<Prepayment>> {
+    credit::list(client, params).await
+}
+
+/// Retrieve a list of all prepayments without filtering
+#[instrument(skip(client))]
+pub async fn list_all(client: &Client) -> Result<Vec<Prepayment>> {
+    list(client, ListParameters::default()).await
+}
+
+/// Retrieve a single prepayment by ID
+#[instrument(skip(client))]
+pub async fn get(client: &Client, prepayment_id: Uuid) -> Result<Prepayment> {
+    Prepayment::get(client, prepayment_id).await
+}
+
+/// Allocate part of a prepayment to one or more invoices
+#[instrument(skip(client, allocations))]
+pub async fn allocate(
+    client: &Client,
+    prepayment_id: Uuid,
+    allocations: &[allocation::Builder],
+) -> Result<Vec<Allocation>> {
+    credit::allocate(client, CREDIT, prepayment_id, allocations).await
+}
+
+/// Delete an allocation, returning the allocated amount to the prepayment's credit
+#[instrument(skip(client))]
+pub async fn delete_allocation(
+    client: &Client,
+    prepayment_id: Uuid,
+    allocation_id: Uuid,
+) -> Result<()> {
+    credit::delete_allocation(client, CREDIT, prepayment_id, allocation_id).await
+}
+
+/// Refund the remaining credit on a prepayment as a cash payment
+#[instrument(skip(client, refund))]
+pub async fn refund(client: &Client, prepayment_id: Uuid, refund: &Refund) -> Result<Payment> {
+    credit::refund(client, CREDIT, prepayment_id, refund).await
+}
+
+/// Get history records for a prepayment
+#[instrument(skip(client))]
+pub async fn get_history(client: &Client, prepayment_id: Uuid) -> Result<Vec<HistoryRecord>> {
+    credit::get_history(client, CREDIT, prepayment_id).await
+}
+
+/// Create a history record for a prepayment
+#[instrument(skip(client))]
+pub async fn create_history(
+    client: &Client,
+    prepayment_id: Uuid,
+    details: &str,
+) -> Result<Vec<HistoryRecord>> {
+    credit::create_history(client, CREDIT, prepayment_id, details).await
+}
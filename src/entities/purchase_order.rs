@@ -5,6 +5,7 @@ use uuid::Uuid;
 
 use crate::{
     contact::{Contact, ContactIdentifier},
+    currency::CurrencyCode,
     entities::{invoice, line_item},
     error::ValidationError,
     line_item::{LineAmountType, LineItem},
@@ -45,7 +46,7 @@ pub struct PurchaseOrder {
     #[serde(rename = "BrandingThemeID", skip_serializing_if = "Option::is_none")]
     pub branding_theme_id: Option<Uuid>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency_code: Option<String>,
+    pub currency_code: Option<CurrencyCode>,
     pub status: Status,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sent_to_contact: Option<bool>,
@@ -128,7 +129,7 @@ pub struct Builder {
     #[serde(rename = "BrandingThemeID", skip_serializing_if = "Option::is_none")]
     pub branding_theme_id: Option<Uuid>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency_code: Option<String>,
+    pub currency_code: Option<CurrencyCode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<Status>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -9,6 +9,7 @@ use uuid::Uuid;
 use crate::{
     Client, UnitDp,
     contact::{Contact, ContactIdentifier},
+    currency::{self, CurrencyCode},
     endpoints::XeroEndpoint,
     entities::{EntityEndpoint, MutationResponse, endpoint_utils},
     error::{Error, Result},
@@ -44,7 +45,7 @@ pub struct Quote {
     pub total_discount: Option<Decimal>,
     #[serde(rename = "UpdatedDateUTC")]
     pub updated_date_utc: String,
-    pub currency_code: String,
+    pub currency_code: CurrencyCode,
     pub currency_rate: Option<Decimal>,
     #[serde(rename = "QuoteID")]
     pub quote_id: Uuid,
@@ -58,6 +59,26 @@ pub struct Quote {
     pub has_attachments: bool,
 }
 
+impl Quote {
+    /// Sub total converted into the organisation's base currency
+    #[must_use]
+    pub fn sub_total_in_base_currency(&self) -> Decimal {
+        currency::to_base_currency(self.sub_total, self.currency_rate)
+    }
+
+    /// Total tax converted into the organisation's base currency
+    #[must_use]
+    pub fn total_tax_in_base_currency(&self) -> Decimal {
+        currency::to_base_currency(self.total_tax, self.currency_rate)
+    }
+
+    /// Total converted into the organisation's base currency
+    #[must_use]
+    pub fn total_in_base_currency(&self) -> Decimal {
+        currency::to_base_currency(self.total, self.currency_rate)
+    }
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "PascalCase")]
 pub(crate) struct ListResponse {
@@ -259,7 +280,7 @@ pub struct QuoteBuilder {
 
     /// The quote's currency code
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency_code: Option<String>,
+    pub currency_code: Option<CurrencyCode>,
 
     /// The quote's branding theme ID
     #[serde(rename = "BrandingThemeID", skip_serializing_if = "Option::is_none")]
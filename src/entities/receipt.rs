@@ -0,0 +1,531 @@
+use std::{ffi::OsStr, path::Path};
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    Client,
+    contact::{Contact, ContactIdentifier},
+    endpoints::XeroEndpoint,
+    entities::{EntityEndpoint, MutationResponse, endpoint_utils},
+    error::{Error, Result},
+    line_item::{self, LineAmountType, LineItem},
+    user::{User, UserReference},
+    utils::{
+        date_format::{xero_date_format, xero_date_format_option, xero_datetime_format_option},
+        serde_helpers::variant_name,
+    },
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/Receipts/";
+
+/// Status of a receipt
+///
+/// Receipts are created as drafts and move through the other statuses as the expense
+/// claim they belong to is submitted, authorised or declined.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    Draft,
+    Submitted,
+    Authorised,
+    Declined,
+    Voided,
+}
+
+impl Status {
+    /// Whether a receipt in this status can be moved to `next` through the API
+    ///
+    /// Drafts can be submitted or voided, submitted receipts can be authorised, declined or
+    /// voided, and authorised receipts can be voided. Declined and voided receipts are final.
+    #[must_use]
+    pub fn can_transition_to(self, next: Status) -> bool {
+        matches!(
+            (self, next),
+            (Status::Draft, Status::Submitted | Status::Voided)
+                | (
+                    Status::Submitted,
+                    Status::Authorised | Status::Declined | Status::Voided
+                )
+                | (Status::Authorised, Status::Voided)
+        )
+    }
+}
+
+/// An expense paid by a user that is reclaimed through an expense claim
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Receipt {
+    #[serde(rename = "ReceiptID")]
+    pub receipt_id: Uuid,
+    #[serde(default)]
+    pub receipt_number: Option<i64>,
+    pub status: Status,
+    #[serde(default)]
+    pub user: Option<User>,
+    #[serde(default)]
+    pub contact: Option<Contact>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub date: Option<Date>,
+    #[serde(
+        rename = "UpdatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub reference: Option<String>,
+    #[serde(default)]
+    pub line_amount_types: Option<LineAmountType>,
+    #[serde(default)]
+    pub line_items: Vec<LineItem>,
+    #[serde(default)]
+    pub sub_total: Decimal,
+    #[serde(default)]
+    pub total_tax: Decimal,
+    #[serde(default)]
+    pub total: Decimal,
+    /// URL to the receipt in Xero
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub has_attachments: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ListResponse {
+    pub receipts: Vec<Receipt>,
+}
+
+impl From<ListResponse> for Vec<Receipt> {
+    fn from(response: ListResponse) -> Self {
+        response.receipts
+    }
+}
+
+/// Parameters for filtering receipt list results
+#[derive(Debug, Serialize, Default)]
+pub struct ListParameters {
+    /// Filter by any element
+    #[serde(rename = "where", skip_serializing_if = "Option::is_none")]
+    pub r#where: Option<String>,
+
+    /// Order by any element
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+}
+
+impl ListParameters {
+    /// Create a new builder for `ListParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Set the where filter
+    #[must_use]
+    pub fn with_where(mut self, filter: impl Into<String>) -> Self {
+        self.r#where = Some(filter.into());
+        self
+    }
+
+    /// Set the order clause
+    #[must_use]
+    pub fn with_order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+}
+
+/// Builder for creating and updating draft receipts
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Builder {
+    /// The user who paid the expense
+    pub user: UserReference,
+    /// The supplier the expense was paid to
+    pub contact: ContactIdentifier,
+    #[serde(with = "xero_date_format")]
+    pub date: Date,
+    pub line_items: Vec<line_item::Builder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_amount_types: Option<LineAmountType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+}
+
+impl Builder {
+    /// Create a new receipt builder
+    #[must_use]
+    pub fn new(
+        user: impl Into<UserReference>,
+        contact: ContactIdentifier,
+        date: Date,
+        line_items: Vec<line_item::Builder>,
+    ) -> Self {
+        Self {
+            user: user.into(),
+            contact,
+            date,
+            line_items,
+            line_amount_types: None,
+            reference: None,
+            status: None,
+        }
+    }
+
+    /// Add a line item
+    #[must_use]
+    pub fn with_line_item(mut self, line_item: line_item::Builder) -> Self {
+        self.line_items.push(line_item);
+        self
+    }
+
+    /// Set the line amount types
+    #[must_use]
+    pub fn with_line_amount_types(mut self, line_amount_types: LineAmountType) -> Self {
+        self.line_amount_types = Some(line_amount_types);
+        self
+    }
+
+    /// Set the reference
+    #[must_use]
+    pub fn with_reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the status
+    #[must_use]
+    pub fn with_status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// Request wrapper for receipts
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ReceiptWrapper<'a> {
+    pub receipts: Vec<&'a Builder>,
+}
+
+/// Request body for changing the status of a receipt
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct StatusUpdate {
+    #[serde(rename = "ReceiptID")]
+    pub receipt_id: Uuid,
+    pub status: Status,
+}
+
+/// Request wrapper for receipt status changes
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct StatusUpdateWrapper {
+    pub receipts: Vec<StatusUpdate>,
+}
+
+/// History record for a receipt
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecord {
+    /// The details of the history record
+    pub details: String,
+
+    /// The date and time of the history record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_utc: Option<String>,
+
+    /// The user who created the history record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+
+    /// The changes made
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<String>,
+}
+
+/// Wrapper for history records response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecords {
+    pub history_records: Vec<HistoryRecord>,
+}
+
+/// Wrapper for posting history records
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecordsRequest {
+    pub history_records: Vec<HistoryRecord>,
+}
+
+/// Attachment details for a receipt
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Attachment {
+    #[serde(rename = "AttachmentID")]
+    pub attachment_id: Uuid,
+    pub file_name: String,
+    pub url: String,
+    pub mime_type: String,
+    pub content_length: i64,
+}
+
+/// Attachments response wrapper
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Attachments {
+    pub attachments: Vec<Attachment>,
+}
+
+impl EntityEndpoint<Receipt, ListParameters> for Receipt {
+    fn endpoint() -> &'static str {
+        ENDPOINT
+    }
+
+    async fn get(client: &Client, id: Uuid) -> Result<Receipt> {
+        endpoint_utils::get::<Receipt, ListResponse>(client, ENDPOINT, id, "Receipt").await
+    }
+
+    async fn list(client: &Client, params: ListParameters) -> Result<Vec<Receipt>> {
+        endpoint_utils::list::<Receipt, ListResponse, _>(client, ENDPOINT, &params).await
+    }
+}
+
+/// Retrieve a list of receipts with filtering
+#[instrument(skip(client))]
+pub async fn list(client: &Client, params: ListParameters) -> Result<Vec<Receipt>> {
+    Receipt::list(client, params).await
+}
+
+/// Retrieve a list of all receipts without filtering
+#[instrument(skip(client))]
+pub async fn list_all(client: &Client) -> Result<Vec<Receipt>> {
+    list(client, ListParameters::default()).await
+}
+
+/// Retrieve a single receipt by ID
+#[instrument(skip(client))]
+pub async fn get(client: &Client, receipt_id: Uuid) -> Result<Receipt> {
+    Receipt::get(client, receipt_id).await
+}
+
+/// Create a draft receipt
+#[instrument(skip(client, receipt))]
+pub async fn create(client: &Client, receipt: &Builder) -> Result<Receipt> {
+    let wrapper = ReceiptWrapper {
+        receipts: vec![receipt],
+    };
+
+    let response: MutationResponse = client
+        .put_endpoint(XeroEndpoint::Custom(vec!["Receipts".to_string()]), &wrapper)
+        .await?;
+
+    response
+        .data
+        .get_receipts()
+        .and_then(|receipts| receipts.into_iter().next())
+        .ok_or(Error::NotFound {
+            entity: "Receipt".to_string(),
+            url: ENDPOINT.to_string(),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No receipt returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Update a draft receipt
+#[instrument(skip(client, receipt))]
+pub async fn update(client: &Client, receipt_id: Uuid, receipt: &Builder) -> Result<Receipt> {
+    let wrapper = ReceiptWrapper {
+        receipts: vec![receipt],
+    };
+
+    let endpoint = XeroEndpoint::Custom(vec!["Receipts".to_string(), receipt_id.to_string()]);
+    let response: MutationResponse = client.post_endpoint(endpoint, &wrapper).await?;
+
+    response
+        .data
+        .get_receipts()
+        .and_then(|receipts| receipts.into_iter().next())
+        .ok_or(Error::NotFound {
+            entity: "Receipt".to_string(),
+            url: format!("{ENDPOINT}{receipt_id}"),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No receipt returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Change the status of a receipt, e.g. to `Voided`
+///
+/// The receipt is fetched first, and [`Error::InvalidStatusTransition`] is returned without
+/// writing anything unless its current status can move to `status`; see
+/// [`Status::can_transition_to`] for the transitions Xero allows.
+#[instrument(skip(client))]
+pub async fn update_status(client: &Client, receipt_id: Uuid, status: Status) -> Result<Receipt> {
+    let current = Receipt::get(client, receipt_id).await?;
+    if !current.status.can_transition_to(status) {
+        return Err(Error::InvalidStatusTransition {
+            entity: "Receipt".to_string(),
+            from: variant_name(&current.status),
+            to: variant_name(&status),
+        });
+    }
+
+    let wrapper = StatusUpdateWrapper {
+        receipts: vec![StatusUpdate { receipt_id, status }],
+    };
+
+    let endpoint = XeroEndpoint::Custom(vec!["Receipts".to_string(), receipt_id.to_string()]);
+    let response: MutationResponse = client.post_endpoint(endpoint, &wrapper).await?;
+
+    response
+        .data
+        .get_receipts()
+        .and_then(|receipts| receipts.into_iter().next())
+        .ok_or(Error::NotFound {
+            entity: "Receipt".to_string(),
+            url: format!("{ENDPOINT}{receipt_id}"),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No receipt returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Void a receipt
+pub async fn void(client: &Client, receipt_id: Uuid) -> Result<Receipt> {
+    update_status(client, receipt_id, Status::Voided).await
+}
+
+/// Get history records for a receipt
+#[instrument(skip(client))]
+pub async fn get_history(client: &Client, receipt_id: Uuid) -> Result<Vec<HistoryRecord>> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "Receipts".to_string(),
+        receipt_id.to_string(),
+        "History".to_string(),
+    ]);
+    let response: HistoryRecords = client.get_endpoint(endpoint, &()).await?;
+    Ok(response.history_records)
+}
+
+/// Create a history record for a receipt
+#[instrument(skip(client))]
+pub async fn create_history(
+    client: &Client,
+    receipt_id: Uuid,
+    details: &str,
+) -> Result<Vec<HistoryRecord>> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "Receipts".to_string(),
+        receipt_id.to_string(),
+        "History".to_string(),
+    ]);
+
+    let request = HistoryRecordsRequest {
+        history_records: vec![HistoryRecord {
+            details: details.to_string(),
+            date_utc: None,
+            user: None,
+            changes: None,
+        }],
+    };
+
+    let response: HistoryRecords = client.put_endpoint(endpoint, &request).await?;
+    Ok(response.history_records)
+}
+
+/// List attachments for a receipt
+#[instrument(skip(client))]
+pub async fn list_attachments(client: &Client, receipt_id: Uuid) -> Result<Vec<Attachment>> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "Receipts".to_string(),
+        receipt_id.to_string(),
+        "Attachments".to_string(),
+    ]);
+    let response: Attachments = client.get_endpoint(endpoint, &()).await?;
+    Ok(response.attachments)
+}
+
+/// Upload an attachment, such as a scan of the paper receipt
+#[instrument(skip(client, attachment_content))]
+pub async fn upload_attachment(
+    client: &Client,
+    receipt_id: Uuid,
+    filename: &str,
+    attachment_content: &[u8],
+) -> Result<Attachment> {
+    const MAX_ATTACHMENT_SIZE: usize = 25 * 1024 * 1024; // 25 MB
+
+    if filename.is_empty() {
+        return Err(Error::InvalidFilename);
+    }
+
+    let ext = Path::new(filename).extension().and_then(OsStr::to_str);
+
+    let content_type = match ext {
+        Some("pdf") => "application/pdf",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("txt") => "text/plain",
+        Some("csv") => "text/csv",
+        _ => "application/octet-stream",
+    };
+
+    if attachment_content.len() > MAX_ATTACHMENT_SIZE {
+        return Err(Error::AttachmentTooLarge);
+    }
+
+    let endpoint = XeroEndpoint::Custom(vec![
+        "Receipts".to_string(),
+        receipt_id.to_string(),
+        "Attachments".to_string(),
+        filename.to_string(),
+    ]);
+
+    let url = endpoint.to_url()?;
+    let response = client
+        .build_request(reqwest::Method::PUT, url)
+        .await
+        .header(reqwest::header::CONTENT_TYPE, content_type)
+        .header(reqwest::header::CONTENT_LENGTH, attachment_content.len())
+        .body(attachment_content.to_vec())
+        .send()
+        .await?;
+
+    let status = response.status();
+
+    if status.is_success() {
+        let attachments: Attachments = response.json().await?;
+        attachments
+            .attachments
+            .into_iter()
+            .next()
+            .ok_or(Error::NotFound {
+                entity: "Receipt Attachment".to_string(),
+                url: endpoint.to_string(),
+                status_code: status,
+                response_body: Some("No attachment was returned after upload".to_string()),
+                span_trace: SpanTrace::capture(),
+            })
+    } else {
+        Err(Error::NotFound {
+            entity: "Receipt Attachment".to_string(),
+            url: endpoint.to_string(),
+            status_code: status,
+            response_body: Some(format!(
+                "Failed to upload attachment for receipt with ID {receipt_id}"
+            )),
+            span_trace: SpanTrace::capture(),
+        })
+    }
+}
@@ -0,0 +1,693 @@
+use std::{ffi::OsStr, path::Path};
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, Duration, Month};
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    Client,
+    contact::{Contact, ContactIdentifier},
+    currency::CurrencyCode,
+    endpoints::XeroEndpoint,
+    entities::{EntityEndpoint, MutationResponse, endpoint_utils},
+    error::{Error, Result},
+    invoice::Type,
+    line_item::{self, LineAmountType, LineItem},
+    utils::date_format::xero_date_format_option,
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/RepeatingInvoices/";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    Draft,
+    Authorised,
+    Deleted,
+}
+
+/// The unit of a schedule's period
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Unit {
+    Weekly,
+    Monthly,
+}
+
+/// How the due date of each generated invoice is calculated
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DueDateType {
+    /// `due_date` days after the invoice date
+    DaysAfterBillDate,
+    /// `due_date` days after the end of the invoice month
+    DaysAfterBillMonth,
+    /// Day `due_date` of the invoice month
+    OfCurrentMonth,
+    /// Day `due_date` of the month following the invoice month
+    OfFollowingMonth,
+    /// `due_date` days after the invoice date, as used by sales invoices
+    DaysAfterInvoiceDate,
+    /// `due_date` days after the end of the invoice month, as used by sales invoices
+    DaysAfterInvoiceMonth,
+    /// A due date type this crate does not know
+    #[serde(other)]
+    Other,
+}
+
+/// When a repeating invoice generates invoices and when those invoices fall due
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Schedule {
+    /// Number of units between invoices, e.g. 2 with `Unit::Weekly` is fortnightly
+    pub period: u32,
+    pub unit: Unit,
+    /// Days (or day of month) used to calculate the due date, see `DueDateType`
+    pub due_date: u32,
+    pub due_date_type: DueDateType,
+    /// Date the first invoice is raised
+    #[serde(
+        default,
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_date: Option<Date>,
+    /// Date the next invoice will be raised
+    #[serde(
+        default,
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub next_scheduled_date: Option<Date>,
+    /// Date after which no more invoices are raised
+    #[serde(
+        default,
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub end_date: Option<Date>,
+}
+
+impl Schedule {
+    /// Create a schedule raising an invoice every `period` `unit`s from `start_date`
+    ///
+    /// Invoices are due on the day they are raised until `with_due_date` is used.
+    #[must_use]
+    pub fn new(period: u32, unit: Unit, start_date: Date) -> Self {
+        Self {
+            period,
+            unit,
+            due_date: 0,
+            due_date_type: DueDateType::DaysAfterBillDate,
+            start_date: Some(start_date),
+            next_scheduled_date: None,
+            end_date: None,
+        }
+    }
+
+    /// Set how the due date of each invoice is calculated
+    #[must_use]
+    pub fn with_due_date(mut self, due_date: u32, due_date_type: DueDateType) -> Self {
+        self.due_date = due_date;
+        self.due_date_type = due_date_type;
+        self
+    }
+
+    /// Stop raising invoices after `end_date`
+    #[must_use]
+    pub fn with_end_date(mut self, end_date: Date) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Project the next `count` dates invoices will be raised on
+    ///
+    /// Starts from the next scheduled date (or the start date for a schedule that has not
+    /// run yet) and stops early at the end date, or once dates can no longer be represented.
+    /// Monthly schedules keep the day of month of the start date, falling back to the last
+    /// day of shorter months.
+    #[must_use]
+    pub fn next_dates(&self, count: usize) -> Vec<Date> {
+        let Some(first) = self.next_scheduled_date.or(self.start_date) else {
+            return Vec::new();
+        };
+        let anchor_day = self.start_date.unwrap_or(first).day();
+
+        let mut dates = Vec::new();
+        for step in 0..count {
+            let Some(offset) = u32::try_from(step)
+                .ok()
+                .and_then(|step| self.period.checked_mul(step))
+            else {
+                break;
+            };
+            let date = match self.unit {
+                Unit::Weekly => first.checked_add(Duration::weeks(i64::from(offset))),
+                Unit::Monthly => add_months(first, offset, anchor_day),
+            };
+            match date {
+                Some(date) if self.end_date.is_none_or(|end| date <= end) => dates.push(date),
+                _ => break,
+            }
+            if self.period == 0 {
+                break;
+            }
+        }
+        dates
+    }
+
+    /// Calculate the due date of an invoice raised on `bill_date`
+    ///
+    /// Returns `None` for due date types this crate does not know.
+    #[must_use]
+    pub fn due_date_for(&self, bill_date: Date) -> Option<Date> {
+        let days = Duration::days(i64::from(self.due_date));
+        match self.due_date_type {
+            DueDateType::DaysAfterBillDate | DueDateType::DaysAfterInvoiceDate => {
+                bill_date.checked_add(days)
+            }
+            DueDateType::DaysAfterBillMonth | DueDateType::DaysAfterInvoiceMonth => {
+                let month_end = bill_date.replace_day(bill_date.month().length(bill_date.year()));
+                month_end.ok()?.checked_add(days)
+            }
+            DueDateType::OfCurrentMonth => {
+                add_months(bill_date, 0, u8::try_from(self.due_date).ok()?)
+            }
+            DueDateType::OfFollowingMonth => {
+                add_months(bill_date, 1, u8::try_from(self.due_date).ok()?)
+            }
+            DueDateType::Other => None,
+        }
+    }
+}
+
+/// Move `date` forward by `months`, landing on `day` or the last day of a shorter month
+fn add_months(date: Date, months: u32, day: u8) -> Option<Date> {
+    let month_index = i64::from(date.year()) * 12 + i64::from(u8::from(date.month())) - 1;
+    let target = month_index + i64::from(months);
+    let year = i32::try_from(target.div_euclid(12)).ok()?;
+    let month = Month::try_from(u8::try_from(target.rem_euclid(12) + 1).ok()?).ok()?;
+    let day = day.clamp(1, month.length(year));
+    Date::from_calendar_date(year, month, day).ok()
+}
+
+/// A template that raises invoices on a schedule
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RepeatingInvoice {
+    #[serde(rename = "RepeatingInvoiceID")]
+    pub repeating_invoice_id: Uuid,
+    pub r#type: Type,
+    pub contact: Contact,
+    pub schedule: Schedule,
+    #[serde(default)]
+    pub line_items: Vec<LineItem>,
+    #[serde(default)]
+    pub line_amount_types: Option<LineAmountType>,
+    #[serde(default)]
+    pub reference: Option<String>,
+    #[serde(rename = "BrandingThemeID", default)]
+    pub branding_theme_id: Option<Uuid>,
+    #[serde(default)]
+    pub currency_code: Option<CurrencyCode>,
+    pub status: Status,
+    pub sub_total: Decimal,
+    pub total_tax: Decimal,
+    pub total: Decimal,
+    #[serde(default)]
+    pub has_attachments: bool,
+    /// Whether generated invoices are emailed to the contact
+    #[serde(default)]
+    pub approved_for_sending: Option<bool>,
+    /// Whether a copy of each emailed invoice is sent to the sender
+    #[serde(default)]
+    pub send_copy: Option<bool>,
+    /// Whether generated invoices are marked as sent
+    #[serde(default)]
+    pub mark_as_sent: Option<bool>,
+    /// Whether a PDF of the invoice is attached to the email
+    #[serde(rename = "IncludePDF", default)]
+    pub include_pdf: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ListResponse {
+    pub repeating_invoices: Vec<RepeatingInvoice>,
+}
+
+impl From<ListResponse> for Vec<RepeatingInvoice> {
+    fn from(response: ListResponse) -> Self {
+        response.repeating_invoices
+    }
+}
+
+/// Parameters for filtering repeating invoice list results
+#[derive(Debug, Serialize, Default)]
+pub struct ListParameters {
+    /// Filter by any element
+    #[serde(rename = "where", skip_serializing_if = "Option::is_none")]
+    pub r#where: Option<String>,
+
+    /// Order by any element
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+}
+
+impl ListParameters {
+    /// Create a new builder for `ListParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Set the where filter
+    #[must_use]
+    pub fn with_where(mut self, filter: impl Into<String>) -> Self {
+        self.r#where = Some(filter.into());
+        self
+    }
+
+    /// Set the order clause
+    #[must_use]
+    pub fn with_order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+}
+
+/// Builder for creating and updating repeating invoices
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct Builder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Type>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact: Option<ContactIdentifier>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<Schedule>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub line_items: Vec<line_item::Builder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_amount_types: Option<LineAmountType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    #[serde(rename = "BrandingThemeID", skip_serializing_if = "Option::is_none")]
+    pub branding_theme_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<CurrencyCode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approved_for_sending: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_copy: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mark_as_sent: Option<bool>,
+    #[serde(rename = "IncludePDF", skip_serializing_if = "Option::is_none")]
+    pub include_pdf: Option<bool>,
+}
+
+impl Builder {
+    /// Create a new repeating invoice builder
+    #[must_use]
+    pub fn new(
+        r#type: Type,
+        contact: ContactIdentifier,
+        schedule: Schedule,
+        line_items: Vec<line_item::Builder>,
+    ) -> Self {
+        Self {
+            r#type: Some(r#type),
+            contact: Some(contact),
+            schedule: Some(schedule),
+            line_items,
+            ..Self::default()
+        }
+    }
+
+    /// Set the schedule
+    #[must_use]
+    pub fn with_schedule(mut self, schedule: Schedule) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
+
+    /// Add a line item
+    #[must_use]
+    pub fn with_line_item(mut self, line_item: line_item::Builder) -> Self {
+        self.line_items.push(line_item);
+        self
+    }
+
+    /// Set the line amount types
+    #[must_use]
+    pub fn with_line_amount_types(mut self, line_amount_types: LineAmountType) -> Self {
+        self.line_amount_types = Some(line_amount_types);
+        self
+    }
+
+    /// Set the reference
+    #[must_use]
+    pub fn with_reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// Set the branding theme
+    #[must_use]
+    pub fn with_branding_theme_id(mut self, branding_theme_id: Uuid) -> Self {
+        self.branding_theme_id = Some(branding_theme_id);
+        self
+    }
+
+    /// Set the currency code
+    #[must_use]
+    pub fn with_currency_code(mut self, currency_code: CurrencyCode) -> Self {
+        self.currency_code = Some(currency_code);
+        self
+    }
+
+    /// Set the status
+    #[must_use]
+    pub fn with_status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Email generated invoices to the contact, optionally with a copy to the sender and a
+    /// PDF attached
+    #[must_use]
+    pub fn with_email(mut self, send_copy: bool, include_pdf: bool) -> Self {
+        self.approved_for_sending = Some(true);
+        self.send_copy = Some(send_copy);
+        self.include_pdf = Some(include_pdf);
+        self
+    }
+
+    /// Mark generated invoices as sent
+    #[must_use]
+    pub fn with_mark_as_sent(mut self, mark_as_sent: bool) -> Self {
+        self.mark_as_sent = Some(mark_as_sent);
+        self
+    }
+}
+
+/// Request wrapper for repeating invoices
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct RepeatingInvoiceWrapper<'a> {
+    pub repeating_invoices: Vec<&'a Builder>,
+}
+
+/// History record for a repeating invoice
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecord {
+    /// The details of the history record
+    pub details: String,
+
+    /// The date and time of the history record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_utc: Option<String>,
+
+    /// The user who created the history record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+
+    /// The changes made
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<String>,
+}
+
+/// Wrapper for history records response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecords {
+    pub history_records: Vec<HistoryRecord>,
+}
+
+/// Wrapper for posting history records
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryRecordsRequest {
+    pub history_records: Vec<HistoryRecord>,
+}
+
+/// Attachment details for a repeating invoice
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Attachment {
+    #[serde(rename = "AttachmentID")]
+    pub attachment_id: Uuid,
+    pub file_name: String,
+    pub url: String,
+    pub mime_type: String,
+    pub content_length: i64,
+}
+
+/// Attachments response wrapper
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Attachments {
+    pub attachments: Vec<Attachment>,
+}
+
+impl EntityEndpoint<RepeatingInvoice, ListParameters> for RepeatingInvoice {
+    fn endpoint() -> &'static str {
+        ENDPOINT
+    }
+
+    async fn get(client: &Client, id: Uuid) -> Result<RepeatingInvoice> {
+        endpoint_utils::get::<RepeatingInvoice, ListResponse>(
+            client,
+            ENDPOINT,
+            id,
+            "RepeatingInvoice",
+        )
+        .await
+    }
+
+    async fn list(client: &Client, params: ListParameters) -> Result<Vec<RepeatingInvoice>> {
+        endpoint_utils::list::<RepeatingInvoice, ListResponse, _>(client, ENDPOINT, &params).await
+    }
+}
+
+/// Retrieve a list of repeating invoices with filtering
+#[instrument(skip(client))]
+pub async fn list(client: &Client, params: ListParameters) -> Result<Vec<RepeatingInvoice>> {
+    RepeatingInvoice::list(client, params).await
+}
+
+/// Retrieve a list of all repeating invoices without filtering
+#[instrument(skip(client))]
+pub async fn list_all(client: &Client) -> Result<Vec<RepeatingInvoice>> {
+    list(client, ListParameters::default()).await
+}
+
+/// Retrieve a single repeating invoice by ID
+#[instrument(skip(client))]
+pub async fn get(client: &Client, repeating_invoice_id: Uuid) -> Result<RepeatingInvoice> {
+    RepeatingInvoice::get(client, repeating_invoice_id).await
+}
+
+/// Create a repeating invoice
+#[instrument(skip(client, repeating_invoice))]
+pub async fn create(client: &Client, repeating_invoice: &Builder) -> Result<RepeatingInvoice> {
+    let wrapper = RepeatingInvoiceWrapper {
+        repeating_invoices: vec![repeating_invoice],
+    };
+
+    let response: MutationResponse = client
+        .put_endpoint(
+            XeroEndpoint::Custom(vec!["RepeatingInvoices".to_string()]),
+            &wrapper,
+        )
+        .await?;
+
+    response
+        .data
+        .get_repeating_invoices()
+        .and_then(|invoices| invoices.into_iter().next())
+        .ok_or(Error::NotFound {
+            entity: "RepeatingInvoice".to_string(),
+            url: ENDPOINT.to_string(),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No repeating invoice returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Update a repeating invoice
+#[instrument(skip(client, repeating_invoice))]
+pub async fn update(
+    client: &Client,
+    repeating_invoice_id: Uuid,
+    repeating_invoice: &Builder,
+) -> Result<RepeatingInvoice> {
+    let wrapper = RepeatingInvoiceWrapper {
+        repeating_invoices: vec![repeating_invoice],
+    };
+
+    let endpoint = XeroEndpoint::Custom(vec![
+        "RepeatingInvoices".to_string(),
+        repeating_invoice_id.to_string(),
+    ]);
+    let response: MutationResponse = client.post_endpoint(endpoint, &wrapper).await?;
+
+    response
+        .data
+        .get_repeating_invoices()
+        .and_then(|invoices| invoices.into_iter().next())
+        .ok_or(Error::NotFound {
+            entity: "RepeatingInvoice".to_string(),
+            url: format!("{ENDPOINT}{repeating_invoice_id}"),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some("No repeating invoice returned in response".to_string()),
+            span_trace: SpanTrace::capture(),
+        })
+}
+
+/// Delete a repeating invoice
+///
+/// Invoices already raised from the template are not affected.
+#[instrument(skip(client))]
+pub async fn delete(client: &Client, repeating_invoice_id: Uuid) -> Result<RepeatingInvoice> {
+    let builder = Builder::default().with_status(Status::Deleted);
+    update(client, repeating_invoice_id, &builder).await
+}
+
+/// Get history records for a repeating invoice
+#[instrument(skip(client))]
+pub async fn get_history(
+    client: &Client,
+    repeating_invoice_id: Uuid,
+) -> Result<Vec<HistoryRecord>> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "RepeatingInvoices".to_string(),
+        repeating_invoice_id.to_string(),
+        "History".to_string(),
+    ]);
+    let response: HistoryRecords = client.get_endpoint(endpoint, &()).await?;
+    Ok(response.history_records)
+}
+
+/// Create a history record for a repeating invoice
+#[instrument(skip(client))]
+pub async fn create_history(
+    client: &Client,
+    repeating_invoice_id: Uuid,
+    details: &str,
+) -> Result<Vec<HistoryRecord>> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "RepeatingInvoices".to_string(),
+        repeating_invoice_id.to_string(),
+        "History".to_string(),
+    ]);
+
+    let request = HistoryRecordsRequest {
+        history_records: vec![HistoryRecord {
+            details: details.to_string(),
+            date_utc: None,
+            user: None,
+            changes: None,
+        }],
+    };
+
+    let response: HistoryRecords = client.put_endpoint(endpoint, &request).await?;
+    Ok(response.history_records)
+}
+
+/// List attachments for a repeating invoice
+#[instrument(skip(client))]
+pub async fn list_attachments(
+    client: &Client,
+    repeating_invoice_id: Uuid,
+) -> Result<Vec<Attachment>> {
+    let endpoint = XeroEndpoint::Custom(vec![
+        "RepeatingInvoices".to_string(),
+        repeating_invoice_id.to_string(),
+        "Attachments".to_string(),
+    ]);
+    let response: Attachments = client.get_endpoint(endpoint, &()).await?;
+    Ok(response.attachments)
+}
+
+/// Upload an attachment to a repeating invoice
+///
+/// Attachments are copied onto each invoice the template raises.
+#[instrument(skip(client, attachment_content))]
+pub async fn upload_attachment(
+    client: &Client,
+    repeating_invoice_id: Uuid,
+    filename: &str,
+    attachment_content: &[u8],
+) -> Result<Attachment> {
+    const MAX_ATTACHMENT_SIZE: usize = 25 * 1024 * 1024; // 25 MB
+
+    if filename.is_empty() {
+        return Err(Error::InvalidFilename);
+    }
+
+    let ext = Path::new(filename).extension().and_then(OsStr::to_str);
+
+    let content_type = match ext {
+        Some("pdf") => "application/pdf",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("txt") => "text/plain",
+        Some("csv") => "text/csv",
+        _ => "application/octet-stream",
+    };
+
+    if attachment_content.len() > MAX_ATTACHMENT_SIZE {
+        return Err(Error::AttachmentTooLarge);
+    }
+
+    let endpoint = XeroEndpoint::Custom(vec![
+        "RepeatingInvoices".to_string(),
+        repeating_invoice_id.to_string(),
+        "Attachments".to_string(),
+        filename.to_string(),
+    ]);
+
+    let url = endpoint.to_url()?;
+    let response = client
+        .build_request(reqwest::Method::PUT, url)
+        .await
+        .header(reqwest::header::CONTENT_TYPE, content_type)
+        .header(reqwest::header::CONTENT_LENGTH, attachment_content.len())
+        .body(attachment_content.to_vec())
+        .send()
+        .await?;
+
+    let status = response.status();
+
+    if status.is_success() {
+        let attachments: Attachments = response.json().await?;
+        attachments
+            .attachments
+            .into_iter()
+            .next()
+            .ok_or(Error::NotFound {
+                entity: "RepeatingInvoice Attachment".to_string(),
+                url: endpoint.to_string(),
+                status_code: status,
+                response_body: Some("No attachment was returned after upload".to_string()),
+                span_trace: SpanTrace::capture(),
+            })
+    } else {
+        Err(Error::NotFound {
+            entity: "RepeatingInvoice Attachment".to_string(),
+            url: endpoint.to_string(),
+            status_code: status,
+            response_body: Some(format!(
+                "Failed to upload attachment for repeating invoice with ID {repeating_invoice_id}"
+            )),
+            span_trace: SpanTrace::capture(),
+        })
+    }
+}
@@ -0,0 +1,782 @@
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    Client,
+    endpoints::XeroEndpoint,
+    error::{Error, Result},
+    utils::date_format::{xero_date_format_option, xero_datetime_format_option},
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/Reports/";
+
+/// The length of each period in a multi-period report
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Timeframe {
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Timeframe {
+    /// The number of months in the timeframe, as used by the budget summary report
+    #[must_use]
+    pub fn months(self) -> u8 {
+        match self {
+            Timeframe::Month => 1,
+            Timeframe::Quarter => 3,
+            Timeframe::Year => 12,
+        }
+    }
+}
+
+/// An attribute attached to a report cell, such as the account a value belongs to
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CellAttribute {
+    pub id: String,
+    pub value: String,
+}
+
+/// A single cell of a report row
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Cell {
+    #[serde(default)]
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<CellAttribute>,
+}
+
+impl Cell {
+    /// Look up an attribute value by its ID
+    #[must_use]
+    pub fn attribute(&self, id: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.id.eq_ignore_ascii_case(id))
+            .map(|attribute| attribute.value.as_str())
+    }
+
+    /// The account this cell's value is for, if any
+    #[must_use]
+    pub fn account_id(&self) -> Option<Uuid> {
+        self.attribute("account")
+            .and_then(|value| Uuid::parse_str(value).ok())
+    }
+
+    /// Parse the cell's value as a decimal, ignoring thousands separators
+    ///
+    /// Returns `None` for labels and empty cells.
+    #[must_use]
+    pub fn as_decimal(&self) -> Option<Decimal> {
+        let value = self.value.trim().replace(',', "");
+        Decimal::from_str(&value).ok()
+    }
+}
+
+/// A row of a report
+///
+/// Reports are a tree: sections group rows and usually end in a summary row with the
+/// section total.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "RowType")]
+pub enum ReportRow {
+    /// Column headings
+    Header {
+        #[serde(rename = "Cells", default)]
+        cells: Vec<Cell>,
+    },
+    /// A titled group of rows
+    Section {
+        #[serde(rename = "Title", default)]
+        title: String,
+        #[serde(rename = "Rows", default)]
+        rows: Vec<ReportRow>,
+    },
+    /// A row of values, typically for a single account
+    Row {
+        #[serde(rename = "Cells", default)]
+        cells: Vec<Cell>,
+    },
+    /// A total for the section the row belongs to
+    SummaryRow {
+        #[serde(rename = "Cells", default)]
+        cells: Vec<Cell>,
+    },
+    /// A row type this crate does not know, kept so the rest of the report still parses
+    #[serde(other)]
+    Unknown,
+}
+
+impl ReportRow {
+    /// The cells of the row. Sections and unknown rows have no cells
+    #[must_use]
+    pub fn cells(&self) -> &[Cell] {
+        match self {
+            ReportRow::Header { cells }
+            | ReportRow::Row { cells }
+            | ReportRow::SummaryRow { cells } => cells,
+            ReportRow::Section { .. } | ReportRow::Unknown => &[],
+        }
+    }
+
+    /// The rows nested in a section. Other rows have no children
+    #[must_use]
+    pub fn rows(&self) -> &[ReportRow] {
+        match self {
+            ReportRow::Section { rows, .. } => rows,
+            _ => &[],
+        }
+    }
+
+    /// The section title, or the value of the first cell for other rows
+    #[must_use]
+    pub fn label(&self) -> Option<&str> {
+        match self {
+            ReportRow::Section { title, .. } => Some(title.as_str()),
+            _ => self.cells().first().map(|cell| cell.value.as_str()),
+        }
+    }
+
+    /// The account the row is for, taken from the attributes of its cells
+    #[must_use]
+    pub fn account_id(&self) -> Option<Uuid> {
+        self.cells().iter().find_map(Cell::account_id)
+    }
+
+    /// The decimal value in the given column, where column 0 is the label
+    #[must_use]
+    pub fn value(&self, column: usize) -> Option<Decimal> {
+        self.cells().get(column).and_then(Cell::as_decimal)
+    }
+
+    /// The summary row of a section, if any
+    #[must_use]
+    pub fn summary(&self) -> Option<&ReportRow> {
+        self.rows()
+            .iter()
+            .find(|row| matches!(row, ReportRow::SummaryRow { .. }))
+    }
+
+    fn collect<'a>(&'a self, rows: &mut Vec<&'a ReportRow>) {
+        rows.push(self);
+        for row in self.rows() {
+            row.collect(rows);
+        }
+    }
+}
+
+/// A contact's totals on the US 1099 report
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TenNinetyNineContact {
+    #[serde(rename = "ContactId", default)]
+    pub contact_id: Option<Uuid>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(rename = "FederalTaxIDType", default)]
+    pub federal_tax_id_type: Option<String>,
+    #[serde(rename = "TaxID", default)]
+    pub tax_id: Option<String>,
+    #[serde(default)]
+    pub street_address: Option<String>,
+    #[serde(default)]
+    pub city: Option<String>,
+    #[serde(default)]
+    pub state: Option<String>,
+    #[serde(default)]
+    pub zip: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub box1: Decimal,
+    #[serde(default)]
+    pub box2: Decimal,
+    #[serde(default)]
+    pub box3: Decimal,
+    #[serde(default)]
+    pub box4: Decimal,
+    #[serde(default)]
+    pub box5: Decimal,
+    #[serde(default)]
+    pub box6: Decimal,
+    #[serde(default)]
+    pub box7: Decimal,
+    #[serde(default)]
+    pub box8: Decimal,
+    #[serde(default)]
+    pub box9: Decimal,
+    #[serde(default)]
+    pub box10: Decimal,
+    #[serde(default)]
+    pub box11: Decimal,
+    #[serde(default)]
+    pub box13: Decimal,
+    #[serde(default)]
+    pub box14: Decimal,
+}
+
+/// A Xero report
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Report {
+    /// The report identifier, e.g. `ProfitAndLoss`, or a UUID for published reports
+    #[serde(rename = "ReportID", default)]
+    pub report_id: Option<String>,
+    #[serde(default)]
+    pub report_name: Option<String>,
+    #[serde(default)]
+    pub report_type: Option<String>,
+    #[serde(default)]
+    pub report_titles: Vec<String>,
+    /// The date or date range the report covers, as displayed by Xero
+    #[serde(default)]
+    pub report_date: Option<String>,
+    #[serde(
+        rename = "UpdatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub rows: Vec<ReportRow>,
+    /// Only populated for the 1099 report
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contacts: Vec<TenNinetyNineContact>,
+}
+
+impl Report {
+    /// The column headings
+    #[must_use]
+    pub fn header(&self) -> Option<&[Cell]> {
+        self.rows.iter().find_map(|row| match row {
+            ReportRow::Header { cells } => Some(cells.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// All rows of the report, depth first
+    #[must_use]
+    pub fn all_rows(&self) -> Vec<&ReportRow> {
+        let mut rows = Vec::new();
+        for row in &self.rows {
+            row.collect(&mut rows);
+        }
+        rows
+    }
+
+    /// Find a section by title, ignoring case
+    ///
+    /// Sections nested inside other sections are searched too, depth first, so the first
+    /// matching section at any depth is returned.
+    #[must_use]
+    pub fn section(&self, title: &str) -> Option<&ReportRow> {
+        self.all_rows().into_iter().find(|row| {
+            matches!(row, ReportRow::Section { title: t, .. } if t.eq_ignore_ascii_case(title))
+        })
+    }
+
+    /// Find the row for an account
+    #[must_use]
+    pub fn account_row(&self, account_id: Uuid) -> Option<&ReportRow> {
+        self.all_rows().into_iter().find(|row| {
+            matches!(row, ReportRow::Row { .. }) && row.account_id() == Some(account_id)
+        })
+    }
+
+    /// The value for an account in the given column, where column 0 is the label
+    ///
+    /// Column 1 is the first period of the report, or the only value for single-period
+    /// reports.
+    #[must_use]
+    pub fn account_value(&self, account_id: Uuid, column: usize) -> Option<Decimal> {
+        self.account_row(account_id)
+            .and_then(|row| row.value(column))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ReportsResponse {
+    #[serde(default)]
+    pub reports: Vec<Report>,
+}
+
+/// Parameters for the Profit and Loss report
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfitAndLossParameters {
+    #[serde(
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub from_date: Option<Date>,
+    #[serde(
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub to_date: Option<Date>,
+    /// Number of periods to compare, up to 11
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub periods: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeframe: Option<Timeframe>,
+    #[serde(rename = "trackingCategoryID", skip_serializing_if = "Option::is_none")]
+    pub tracking_category_id: Option<Uuid>,
+    #[serde(rename = "trackingOptionID", skip_serializing_if = "Option::is_none")]
+    pub tracking_option_id: Option<Uuid>,
+    #[serde(
+        rename = "trackingCategoryID2",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tracking_category_id_2: Option<Uuid>,
+    #[serde(rename = "trackingOptionID2", skip_serializing_if = "Option::is_none")]
+    pub tracking_option_id_2: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub standard_layout: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payments_only: Option<bool>,
+}
+
+impl ProfitAndLossParameters {
+    /// Create a new builder for `ProfitAndLossParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Report on the period from `from_date` to `to_date`
+    #[must_use]
+    pub fn with_date_range(mut self, from_date: Date, to_date: Date) -> Self {
+        self.from_date = Some(from_date);
+        self.to_date = Some(to_date);
+        self
+    }
+
+    /// Compare against `periods` earlier periods of the given timeframe
+    #[must_use]
+    pub fn with_periods(mut self, periods: u8, timeframe: Timeframe) -> Self {
+        self.periods = Some(periods);
+        self.timeframe = Some(timeframe);
+        self
+    }
+
+    /// Break the report down by a tracking category, optionally limited to one option
+    #[must_use]
+    pub fn with_tracking_category(
+        mut self,
+        tracking_category_id: Uuid,
+        tracking_option_id: Option<Uuid>,
+    ) -> Self {
+        self.tracking_category_id = Some(tracking_category_id);
+        self.tracking_option_id = tracking_option_id;
+        self
+    }
+
+    /// Further break the report down by a second tracking category
+    #[must_use]
+    pub fn with_second_tracking_category(
+        mut self,
+        tracking_category_id: Uuid,
+        tracking_option_id: Option<Uuid>,
+    ) -> Self {
+        self.tracking_category_id_2 = Some(tracking_category_id);
+        self.tracking_option_id_2 = tracking_option_id;
+        self
+    }
+
+    /// Use the standard layout rather than the organisation's custom layout
+    #[must_use]
+    pub fn with_standard_layout(mut self, standard_layout: bool) -> Self {
+        self.standard_layout = Some(standard_layout);
+        self
+    }
+
+    /// Only include cash transactions
+    #[must_use]
+    pub fn with_payments_only(mut self, payments_only: bool) -> Self {
+        self.payments_only = Some(payments_only);
+        self
+    }
+}
+
+/// Parameters for the Balance Sheet report
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceSheetParameters {
+    #[serde(
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub date: Option<Date>,
+    /// Number of periods to compare, up to 11
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub periods: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeframe: Option<Timeframe>,
+    #[serde(rename = "trackingOptionID1", skip_serializing_if = "Option::is_none")]
+    pub tracking_option_id_1: Option<Uuid>,
+    #[serde(rename = "trackingOptionID2", skip_serializing_if = "Option::is_none")]
+    pub tracking_option_id_2: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub standard_layout: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payments_only: Option<bool>,
+}
+
+impl BalanceSheetParameters {
+    /// Create a new builder for `BalanceSheetParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Report balances as at `date`
+    #[must_use]
+    pub fn with_date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Compare against `periods` earlier periods of the given timeframe
+    #[must_use]
+    pub fn with_periods(mut self, periods: u8, timeframe: Timeframe) -> Self {
+        self.periods = Some(periods);
+        self.timeframe = Some(timeframe);
+        self
+    }
+
+    /// Only include transactions with the given tracking options
+    #[must_use]
+    pub fn with_tracking_options(
+        mut self,
+        tracking_option_id_1: Uuid,
+        tracking_option_id_2: Option<Uuid>,
+    ) -> Self {
+        self.tracking_option_id_1 = Some(tracking_option_id_1);
+        self.tracking_option_id_2 = tracking_option_id_2;
+        self
+    }
+
+    /// Use the standard layout rather than the organisation's custom layout
+    #[must_use]
+    pub fn with_standard_layout(mut self, standard_layout: bool) -> Self {
+        self.standard_layout = Some(standard_layout);
+        self
+    }
+
+    /// Only include cash transactions
+    #[must_use]
+    pub fn with_payments_only(mut self, payments_only: bool) -> Self {
+        self.payments_only = Some(payments_only);
+        self
+    }
+}
+
+/// Parameters for the Trial Balance report
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TrialBalanceParameters {
+    #[serde(
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub date: Option<Date>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payments_only: Option<bool>,
+}
+
+impl TrialBalanceParameters {
+    /// Create a new builder for `TrialBalanceParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Report balances as at `date`
+    #[must_use]
+    pub fn with_date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Only include cash transactions
+    #[must_use]
+    pub fn with_payments_only(mut self, payments_only: bool) -> Self {
+        self.payments_only = Some(payments_only);
+        self
+    }
+}
+
+/// Parameters for the Aged Receivables and Aged Payables by Contact reports
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgedParameters {
+    #[serde(rename = "contactId")]
+    pub contact_id: Uuid,
+    /// Age invoices as at this date
+    #[serde(
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub date: Option<Date>,
+    #[serde(
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub from_date: Option<Date>,
+    #[serde(
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub to_date: Option<Date>,
+}
+
+impl AgedParameters {
+    /// Create parameters for the given contact
+    #[must_use]
+    pub fn new(contact_id: Uuid) -> Self {
+        Self {
+            contact_id,
+            date: None,
+            from_date: None,
+            to_date: None,
+        }
+    }
+
+    /// Age invoices as at `date`
+    #[must_use]
+    pub fn with_date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Only include invoices dated between `from_date` and `to_date`
+    #[must_use]
+    pub fn with_date_range(mut self, from_date: Date, to_date: Date) -> Self {
+        self.from_date = Some(from_date);
+        self.to_date = Some(to_date);
+        self
+    }
+}
+
+/// Parameters for the Bank Summary report
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BankSummaryParameters {
+    #[serde(
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub from_date: Option<Date>,
+    #[serde(
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub to_date: Option<Date>,
+}
+
+impl BankSummaryParameters {
+    /// Create a new builder for `BankSummaryParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Report on the period from `from_date` to `to_date`
+    #[must_use]
+    pub fn with_date_range(mut self, from_date: Date, to_date: Date) -> Self {
+        self.from_date = Some(from_date);
+        self.to_date = Some(to_date);
+        self
+    }
+}
+
+/// Parameters for the Budget Summary report
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetSummaryParameters {
+    #[serde(
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub date: Option<Date>,
+    /// Number of periods to show, up to 12
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub periods: Option<u8>,
+    /// Length of each period in months (1, 3 or 12)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeframe: Option<u8>,
+}
+
+impl BudgetSummaryParameters {
+    /// Create a new builder for `BudgetSummaryParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Start the report at `date`
+    #[must_use]
+    pub fn with_date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Show `periods` periods of the given timeframe
+    #[must_use]
+    pub fn with_periods(mut self, periods: u8, timeframe: Timeframe) -> Self {
+        self.periods = Some(periods);
+        self.timeframe = Some(timeframe.months());
+        self
+    }
+}
+
+/// Parameters for the Executive Summary report
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutiveSummaryParameters {
+    /// Report on the month containing this date
+    #[serde(
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub date: Option<Date>,
+}
+
+impl ExecutiveSummaryParameters {
+    /// Create a new builder for `ExecutiveSummaryParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Report on the month containing `date`
+    #[must_use]
+    pub fn with_date(mut self, date: Date) -> Self {
+        self.date = Some(date);
+        self
+    }
+}
+
+/// Parameters for the US 1099 report
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TenNinetyNineParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_year: Option<i32>,
+}
+
+impl TenNinetyNineParameters {
+    /// Create a new builder for `TenNinetyNineParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Report on the given tax year
+    #[must_use]
+    pub fn with_report_year(mut self, report_year: i32) -> Self {
+        self.report_year = Some(report_year);
+        self
+    }
+}
+
+/// Fetch a report by name
+async fn get_report<P: Serialize + std::fmt::Debug + Sync>(
+    client: &Client,
+    name: &str,
+    params: &P,
+) -> Result<Report> {
+    let endpoint = XeroEndpoint::Custom(vec!["Reports".to_string(), name.to_string()]);
+    let response: ReportsResponse = client.get_endpoint(endpoint, params).await?;
+    response.reports.into_iter().next().ok_or(Error::NotFound {
+        entity: "Report".to_string(),
+        url: format!("{ENDPOINT}{name}"),
+        status_code: reqwest::StatusCode::NOT_FOUND,
+        response_body: Some(format!("No {name} report returned in response")),
+        span_trace: SpanTrace::capture(),
+    })
+}
+
+/// Retrieve the Profit and Loss report
+#[instrument(skip(client))]
+pub async fn profit_and_loss(client: &Client, params: ProfitAndLossParameters) -> Result<Report> {
+    get_report(client, "ProfitAndLoss", &params).await
+}
+
+/// Retrieve the Balance Sheet report
+#[instrument(skip(client))]
+pub async fn balance_sheet(client: &Client, params: BalanceSheetParameters) -> Result<Report> {
+    get_report(client, "BalanceSheet", &params).await
+}
+
+/// Retrieve the Trial Balance report
+#[instrument(skip(client))]
+pub async fn trial_balance(client: &Client, params: TrialBalanceParameters) -> Result<Report> {
+    get_report(client, "TrialBalance", &params).await
+}
+
+/// Retrieve the Aged Receivables by Contact report
+#[instrument(skip(client))]
+pub async fn aged_receivables(client: &Client, params: AgedParameters) -> Result<Report> {
+    get_report(client, "AgedReceivablesByContact", &params).await
+}
+
+/// Retrieve the Aged Payables by Contact report
+#[instrument(skip(client))]
+pub async fn aged_payables(client: &Client, params: AgedParameters) -> Result<Report> {
+    get_report(client, "AgedPayablesByContact", &params).await
+}
+
+/// Retrieve the Bank Summary report
+#[instrument(skip(client))]
+pub async fn bank_summary(client: &Client, params: BankSummaryParameters) -> Result<Report> {
+    get_report(client, "BankSummary", &params).await
+}
+
+/// Retrieve the Budget Summary report
+#[instrument(skip(client))]
+pub async fn budget_summary(client: &Client, params: BudgetSummaryParameters) -> Result<Report> {
+    get_report(client, "BudgetSummary", &params).await
+}
+
+/// Retrieve the Executive Summary report
+#[instrument(skip(client))]
+pub async fn executive_summary(
+    client: &Client,
+    params: ExecutiveSummaryParameters,
+) -> Result<Report> {
+    get_report(client, "ExecutiveSummary", &params).await
+}
+
+/// Retrieve the US 1099 report
+///
+/// Contact totals are returned in `Report::contacts` rather than as rows.
+#[instrument(skip(client))]
+pub async fn ten_ninety_nine(client: &Client, params: TenNinetyNineParameters) -> Result<Report> {
+    get_report(client, "TenNinetyNine", &params).await
+}
+
+/// List published reports, such as AU BAS and NZ GST returns
+///
+/// Only the report metadata is returned; use [`get_published`] to fetch the rows.
+#[instrument(skip(client))]
+pub async fn list_published(client: &Client) -> Result<Vec<Report>> {
+    let endpoint = XeroEndpoint::Custom(vec!["Reports".to_string()]);
+    let response: ReportsResponse = client.get_endpoint(endpoint, &()).await?;
+    Ok(response.reports)
+}
+
+/// Retrieve a published report, such as an AU BAS or NZ GST return, by ID
+#[instrument(skip(client))]
+pub async fn get_published(client: &Client, report_id: Uuid) -> Result<Report> {
+    get_report(client, &report_id.to_string(), &()).await
+}
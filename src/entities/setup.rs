@@ -0,0 +1,331 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, Month};
+
+use crate::{
+    Client, account,
+    currency::CurrencyCode,
+    endpoints::XeroEndpoint,
+    error::{Error, Result},
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/Setup/";
+
+/// The month an organisation switched to Xero
+///
+/// Conversion balances are the balances as at the end of the month before this date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ConversionDate {
+    /// Month of the year, 1 to 12
+    pub month: u8,
+    pub year: i32,
+}
+
+impl ConversionDate {
+    /// Create a conversion date for the given month
+    #[must_use]
+    pub fn new(year: i32, month: Month) -> Self {
+        Self {
+            month: u8::from(month),
+            year,
+        }
+    }
+
+    /// The conversion date for the month containing `date`
+    #[must_use]
+    pub fn containing(date: Date) -> Self {
+        Self::new(date.year(), date.month())
+    }
+}
+
+/// A foreign currency component of a conversion balance
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BalanceDetail {
+    /// The balance in the foreign currency, positive for debits and negative for credits
+    pub balance: Decimal,
+    pub currency_code: CurrencyCode,
+    /// The rate used to convert the balance to the base currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_rate: Option<Decimal>,
+}
+
+/// The opening balance of one account
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ConversionBalance {
+    pub account_code: String,
+    /// The balance in the base currency, positive for debits and negative for credits
+    pub balance: Decimal,
+    /// Foreign currency breakdown, for accounts receivable, accounts payable and foreign
+    /// currency bank accounts
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub balance_details: Vec<BalanceDetail>,
+}
+
+impl ConversionBalance {
+    /// A debit balance of `amount` on the account
+    #[must_use]
+    pub fn debit(account_code: impl Into<String>, amount: Decimal) -> Self {
+        Self {
+            account_code: account_code.into(),
+            balance: amount.abs(),
+            balance_details: Vec::new(),
+        }
+    }
+
+    /// A credit balance of `amount` on the account
+    #[must_use]
+    pub fn credit(account_code: impl Into<String>, amount: Decimal) -> Self {
+        Self {
+            account_code: account_code.into(),
+            balance: -amount.abs(),
+            balance_details: Vec::new(),
+        }
+    }
+
+    /// Add a foreign currency component to the balance
+    #[must_use]
+    pub fn with_balance_detail(
+        mut self,
+        balance: Decimal,
+        currency_code: CurrencyCode,
+        currency_rate: Option<Decimal>,
+    ) -> Self {
+        self.balance_details.push(BalanceDetail {
+            balance,
+            currency_code,
+            currency_rate,
+        });
+        self
+    }
+}
+
+/// The opening balances of a set of accounts
+///
+/// Debits are positive and credits negative, so the balances must sum to zero.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ConversionBalances(pub Vec<ConversionBalance>);
+
+impl ConversionBalances {
+    /// Create an empty set of conversion balances
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a balance
+    #[must_use]
+    pub fn with_balance(mut self, balance: ConversionBalance) -> Self {
+        self.0.push(balance);
+        self
+    }
+
+    /// Add a debit balance on an account
+    #[must_use]
+    pub fn with_debit(self, account_code: impl Into<String>, amount: Decimal) -> Self {
+        self.with_balance(ConversionBalance::debit(account_code, amount))
+    }
+
+    /// Add a credit balance on an account
+    #[must_use]
+    pub fn with_credit(self, account_code: impl Into<String>, amount: Decimal) -> Self {
+        self.with_balance(ConversionBalance::credit(account_code, amount))
+    }
+
+    /// The sum of all debit balances
+    #[must_use]
+    pub fn total_debits(&self) -> Decimal {
+        self.0
+            .iter()
+            .map(|balance| balance.balance)
+            .filter(Decimal::is_sign_positive)
+            .sum()
+    }
+
+    /// The sum of all credit balances, as a positive amount
+    #[must_use]
+    pub fn total_credits(&self) -> Decimal {
+        -self
+            .0
+            .iter()
+            .map(|balance| balance.balance)
+            .filter(Decimal::is_sign_negative)
+            .sum::<Decimal>()
+    }
+
+    /// Whether total debits equal total credits
+    #[must_use]
+    pub fn is_balanced(&self) -> bool {
+        self.total_debits() == self.total_credits()
+    }
+
+    /// Whether no balances have been added
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Builder for the chart of accounts, conversion date and opening balances of an organisation
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "PascalCase")]
+pub struct Builder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conversion_date: Option<ConversionDate>,
+    #[serde(skip_serializing_if = "ConversionBalances::is_empty")]
+    pub conversion_balances: ConversionBalances,
+    /// The chart of accounts. Accounts not included are deleted or archived where possible
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub accounts: Vec<account::Builder>,
+}
+
+impl Builder {
+    /// Create an empty setup request
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the conversion date
+    #[must_use]
+    pub fn with_conversion_date(mut self, conversion_date: ConversionDate) -> Self {
+        self.conversion_date = Some(conversion_date);
+        self
+    }
+
+    /// Set the opening balances
+    #[must_use]
+    pub fn with_conversion_balances(mut self, conversion_balances: ConversionBalances) -> Self {
+        self.conversion_balances = conversion_balances;
+        self
+    }
+
+    /// Add an account to the chart of accounts
+    #[must_use]
+    pub fn with_account(mut self, account: account::Builder) -> Self {
+        self.accounts.push(account);
+        self
+    }
+
+    /// Add several accounts to the chart of accounts
+    #[must_use]
+    pub fn with_accounts(mut self, accounts: impl IntoIterator<Item = account::Builder>) -> Self {
+        self.accounts.extend(accounts);
+        self
+    }
+}
+
+/// Counts of the accounts affected by a setup request
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccountsSummary {
+    #[serde(default)]
+    pub total: u32,
+    #[serde(default)]
+    pub new: u32,
+    #[serde(default)]
+    pub updated: u32,
+    #[serde(default)]
+    pub deleted: u32,
+    #[serde(default)]
+    pub locked: u32,
+    #[serde(default)]
+    pub system: u32,
+    #[serde(default)]
+    pub errored: u32,
+    #[serde(default)]
+    pub present: bool,
+    #[serde(default)]
+    pub new_or_updated: u32,
+}
+
+/// Whether the organisation details were present in a setup request
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OrganisationSummary {
+    #[serde(default)]
+    pub present: bool,
+}
+
+/// The result of a setup request
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ImportSummary {
+    #[serde(default)]
+    pub accounts: AccountsSummary,
+    #[serde(default)]
+    pub organisation: OrganisationSummary,
+}
+
+/// The body Xero returns from a setup request
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ImportSummaryResponse {
+    pub import_summary: ImportSummary,
+}
+
+/// Post the chart of accounts, conversion date and opening balances
+///
+/// Returns `Error::ConversionBalancesUnbalanced` without calling the API if the conversion
+/// balances' debits and credits differ.
+#[instrument(skip(client, setup))]
+pub async fn post(client: &Client, setup: &Builder) -> Result<ImportSummary> {
+    let balances = &setup.conversion_balances;
+    if !balances.is_balanced() {
+        return Err(Error::ConversionBalancesUnbalanced {
+            debits: balances.total_debits(),
+            credits: balances.total_credits(),
+        });
+    }
+
+    let response: ImportSummaryResponse = client
+        .post_endpoint(XeroEndpoint::Custom(vec!["Setup".to_string()]), setup)
+        .await?;
+    Ok(response.import_summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_import_summary_response() {
+        // Example response from the Setup endpoint in the OpenAPI spec
+        let json = r#"{
+            "Id": "80dcb65b-4d14-4350-84e6-1438a809244a",
+            "Status": "OK",
+            "ProviderName": "Java Public Example",
+            "DateTimeUTC": "/Date(1604457589645)/",
+            "ImportSummary": {
+                "Accounts": {
+                    "Total": 17,
+                    "New": 0,
+                    "Updated": 8,
+                    "Deleted": 0,
+                    "Locked": 0,
+                    "System": 9,
+                    "Errored": 0,
+                    "Present": true,
+                    "NewOrUpdated": 8
+                },
+                "Organisation": {
+                    "Present": false
+                }
+            }
+        }"#;
+
+        let response: ImportSummaryResponse =
+            serde_json::from_str(json).expect("deserialization should succeed");
+        let summary = response.import_summary;
+
+        assert_eq!(summary.accounts.total, 17);
+        assert_eq!(summary.accounts.updated, 8);
+        assert_eq!(summary.accounts.system, 9);
+        assert_eq!(summary.accounts.new_or_updated, 8);
+        assert!(summary.accounts.present);
+        assert!(!summary.organisation.present);
+    }
+}
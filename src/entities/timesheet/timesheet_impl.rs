@@ -272,6 +272,203 @@ impl Timesheet {
         Ok(response.timesheets.into_iter().next().unwrap())
     }
 
-    // Note: Timesheets cannot be deleted via the Xero API. Instead, update their status to "Processed".
-    // The delete method has been removed as it is not supported by the Xero API.
+    /// Creates many timesheets in one request
+    ///
+    /// The returned timesheets are in the same order as `timesheets`. If any timesheet fails
+    /// validation, Xero rejects the whole request; use
+    /// [`crate::error::Error::timesheet_validation_errors`] to find which inputs failed.
+    pub async fn post_many(
+        client: &crate::client::Client,
+        timesheets: &[PostTimesheet],
+    ) -> Result<Vec<Timesheet>> {
+        info!("Creating {} timesheets", timesheets.len());
+
+        let url = "https://api.xero.com/payroll.xro/1.0/Timesheets";
+        debug!("POST URL: {}", url);
+
+        let response: TimesheetResponse = client.post(url, &timesheets).await?;
+        debug!("Response contains {} timesheets", response.timesheets.len());
+        Ok(response.timesheets)
+    }
+
+    /// Updates many timesheets in one request
+    ///
+    /// The returned timesheets are in the same order as `timesheets`. If any timesheet fails
+    /// validation, Xero rejects the whole request; use
+    /// [`crate::error::Error::timesheet_validation_errors`] to find which inputs failed.
+    pub async fn update_many(
+        client: &crate::client::Client,
+        timesheets: &[Timesheet],
+    ) -> Result<Vec<Timesheet>> {
+        info!("Updating {} timesheets", timesheets.len());
+
+        let url = "https://api.xero.com/payroll.xro/1.0/Timesheets";
+        debug!("POST URL: {}", url);
+
+        let response: TimesheetResponse = client.post(url, &timesheets).await?;
+        debug!("Response contains {} timesheets", response.timesheets.len());
+        Ok(response.timesheets)
+    }
+
+    /// Approves a draft timesheet so it is included in the next pay run
+    pub async fn approve(client: &crate::client::Client, timesheet_id: Uuid) -> Result<Timesheet> {
+        Self::set_status(client, timesheet_id, TimesheetStatus::Approved).await
+    }
+
+    /// Reverts an approved timesheet to draft
+    ///
+    /// Processed timesheets belong to a posted pay run and cannot be reverted.
+    pub async fn revert_to_draft(
+        client: &crate::client::Client,
+        timesheet_id: Uuid,
+    ) -> Result<Timesheet> {
+        Self::set_status(client, timesheet_id, TimesheetStatus::Draft).await
+    }
+
+    /// Removes all of a timesheet's lines, leaving an empty draft
+    ///
+    /// The Payroll AU API has no endpoint for deleting timesheets, so the timesheet itself
+    /// remains; with no hours there is nothing for a pay run to pick up. Processed timesheets
+    /// cannot be changed.
+    pub async fn clear_lines(
+        client: &crate::client::Client,
+        timesheet_id: Uuid,
+    ) -> Result<Timesheet> {
+        info!("Clearing lines of timesheet with ID: {}", timesheet_id);
+
+        let timesheet = Self::get(client, timesheet_id).await?;
+        Self::update(client, &timesheet.without_lines()).await
+    }
+
+    async fn set_status(
+        client: &crate::client::Client,
+        timesheet_id: Uuid,
+        status: TimesheetStatus,
+    ) -> Result<Timesheet> {
+        info!(
+            "Setting status of timesheet {} to {:?}",
+            timesheet_id, status
+        );
+
+        let timesheet = Self::get(client, timesheet_id).await?;
+        Self::update(client, &timesheet.with_status(status)).await
+    }
+
+    /// This timesheet with its status changed
+    fn with_status(mut self, status: TimesheetStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// This timesheet as a draft with no lines or hours
+    fn without_lines(mut self) -> Self {
+        self.status = TimesheetStatus::Draft;
+        self.timesheet_lines.clear();
+        self.hours = 0.0;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+    use time::macros::date;
+
+    fn approved_timesheet() -> Timesheet {
+        serde_json::from_value(json!({
+            "TimesheetID": "049765fc-4506-48fb-bf88-3578dec0ec47",
+            "EmployeeID": "b34e89ff-770d-4099-b7e5-f968767118bc",
+            "StartDate": "/Date(1573171200000+0000)/",
+            "EndDate": "/Date(1573689600000+0000)/",
+            "Status": "APPROVED",
+            "Hours": 22.0,
+            "TimesheetLines": [{
+                "EarningsRateID": "ab874dfb-ab09-4c91-954e-43acf6fc23b4",
+                "NumberOfUnits": [8.0, 8.0, 6.0, 0.0, 0.0, 0.0, 0.0]
+            }]
+        }))
+        .expect("timesheet should deserialize")
+    }
+
+    /// The body `update` posts for a timesheet
+    fn update_body(timesheet: &Timesheet) -> Value {
+        serde_json::to_value(vec![timesheet.clone()]).expect("timesheet should serialize")
+    }
+
+    #[test]
+    fn batch_writes_serialize_as_one_array() {
+        let employee_id = Uuid::new_v4();
+        let timesheets: &[PostTimesheet] = &[
+            PostTimesheet {
+                timesheet_id: None,
+                employee_id,
+                start_date: date!(2025 - 03 - 03),
+                end_date: date!(2025 - 03 - 09),
+                status: None,
+                timesheet_lines: None,
+            },
+            PostTimesheet {
+                timesheet_id: None,
+                employee_id,
+                start_date: date!(2025 - 03 - 10),
+                end_date: date!(2025 - 03 - 16),
+                status: Some(TimesheetStatus::Draft),
+                timesheet_lines: None,
+            },
+        ];
+
+        // `post_many` and `update_many` post the slice itself
+        let body = serde_json::to_value(timesheets).expect("timesheets should serialize");
+        let items = body.as_array().expect("body should be a bare array");
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0]["StartDate"], "2025-03-03");
+        assert_eq!(items[1]["StartDate"], "2025-03-10");
+        assert_eq!(items[1]["Status"], "DRAFT");
+
+        let updates = [
+            approved_timesheet(),
+            approved_timesheet().with_status(TimesheetStatus::Draft),
+        ];
+        let body = serde_json::to_value(&updates[..]).expect("timesheets should serialize");
+        assert_eq!(body.as_array().map(Vec::len), Some(2));
+        assert_eq!(
+            body[0]["TimesheetID"],
+            "049765fc-4506-48fb-bf88-3578dec0ec47"
+        );
+    }
+
+    #[test]
+    fn status_changes_keep_the_lines() {
+        let mut draft = approved_timesheet();
+        draft.status = TimesheetStatus::Draft;
+
+        let approved = update_body(&draft.with_status(TimesheetStatus::Approved));
+        assert_eq!(approved[0]["Status"], "APPROVED");
+        assert_eq!(approved[0]["Hours"], 22.0);
+        assert_eq!(
+            approved[0]["TimesheetLines"][0]["NumberOfUnits"],
+            json!([8.0, 8.0, 6.0, 0.0, 0.0, 0.0, 0.0])
+        );
+
+        let reverted = update_body(&approved_timesheet().with_status(TimesheetStatus::Draft));
+        assert_eq!(reverted[0]["Status"], "DRAFT");
+        assert_eq!(
+            reverted[0]["TimesheetLines"].as_array().map(Vec::len),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn clearing_lines_sends_an_empty_draft() {
+        let cleared = update_body(&approved_timesheet().without_lines());
+
+        assert_eq!(
+            cleared[0]["TimesheetID"],
+            "049765fc-4506-48fb-bf88-3578dec0ec47"
+        );
+        assert_eq!(cleared[0]["Status"], "DRAFT");
+        assert_eq!(cleared[0]["Hours"], 0.0);
+        assert_eq!(cleared[0]["TimesheetLines"], json!([]));
+    }
 }
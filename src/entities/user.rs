@@ -0,0 +1,188 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+    Client,
+    endpoints::XeroEndpoint,
+    entities::{EntityEndpoint, endpoint_utils},
+    error::Result,
+    utils::date_format::xero_datetime_format_option,
+};
+
+pub const ENDPOINT: &str = "https://api.xero.com/api.xro/2.0/Users/";
+
+/// The role a user has in the organisation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OrganisationRole {
+    ReadOnly,
+    InvoiceOnly,
+    Standard,
+    FinancialAdviser,
+    ManagedClient,
+    CashbookClient,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A user of the Xero organisation
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct User {
+    #[serde(rename = "UserID")]
+    pub user_id: Uuid,
+    #[serde(default)]
+    pub email_address: Option<String>,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub last_name: Option<String>,
+    #[serde(
+        rename = "UpdatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+    /// Whether the user is the subscriber (billing owner) of the organisation
+    #[serde(default)]
+    pub is_subscriber: Option<bool>,
+    #[serde(default)]
+    pub organisation_role: Option<OrganisationRole>,
+}
+
+impl User {
+    /// The user's full name
+    #[must_use]
+    pub fn full_name(&self) -> String {
+        [self.first_name.as_deref(), self.last_name.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Reference to a user, e.g. the owner of a receipt or expense claim
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserReference {
+    #[serde(rename = "UserID")]
+    pub user_id: Uuid,
+}
+
+impl From<Uuid> for UserReference {
+    fn from(user_id: Uuid) -> Self {
+        Self { user_id }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct ListResponse {
+    pub users: Vec<User>,
+}
+
+impl From<ListResponse> for Vec<User> {
+    fn from(response: ListResponse) -> Self {
+        response.users
+    }
+}
+
+/// Parameters for filtering user list results
+#[derive(Debug, Serialize, Default)]
+pub struct ListParameters {
+    /// Filter by any element
+    #[serde(rename = "where", skip_serializing_if = "Option::is_none")]
+    pub r#where: Option<String>,
+
+    /// Order by any element
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+
+    /// Only return users modified since this timestamp (sent as the
+    /// `If-Modified-Since` header rather than a query parameter)
+    #[serde(skip)]
+    pub if_modified_since: Option<OffsetDateTime>,
+}
+
+impl ListParameters {
+    /// Create a new builder for `ListParameters`
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Set the where filter
+    #[must_use]
+    pub fn with_where(mut self, filter: impl Into<String>) -> Self {
+        self.r#where = Some(filter.into());
+        self
+    }
+
+    /// Only return the user with the given email address
+    #[must_use]
+    pub fn with_email_address(self, email_address: &str) -> Self {
+        let email_address = email_address.replace('\\', "\\\\").replace('"', "\\\"");
+        self.with_where(format!("EmailAddress==\"{email_address}\""))
+    }
+
+    /// Set the order clause
+    #[must_use]
+    pub fn with_order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
+    /// Only return users modified since the given timestamp
+    #[must_use]
+    pub fn with_if_modified_since(mut self, modified_since: OffsetDateTime) -> Self {
+        self.if_modified_since = Some(modified_since);
+        self
+    }
+}
+
+impl EntityEndpoint<User, ListParameters> for User {
+    fn endpoint() -> &'static str {
+        ENDPOINT
+    }
+
+    async fn get(client: &Client, id: Uuid) -> Result<User> {
+        endpoint_utils::get::<User, ListResponse>(client, ENDPOINT, id, "User").await
+    }
+
+    async fn list(client: &Client, params: ListParameters) -> Result<Vec<User>> {
+        endpoint_utils::list::<User, ListResponse, _>(client, ENDPOINT, &params).await
+    }
+}
+
+/// Retrieve a list of users with filtering
+#[instrument(skip(client))]
+pub async fn list(client: &Client, params: ListParameters) -> Result<Vec<User>> {
+    let response: ListResponse = client
+        .get_endpoint_with_modified_since(
+            XeroEndpoint::Custom(vec!["Users".to_string()]),
+            &params,
+            params.if_modified_since,
+        )
+        .await?;
+    Ok(response.users)
+}
+
+/// Retrieve a list of all users without filtering
+#[instrument(skip(client))]
+pub async fn list_all(client: &Client) -> Result<Vec<User>> {
+    list(client, ListParameters::default()).await
+}
+
+/// Retrieve a single user by ID
+#[instrument(skip(client))]
+pub async fn get(client: &Client, user_id: Uuid) -> Result<User> {
+    User::get(client, user_id).await
+}
+
+/// Find the user with the given email address
+#[instrument(skip(client))]
+pub async fn find_by_email(client: &Client, email_address: &str) -> Result<Option<User>> {
+    let params = ListParameters::builder().with_email_address(email_address);
+    Ok(list(client, params).await?.into_iter().next())
+}
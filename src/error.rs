@@ -209,6 +209,14 @@ pub enum ErrorType {
     NotAvailableException,
     RateLimitExceededException,
     SystemUnavailableException,
+    /// Error reported by the payroll 2.0 (UK and NZ) APIs.
+    ///
+    /// These APIs describe failures with a camelCase `problem` object instead of a typed
+    /// exception; the client maps it onto [`Response`] so callers can handle both alike.
+    PayrollProblem {
+        #[serde(rename = "InvalidFields", default)]
+        invalid_fields: Vec<InvalidField>,
+    },
     Other(String),
 }
 
@@ -372,6 +380,12 @@ impl fmt::Display for Response {
                     }
                 }
             }
+            ErrorType::PayrollProblem { invalid_fields } if !invalid_fields.is_empty() => {
+                write!(f, "\nInvalid fields:")?;
+                for field in invalid_fields {
+                    write!(f, "\n  - {}: {}", field.name, field.reason)?;
+                }
+            }
             ErrorType::QueryParseException => {
                 write!(
                     f,
@@ -412,6 +426,73 @@ pub struct TimesheetValidationError {
     pub timesheet_lines: Vec<serde_json::Value>,
 }
 
+/// A field rejected by the payroll 2.0 (UK and NZ) APIs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidField {
+    pub name: String,
+    pub reason: String,
+}
+
+/// The `problem` object the payroll 2.0 (UK and NZ) APIs return on failure
+///
+/// # Example Response
+/// ```json
+/// {
+///   "problem": {
+///     "type": "application/problem+json",
+///     "title": "BadRequest",
+///     "status": 400,
+///     "detail": "Validation error occurred.",
+///     "invalidFields": [{"name": "FirstName", "reason": "The First Name is required."}]
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Problem {
+    #[serde(default)]
+    pub r#type: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
+    /// HTTP status, which the UK API sends as a number and the NZ API as a string
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::serde_helpers::number_or_string"
+    )]
+    pub status: Option<u64>,
+    #[serde(default)]
+    pub detail: Option<String>,
+    #[serde(default)]
+    pub instance: Option<String>,
+    #[serde(default)]
+    pub invalid_fields: Vec<InvalidField>,
+}
+
+#[derive(Deserialize)]
+struct ProblemResponse {
+    problem: Problem,
+}
+
+impl Response {
+    /// Parse a payroll 2.0 `problem` body into an API error response
+    #[must_use]
+    pub fn from_problem(body: &str) -> Option<Self> {
+        let ProblemResponse { problem } = serde_json::from_str(body).ok()?;
+        Some(Self {
+            error_number: None,
+            status: problem.status,
+            title: problem.title,
+            message: problem.detail.clone(),
+            detail: problem.detail,
+            instance: problem.instance.and_then(|instance| instance.parse().ok()),
+            error: ErrorType::PayrollProblem {
+                invalid_fields: problem.invalid_fields,
+            },
+        })
+    }
+}
+
 /// Format an OAuth2 error with detailed information, including raw response body for Parse errors.
 fn format_oauth2_error(
     error: &oauth2::RequestTokenError<HttpClientError<reqwest::Error>, OAuth2ErrorResponse>,
@@ -470,6 +551,117 @@ pub enum Error {
     )]
     AttachmentTooLarge,
 
+    #[error("invalid currency code: {0:?}")]
+    #[diagnostic(
+        code(xero_rs::invalid_currency_code),
+        help("Currency codes must be three letter ISO 4217 codes such as \"USD\"")
+    )]
+    InvalidCurrencyCode(String),
+
+    #[error("batch payment has no payments")]
+    #[diagnostic(
+        code(xero_rs::batch_payment_empty),
+        help("Add at least one payment with `with_payment` before creating the batch payment")
+    )]
+    BatchPaymentEmpty,
+
+    #[error("batch payment amount {amount} for invoice {invoice_id} is not positive")]
+    #[diagnostic(
+        code(xero_rs::invalid_batch_payment_amount),
+        help("Every payment in a batch payment must be for more than zero")
+    )]
+    InvalidBatchPaymentAmount {
+        /// The invoice the payment is made towards
+        invoice_id: uuid::Uuid,
+        /// The amount of the payment
+        amount: rust_decimal::Decimal,
+    },
+
+    #[error("batch payment total {actual} does not match the expected total {expected}")]
+    #[diagnostic(
+        code(xero_rs::batch_payment_total_mismatch),
+        help("The sum of the payment amounts must equal the total set on the batch payment")
+    )]
+    BatchPaymentTotalMismatch {
+        /// The total the batch payment was expected to have
+        expected: rust_decimal::Decimal,
+        /// The sum of the individual payment amounts
+        actual: rust_decimal::Decimal,
+    },
+
+    #[error("conversion balances do not balance: debits {debits} != credits {credits}")]
+    #[diagnostic(
+        code(xero_rs::conversion_balances_unbalanced),
+        help("Opening balances are a trial balance, so total debits must equal total credits")
+    )]
+    ConversionBalancesUnbalanced {
+        /// The sum of the debit balances
+        debits: rust_decimal::Decimal,
+        /// The sum of the credit balances
+        credits: rust_decimal::Decimal,
+    },
+
+    #[error("no payroll API is available for organisations in country {0:?}")]
+    #[diagnostic(
+        code(xero_rs::unsupported_payroll_region),
+        help("Xero Payroll is only offered in AU, the UK and NZ")
+    )]
+    UnsupportedPayrollRegion(String),
+
+    #[error("payroll for organisations in country {country_code:?} needs the {feature} feature")]
+    #[diagnostic(
+        code(xero_rs::payroll_feature_disabled),
+        help("Enable the {feature} feature of xero-rs in Cargo.toml")
+    )]
+    PayrollFeatureDisabled {
+        /// The organisation's country code
+        country_code: String,
+        /// The cargo feature that adds the region's payroll API
+        feature: &'static str,
+    },
+
+    #[error("employee {0} is not assigned to a payroll calendar")]
+    #[diagnostic(
+        code(xero_rs::missing_payroll_calendar),
+        help("Set the payroll calendar on the timesheet or assign the employee to a calendar")
+    )]
+    MissingPayrollCalendar(uuid::Uuid),
+
+    #[error("timesheet entry on {date} is outside the pay period {start_date} to {end_date}")]
+    #[diagnostic(
+        code(xero_rs::timesheet_entry_outside_period),
+        help("Every timesheet entry must fall within the timesheet's start and end dates")
+    )]
+    TimesheetEntryOutsidePeriod {
+        /// The date of the entry
+        date: time::Date,
+        /// The first day of the pay period
+        start_date: time::Date,
+        /// The last day of the pay period
+        end_date: time::Date,
+    },
+
+    #[error("cannot change {entity} from {from} to {to}")]
+    #[diagnostic(
+        code(xero_rs::invalid_status_transition),
+        help("Fetch the current record and check its status before changing it")
+    )]
+    InvalidStatusTransition {
+        /// The kind of record, e.g. an expense claim or leave application
+        entity: String,
+        /// The record's current status
+        from: String,
+        /// The status that was requested
+        to: String,
+    },
+
+    #[error("leave application {0} has no leave periods to cancel")]
+    #[diagnostic(
+        code(xero_rs::leave_application_without_periods),
+        help("Leave applications without leave periods have nothing left to pay out")
+    )]
+    LeaveApplicationWithoutPeriods(uuid::Uuid),
+
     /// Failed to parse the API response as JSON.
     ///
     /// This error includes the full HTTP response context for debugging.
@@ -703,7 +895,7 @@ impl Error {
     ///     .init();
     ///
     /// // Errors will now include span traces
-    /// if let Err(e) = client.contacts().list().await {
+    /// if let Err(e) = client.contacts().list_all().await {
     ///     if let Some(trace) = e.span_trace() {
     ///         eprintln!("Span trace:\n{}", trace);
     ///     }
@@ -729,6 +921,26 @@ impl Error {
             _ => None,
         }
     }
+
+    /// Get the timesheets that failed validation, with the index of each in the request.
+    ///
+    /// Xero echoes back every timesheet of a rejected request in the order it was sent, so
+    /// the index matches the slice passed to `TimesheetsApi::create_many` or `update_many`.
+    /// Timesheets without validation errors are skipped.
+    #[must_use]
+    pub fn timesheet_validation_errors(&self) -> Vec<(usize, &TimesheetValidationError)> {
+        match self.api_response().map(|response| &response.error) {
+            Some(ErrorType::ValidationException {
+                timesheets: Some(timesheets),
+                ..
+            }) => timesheets
+                .iter()
+                .enumerate()
+                .filter(|(_, timesheet)| !timesheet.validation_errors.is_empty())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl From<reqwest::Error> for Error {
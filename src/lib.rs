@@ -35,7 +35,7 @@
 //! 3. Errors will now automatically include span traces when reported to Sentry:
 //!
 //! ```ignore
-//! if let Err(e) = client.contacts().list().await {
+//! if let Err(e) = client.contacts().list_all().await {
 //!     // The error includes a span trace
 //!     if let Some(trace) = e.span_trace() {
 //!         eprintln!("Span trace:\n{}", trace);
@@ -130,6 +130,9 @@ pub use entities::line_item::LineAmountType as line_amount_types;
 // Re-export Account types for convenience
 pub use entities::account::{Account, AccountClass, AccountStatus, AccountType, BankAccountType};
 
+// Re-export Currency types for convenience
+pub use entities::currency::{Currency, CurrencyCode};
+
 // Re-export Item types for convenience
 pub use entities::item::{Item, PurchaseDetails, SalesDetails};
 
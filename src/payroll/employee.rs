@@ -1,26 +1,429 @@
-use serde::Deserialize;
+//! Employees API for Xero Payroll AU
+//!
+//! Payroll employees carry the details needed to pay someone: their home address, tax
+//! declaration, bank accounts, pay template, opening balances, leave balances and
+//! superannuation memberships. They are unrelated to the employees of the accounting API,
+//! see [`crate::accounting_employee::AccountingEmployee`].
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use tracing::{debug, error, info};
+use tracing_error::SpanTrace;
 use uuid::Uuid;
 
+use crate::{
+    error::Result,
+    payroll::payslip::{
+        DeductionLine, ReimbursementLine, SuperContributionType, SuperannuationLine,
+    },
+    utils::date_format::{xero_date_format_option, xero_datetime_format_option},
+};
+
 pub const ENDPOINT: &str = "https://api.xero.com/payroll.xro/1.0/Employees";
 
-#[derive(Clone, Debug, Deserialize)]
+/// An Australian state or territory
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum State {
+    Act,
+    Nsw,
+    Nt,
+    Qld,
+    Sa,
+    Tas,
+    Vic,
+    Wa,
+}
+
+/// An employee's home address
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HomeAddress {
+    pub address_line1: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address_line2: Option<String>,
+    pub city: String,
+    pub region: State,
+    pub postal_code: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+}
+
+impl HomeAddress {
+    /// Create an address with the fields Xero requires
+    #[must_use]
+    pub fn new(
+        address_line1: impl Into<String>,
+        city: impl Into<String>,
+        region: State,
+        postal_code: impl Into<String>,
+    ) -> Self {
+        Self {
+            address_line1: address_line1.into(),
+            address_line2: None,
+            city: city.into(),
+            region,
+            postal_code: postal_code.into(),
+            country: None,
+        }
+    }
+}
+
+/// The basis an employee is employed on
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum EmploymentBasis {
+    FullTime,
+    PartTime,
+    Casual,
+    LabourHire,
+    SuperIncomeStream,
+    NonEmployee,
+}
+
+/// Why an employee has not provided a tax file number
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TfnExemptionType {
+    NotQuoted,
+    Pending,
+    Pensioner,
+    Under18,
+}
+
+/// An employee's residency status for tax purposes
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ResidencyStatus {
+    AustralianResident,
+    ForeignResident,
+    WorkingHolidayMaker,
+}
+
+/// An employee's tax file number declaration
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TaxDeclaration {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub employment_basis: Option<EmploymentBasis>,
+    #[serde(
+        rename = "TFNExemptionType",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tfn_exemption_type: Option<TfnExemptionType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_file_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub residency_status: Option<ResidencyStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_free_threshold_claimed: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_offset_estimated_amount: Option<Decimal>,
+    #[serde(
+        rename = "HasHELPDebt",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub has_help_debt: Option<bool>,
+    #[serde(
+        rename = "HasSFSSDebt",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub has_sfss_debt: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_trade_support_loan_debt: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upward_variation_tax_withholding_amount: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eligible_to_receive_leave_loading: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approved_withholding_variation_percentage: Option<Decimal>,
+    #[serde(
+        default,
+        rename = "UpdatedDateUTC",
+        with = "xero_datetime_format_option",
+        skip_serializing
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+}
+
+/// A bank account an employee's net pay is paid into
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BankAccount {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statement_text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_name: Option<String>,
+    #[serde(rename = "BSB")]
+    pub bsb: String,
+    pub account_number: String,
+    /// Whether the account receives whatever is left after the other accounts are paid
+    #[serde(default)]
+    pub remainder: bool,
+    /// The fixed amount paid into the account, when it is not the remainder account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Decimal>,
+}
+
+impl BankAccount {
+    /// An account that receives the remainder of the employee's net pay
+    #[must_use]
+    pub fn remainder(bsb: impl Into<String>, account_number: impl Into<String>) -> Self {
+        Self {
+            statement_text: None,
+            account_name: None,
+            bsb: bsb.into(),
+            account_number: account_number.into(),
+            remainder: true,
+            amount: None,
+        }
+    }
+
+    /// An account that receives a fixed amount of the employee's net pay
+    #[must_use]
+    pub fn fixed_amount(
+        bsb: impl Into<String>,
+        account_number: impl Into<String>,
+        amount: Decimal,
+    ) -> Self {
+        Self {
+            remainder: false,
+            amount: Some(amount),
+            ..Self::remainder(bsb, account_number)
+        }
+    }
+}
+
+/// How an earnings line of a pay template is calculated
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum EarningsCalculationType {
+    UseEarningsRate,
+    EnterEarningsRate,
+    AnnualSalary,
+}
+
+/// An earnings line of an employee's pay template
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemplateEarningsLine {
+    #[serde(rename = "EarningsRateID")]
+    pub earnings_rate_id: Uuid,
+    pub calculation_type: EarningsCalculationType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_of_units_per_week: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annual_salary: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_per_unit: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub normal_number_of_units: Option<Decimal>,
+}
+
+/// How leave accrues for a leave line of a pay template
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum LeaveCalculationType {
+    FixedAmountEachPeriod,
+    EnterRateInPayTemplate,
+    BasedOnOrdinaryEarnings,
+    NoCalculationRequired,
+}
+
+/// A leave line of an employee's pay template
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TemplateLeaveLine {
+    #[serde(rename = "LeaveTypeID")]
+    pub leave_type_id: Uuid,
+    pub calculation_type: LeaveCalculationType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annual_number_of_units: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full_time_number_of_units_per_period: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_of_units: Option<Decimal>,
+}
+
+/// The earnings, deductions, super, reimbursements and leave an employee is paid by default
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayTemplate {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub earnings_lines: Vec<TemplateEarningsLine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deduction_lines: Vec<DeductionLine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub super_lines: Vec<SuperannuationLine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reimbursement_lines: Vec<ReimbursementLine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub leave_lines: Vec<TemplateLeaveLine>,
+}
+
+/// Year-to-date earnings brought over from a previous payroll system
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OpeningBalanceEarningsLine {
+    #[serde(rename = "EarningsRateID")]
+    pub earnings_rate_id: Uuid,
+    pub amount: Decimal,
+}
+
+/// Year-to-date deductions brought over from a previous payroll system
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OpeningBalanceDeductionLine {
+    #[serde(rename = "DeductionTypeID")]
+    pub deduction_type_id: Uuid,
+    pub amount: Decimal,
+}
+
+/// Year-to-date super contributions brought over from a previous payroll system
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OpeningBalanceSuperLine {
+    #[serde(rename = "SuperMembershipID")]
+    pub super_membership_id: Uuid,
+    pub contribution_type: SuperContributionType,
+    pub amount: Decimal,
+}
+
+/// Year-to-date reimbursements brought over from a previous payroll system
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OpeningBalanceReimbursementLine {
+    #[serde(rename = "ReimbursementTypeID")]
+    pub reimbursement_type_id: Uuid,
+    pub amount: Decimal,
+}
+
+/// Leave balances brought over from a previous payroll system
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OpeningBalanceLeaveLine {
+    #[serde(rename = "LeaveTypeID")]
+    pub leave_type_id: Uuid,
+    pub number_of_units: Decimal,
+}
+
+/// Year-to-date amounts brought over from a previous payroll system
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OpeningBalances {
+    #[serde(
+        default,
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub opening_balance_date: Option<Date>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub earnings_lines: Vec<OpeningBalanceEarningsLine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deduction_lines: Vec<OpeningBalanceDeductionLine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub super_lines: Vec<OpeningBalanceSuperLine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reimbursement_lines: Vec<OpeningBalanceReimbursementLine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub leave_lines: Vec<OpeningBalanceLeaveLine>,
+}
+
+/// An employee's current balance of one leave type
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LeaveBalance {
+    #[serde(rename = "LeaveTypeID")]
+    pub leave_type_id: Uuid,
+    #[serde(default)]
+    pub leave_name: Option<String>,
+    #[serde(default)]
+    pub number_of_units: Decimal,
+    /// "Hours" or "Days"
+    #[serde(default)]
+    pub type_of_units: Option<String>,
+}
+
+/// An employee's membership of a superannuation fund
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SuperMembership {
+    #[serde(
+        rename = "SuperMembershipID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub super_membership_id: Option<Uuid>,
+    #[serde(rename = "SuperFundID")]
+    pub super_fund_id: Uuid,
+    /// The employee's member number with the fund
+    pub employee_number: String,
+}
+
+impl SuperMembership {
+    /// A new membership of a super fund
+    #[must_use]
+    pub fn new(super_fund_id: Uuid, employee_number: impl Into<String>) -> Self {
+        Self {
+            super_membership_id: None,
+            super_fund_id,
+            employee_number: employee_number.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Employee {
     #[serde(rename = "EmployeeID")]
     pub employee_id: Uuid,
+    #[serde(default)]
+    pub title: Option<String>,
     pub first_name: String,
+    #[serde(default)]
+    pub middle_names: Option<String>,
     pub last_name: String,
     pub email: Option<String>,
     pub status: String,
     #[serde(rename = "PayrollCalendarID")]
     pub payroll_calendar_id: Option<Uuid>,
-    pub date_of_birth: Option<String>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub date_of_birth: Option<Date>,
     pub gender: Option<String>,
     pub phone: Option<String>,
     pub mobile: Option<String>,
-    pub start_date: Option<String>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub start_date: Option<Date>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub termination_date: Option<Date>,
+    #[serde(default)]
+    pub job_title: Option<String>,
+    #[serde(default)]
+    pub classification: Option<String>,
+    #[serde(default)]
+    pub employee_group_name: Option<String>,
     #[serde(rename = "OrdinaryEarningsRateID")]
     pub ordinary_earnings_rate_id: Option<Uuid>,
+    #[serde(default)]
+    pub home_address: Option<HomeAddress>,
+    #[serde(default)]
+    pub tax_declaration: Option<TaxDeclaration>,
+    #[serde(default)]
+    pub bank_accounts: Vec<BankAccount>,
+    #[serde(default)]
+    pub pay_template: Option<PayTemplate>,
+    #[serde(default)]
+    pub opening_balances: Option<OpeningBalances>,
+    #[serde(default)]
+    pub leave_balances: Vec<LeaveBalance>,
+    #[serde(default)]
+    pub super_memberships: Vec<SuperMembership>,
     #[serde(rename = "UpdatedDateUTC")]
     pub updated_date_utc: Option<String>,
     #[serde(rename = "IsSTP2Qualified")]
@@ -32,3 +435,243 @@ pub struct Employee {
 pub(crate) struct ListResponse {
     pub employees: Vec<Employee>,
 }
+
+/// Request structure for creating or updating a payroll employee
+///
+/// Only the fields that are set are sent, so an update leaves everything else unchanged.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PostEmployee {
+    #[serde(rename = "EmployeeID", skip_serializing_if = "Option::is_none")]
+    pub employee_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middle_names: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(
+        default,
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub date_of_birth: Option<Date>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_address: Option<HomeAddress>,
+    #[serde(
+        default,
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_date: Option<Date>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gender: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    #[serde(rename = "PayrollCalendarID", skip_serializing_if = "Option::is_none")]
+    pub payroll_calendar_id: Option<Uuid>,
+    #[serde(
+        rename = "OrdinaryEarningsRateID",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ordinary_earnings_rate_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_declaration: Option<TaxDeclaration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_accounts: Option<Vec<BankAccount>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pay_template: Option<PayTemplate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opening_balances: Option<OpeningBalances>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub super_memberships: Option<Vec<SuperMembership>>,
+}
+
+impl PostEmployee {
+    /// A new employee with the fields Xero requires
+    #[must_use]
+    pub fn new(
+        first_name: impl Into<String>,
+        last_name: impl Into<String>,
+        date_of_birth: Date,
+        home_address: HomeAddress,
+    ) -> Self {
+        Self {
+            first_name: Some(first_name.into()),
+            last_name: Some(last_name.into()),
+            date_of_birth: Some(date_of_birth),
+            home_address: Some(home_address),
+            ..Self::default()
+        }
+    }
+
+    /// An update to an existing employee
+    #[must_use]
+    pub fn update(employee_id: Uuid) -> Self {
+        Self {
+            employee_id: Some(employee_id),
+            ..Self::default()
+        }
+    }
+
+    /// Set the employee's email address
+    #[must_use]
+    pub fn with_email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    /// Set the employee's job title
+    #[must_use]
+    pub fn with_job_title(mut self, job_title: impl Into<String>) -> Self {
+        self.job_title = Some(job_title.into());
+        self
+    }
+
+    /// Set the employee's start date
+    #[must_use]
+    pub fn with_start_date(mut self, start_date: Date) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    /// Set the home address
+    #[must_use]
+    pub fn with_home_address(mut self, home_address: HomeAddress) -> Self {
+        self.home_address = Some(home_address);
+        self
+    }
+
+    /// Pay the employee on a payroll calendar with the given ordinary earnings rate
+    #[must_use]
+    pub fn with_payroll_calendar(
+        mut self,
+        payroll_calendar_id: Uuid,
+        ordinary_earnings_rate_id: Uuid,
+    ) -> Self {
+        self.payroll_calendar_id = Some(payroll_calendar_id);
+        self.ordinary_earnings_rate_id = Some(ordinary_earnings_rate_id);
+        self
+    }
+
+    /// Set the tax declaration
+    #[must_use]
+    pub fn with_tax_declaration(mut self, tax_declaration: TaxDeclaration) -> Self {
+        self.tax_declaration = Some(tax_declaration);
+        self
+    }
+
+    /// Replace the bank accounts
+    #[must_use]
+    pub fn with_bank_accounts(mut self, bank_accounts: Vec<BankAccount>) -> Self {
+        self.bank_accounts = Some(bank_accounts);
+        self
+    }
+
+    /// Set the pay template
+    #[must_use]
+    pub fn with_pay_template(mut self, pay_template: PayTemplate) -> Self {
+        self.pay_template = Some(pay_template);
+        self
+    }
+
+    /// Set the opening balances
+    #[must_use]
+    pub fn with_opening_balances(mut self, opening_balances: OpeningBalances) -> Self {
+        self.opening_balances = Some(opening_balances);
+        self
+    }
+
+    /// Replace the super memberships
+    #[must_use]
+    pub fn with_super_memberships(mut self, super_memberships: Vec<SuperMembership>) -> Self {
+        self.super_memberships = Some(super_memberships);
+        self
+    }
+}
+
+impl Employee {
+    /// Get a single employee by ID, with all of their details
+    pub async fn get(client: &crate::client::Client, employee_id: Uuid) -> Result<Employee> {
+        info!("Getting employee with ID: {}", employee_id);
+
+        let url = format!("{ENDPOINT}/{employee_id}");
+        debug!("GET URL: {}", url);
+
+        let response: ListResponse = client.get(&url, &()).await?;
+        response
+            .employees
+            .into_iter()
+            .next()
+            .ok_or_else(|| not_found(url))
+    }
+
+    /// Create a payroll employee
+    pub async fn create(
+        client: &crate::client::Client,
+        employee: &PostEmployee,
+    ) -> Result<Employee> {
+        info!("Creating employee");
+        // The full record holds the tax file number and bank accounts, so only log the name
+        debug!(
+            "Employee name: {} {}",
+            employee.first_name.as_deref().unwrap_or_default(),
+            employee.last_name.as_deref().unwrap_or_default()
+        );
+
+        let request = vec![employee.clone()];
+        let response: ListResponse = client.post(ENDPOINT, &request).await?;
+        response
+            .employees
+            .into_iter()
+            .next()
+            .ok_or_else(|| not_found(ENDPOINT.to_string()))
+    }
+
+    /// Update a payroll employee
+    pub async fn update(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        employee: &PostEmployee,
+    ) -> Result<Employee> {
+        info!("Updating employee with ID: {}", employee_id);
+        debug!(
+            "Employee name: {} {}",
+            employee.first_name.as_deref().unwrap_or_default(),
+            employee.last_name.as_deref().unwrap_or_default()
+        );
+
+        let url = format!("{ENDPOINT}/{employee_id}");
+        debug!("POST URL: {}", url);
+
+        let mut employee = employee.clone();
+        employee.employee_id = Some(employee_id);
+        let request = vec![employee];
+        let response: ListResponse = client.post(&url, &request).await?;
+        response
+            .employees
+            .into_iter()
+            .next()
+            .ok_or_else(|| not_found(url))
+    }
+}
+
+/// Error for a response that unexpectedly contains no employees
+fn not_found(url: String) -> crate::error::Error {
+    error!("Received empty employees array in response");
+    crate::error::Error::NotFound {
+        entity: "Employee".to_string(),
+        url,
+        status_code: reqwest::StatusCode::NOT_FOUND,
+        response_body: Some("No employee returned in response".to_string()),
+        span_trace: SpanTrace::capture(),
+    }
+}
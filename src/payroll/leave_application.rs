@@ -29,8 +29,11 @@ use tracing_error::SpanTrace;
 use uuid::Uuid;
 
 use crate::{
-    error::Result,
-    utils::date_format::{xero_date_format, xero_date_format_option, xero_datetime_format_option},
+    error::{Error, Result},
+    utils::{
+        date_format::{xero_date_format, xero_date_format_option, xero_datetime_format_option},
+        serde_helpers::variant_name,
+    },
 };
 
 /// Base endpoint for leave applications (v1 - returns only approved leave)
@@ -266,6 +269,24 @@ pub struct LeaveApplicationResponse {
     pub leave_applications: Vec<LeaveApplication>,
 }
 
+/// Response wrapper for leave period calculations
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LeavePeriodsResponse {
+    #[serde(default)]
+    pub leave_periods: Vec<LeavePeriod>,
+}
+
+/// Query parameters for calculating leave periods
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PredictParameters {
+    #[serde(with = "xero_date_format")]
+    start_date: Date,
+    #[serde(with = "xero_date_format")]
+    end_date: Date,
+}
+
 impl LeaveApplication {
     /// List approved leave applications (v1 endpoint)
     ///
@@ -583,4 +604,164 @@ impl LeaveApplication {
 
         Ok(response.leave_applications.into_iter().next().unwrap())
     }
+
+    /// Whether the application is approved and has leave periods that have not been
+    /// processed yet, so [`LeaveApplication::cancel`] can cancel it
+    #[must_use]
+    pub fn is_cancellable(&self) -> bool {
+        self.cancel_error().is_none()
+    }
+
+    /// The error [`LeaveApplication::cancel`] would return for the application, if any
+    ///
+    /// This is [`Error::LeaveApplicationWithoutPeriods`] when there are no leave periods, and
+    /// [`Error::InvalidStatusTransition`] from the blocking period status when a period is
+    /// requested or rejected, or every period has already been processed.
+    #[must_use]
+    pub fn cancel_error(&self) -> Option<Error> {
+        let periods = self.leave_periods.as_deref().unwrap_or_default();
+        if periods.is_empty() {
+            return Some(Error::LeaveApplicationWithoutPeriods(
+                self.leave_application_id,
+            ));
+        }
+
+        let unapproved = periods.iter().find_map(|period| {
+            period.leave_period_status.clone().filter(|status| {
+                matches!(
+                    status,
+                    LeavePeriodStatus::Requested | LeavePeriodStatus::Rejected
+                )
+            })
+        });
+        let scheduled = periods
+            .iter()
+            .any(|period| period.leave_period_status != Some(LeavePeriodStatus::Processed));
+
+        let from = match unapproved {
+            Some(status) => status,
+            None if !scheduled => LeavePeriodStatus::Processed,
+            None => return None,
+        };
+        Some(Error::InvalidStatusTransition {
+            entity: "LeaveApplication".to_string(),
+            from: variant_name(&from),
+            to: "CANCELLED".to_string(),
+        })
+    }
+
+    /// Cancel an approved leave application
+    ///
+    /// The Payroll AU API has no endpoint for cancelling leave, so this sets the units of
+    /// every leave period that has not been processed to zero, which leaves nothing for a pay
+    /// run to pay out. Periods already processed in a pay run are left as they are.
+    ///
+    /// Returns the error from [`LeaveApplication::cancel_error`] unless the application
+    /// is approved and has scheduled periods left: requested leave should be rejected instead,
+    /// and rejected, fully processed or empty leave cannot be cancelled.
+    pub async fn cancel(
+        client: &crate::client::Client,
+        leave_application_id: Uuid,
+    ) -> Result<LeaveApplication> {
+        info!(
+            "Cancelling leave application with ID: {}",
+            leave_application_id
+        );
+
+        let mut leave_application = Self::get(client, leave_application_id).await?;
+        if let Some(e) = leave_application.cancel_error() {
+            error!(
+                "Cannot cancel leave application {}: {}",
+                leave_application_id, e
+            );
+            return Err(e);
+        }
+
+        for period in leave_application.leave_periods.iter_mut().flatten() {
+            if period.leave_period_status != Some(LeavePeriodStatus::Processed) {
+                period.number_of_units = Some(0.0);
+            }
+        }
+        Self::update(client, &leave_application).await
+    }
+
+    /// Calculate the leave periods Xero would create for an employee's leave between two
+    /// dates, without creating a leave application
+    ///
+    /// Each period covers one pay period of the employee's pay calendar, with the units of
+    /// leave that fall within it.
+    ///
+    /// Calls `GET LeaveApplications/{EmployeeID}/{LeaveTypeID}/predict?startDate=&endDate=`,
+    /// documented in the [Payroll AU leave applications reference](https://developer.xero.com/documentation/api/payrollau/leaveapplications).
+    /// The bundled `Xero-OpenAPI/xero-payroll-au.yaml` predates this endpoint.
+    pub async fn preview_periods(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        leave_type_id: Uuid,
+        start_date: Date,
+        end_date: Date,
+    ) -> Result<Vec<LeavePeriod>> {
+        info!(
+            "Calculating leave periods for employee {} from {} to {}",
+            employee_id, start_date, end_date
+        );
+
+        let url = preview_periods_url(employee_id, leave_type_id);
+        let parameters = PredictParameters {
+            start_date,
+            end_date,
+        };
+        debug!("GET URL: {} with {:?}", url, parameters);
+
+        let response: LeavePeriodsResponse = match client.get(&url, &parameters).await {
+            Ok(response) => {
+                info!("Leave period calculation successful");
+                response
+            }
+            Err(e) => {
+                error!("Error calculating leave periods: {:?}", e);
+                return Err(e);
+            }
+        };
+
+        debug!(
+            "Response contains {} leave periods",
+            response.leave_periods.len()
+        );
+        Ok(response.leave_periods)
+    }
+}
+
+/// URL that calculates the leave periods for an employee's leave of one type
+fn preview_periods_url(employee_id: Uuid, leave_type_id: Uuid) -> String {
+    format!("{ENDPOINT}/{employee_id}/{leave_type_id}/predict")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::date;
+
+    #[test]
+    fn preview_periods_request_url() {
+        let employee_id = Uuid::parse_str("cdfb8371-0b21-4b8a-8903-1024df6c391e").unwrap();
+        let leave_type_id = Uuid::parse_str("184ea8f7-d143-46dd-bef3-0c60e1aa6fca").unwrap();
+        let parameters = PredictParameters {
+            start_date: date!(2025 - 01 - 06),
+            end_date: date!(2025 - 01 - 17),
+        };
+
+        let request = reqwest::Client::new()
+            .get(preview_periods_url(employee_id, leave_type_id))
+            .query(&parameters)
+            .build()
+            .expect("request should build");
+
+        assert_eq!(
+            request.url().as_str(),
+            "https://api.xero.com/payroll.xro/1.0/LeaveApplications/\
+             cdfb8371-0b21-4b8a-8903-1024df6c391e/184ea8f7-d143-46dd-bef3-0c60e1aa6fca/predict\
+             ?startDate=2025-01-06&endDate=2025-01-17"
+        );
+    }
 }
@@ -0,0 +1,281 @@
+//! Leave balances for Xero Payroll AU
+//!
+//! Xero only returns an employee's leave balances with their full employee record, keyed by
+//! leave type ID. This module pairs each balance with its [`LeaveType`] and estimates
+//! balances at a future date: leave accrues each pay period at the leave type's normal
+//! entitlement spread over the pay calendar's periods, and the units of scheduled or
+//! requested [`LeavePeriod`]s are taken off.
+//!
+//! The projection is an estimate. Xero accrues leave from the ordinary hours actually paid
+//! in each pay run, which can differ from the normal entitlement.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xero_rs::{Client, KeyPair};
+//!
+//! # async fn example(leave_application_id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! let application = client.leave_applications().get(leave_application_id).await?;
+//! let projection = client.leave_applications().balance_after(&application).await?;
+//! println!(
+//!     "Balance on {} after this request: {}",
+//!     projection.date, projection.balance
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::Date;
+use tracing::{debug, info};
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use super::{
+    employee::{self, Employee},
+    leave_application::{self, LeaveApplication, LeavePeriod, LeavePeriodStatus},
+    settings::{
+        leave_types::LeaveType,
+        pay_calendar::{CalendarType, PayCalendar},
+        pay_items::PayItems,
+    },
+};
+use crate::error::{Error, Result};
+
+/// An employee's balance of one leave type
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LeaveTypeBalance {
+    pub leave_type_id: Uuid,
+    /// Name of the leave type, e.g. "Annual Leave"
+    pub name: Option<String>,
+    /// Units of leave available to the employee
+    pub balance: Decimal,
+    /// "Hours" or "Days"
+    pub type_of_units: Option<String>,
+    /// The leave type, unless it has since been removed from the organisation's pay items
+    pub leave_type: Option<LeaveType>,
+}
+
+/// An estimate of an employee's balance of one leave type at a future date
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LeaveProjection {
+    pub leave_type_id: Uuid,
+    /// The date the balance is projected to
+    pub date: Date,
+    /// The balance today
+    pub opening_balance: Decimal,
+    /// Pay periods that end between the start of the current pay period and `date`
+    pub pay_periods: u32,
+    /// Units accrued over those pay periods
+    pub accrued: Decimal,
+    /// Units of leave scheduled or requested up to `date`
+    pub taken: Decimal,
+    /// The projected balance at `date`
+    pub balance: Decimal,
+}
+
+impl LeaveTypeBalance {
+    /// Pair an employee's leave balances with the organisation's leave types
+    #[must_use]
+    pub fn from_balances(
+        balances: Vec<employee::LeaveBalance>,
+        leave_types: &[LeaveType],
+    ) -> Vec<LeaveTypeBalance> {
+        balances
+            .into_iter()
+            .map(|balance| {
+                let leave_type = leave_types
+                    .iter()
+                    .find(|leave_type| leave_type.leave_type_id == balance.leave_type_id)
+                    .cloned();
+                LeaveTypeBalance {
+                    leave_type_id: balance.leave_type_id,
+                    name: balance.leave_name.or_else(|| {
+                        leave_type
+                            .as_ref()
+                            .map(|leave_type| leave_type.name.clone())
+                    }),
+                    balance: balance.number_of_units,
+                    type_of_units: balance.type_of_units.or_else(|| {
+                        leave_type
+                            .as_ref()
+                            .and_then(|leave_type| leave_type.type_of_units.clone())
+                    }),
+                    leave_type,
+                }
+            })
+            .collect()
+    }
+
+    /// Units accrued in one pay period of a calendar, from the leave type's normal yearly
+    /// entitlement
+    ///
+    /// Zero when the leave type is unknown or has no normal entitlement.
+    #[must_use]
+    pub fn accrual_per_period(&self, calendar_type: &CalendarType) -> Decimal {
+        self.leave_type
+            .as_ref()
+            .and_then(|leave_type| leave_type.normal_entitlement)
+            .and_then(|entitlement| Decimal::try_from(entitlement).ok())
+            .map_or(Decimal::ZERO, |entitlement| {
+                entitlement / Decimal::from(calendar_type.periods_per_year())
+            })
+    }
+
+    /// Estimate the balance at `date`
+    ///
+    /// Leave accrues for every period of the calendar, starting with its current period,
+    /// that ends on or before `date`. The units of the leave periods that end on or before
+    /// `date` are taken off, unless they have already been processed (and so are part of
+    /// the balance) or were rejected. Pass the periods of the employee's other leave of this
+    /// type along with those of a new request to see the balance after the request.
+    /// Accrued units are rounded to 4 decimal places.
+    #[must_use]
+    pub fn project(
+        &self,
+        calendar: &PayCalendar,
+        date: Date,
+        leave_periods: &[LeavePeriod],
+    ) -> LeaveProjection {
+        let pay_periods = calendar
+            .pay_periods()
+            .take_while(|period| period.end_date <= date)
+            .count();
+        let pay_periods = u32::try_from(pay_periods).unwrap_or(u32::MAX);
+        let accrued = (self.accrual_per_period(&calendar.calendar_type)
+            * Decimal::from(pay_periods))
+        .round_dp(4);
+
+        let taken = leave_periods
+            .iter()
+            .filter(|period| {
+                !matches!(
+                    period.leave_period_status,
+                    Some(LeavePeriodStatus::Processed | LeavePeriodStatus::Rejected)
+                )
+            })
+            .filter(|period| {
+                period
+                    .pay_period_end_date
+                    .or(period.pay_period_start_date)
+                    .is_some_and(|end_date| end_date <= date)
+            })
+            .filter_map(|period| period.number_of_units)
+            .filter_map(|units| Decimal::try_from(units).ok())
+            .sum::<Decimal>();
+
+        LeaveProjection {
+            leave_type_id: self.leave_type_id,
+            date,
+            opening_balance: self.balance,
+            pay_periods,
+            accrued,
+            taken,
+            balance: self.balance + accrued - taken,
+        }
+    }
+
+    /// Estimate the balance after leave that ends on `leave_end_date`
+    ///
+    /// A leave request's last period usually ends after the leave itself, so this projects
+    /// to the end of the pay period containing `leave_end_date` to include all of the
+    /// request's units. Leave ending before the calendar's current period is projected to
+    /// `leave_end_date` itself.
+    #[must_use]
+    pub fn project_leave(
+        &self,
+        calendar: &PayCalendar,
+        leave_end_date: Date,
+        leave_periods: &[LeavePeriod],
+    ) -> LeaveProjection {
+        let date = calendar
+            .pay_periods()
+            .take_while(|period| period.start_date <= leave_end_date)
+            .find(|period| period.contains(leave_end_date))
+            .map_or(leave_end_date, |period| period.end_date);
+        self.project(calendar, date, leave_periods)
+    }
+}
+
+/// List an employee's leave balances, paired with their leave types
+pub async fn list(
+    client: &crate::client::Client,
+    employee_id: Uuid,
+) -> Result<Vec<LeaveTypeBalance>> {
+    info!(
+        "Listing leave balances for employee with ID: {}",
+        employee_id
+    );
+
+    let employee = Employee::get(client, employee_id).await?;
+    let pay_items = PayItems::get(client).await?;
+    debug!(
+        "Employee has {} leave balances",
+        employee.leave_balances.len()
+    );
+
+    Ok(LeaveTypeBalance::from_balances(
+        employee.leave_balances,
+        &pay_items.leave_types,
+    ))
+}
+
+/// Estimate an employee's balance of a leave application's leave type at the end of the pay
+/// period its leave ends in, after the application and the employee's other scheduled or
+/// requested leave of that type
+///
+/// Use it on an application awaiting approval to see the balance the employee would be
+/// left with.
+pub async fn project_application(
+    client: &crate::client::Client,
+    application: &LeaveApplication,
+) -> Result<LeaveProjection> {
+    info!(
+        "Projecting leave balance for leave application with ID: {}",
+        application.leave_application_id
+    );
+
+    let employee = Employee::get(client, application.employee_id).await?;
+    let payroll_calendar_id = employee
+        .payroll_calendar_id
+        .ok_or(Error::MissingPayrollCalendar(employee.employee_id))?;
+    let calendar = client.pay_calendars().get(payroll_calendar_id).await?;
+    let pay_items = PayItems::get(client).await?;
+
+    let balance = LeaveTypeBalance::from_balances(employee.leave_balances, &pay_items.leave_types)
+        .into_iter()
+        .find(|balance| balance.leave_type_id == application.leave_type_id)
+        .ok_or_else(|| Error::NotFound {
+            entity: "LeaveBalance".to_string(),
+            url: employee::ENDPOINT.to_string(),
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            response_body: Some(format!(
+                "Employee {} has no balance of leave type {}",
+                application.employee_id, application.leave_type_id
+            )),
+            span_trace: SpanTrace::capture(),
+        })?;
+
+    let parameters = leave_application::ListParameters {
+        employee_id: Some(application.employee_id),
+        ..Default::default()
+    };
+    let other_leave = LeaveApplication::list_v2(client, Some(&parameters), None).await?;
+    let leave_periods: Vec<LeavePeriod> = other_leave
+        .iter()
+        .filter(|other| {
+            other.leave_type_id == application.leave_type_id
+                && other.leave_application_id != application.leave_application_id
+        })
+        .chain(std::iter::once(application))
+        .filter_map(|leave| leave.leave_periods.as_deref())
+        .flatten()
+        .cloned()
+        .collect();
+
+    Ok(balance.project_leave(&calendar, application.end_date, &leave_periods))
+}
@@ -1,3 +1,14 @@
 pub mod employee;
 pub mod leave_application;
+pub mod leave_balance;
+#[cfg(feature = "payroll-nz")]
+pub mod nz;
+pub mod pay_run;
+pub mod payslip;
+pub mod region;
 pub mod settings;
+pub mod superfund;
+#[cfg(feature = "payroll-uk")]
+pub mod uk;
+#[cfg(any(feature = "payroll-uk", feature = "payroll-nz"))]
+pub mod v2;
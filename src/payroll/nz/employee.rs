@@ -0,0 +1,579 @@
+//! Employees API for Xero Payroll NZ
+//!
+//! Covers the employee record itself, the employment details that put an employee on a
+//! payroll calendar, the employee's IRD tax and KiwiSaver details, and the opening balances
+//! carried over from a previous payroll system.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use time::macros::date;
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::nz::employee::{Address, EngagementType, Employment, PostEmployee};
+//!
+//! # async fn example(calendar_id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! let address = Address::new("1 Queen Street", "Auckland", "1010");
+//! let employee = PostEmployee::new("Aroha", "Ngata", date!(1992 - 03 - 18), address);
+//! let employee = client.payroll_nz().employees().create(&employee).await?;
+//!
+//! let employment = Employment::new(calendar_id, date!(2024 - 04 - 01), EngagementType::Permanent);
+//! client
+//!     .payroll_nz()
+//!     .employees()
+//!     .create_employment(employee.employee_id, &employment)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::{Pagination, list_all, not_found};
+use crate::{
+    error::Result,
+    utils::date_format::{xero_date_format, xero_date_format_option, xero_datetime_format_option},
+};
+
+/// Base endpoint for NZ employees
+pub const ENDPOINT: &str = "https://api.xero.com/payroll.xro/2.0/Employees";
+
+/// Gender of an employee
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum Gender {
+    #[serde(rename = "M")]
+    Male,
+    #[serde(rename = "F")]
+    Female,
+}
+
+/// Basis an employee is engaged on
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum EngagementType {
+    Permanent,
+    /// Employment ending on a fixed date, see `fixed_term_end_date`
+    FixedTerm,
+    Casual,
+}
+
+/// IRD tax code of an employee
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TaxCode {
+    Nd,
+    M,
+    Me,
+    Msl,
+    Mesl,
+    Sb,
+    S,
+    Sh,
+    St,
+    Sbsl,
+    Ssl,
+    Shsl,
+    Stsl,
+    Wt,
+    Cae,
+    Edw,
+    Nsw,
+    Stc,
+    Stcsl,
+}
+
+/// KiwiSaver membership of an employee
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum KiwiSaverContributions {
+    MakeContributions,
+    OptOut,
+    OnAContributionsHoliday,
+    OnASavingsSuspension,
+    NotCurrentlyAKiwiSaverMember,
+}
+
+/// Home address of an NZ employee
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Address {
+    pub address_line1: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address_line2: Option<String>,
+    pub city: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suburb: Option<String>,
+    pub post_code: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_name: Option<String>,
+}
+
+impl Address {
+    /// An address with the fields the API requires
+    #[must_use]
+    pub fn new(
+        address_line1: impl Into<String>,
+        city: impl Into<String>,
+        post_code: impl Into<String>,
+    ) -> Self {
+        Self {
+            address_line1: address_line1.into(),
+            address_line2: None,
+            city: city.into(),
+            suburb: None,
+            post_code: post_code.into(),
+            country_name: None,
+        }
+    }
+
+    /// Set the suburb
+    #[must_use]
+    pub fn with_suburb(mut self, suburb: impl Into<String>) -> Self {
+        self.suburb = Some(suburb.into());
+        self
+    }
+}
+
+/// An employee in Xero Payroll NZ
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Employee {
+    #[serde(rename = "employeeID")]
+    pub employee_id: Uuid,
+    #[serde(default)]
+    pub title: Option<String>,
+    pub first_name: String,
+    pub last_name: String,
+    #[serde(default, with = "xero_date_format_option")]
+    pub date_of_birth: Option<Date>,
+    #[serde(default)]
+    pub address: Option<Address>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub gender: Option<Gender>,
+    #[serde(default)]
+    pub phone_number: Option<String>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub start_date: Option<Date>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub end_date: Option<Date>,
+    #[serde(rename = "payrollCalendarID", default)]
+    pub payroll_calendar_id: Option<Uuid>,
+    #[serde(
+        rename = "updatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+    #[serde(
+        rename = "createdDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub created_date_utc: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub job_title: Option<String>,
+    #[serde(default)]
+    pub engagement_type: Option<EngagementType>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub fixed_term_end_date: Option<Date>,
+}
+
+/// Request structure for creating or updating an NZ employee
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostEmployee {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub first_name: String,
+    pub last_name: String,
+    #[serde(with = "xero_date_format")]
+    pub date_of_birth: Date,
+    pub address: Address,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gender: Option<Gender>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+}
+
+impl PostEmployee {
+    /// An employee with the fields the API requires
+    #[must_use]
+    pub fn new(
+        first_name: impl Into<String>,
+        last_name: impl Into<String>,
+        date_of_birth: Date,
+        address: Address,
+    ) -> Self {
+        Self {
+            title: None,
+            first_name: first_name.into(),
+            last_name: last_name.into(),
+            date_of_birth,
+            address,
+            gender: None,
+            email: None,
+            phone_number: None,
+            job_title: None,
+        }
+    }
+
+    /// Set the employee's title, e.g. `"Ms"`
+    #[must_use]
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the employee's gender
+    #[must_use]
+    pub fn with_gender(mut self, gender: Gender) -> Self {
+        self.gender = Some(gender);
+        self
+    }
+
+    /// Set the employee's email address
+    #[must_use]
+    pub fn with_email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    /// Set the employee's phone number
+    #[must_use]
+    pub fn with_phone_number(mut self, phone_number: impl Into<String>) -> Self {
+        self.phone_number = Some(phone_number.into());
+        self
+    }
+
+    /// Set the employee's job title
+    #[must_use]
+    pub fn with_job_title(mut self, job_title: impl Into<String>) -> Self {
+        self.job_title = Some(job_title.into());
+        self
+    }
+}
+
+/// Employment details that place an employee on a payroll calendar
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Employment {
+    #[serde(rename = "payrollCalendarID")]
+    pub payroll_calendar_id: Uuid,
+    #[serde(
+        rename = "payRunCalendarID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pay_run_calendar_id: Option<Uuid>,
+    #[serde(with = "xero_date_format")]
+    pub start_date: Date,
+    pub engagement_type: EngagementType,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "xero_date_format_option"
+    )]
+    pub fixed_term_end_date: Option<Date>,
+}
+
+impl Employment {
+    /// Employment starting on `start_date` on the given payroll calendar
+    #[must_use]
+    pub fn new(
+        payroll_calendar_id: Uuid,
+        start_date: Date,
+        engagement_type: EngagementType,
+    ) -> Self {
+        Self {
+            payroll_calendar_id,
+            pay_run_calendar_id: None,
+            start_date,
+            engagement_type,
+            fixed_term_end_date: None,
+        }
+    }
+
+    /// Set the date fixed term employment ends
+    #[must_use]
+    pub fn with_fixed_term_end_date(mut self, fixed_term_end_date: Date) -> Self {
+        self.fixed_term_end_date = Some(fixed_term_end_date);
+        self
+    }
+}
+
+/// An employee's IRD tax, student loan and KiwiSaver details
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployeeTax {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ird_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<TaxCode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub special_tax_rate_percentage: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_special_student_loan_rate: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub special_student_loan_rate_percentage: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_eligible_for_kiwi_saver: Option<bool>,
+    /// Employer superannuation contribution tax rate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub esct_rate_percentage: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kiwi_saver_contributions: Option<KiwiSaverContributions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kiwi_saver_employee_contribution_rate_percentage: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kiwi_saver_employer_contribution_rate_percentage: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kiwi_saver_employer_salary_sacrifice_contribution_rate_percentage: Option<Decimal>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "xero_date_format_option"
+    )]
+    pub kiwi_saver_opt_out_date: Option<Date>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "xero_date_format_option"
+    )]
+    pub kiwi_saver_contribution_holiday_end_date: Option<Date>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_student_loan_balance: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub student_loan_balance: Option<Decimal>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "xero_date_format_option"
+    )]
+    pub student_loan_as_at: Option<Date>,
+}
+
+/// Earnings paid to an employee by a previous payroll system, for one pay period
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpeningBalance {
+    #[serde(with = "xero_date_format")]
+    pub period_end_date: Date,
+    #[serde(default)]
+    pub days_paid: i32,
+    #[serde(default)]
+    pub unpaid_weeks: i32,
+    #[serde(default)]
+    pub gross_earnings: Decimal,
+}
+
+/// Response wrapper for NZ employee lists
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployeesResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub employees: Vec<Employee>,
+}
+
+/// Response wrapper for a single NZ employee
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployeeResponse {
+    #[serde(default)]
+    pub employee: Option<Employee>,
+}
+
+/// Response wrapper for employment details
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmploymentResponse {
+    #[serde(default)]
+    pub employment: Option<Employment>,
+}
+
+/// Response wrapper for an employee's tax details
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployeeTaxResponse {
+    #[serde(default)]
+    pub employee_tax: Option<EmployeeTax>,
+}
+
+/// Response wrapper for an employee's opening balances
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpeningBalancesResponse {
+    #[serde(default)]
+    pub opening_balances: Vec<OpeningBalance>,
+}
+
+impl Employee {
+    /// List all employees, fetching every page
+    pub async fn list(client: &crate::client::Client) -> Result<Vec<Employee>> {
+        info!("Listing NZ employees");
+
+        list_all(client, ENDPOINT, &(), |response: EmployeesResponse| {
+            (response.pagination, response.employees)
+        })
+        .await
+    }
+
+    /// Get a single employee by ID
+    pub async fn get(client: &crate::client::Client, employee_id: Uuid) -> Result<Employee> {
+        info!("Getting NZ employee with ID: {}", employee_id);
+
+        let url = format!("{ENDPOINT}/{employee_id}");
+        debug!("GET URL: {}", url);
+
+        let response: EmployeeResponse = client.get(&url, &()).await?;
+        response.employee.ok_or_else(|| not_found("Employee", url))
+    }
+
+    /// Create an employee
+    pub async fn create(
+        client: &crate::client::Client,
+        employee: &PostEmployee,
+    ) -> Result<Employee> {
+        info!("Creating NZ employee");
+        // The full record holds the date of birth and home address, so only log the name
+        debug!(
+            "Employee name: {} {}",
+            employee.first_name, employee.last_name
+        );
+
+        let response: EmployeeResponse = client.post(ENDPOINT, employee).await?;
+        response
+            .employee
+            .ok_or_else(|| not_found("Employee", ENDPOINT.to_string()))
+    }
+
+    /// Update an employee
+    pub async fn update(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        employee: &PostEmployee,
+    ) -> Result<Employee> {
+        info!("Updating NZ employee with ID: {}", employee_id);
+        debug!(
+            "Employee name: {} {}",
+            employee.first_name, employee.last_name
+        );
+
+        let url = format!("{ENDPOINT}/{employee_id}");
+        debug!("PUT URL: {}", url);
+
+        let response: EmployeeResponse = client.put(&url, employee).await?;
+        response.employee.ok_or_else(|| not_found("Employee", url))
+    }
+}
+
+impl Employment {
+    /// Create the employment details for an employee
+    pub async fn create(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        employment: &Employment,
+    ) -> Result<Employment> {
+        info!(
+            "Creating employment for NZ employee with ID: {}",
+            employee_id
+        );
+        // Employment details include the engagement terms, so only log the calendar
+        debug!("Payroll calendar ID: {}", employment.payroll_calendar_id);
+
+        let url = format!("{ENDPOINT}/{employee_id}/Employment");
+        debug!("POST URL: {}", url);
+
+        let response: EmploymentResponse = client.post(&url, employment).await?;
+        response
+            .employment
+            .ok_or_else(|| not_found("Employment", url))
+    }
+}
+
+impl EmployeeTax {
+    /// Get the tax details of an employee
+    pub async fn get(client: &crate::client::Client, employee_id: Uuid) -> Result<EmployeeTax> {
+        info!(
+            "Getting tax details for NZ employee with ID: {}",
+            employee_id
+        );
+
+        let url = format!("{ENDPOINT}/{employee_id}/Tax");
+        debug!("GET URL: {}", url);
+
+        let response: EmployeeTaxResponse = client.get(&url, &()).await?;
+        response
+            .employee_tax
+            .ok_or_else(|| not_found("EmployeeTax", url))
+    }
+
+    /// Update the tax details of an employee
+    pub async fn update(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        tax: &EmployeeTax,
+    ) -> Result<EmployeeTax> {
+        info!(
+            "Updating tax details for NZ employee with ID: {}",
+            employee_id
+        );
+
+        let url = format!("{ENDPOINT}/{employee_id}/Tax");
+        debug!("POST URL: {}", url);
+
+        let response: EmployeeTaxResponse = client.post(&url, tax).await?;
+        response
+            .employee_tax
+            .ok_or_else(|| not_found("EmployeeTax", url))
+    }
+}
+
+impl OpeningBalance {
+    /// List the opening balances of an employee
+    pub async fn list(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+    ) -> Result<Vec<OpeningBalance>> {
+        info!(
+            "Listing opening balances for NZ employee with ID: {}",
+            employee_id
+        );
+
+        let url = format!("{ENDPOINT}/{employee_id}/OpeningBalances");
+        let response: OpeningBalancesResponse = client.get(&url, &()).await?;
+        debug!(
+            "Response contains {} opening balances",
+            response.opening_balances.len()
+        );
+        Ok(response.opening_balances)
+    }
+
+    /// Record the opening balances of an employee
+    pub async fn create(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        opening_balances: &[OpeningBalance],
+    ) -> Result<Vec<OpeningBalance>> {
+        info!(
+            "Creating {} opening balances for NZ employee with ID: {}",
+            opening_balances.len(),
+            employee_id
+        );
+
+        let url = format!("{ENDPOINT}/{employee_id}/OpeningBalances");
+        let response: OpeningBalancesResponse = client.post(&url, &opening_balances).await?;
+        Ok(response.opening_balances)
+    }
+}
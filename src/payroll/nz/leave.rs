@@ -0,0 +1,374 @@
+//! Employee Leave API for Xero Payroll NZ
+//!
+//! Leave is recorded against an employee for a date range and split by Xero into periods
+//! that line up with the employee's pay periods. NZ periods also report the units actually
+//! taken, and periods in future pay runs are `Estimated`. Before an employee can take
+//! leave their holiday pay and sick leave entitlements are set up with [`LeaveSetup`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use rust_decimal_macros::dec;
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::nz::leave::LeaveSetup;
+//!
+//! # async fn example(employee_id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! let setup = LeaveSetup {
+//!     include_holiday_pay: Some(true),
+//!     annual_leave_opening_balance: Some(dec!(40)),
+//!     ..LeaveSetup::default()
+//! };
+//! client.payroll_nz().employees().set_up_leave(employee_id, &setup).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::{Pagination, employee::ENDPOINT, not_found};
+use crate::{
+    error::Result,
+    utils::date_format::{xero_date_format, xero_date_format_option, xero_datetime_format_option},
+};
+
+/// Status of a leave period
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum LeavePeriodStatus {
+    /// The leave has been approved but not yet paid
+    Approved,
+    /// The leave has been paid in a posted pay run
+    Completed,
+    /// The period falls in a pay run that has not been created yet
+    Estimated,
+}
+
+/// The part of a leave request falling in one pay period
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeavePeriod {
+    #[serde(with = "xero_date_format")]
+    pub period_start_date: Date,
+    #[serde(with = "xero_date_format")]
+    pub period_end_date: Date,
+    #[serde(default)]
+    pub number_of_units: Decimal,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_of_units_taken: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_of_units: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_of_units_taken: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub period_status: Option<LeavePeriodStatus>,
+}
+
+/// Leave taken or planned by an NZ employee
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployeeLeave {
+    #[serde(rename = "leaveID")]
+    pub leave_id: Uuid,
+    #[serde(rename = "leaveTypeID")]
+    pub leave_type_id: Uuid,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(with = "xero_date_format")]
+    pub start_date: Date,
+    #[serde(with = "xero_date_format")]
+    pub end_date: Date,
+    #[serde(default)]
+    pub periods: Vec<LeavePeriod>,
+    #[serde(
+        rename = "updatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+}
+
+/// Request structure for creating or updating employee leave
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostLeave {
+    #[serde(rename = "leaveTypeID")]
+    pub leave_type_id: Uuid,
+    pub description: String,
+    #[serde(with = "xero_date_format")]
+    pub start_date: Date,
+    #[serde(with = "xero_date_format")]
+    pub end_date: Date,
+    /// Explicit periods; when empty Xero works them out from the employee's calendar
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub periods: Vec<LeavePeriod>,
+}
+
+impl PostLeave {
+    /// Leave of the given type between two dates, inclusive
+    #[must_use]
+    pub fn new(
+        leave_type_id: Uuid,
+        description: impl Into<String>,
+        start_date: Date,
+        end_date: Date,
+    ) -> Self {
+        Self {
+            leave_type_id,
+            description: description.into(),
+            start_date,
+            end_date,
+            periods: Vec::new(),
+        }
+    }
+
+    /// Set the periods the leave is split into
+    #[must_use]
+    pub fn with_periods(mut self, periods: Vec<LeavePeriod>) -> Self {
+        self.periods = periods;
+        self
+    }
+}
+
+/// Remaining balance of one leave type for an employee
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveBalance {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(rename = "leaveTypeID")]
+    pub leave_type_id: Uuid,
+    #[serde(default)]
+    pub balance: Decimal,
+    #[serde(default)]
+    pub type_of_units: Option<String>,
+}
+
+/// Holiday pay and sick leave entitlements of an employee
+///
+/// A few of the sick leave and anniversary fields start with a capital letter in the API,
+/// unlike the rest of the payroll 2.0 JSON.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveSetup {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_holiday_pay: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holiday_pay_opening_balance: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annual_leave_opening_balance: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub negative_annual_leave_balance_paid_amount: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sick_leave_hours_to_accrue_annually: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sick_leave_maximum_hours_to_accrue: Option<Decimal>,
+    #[serde(
+        rename = "SickLeaveToAccrueAnnually",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sick_leave_to_accrue_annually: Option<Decimal>,
+    #[serde(
+        rename = "SickLeaveMaximumToAccrue",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sick_leave_maximum_to_accrue: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sick_leave_opening_balance: Option<Decimal>,
+    /// When sick leave accrues, e.g. `"OnAnniversaryDate"`
+    #[serde(
+        rename = "SickLeaveScheduleOfAccrual",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sick_leave_schedule_of_accrual: Option<String>,
+    #[serde(
+        rename = "SickLeaveAnniversaryDate",
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "xero_date_format_option"
+    )]
+    pub sick_leave_anniversary_date: Option<Date>,
+    #[serde(
+        rename = "AnnualLeaveAnniversaryDate",
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "xero_date_format_option"
+    )]
+    pub annual_leave_anniversary_date: Option<Date>,
+}
+
+/// Response wrapper for employee leave, holding either a list or a single record
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub leave: LeaveRecords,
+}
+
+/// The `leave` field is an array when listing and an object for a single record
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum LeaveRecords {
+    Many(Vec<EmployeeLeave>),
+    One(Box<EmployeeLeave>),
+}
+
+impl Default for LeaveRecords {
+    fn default() -> Self {
+        Self::Many(Vec::new())
+    }
+}
+
+impl LeaveRecords {
+    /// All of the leave records
+    #[must_use]
+    pub fn into_vec(self) -> Vec<EmployeeLeave> {
+        match self {
+            Self::Many(leave) => leave,
+            Self::One(leave) => vec![*leave],
+        }
+    }
+
+    /// The first leave record, if any
+    #[must_use]
+    pub fn into_first(self) -> Option<EmployeeLeave> {
+        self.into_vec().into_iter().next()
+    }
+}
+
+/// Response wrapper for employee leave balances
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveBalancesResponse {
+    #[serde(default)]
+    pub leave_balances: Vec<LeaveBalance>,
+}
+
+/// Response wrapper for an employee's leave setup
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveSetupResponse {
+    #[serde(default)]
+    pub leave_setup: Option<LeaveSetup>,
+}
+
+impl EmployeeLeave {
+    /// List an employee's leave
+    pub async fn list(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+    ) -> Result<Vec<EmployeeLeave>> {
+        info!("Listing leave for NZ employee with ID: {}", employee_id);
+
+        let url = format!("{ENDPOINT}/{employee_id}/Leave");
+        let response: LeaveResponse = client.get(&url, &()).await?;
+        let leave = response.leave.into_vec();
+        debug!("Response contains {} leave records", leave.len());
+        Ok(leave)
+    }
+
+    /// Record leave for an employee
+    pub async fn create(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        leave: &PostLeave,
+    ) -> Result<EmployeeLeave> {
+        info!("Creating leave for NZ employee with ID: {}", employee_id);
+        debug!("Leave type ID: {}", leave.leave_type_id);
+
+        let url = format!("{ENDPOINT}/{employee_id}/Leave");
+        let response: LeaveResponse = client.post(&url, leave).await?;
+        response
+            .leave
+            .into_first()
+            .ok_or_else(|| not_found("EmployeeLeave", url))
+    }
+
+    /// Update a leave record
+    pub async fn update(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        leave_id: Uuid,
+        leave: &PostLeave,
+    ) -> Result<EmployeeLeave> {
+        info!(
+            "Updating leave {} for NZ employee {}",
+            leave_id, employee_id
+        );
+        debug!("Leave type ID: {}", leave.leave_type_id);
+
+        let url = format!("{ENDPOINT}/{employee_id}/Leave/{leave_id}");
+        debug!("PUT URL: {}", url);
+
+        let response: LeaveResponse = client.put(&url, leave).await?;
+        response
+            .leave
+            .into_first()
+            .ok_or_else(|| not_found("EmployeeLeave", url))
+    }
+
+    /// Delete a leave record
+    pub async fn delete(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        leave_id: Uuid,
+    ) -> Result<()> {
+        info!(
+            "Deleting leave {} for NZ employee {}",
+            leave_id, employee_id
+        );
+
+        let url = format!("{ENDPOINT}/{employee_id}/Leave/{leave_id}");
+        debug!("DELETE URL: {}", url);
+
+        client.delete(&url).await
+    }
+}
+
+impl LeaveBalance {
+    /// List an employee's leave balances
+    pub async fn list(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+    ) -> Result<Vec<LeaveBalance>> {
+        info!(
+            "Listing leave balances for NZ employee with ID: {}",
+            employee_id
+        );
+
+        let url = format!("{ENDPOINT}/{employee_id}/LeaveBalances");
+        let response: LeaveBalancesResponse = client.get(&url, &()).await?;
+        debug!(
+            "Response contains {} leave balances",
+            response.leave_balances.len()
+        );
+        Ok(response.leave_balances)
+    }
+}
+
+impl LeaveSetup {
+    /// Set up an employee's holiday pay and sick leave entitlements
+    pub async fn create(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        setup: &LeaveSetup,
+    ) -> Result<LeaveSetup> {
+        info!("Setting up leave for NZ employee with ID: {}", employee_id);
+
+        let url = format!("{ENDPOINT}/{employee_id}/LeaveSetup");
+        let response: LeaveSetupResponse = client.post(&url, setup).await?;
+        response
+            .leave_setup
+            .ok_or_else(|| not_found("LeaveSetup", url))
+    }
+}
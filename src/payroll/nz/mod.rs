@@ -0,0 +1,43 @@
+//! Xero Payroll NZ
+//!
+//! The NZ payroll API is, like the UK one, version 2.0 of Xero's payroll API: camelCase
+//! JSON, paged collections, and failures reported with a `problem` object that the client
+//! surfaces as [`crate::error::Error::API`] with [`crate::error::ErrorType::PayrollProblem`].
+//! On top of the shared shape it adds KiwiSaver and IRD tax details, statutory deductions,
+//! superannuation and holiday pay setup.
+//!
+//! This module is only available with the `payroll-nz` feature.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xero_rs::{Client, KeyPair};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! for employee in client.payroll_nz().employees().list().await? {
+//!     println!("{} {}", employee.first_name, employee.last_name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod employee;
+pub mod leave;
+pub mod pay_items;
+pub mod pay_run;
+pub mod pay_template;
+pub mod payslip;
+pub mod timesheet;
+
+pub use super::v2::{BASE_URL, Pagination};
+pub(crate) use super::v2::{Region, list_all, not_found};
+
+/// How the shared payroll 2.0 requests are made for the NZ
+pub(crate) const REGION: Region = Region {
+    name: "NZ",
+    earnings_template_path: "Earnings",
+    payslips_endpoint: payslip::ENDPOINT,
+};
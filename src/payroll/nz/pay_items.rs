@@ -0,0 +1,828 @@
+//! Pay Items API for Xero Payroll NZ
+//!
+//! Pay items are the organisation-wide settings employees are paid from: earnings rates,
+//! deductions, superannuation, leave types and reimbursements. Statutory deductions (PAYE
+//! related items such as student loan repayments, child support and KiwiSaver) are set up
+//! by Xero and can only be read.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use rust_decimal_macros::dec;
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::nz::pay_items::{Superannuation, SuperannuationCategory};
+//!
+//! # async fn example(liability_account_id: uuid::Uuid, expense_account_id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! let kiwi_saver = Superannuation::percentage_of_taxable_earnings(
+//!     "KiwiSaver",
+//!     SuperannuationCategory::KiwiSaver,
+//!     liability_account_id,
+//!     expense_account_id,
+//!     dec!(3),
+//! );
+//! let kiwi_saver = client.payroll_nz().pay_items().create_superannuation(&kiwi_saver).await?;
+//! println!("Created superannuation {:?}", kiwi_saver.id);
+//! # Ok(())
+//! # }
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::{BASE_URL, Pagination, list_all, not_found};
+use crate::{error::Result, utils::date_format::xero_datetime_format_option};
+
+/// Kind of earnings an earnings rate pays
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum EarningsType {
+    Allowance,
+    Backpay,
+    Bonus,
+    Commission,
+    DiscretionaryPayments,
+    HolidayPay,
+    LumpSum,
+    OtherEarnings,
+    OtherGrossEarnings,
+    OvertimeEarnings,
+    RegularEarnings,
+    SalarySacrificeForKiwiSaver,
+    #[serde(rename = "Tips(Direct)")]
+    TipsDirect,
+    #[serde(rename = "Tips(Non-Direct)")]
+    TipsNonDirect,
+    WithholdingIncome,
+    /// An earnings type this crate does not know about yet
+    #[serde(other)]
+    Other,
+}
+
+/// How an earnings rate is calculated
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum RateType {
+    RatePerUnit,
+    MultipleOfOrdinaryEarningsRate,
+    FixedAmount,
+}
+
+/// Category of a deduction
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum DeductionCategory {
+    PayrollGiving,
+    KiwiSaverVoluntaryContributions,
+    Superannuation,
+    NzOther,
+    /// A deduction category this crate does not know about yet
+    #[serde(other)]
+    Other,
+}
+
+/// Category of a statutory deduction
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum StatutoryDeductionCategory {
+    PriorityOrder,
+    NonPriorityOrder,
+    TableBased,
+    ChildSupport,
+    CourtFines,
+    InlandRevenueArrears,
+    MsdRepayments,
+    StudentLoan,
+    AdditionalStudentLoan,
+    VoluntaryStudentLoan,
+    KiwiSaver,
+    /// A statutory deduction category this crate does not know about yet
+    #[serde(other)]
+    Other,
+}
+
+/// Kind of superannuation scheme
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum SuperannuationCategory {
+    KiwiSaver,
+    ComplyingFund,
+    Other,
+}
+
+/// How a superannuation contribution is calculated
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum SuperannuationCalculationType {
+    FixedAmount,
+    PercentageOfTaxableEarnings,
+}
+
+/// GST treatment of a reimbursement
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ReimbursementCategory {
+    #[serde(rename = "GST")]
+    Gst,
+    #[serde(rename = "NoGST")]
+    NoGst,
+    #[serde(rename = "GSTInclusive")]
+    GstInclusive,
+}
+
+/// How a reimbursement amount is calculated
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ReimbursementCalculationType {
+    Unknown,
+    FixedAmount,
+    RatePerUnit,
+}
+
+/// Unit a rate per unit reimbursement is paid in
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ReimbursementUnits {
+    Hours,
+    #[serde(rename = "km")]
+    Kilometres,
+}
+
+/// An earnings rate in Xero Payroll NZ
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarningsRate {
+    #[serde(
+        rename = "earningsRateID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub earnings_rate_id: Option<Uuid>,
+    pub name: String,
+    pub earnings_type: EarningsType,
+    pub rate_type: RateType,
+    pub type_of_units: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_record: Option<bool>,
+    #[serde(rename = "expenseAccountID")]
+    pub expense_account_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_per_unit: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multiple_of_ordinary_earnings_rate: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixed_amount: Option<Decimal>,
+}
+
+impl EarningsRate {
+    /// An earnings rate with the fields the API requires
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        earnings_type: EarningsType,
+        rate_type: RateType,
+        type_of_units: impl Into<String>,
+        expense_account_id: Uuid,
+    ) -> Self {
+        Self {
+            earnings_rate_id: None,
+            name: name.into(),
+            earnings_type,
+            rate_type,
+            type_of_units: type_of_units.into(),
+            current_record: None,
+            expense_account_id,
+            rate_per_unit: None,
+            multiple_of_ordinary_earnings_rate: None,
+            fixed_amount: None,
+        }
+    }
+
+    /// Set the rate paid per unit
+    #[must_use]
+    pub fn with_rate_per_unit(mut self, rate_per_unit: Decimal) -> Self {
+        self.rate_per_unit = Some(rate_per_unit);
+        self
+    }
+
+    /// Set the multiple of the employee's ordinary earnings rate, e.g. 1.5 for time and a half
+    #[must_use]
+    pub fn with_multiple_of_ordinary_earnings_rate(mut self, multiple: Decimal) -> Self {
+        self.multiple_of_ordinary_earnings_rate = Some(multiple);
+        self
+    }
+
+    /// Set the fixed amount paid
+    #[must_use]
+    pub fn with_fixed_amount(mut self, fixed_amount: Decimal) -> Self {
+        self.fixed_amount = Some(fixed_amount);
+        self
+    }
+}
+
+/// A deduction in Xero Payroll NZ
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Deduction {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deduction_id: Option<Uuid>,
+    pub deduction_name: String,
+    pub deduction_category: DeductionCategory,
+    pub liability_account_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_record: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub standard_amount: Option<Decimal>,
+}
+
+impl Deduction {
+    /// A deduction with the fields the API requires
+    #[must_use]
+    pub fn new(
+        deduction_name: impl Into<String>,
+        deduction_category: DeductionCategory,
+        liability_account_id: Uuid,
+    ) -> Self {
+        Self {
+            deduction_id: None,
+            deduction_name: deduction_name.into(),
+            deduction_category,
+            liability_account_id,
+            current_record: None,
+            standard_amount: None,
+        }
+    }
+
+    /// Deduct a fixed amount each pay period
+    #[must_use]
+    pub fn with_standard_amount(mut self, standard_amount: Decimal) -> Self {
+        self.standard_amount = Some(standard_amount);
+        self
+    }
+}
+
+/// A statutory deduction in Xero Payroll NZ
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatutoryDeduction {
+    pub id: Uuid,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub statutory_deduction_category: Option<StatutoryDeductionCategory>,
+    #[serde(default)]
+    pub liability_account_id: Option<Uuid>,
+    #[serde(default)]
+    pub current_record: Option<bool>,
+}
+
+/// A superannuation scheme in Xero Payroll NZ
+///
+/// The API calls these benefits, as in UK payroll.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Superannuation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Uuid>,
+    pub name: String,
+    pub category: SuperannuationCategory,
+    pub liability_account_id: Uuid,
+    pub expense_account_id: Uuid,
+    #[serde(
+        rename = "calculationTypeNZ",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub calculation_type: Option<SuperannuationCalculationType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub standard_amount: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub percentage: Option<Decimal>,
+    /// Most the employer contributes, as a percentage of taxable earnings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_max: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_record: Option<bool>,
+}
+
+impl Superannuation {
+    /// A scheme contributing a percentage of taxable earnings
+    #[must_use]
+    pub fn percentage_of_taxable_earnings(
+        name: impl Into<String>,
+        category: SuperannuationCategory,
+        liability_account_id: Uuid,
+        expense_account_id: Uuid,
+        percentage: Decimal,
+    ) -> Self {
+        Self {
+            id: None,
+            name: name.into(),
+            category,
+            liability_account_id,
+            expense_account_id,
+            calculation_type: Some(SuperannuationCalculationType::PercentageOfTaxableEarnings),
+            standard_amount: None,
+            percentage: Some(percentage),
+            company_max: None,
+            current_record: None,
+        }
+    }
+
+    /// A scheme contributing a fixed amount each pay period
+    #[must_use]
+    pub fn fixed_amount(
+        name: impl Into<String>,
+        category: SuperannuationCategory,
+        liability_account_id: Uuid,
+        expense_account_id: Uuid,
+        standard_amount: Decimal,
+    ) -> Self {
+        Self {
+            calculation_type: Some(SuperannuationCalculationType::FixedAmount),
+            standard_amount: Some(standard_amount),
+            percentage: None,
+            ..Self::percentage_of_taxable_earnings(
+                name,
+                category,
+                liability_account_id,
+                expense_account_id,
+                Decimal::ZERO,
+            )
+        }
+    }
+}
+
+/// A leave type in Xero Payroll NZ
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveType {
+    #[serde(
+        rename = "leaveTypeID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub leave_type_id: Option<Uuid>,
+    pub name: String,
+    pub is_paid_leave: bool,
+    pub show_on_payslip: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+    /// Unit leave is taken in, e.g. `"Hours"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_of_units: Option<String>,
+    /// Unit leave accrues in, which may differ from the unit it is taken in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_of_units_to_accrue: Option<String>,
+    #[serde(
+        rename = "updatedDateUTC",
+        default,
+        skip_serializing,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+}
+
+impl LeaveType {
+    /// A leave type with the fields the API requires
+    #[must_use]
+    pub fn new(name: impl Into<String>, is_paid_leave: bool, show_on_payslip: bool) -> Self {
+        Self {
+            leave_type_id: None,
+            name: name.into(),
+            is_paid_leave,
+            show_on_payslip,
+            is_active: None,
+            type_of_units: None,
+            type_of_units_to_accrue: None,
+            updated_date_utc: None,
+        }
+    }
+}
+
+/// A reimbursement in Xero Payroll NZ
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reimbursement {
+    #[serde(
+        rename = "reimbursementID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub reimbursement_id: Option<Uuid>,
+    pub name: String,
+    #[serde(rename = "accountID")]
+    pub account_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_record: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reimbursement_category: Option<ReimbursementCategory>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calculation_type: Option<ReimbursementCalculationType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub standard_amount: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub standard_type_of_units: Option<ReimbursementUnits>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub standard_rate_per_unit: Option<Decimal>,
+}
+
+impl Reimbursement {
+    /// A reimbursement paid from the given account
+    #[must_use]
+    pub fn new(name: impl Into<String>, account_id: Uuid) -> Self {
+        Self {
+            reimbursement_id: None,
+            name: name.into(),
+            account_id,
+            current_record: None,
+            reimbursement_category: None,
+            calculation_type: None,
+            standard_amount: None,
+            standard_type_of_units: None,
+            standard_rate_per_unit: None,
+        }
+    }
+
+    /// Set how GST applies to the reimbursement
+    #[must_use]
+    pub fn with_category(mut self, category: ReimbursementCategory) -> Self {
+        self.reimbursement_category = Some(category);
+        self
+    }
+
+    /// Reimburse a fixed amount each pay period
+    #[must_use]
+    pub fn with_standard_amount(mut self, standard_amount: Decimal) -> Self {
+        self.calculation_type = Some(ReimbursementCalculationType::FixedAmount);
+        self.standard_amount = Some(standard_amount);
+        self
+    }
+
+    /// Reimburse a rate per hour or kilometre
+    #[must_use]
+    pub fn with_rate_per_unit(mut self, units: ReimbursementUnits, rate_per_unit: Decimal) -> Self {
+        self.calculation_type = Some(ReimbursementCalculationType::RatePerUnit);
+        self.standard_type_of_units = Some(units);
+        self.standard_rate_per_unit = Some(rate_per_unit);
+        self
+    }
+}
+
+/// Response wrapper for earnings rates
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarningsRatesResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub earnings_rates: Vec<EarningsRate>,
+}
+
+/// Response wrapper for a single earnings rate
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarningsRateResponse {
+    #[serde(default)]
+    pub earnings_rate: Option<EarningsRate>,
+}
+
+/// Response wrapper for deductions
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeductionsResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub deductions: Vec<Deduction>,
+}
+
+/// Response wrapper for a single deduction
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeductionResponse {
+    #[serde(default)]
+    pub deduction: Option<Deduction>,
+}
+
+/// Response wrapper for statutory deductions
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatutoryDeductionsResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub statutory_deductions: Vec<StatutoryDeduction>,
+}
+
+/// Response wrapper for a single statutory deduction
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatutoryDeductionResponse {
+    #[serde(default)]
+    pub statutory_deduction: Option<StatutoryDeduction>,
+}
+
+/// Response wrapper for superannuation schemes
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuperannuationsResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub benefits: Vec<Superannuation>,
+}
+
+/// Response wrapper for a single superannuation scheme
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuperannuationResponse {
+    #[serde(default)]
+    pub benefit: Option<Superannuation>,
+}
+
+/// Response wrapper for leave types
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveTypesResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub leave_types: Vec<LeaveType>,
+}
+
+/// Response wrapper for a single leave type
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveTypeResponse {
+    #[serde(default)]
+    pub leave_type: Option<LeaveType>,
+}
+
+/// Response wrapper for reimbursements
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReimbursementsResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub reimbursements: Vec<Reimbursement>,
+}
+
+/// Response wrapper for a single reimbursement
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReimbursementResponse {
+    #[serde(default)]
+    pub reimbursement: Option<Reimbursement>,
+}
+
+impl EarningsRate {
+    /// List all earnings rates, fetching every page
+    pub async fn list(client: &crate::client::Client) -> Result<Vec<EarningsRate>> {
+        info!("Listing NZ earnings rates");
+
+        let url = format!("{BASE_URL}/EarningsRates");
+        list_all(client, &url, &(), |response: EarningsRatesResponse| {
+            (response.pagination, response.earnings_rates)
+        })
+        .await
+    }
+
+    /// Get a single earnings rate by ID
+    pub async fn get(
+        client: &crate::client::Client,
+        earnings_rate_id: Uuid,
+    ) -> Result<EarningsRate> {
+        info!("Getting NZ earnings rate with ID: {}", earnings_rate_id);
+
+        let url = format!("{BASE_URL}/EarningsRates/{earnings_rate_id}");
+        debug!("GET URL: {}", url);
+
+        let response: EarningsRateResponse = client.get(&url, &()).await?;
+        response
+            .earnings_rate
+            .ok_or_else(|| not_found("EarningsRate", url))
+    }
+
+    /// Create an earnings rate
+    pub async fn create(
+        client: &crate::client::Client,
+        earnings_rate: &EarningsRate,
+    ) -> Result<EarningsRate> {
+        info!("Creating NZ earnings rate");
+        debug!("Earnings rate data: {:?}", earnings_rate);
+
+        let url = format!("{BASE_URL}/EarningsRates");
+        let response: EarningsRateResponse = client.post(&url, earnings_rate).await?;
+        response
+            .earnings_rate
+            .ok_or_else(|| not_found("EarningsRate", url))
+    }
+}
+
+impl Deduction {
+    /// List all deductions, fetching every page
+    pub async fn list(client: &crate::client::Client) -> Result<Vec<Deduction>> {
+        info!("Listing NZ deductions");
+
+        let url = format!("{BASE_URL}/Deductions");
+        list_all(client, &url, &(), |response: DeductionsResponse| {
+            (response.pagination, response.deductions)
+        })
+        .await
+    }
+
+    /// Get a single deduction by ID
+    pub async fn get(client: &crate::client::Client, deduction_id: Uuid) -> Result<Deduction> {
+        info!("Getting NZ deduction with ID: {}", deduction_id);
+
+        let url = format!("{BASE_URL}/Deductions/{deduction_id}");
+        debug!("GET URL: {}", url);
+
+        let response: DeductionResponse = client.get(&url, &()).await?;
+        response
+            .deduction
+            .ok_or_else(|| not_found("Deduction", url))
+    }
+
+    /// Create a deduction
+    pub async fn create(
+        client: &crate::client::Client,
+        deduction: &Deduction,
+    ) -> Result<Deduction> {
+        info!("Creating NZ deduction");
+        debug!("Deduction data: {:?}", deduction);
+
+        let url = format!("{BASE_URL}/Deductions");
+        let response: DeductionResponse = client.post(&url, deduction).await?;
+        response
+            .deduction
+            .ok_or_else(|| not_found("Deduction", url))
+    }
+}
+
+impl StatutoryDeduction {
+    /// List all statutory deductions, fetching every page
+    pub async fn list(client: &crate::client::Client) -> Result<Vec<StatutoryDeduction>> {
+        info!("Listing NZ statutory deductions");
+
+        let url = format!("{BASE_URL}/StatutoryDeductions");
+        list_all(
+            client,
+            &url,
+            &(),
+            |response: StatutoryDeductionsResponse| {
+                (response.pagination, response.statutory_deductions)
+            },
+        )
+        .await
+    }
+
+    /// Get a single statutory deduction by ID
+    pub async fn get(
+        client: &crate::client::Client,
+        statutory_deduction_id: Uuid,
+    ) -> Result<StatutoryDeduction> {
+        info!(
+            "Getting NZ statutory deduction with ID: {}",
+            statutory_deduction_id
+        );
+
+        let url = format!("{BASE_URL}/StatutoryDeductions/{statutory_deduction_id}");
+        debug!("GET URL: {}", url);
+
+        let response: StatutoryDeductionResponse = client.get(&url, &()).await?;
+        response
+            .statutory_deduction
+            .ok_or_else(|| not_found("StatutoryDeduction", url))
+    }
+}
+
+impl Superannuation {
+    /// List all superannuation schemes, fetching every page
+    pub async fn list(client: &crate::client::Client) -> Result<Vec<Superannuation>> {
+        info!("Listing NZ superannuation schemes");
+
+        let url = format!("{BASE_URL}/Superannuations");
+        list_all(client, &url, &(), |response: SuperannuationsResponse| {
+            (response.pagination, response.benefits)
+        })
+        .await
+    }
+
+    /// Get a single superannuation scheme by ID
+    pub async fn get(
+        client: &crate::client::Client,
+        superannuation_id: Uuid,
+    ) -> Result<Superannuation> {
+        info!(
+            "Getting NZ superannuation scheme with ID: {}",
+            superannuation_id
+        );
+
+        let url = format!("{BASE_URL}/Superannuations/{superannuation_id}");
+        debug!("GET URL: {}", url);
+
+        let response: SuperannuationResponse = client.get(&url, &()).await?;
+        response
+            .benefit
+            .ok_or_else(|| not_found("Superannuation", url))
+    }
+
+    /// Create a superannuation scheme
+    pub async fn create(
+        client: &crate::client::Client,
+        superannuation: &Superannuation,
+    ) -> Result<Superannuation> {
+        info!("Creating NZ superannuation scheme");
+        debug!("Superannuation data: {:?}", superannuation);
+
+        let url = format!("{BASE_URL}/Superannuations");
+        let response: SuperannuationResponse = client.post(&url, superannuation).await?;
+        response
+            .benefit
+            .ok_or_else(|| not_found("Superannuation", url))
+    }
+}
+
+impl LeaveType {
+    /// List all leave types, fetching every page
+    pub async fn list(client: &crate::client::Client) -> Result<Vec<LeaveType>> {
+        info!("Listing NZ leave types");
+
+        let url = format!("{BASE_URL}/LeaveTypes");
+        list_all(client, &url, &(), |response: LeaveTypesResponse| {
+            (response.pagination, response.leave_types)
+        })
+        .await
+    }
+
+    /// Get a single leave type by ID
+    pub async fn get(client: &crate::client::Client, leave_type_id: Uuid) -> Result<LeaveType> {
+        info!("Getting NZ leave type with ID: {}", leave_type_id);
+
+        let url = format!("{BASE_URL}/LeaveTypes/{leave_type_id}");
+        debug!("GET URL: {}", url);
+
+        let response: LeaveTypeResponse = client.get(&url, &()).await?;
+        response
+            .leave_type
+            .ok_or_else(|| not_found("LeaveType", url))
+    }
+
+    /// Create a leave type
+    pub async fn create(
+        client: &crate::client::Client,
+        leave_type: &LeaveType,
+    ) -> Result<LeaveType> {
+        info!("Creating NZ leave type");
+        debug!("Leave type data: {:?}", leave_type);
+
+        let url = format!("{BASE_URL}/LeaveTypes");
+        let response: LeaveTypeResponse = client.post(&url, leave_type).await?;
+        response
+            .leave_type
+            .ok_or_else(|| not_found("LeaveType", url))
+    }
+}
+
+impl Reimbursement {
+    /// List all reimbursements, fetching every page
+    pub async fn list(client: &crate::client::Client) -> Result<Vec<Reimbursement>> {
+        info!("Listing NZ reimbursements");
+
+        let url = format!("{BASE_URL}/Reimbursements");
+        list_all(client, &url, &(), |response: ReimbursementsResponse| {
+            (response.pagination, response.reimbursements)
+        })
+        .await
+    }
+
+    /// Get a single reimbursement by ID
+    pub async fn get(
+        client: &crate::client::Client,
+        reimbursement_id: Uuid,
+    ) -> Result<Reimbursement> {
+        info!("Getting NZ reimbursement with ID: {}", reimbursement_id);
+
+        let url = format!("{BASE_URL}/Reimbursements/{reimbursement_id}");
+        debug!("GET URL: {}", url);
+
+        let response: ReimbursementResponse = client.get(&url, &()).await?;
+        response
+            .reimbursement
+            .ok_or_else(|| not_found("Reimbursement", url))
+    }
+
+    /// Create a reimbursement
+    pub async fn create(
+        client: &crate::client::Client,
+        reimbursement: &Reimbursement,
+    ) -> Result<Reimbursement> {
+        info!("Creating NZ reimbursement");
+        debug!("Reimbursement data: {:?}", reimbursement);
+
+        let url = format!("{BASE_URL}/Reimbursements");
+        let response: ReimbursementResponse = client.post(&url, reimbursement).await?;
+        response
+            .reimbursement
+            .ok_or_else(|| not_found("Reimbursement", url))
+    }
+}
@@ -0,0 +1,166 @@
+//! Pay Runs API for Xero Payroll NZ
+//!
+//! Unlike UK payroll, NZ pay runs can be created through the API for a payroll calendar.
+//! Draft pay runs can then be updated, and each pay run carries the payslips of its
+//! employees (see [`super::payslip`]).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::nz::pay_run::PostPayRun;
+//!
+//! # async fn example(calendar_id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! let pay_run = client.payroll_nz().pay_runs().create(&PostPayRun::scheduled(calendar_id)).await?;
+//! println!("{:?}: {:?}", pay_run.payment_date, pay_run.total_pay);
+//! # Ok(())
+//! # }
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+use super::payslip::Payslip;
+use crate::{
+    payroll::v2::pay_run,
+    utils::date_format::{xero_date_format_option, xero_datetime_format_option},
+};
+
+pub use pay_run::{ENDPOINT, PayRunStatus};
+
+/// Kind of NZ pay run
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PayRunType {
+    Scheduled,
+    Unscheduled,
+    EarlierYearUpdate,
+}
+
+/// Pay frequency of the calendar a pay run belongs to
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum CalendarType {
+    Weekly,
+    Fortnightly,
+    FourWeekly,
+    Monthly,
+    Annual,
+    Quarterly,
+    TwiceMonthly,
+}
+
+/// A pay run in Xero Payroll NZ
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayRun {
+    #[serde(rename = "payRunID")]
+    pub pay_run_id: Uuid,
+    #[serde(rename = "payrollCalendarID", default)]
+    pub payroll_calendar_id: Option<Uuid>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub period_start_date: Option<Date>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub period_end_date: Option<Date>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub payment_date: Option<Date>,
+    #[serde(default)]
+    pub total_cost: Option<Decimal>,
+    #[serde(default)]
+    pub total_pay: Option<Decimal>,
+    #[serde(default)]
+    pub pay_run_status: Option<PayRunStatus>,
+    #[serde(default)]
+    pub pay_run_type: Option<PayRunType>,
+    #[serde(default)]
+    pub calendar_type: Option<CalendarType>,
+    #[serde(default, with = "xero_datetime_format_option")]
+    pub posted_date_time: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub pay_slips: Vec<Payslip>,
+}
+
+/// Request structure for creating or updating an NZ pay run
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostPayRun {
+    #[serde(
+        rename = "payrollCalendarID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub payroll_calendar_id: Option<Uuid>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "xero_date_format_option"
+    )]
+    pub period_start_date: Option<Date>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "xero_date_format_option"
+    )]
+    pub period_end_date: Option<Date>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "xero_date_format_option"
+    )]
+    pub payment_date: Option<Date>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pay_run_status: Option<PayRunStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pay_run_type: Option<PayRunType>,
+}
+
+impl PostPayRun {
+    /// The next scheduled pay run of a payroll calendar
+    #[must_use]
+    pub fn scheduled(payroll_calendar_id: Uuid) -> Self {
+        Self {
+            payroll_calendar_id: Some(payroll_calendar_id),
+            pay_run_type: Some(PayRunType::Scheduled),
+            ..Self::default()
+        }
+    }
+
+    /// An unscheduled pay run of a payroll calendar for the given period
+    #[must_use]
+    pub fn unscheduled(
+        payroll_calendar_id: Uuid,
+        period_start_date: Date,
+        period_end_date: Date,
+    ) -> Self {
+        Self {
+            payroll_calendar_id: Some(payroll_calendar_id),
+            period_start_date: Some(period_start_date),
+            period_end_date: Some(period_end_date),
+            pay_run_type: Some(PayRunType::Unscheduled),
+            ..Self::default()
+        }
+    }
+
+    /// Set the date employees are paid
+    #[must_use]
+    pub fn with_payment_date(mut self, payment_date: Date) -> Self {
+        self.payment_date = Some(payment_date);
+        self
+    }
+
+    /// Set the pay run status
+    #[must_use]
+    pub fn with_status(mut self, status: PayRunStatus) -> Self {
+        self.pay_run_status = Some(status);
+        self
+    }
+}
+
+/// Response wrapper for NZ pay runs
+pub type PayRunsResponse = pay_run::PayRunsResponse<PayRun>;
+
+/// Response wrapper for a single NZ pay run
+pub type PayRunResponse = pay_run::PayRunResponse<PayRun>;
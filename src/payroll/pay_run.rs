@@ -0,0 +1,366 @@
+//! Pay Runs API for Xero Payroll AU
+//!
+//! A pay run pays the employees on a payroll calendar for one pay period. Pay runs are
+//! created as drafts, reviewed and adjusted through their payslips (see
+//! [`crate::payroll::payslip`]), and then posted.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::pay_run::PostPayRun;
+//!
+//! # async fn example(calendar_id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! let draft = client.pay_runs().create(&PostPayRun::new(calendar_id)).await?;
+//! let posted = client.pay_runs().post(draft.pay_run_id).await?;
+//! println!("Net pay: {}", posted.net_pay);
+//! # Ok(())
+//! # }
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use tracing::{debug, error, info};
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    error::Result,
+    utils::date_format::{xero_date_format_option, xero_datetime_format_option},
+};
+
+/// Base endpoint for pay runs
+pub const ENDPOINT: &str = "https://api.xero.com/payroll.xro/1.0/PayRuns";
+
+/// Status of a pay run
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PayRunStatus {
+    /// The pay run can still be changed
+    Draft,
+    /// The pay run has been finalised and its journals posted
+    Posted,
+}
+
+/// Summary of one employee's payslip within a pay run
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayslipSummary {
+    #[serde(rename = "PayslipID")]
+    pub payslip_id: Uuid,
+    #[serde(rename = "EmployeeID")]
+    pub employee_id: Uuid,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub last_name: Option<String>,
+    #[serde(default)]
+    pub employee_group: Option<String>,
+    #[serde(default)]
+    pub wages: Decimal,
+    #[serde(default)]
+    pub deductions: Decimal,
+    #[serde(default)]
+    pub tax: Decimal,
+    #[serde(rename = "Super", default)]
+    pub superannuation: Decimal,
+    #[serde(default)]
+    pub reimbursements: Decimal,
+    #[serde(default)]
+    pub net_pay: Decimal,
+    #[serde(
+        default,
+        rename = "UpdatedDateUTC",
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+}
+
+/// A pay run in Xero Payroll AU
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayRun {
+    #[serde(rename = "PayRunID")]
+    pub pay_run_id: Uuid,
+
+    #[serde(rename = "PayrollCalendarID")]
+    pub payroll_calendar_id: Uuid,
+
+    #[serde(default, with = "xero_date_format_option")]
+    pub pay_run_period_start_date: Option<Date>,
+
+    #[serde(default, with = "xero_date_format_option")]
+    pub pay_run_period_end_date: Option<Date>,
+
+    pub pay_run_status: PayRunStatus,
+
+    #[serde(default, with = "xero_date_format_option")]
+    pub payment_date: Option<Date>,
+
+    /// Message shown on every payslip in the pay run
+    #[serde(default)]
+    pub payslip_message: Option<String>,
+
+    /// Payslip summaries. Only returned when fetching a single pay run
+    #[serde(default)]
+    pub payslips: Vec<PayslipSummary>,
+
+    #[serde(default)]
+    pub wages: Decimal,
+
+    #[serde(default)]
+    pub deductions: Decimal,
+
+    #[serde(default)]
+    pub tax: Decimal,
+
+    #[serde(rename = "Super", default)]
+    pub superannuation: Decimal,
+
+    #[serde(rename = "Reimbursement", default)]
+    pub reimbursements: Decimal,
+
+    #[serde(default)]
+    pub net_pay: Decimal,
+
+    #[serde(
+        default,
+        rename = "UpdatedDateUTC",
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+}
+
+impl PayRun {
+    /// The total cost of the pay run to the employer: wages, superannuation and
+    /// reimbursements
+    #[must_use]
+    pub fn employer_cost(&self) -> Decimal {
+        self.wages + self.superannuation + self.reimbursements
+    }
+
+    /// Whether the pay run totals equal the sum of its payslip summaries
+    ///
+    /// Always true when the payslips were not returned, e.g. from `list`.
+    #[must_use]
+    pub fn payslips_reconcile(&self) -> bool {
+        if self.payslips.is_empty() {
+            return true;
+        }
+
+        let sum = |field: fn(&PayslipSummary) -> Decimal| -> Decimal {
+            self.payslips.iter().map(field).sum()
+        };
+
+        sum(|p| p.wages) == self.wages
+            && sum(|p| p.deductions) == self.deductions
+            && sum(|p| p.tax) == self.tax
+            && sum(|p| p.superannuation) == self.superannuation
+            && sum(|p| p.reimbursements) == self.reimbursements
+            && sum(|p| p.net_pay) == self.net_pay
+    }
+}
+
+/// Parameters for filtering pay run list results
+#[derive(Debug, Serialize, Default)]
+pub struct ListParameters {
+    /// Filter by any field using Xero's WHERE syntax (e.g., `PayRunStatus=="DRAFT"`)
+    #[serde(rename = "where", skip_serializing_if = "Option::is_none")]
+    pub where_filter: Option<String>,
+
+    /// Order results by a specific field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+
+    /// Page number for pagination
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i32>,
+}
+
+/// Request structure for creating a draft pay run
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PostPayRun {
+    #[serde(rename = "PayrollCalendarID")]
+    pub payroll_calendar_id: Uuid,
+
+    /// Defaults to the next unpaid period of the calendar
+    #[serde(
+        default,
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pay_run_period_start_date: Option<Date>,
+
+    #[serde(
+        default,
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pay_run_period_end_date: Option<Date>,
+
+    #[serde(
+        default,
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub payment_date: Option<Date>,
+}
+
+impl PostPayRun {
+    /// A draft pay run for the next period of a payroll calendar
+    #[must_use]
+    pub fn new(payroll_calendar_id: Uuid) -> Self {
+        Self {
+            payroll_calendar_id,
+            pay_run_period_start_date: None,
+            pay_run_period_end_date: None,
+            payment_date: None,
+        }
+    }
+
+    /// Override the pay period and payment date
+    #[must_use]
+    pub fn with_period(mut self, start_date: Date, end_date: Date, payment_date: Date) -> Self {
+        self.pay_run_period_start_date = Some(start_date);
+        self.pay_run_period_end_date = Some(end_date);
+        self.payment_date = Some(payment_date);
+        self
+    }
+}
+
+/// Request structure for updating a pay run
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdatePayRun {
+    #[serde(rename = "PayRunID")]
+    pub pay_run_id: Uuid,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pay_run_status: Option<PayRunStatus>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payslip_message: Option<String>,
+}
+
+impl UpdatePayRun {
+    /// An update to the given pay run
+    #[must_use]
+    pub fn new(pay_run_id: Uuid) -> Self {
+        Self {
+            pay_run_id,
+            pay_run_status: None,
+            payslip_message: None,
+        }
+    }
+
+    /// Change the status of the pay run
+    #[must_use]
+    pub fn with_status(mut self, status: PayRunStatus) -> Self {
+        self.pay_run_status = Some(status);
+        self
+    }
+
+    /// Set the message shown on every payslip
+    #[must_use]
+    pub fn with_payslip_message(mut self, message: impl Into<String>) -> Self {
+        self.payslip_message = Some(message.into());
+        self
+    }
+}
+
+/// Response wrapper for pay run API calls
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayRunResponse {
+    pub pay_runs: Vec<PayRun>,
+}
+
+impl PayRun {
+    /// List pay runs
+    pub async fn list(
+        client: &crate::client::Client,
+        parameters: Option<&ListParameters>,
+    ) -> Result<Vec<PayRun>> {
+        info!("Listing pay runs with filters: {:?}", parameters);
+
+        let response: PayRunResponse = match parameters {
+            Some(params) => client.get(ENDPOINT, params).await?,
+            None => client.get(ENDPOINT, &()).await?,
+        };
+
+        debug!("Response contains {} pay runs", response.pay_runs.len());
+        Ok(response.pay_runs)
+    }
+
+    /// Get a single pay run by ID, including its payslip summaries
+    pub async fn get(client: &crate::client::Client, pay_run_id: Uuid) -> Result<PayRun> {
+        info!("Getting pay run with ID: {}", pay_run_id);
+
+        let url = format!("{ENDPOINT}/{pay_run_id}");
+        debug!("GET URL: {}", url);
+
+        let response: PayRunResponse = client.get(&url, &()).await?;
+        response
+            .pay_runs
+            .into_iter()
+            .next()
+            .ok_or_else(|| not_found(url))
+    }
+
+    /// Create a draft pay run
+    pub async fn create(client: &crate::client::Client, pay_run: &PostPayRun) -> Result<PayRun> {
+        info!(
+            "Creating pay run for payroll calendar: {}",
+            pay_run.payroll_calendar_id
+        );
+        debug!("Pay run data: {:?}", pay_run);
+
+        let request = vec![pay_run.clone()];
+        let response: PayRunResponse = client.post(ENDPOINT, &request).await?;
+        response
+            .pay_runs
+            .into_iter()
+            .next()
+            .ok_or_else(|| not_found(ENDPOINT.to_string()))
+    }
+
+    /// Update a pay run
+    pub async fn update(client: &crate::client::Client, pay_run: &UpdatePayRun) -> Result<PayRun> {
+        info!("Updating pay run with ID: {}", pay_run.pay_run_id);
+        debug!("Updated pay run data: {:?}", pay_run);
+
+        let url = format!("{ENDPOINT}/{}", pay_run.pay_run_id);
+        debug!("POST URL: {}", url);
+
+        let request = vec![pay_run.clone()];
+        let response: PayRunResponse = client.post(&url, &request).await?;
+        response
+            .pay_runs
+            .into_iter()
+            .next()
+            .ok_or_else(|| not_found(url))
+    }
+
+    /// Post a draft pay run, finalising its payslips
+    pub async fn post(client: &crate::client::Client, pay_run_id: Uuid) -> Result<PayRun> {
+        let update = UpdatePayRun::new(pay_run_id).with_status(PayRunStatus::Posted);
+        Self::update(client, &update).await
+    }
+}
+
+/// Error for a response that unexpectedly contains no pay runs
+fn not_found(url: String) -> crate::error::Error {
+    error!("Received empty pay runs array in response");
+    crate::error::Error::NotFound {
+        entity: "PayRun".to_string(),
+        url,
+        status_code: reqwest::StatusCode::NOT_FOUND,
+        response_body: Some("No pay run returned in response".to_string()),
+        span_trace: SpanTrace::capture(),
+    }
+}
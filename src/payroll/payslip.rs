@@ -0,0 +1,409 @@
+//! Payslips API for Xero Payroll AU
+//!
+//! Payslips belong to a pay run (see [`crate::payroll::pay_run`]). While the pay run is a
+//! draft, the earnings, deduction, leave, reimbursement, superannuation and tax lines of each
+//! payslip can be replaced.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use tracing::{debug, error, info};
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    error::Result,
+    utils::date_format::{xero_date_format_option, xero_datetime_format_option},
+};
+
+/// Base endpoint for payslips
+pub const ENDPOINT: &str = "https://api.xero.com/payroll.xro/1.0/Payslip";
+
+/// An ordinary or other earnings line
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct EarningsLine {
+    #[serde(rename = "EarningsRateID")]
+    pub earnings_rate_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_per_unit: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_of_units: Option<Decimal>,
+    /// Used instead of units for fixed amount earnings rates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixed_amount: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Decimal>,
+}
+
+impl EarningsLine {
+    /// Earnings of `number_of_units` at the rate's default rate
+    #[must_use]
+    pub fn units(earnings_rate_id: Uuid, number_of_units: Decimal) -> Self {
+        Self {
+            earnings_rate_id,
+            number_of_units: Some(number_of_units),
+            ..Self::default()
+        }
+    }
+
+    /// A fixed amount of earnings
+    #[must_use]
+    pub fn fixed_amount(earnings_rate_id: Uuid, fixed_amount: Decimal) -> Self {
+        Self {
+            earnings_rate_id,
+            fixed_amount: Some(fixed_amount),
+            ..Self::default()
+        }
+    }
+
+    /// Override the rate per unit
+    #[must_use]
+    pub fn with_rate_per_unit(mut self, rate_per_unit: Decimal) -> Self {
+        self.rate_per_unit = Some(rate_per_unit);
+        self
+    }
+}
+
+/// Earnings for leave taken in the pay period
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LeaveEarningsLine {
+    #[serde(rename = "EarningsRateID")]
+    pub earnings_rate_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_per_unit: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_of_units: Option<Decimal>,
+}
+
+/// How a deduction is calculated
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DeductionCalculationType {
+    FixedAmount,
+    PreTax,
+    PostTax,
+}
+
+/// A deduction from the employee's pay
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeductionLine {
+    #[serde(rename = "DeductionTypeID")]
+    pub deduction_type_id: Uuid,
+    pub calculation_type: DeductionCalculationType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_of_units: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub percentage: Option<Decimal>,
+}
+
+impl DeductionLine {
+    /// A fixed amount deduction
+    #[must_use]
+    pub fn fixed_amount(deduction_type_id: Uuid, amount: Decimal) -> Self {
+        Self {
+            deduction_type_id,
+            calculation_type: DeductionCalculationType::FixedAmount,
+            number_of_units: None,
+            amount: Some(amount),
+            percentage: None,
+        }
+    }
+}
+
+/// Leave accrued in the pay period
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LeaveAccrualLine {
+    #[serde(rename = "LeaveTypeID")]
+    pub leave_type_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_of_units: Option<Decimal>,
+    /// Let Xero calculate the accrual from the employee's leave settings
+    #[serde(default)]
+    pub auto_calculate: bool,
+}
+
+/// A reimbursement paid to the employee
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReimbursementLine {
+    #[serde(rename = "ReimbursementTypeID")]
+    pub reimbursement_type_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expense_account: Option<String>,
+    pub amount: Decimal,
+}
+
+/// The type of a superannuation contribution
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SuperContributionType {
+    /// Superannuation guarantee contribution
+    Sgc,
+    SalarySacrifice,
+    EmployerAdditional,
+    Employee,
+}
+
+/// How a superannuation contribution is calculated
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SuperCalculationType {
+    FixedAmount,
+    PercentageOfEarnings,
+    Statutory,
+}
+
+/// A superannuation contribution
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SuperannuationLine {
+    /// Missing on statutory lines that are not tied to a super membership
+    #[serde(
+        rename = "SuperMembershipID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub super_membership_id: Option<Uuid>,
+    pub contribution_type: SuperContributionType,
+    pub calculation_type: SuperCalculationType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum_monthly_earnings: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expense_account_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub liability_account_code: Option<String>,
+    #[serde(
+        default,
+        with = "xero_date_format_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub payment_date_for_this_period: Option<Date>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub percentage: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Decimal>,
+}
+
+/// Tax withheld from the employee's pay
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TaxLine {
+    #[serde(
+        rename = "PayslipTaxLineID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub payslip_tax_line_id: Option<Uuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_type_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub amount: Decimal,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub liability_account: Option<String>,
+}
+
+/// A payslip in Xero Payroll AU
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Payslip {
+    #[serde(rename = "PayslipID")]
+    pub payslip_id: Uuid,
+    #[serde(rename = "EmployeeID")]
+    pub employee_id: Uuid,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub last_name: Option<String>,
+    #[serde(default)]
+    pub wages: Decimal,
+    #[serde(default)]
+    pub deductions: Decimal,
+    #[serde(default)]
+    pub tax: Decimal,
+    #[serde(rename = "Super", default)]
+    pub superannuation: Decimal,
+    #[serde(default)]
+    pub reimbursements: Decimal,
+    #[serde(default)]
+    pub net_pay: Decimal,
+    #[serde(default)]
+    pub earnings_lines: Vec<EarningsLine>,
+    #[serde(default)]
+    pub leave_earnings_lines: Vec<LeaveEarningsLine>,
+    #[serde(default)]
+    pub timesheet_earnings_lines: Vec<EarningsLine>,
+    #[serde(default)]
+    pub deduction_lines: Vec<DeductionLine>,
+    #[serde(default)]
+    pub leave_accrual_lines: Vec<LeaveAccrualLine>,
+    #[serde(default)]
+    pub reimbursement_lines: Vec<ReimbursementLine>,
+    #[serde(default)]
+    pub superannuation_lines: Vec<SuperannuationLine>,
+    #[serde(default)]
+    pub tax_lines: Vec<TaxLine>,
+    #[serde(
+        default,
+        rename = "UpdatedDateUTC",
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+}
+
+impl Payslip {
+    /// The total cost of the payslip to the employer: wages, superannuation and
+    /// reimbursements
+    #[must_use]
+    pub fn employer_cost(&self) -> Decimal {
+        self.wages + self.superannuation + self.reimbursements
+    }
+}
+
+/// Request structure for updating the lines of a payslip
+///
+/// Each collection that is set replaces the existing lines of that kind; collections left
+/// as `None` are unchanged.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdatePayslip {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub earnings_lines: Option<Vec<EarningsLine>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leave_earnings_lines: Option<Vec<LeaveEarningsLine>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deduction_lines: Option<Vec<DeductionLine>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leave_accrual_lines: Option<Vec<LeaveAccrualLine>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reimbursement_lines: Option<Vec<ReimbursementLine>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub superannuation_lines: Option<Vec<SuperannuationLine>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_lines: Option<Vec<TaxLine>>,
+}
+
+impl UpdatePayslip {
+    /// Create an update that leaves every collection unchanged
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the earnings lines
+    #[must_use]
+    pub fn with_earnings_lines(mut self, lines: Vec<EarningsLine>) -> Self {
+        self.earnings_lines = Some(lines);
+        self
+    }
+
+    /// Replace the leave earnings lines
+    #[must_use]
+    pub fn with_leave_earnings_lines(mut self, lines: Vec<LeaveEarningsLine>) -> Self {
+        self.leave_earnings_lines = Some(lines);
+        self
+    }
+
+    /// Replace the deduction lines
+    #[must_use]
+    pub fn with_deduction_lines(mut self, lines: Vec<DeductionLine>) -> Self {
+        self.deduction_lines = Some(lines);
+        self
+    }
+
+    /// Replace the leave accrual lines
+    #[must_use]
+    pub fn with_leave_accrual_lines(mut self, lines: Vec<LeaveAccrualLine>) -> Self {
+        self.leave_accrual_lines = Some(lines);
+        self
+    }
+
+    /// Replace the reimbursement lines
+    #[must_use]
+    pub fn with_reimbursement_lines(mut self, lines: Vec<ReimbursementLine>) -> Self {
+        self.reimbursement_lines = Some(lines);
+        self
+    }
+
+    /// Replace the superannuation lines
+    #[must_use]
+    pub fn with_superannuation_lines(mut self, lines: Vec<SuperannuationLine>) -> Self {
+        self.superannuation_lines = Some(lines);
+        self
+    }
+
+    /// Replace the tax lines
+    #[must_use]
+    pub fn with_tax_lines(mut self, lines: Vec<TaxLine>) -> Self {
+        self.tax_lines = Some(lines);
+        self
+    }
+}
+
+/// Response wrapper for payslip API calls
+///
+/// Xero returns a single `Payslip` from `GET` and a `Payslips` array from `POST`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayslipResponse {
+    #[serde(default)]
+    pub payslip: Option<Payslip>,
+    #[serde(default)]
+    pub payslips: Vec<Payslip>,
+}
+
+impl PayslipResponse {
+    fn into_payslip(self) -> Option<Payslip> {
+        self.payslip.or_else(|| self.payslips.into_iter().next())
+    }
+}
+
+impl Payslip {
+    /// Get a payslip by ID, including all of its lines
+    pub async fn get(client: &crate::client::Client, payslip_id: Uuid) -> Result<Payslip> {
+        info!("Getting payslip with ID: {}", payslip_id);
+
+        let url = format!("{ENDPOINT}/{payslip_id}");
+        debug!("GET URL: {}", url);
+
+        let response: PayslipResponse = client.get(&url, &()).await?;
+        response.into_payslip().ok_or_else(|| not_found(url))
+    }
+
+    /// Replace lines on a payslip of a draft pay run
+    pub async fn update(
+        client: &crate::client::Client,
+        payslip_id: Uuid,
+        payslip: &UpdatePayslip,
+    ) -> Result<Payslip> {
+        info!("Updating payslip with ID: {}", payslip_id);
+        debug!("Updated payslip data: {:?}", payslip);
+
+        let url = format!("{ENDPOINT}/{payslip_id}");
+        debug!("POST URL: {}", url);
+
+        let request = vec![payslip.clone()];
+        let response: PayslipResponse = client.post(&url, &request).await?;
+        response.into_payslip().ok_or_else(|| not_found(url))
+    }
+}
+
+/// Error for a response that unexpectedly contains no payslip
+fn not_found(url: String) -> crate::error::Error {
+    error!("Received no payslip in response");
+    crate::error::Error::NotFound {
+        entity: "Payslip".to_string(),
+        url,
+        status_code: reqwest::StatusCode::NOT_FOUND,
+        response_body: Some("No payslip returned in response".to_string()),
+        span_trace: SpanTrace::capture(),
+    }
+}
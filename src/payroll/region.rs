@@ -0,0 +1,699 @@
+//! Region independent access to Xero Payroll
+//!
+//! Xero runs a separate payroll API in each country it offers payroll in: the AU API lives
+//! directly under [`crate::payroll`] and [`crate::entities::timesheet`], while the UK and NZ
+//! APIs live under `payroll::uk` and `payroll::nz` behind the `payroll-uk` and `payroll-nz`
+//! features. Their employees, timesheets, leave and pay runs all have different shapes.
+//!
+//! [`CommonPayroll`] covers the operations every region supports, using the region neutral
+//! types of this module. Each region's API implements it, and [`RegionalPayroll`] picks the
+//! right one for the organisation's [`PayrollRegion`], so a product that serves several
+//! countries can share one code path and only drop down to the regional APIs for the rest.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::region::CommonPayroll;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! let payroll = client.payroll().await?;
+//! println!("Payroll region: {:?}", payroll.region());
+//!
+//! for employee in payroll.list_employees().await? {
+//!     for balance in payroll.leave_balances(employee.employee_id).await? {
+//!         println!("{}: {:?} {}", employee.last_name, balance.name, balance.balance);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+use super::{employee, pay_run};
+use crate::{
+    client::PayrollAuApi,
+    entities::timesheet::{self, PostTimesheet},
+    error::{Error, Result},
+};
+#[cfg(feature = "payroll-nz")]
+use {super::nz, crate::client::PayrollNzApi};
+#[cfg(feature = "payroll-uk")]
+use {super::uk, crate::client::PayrollUkApi};
+
+pub use super::pay_run::PayRunStatus;
+
+/// The number of records a full page of the AU payroll API holds
+const AU_PAGE_SIZE: usize = 100;
+
+/// A country Xero offers payroll in
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub enum PayrollRegion {
+    /// Australia, served by the payroll 1.0 API
+    Au,
+    /// The United Kingdom, served by the UK payroll 2.0 API
+    Uk,
+    /// New Zealand, served by the NZ payroll 2.0 API
+    Nz,
+}
+
+impl PayrollRegion {
+    /// The region for an ISO 3166 country code, as found on
+    /// [`crate::organisation::Organisation::country_code`]
+    ///
+    /// Both "GB" and "UK" map to [`PayrollRegion::Uk`]. Countries without Xero Payroll
+    /// return `None`.
+    #[must_use]
+    pub fn from_country_code(country_code: &str) -> Option<Self> {
+        match country_code.trim().to_ascii_uppercase().as_str() {
+            "AU" => Some(Self::Au),
+            "GB" | "UK" => Some(Self::Uk),
+            "NZ" => Some(Self::Nz),
+            _ => None,
+        }
+    }
+
+    /// The ISO 3166 country code of the region
+    #[must_use]
+    pub fn country_code(self) -> &'static str {
+        match self {
+            Self::Au => "AU",
+            Self::Uk => "GB",
+            Self::Nz => "NZ",
+        }
+    }
+
+    /// The cargo feature that adds the region's payroll API, or `None` for AU, which is
+    /// always available
+    #[must_use]
+    pub fn feature(self) -> Option<&'static str> {
+        match self {
+            Self::Au => None,
+            Self::Uk => Some("payroll-uk"),
+            Self::Nz => Some("payroll-nz"),
+        }
+    }
+
+    /// Whether this build of the crate includes the region's payroll API
+    #[must_use]
+    pub fn is_enabled(self) -> bool {
+        match self {
+            Self::Au => true,
+            Self::Uk => cfg!(feature = "payroll-uk"),
+            Self::Nz => cfg!(feature = "payroll-nz"),
+        }
+    }
+}
+
+/// A payroll employee, in any region
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PayrollEmployee {
+    pub employee_id: Uuid,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: Option<String>,
+    pub start_date: Option<Date>,
+    /// Termination date in AU, end date of employment in the UK and NZ
+    pub end_date: Option<Date>,
+    /// The calendar the employee is paid on, when the region returns it in lists
+    pub payroll_calendar_id: Option<Uuid>,
+}
+
+/// Hours worked on one day at one earnings rate
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TimesheetEntry {
+    pub date: Date,
+    pub earnings_rate_id: Uuid,
+    pub hours: Decimal,
+}
+
+/// Hours to submit as a timesheet for one employee and pay period, in any region
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TimesheetHours {
+    pub employee_id: Uuid,
+    pub start_date: Date,
+    pub end_date: Date,
+    /// Calendar of the pay period. UK and NZ timesheets need one, and the employee's own
+    /// calendar is looked up when it is not set. AU timesheets ignore it.
+    pub payroll_calendar_id: Option<Uuid>,
+    pub entries: Vec<TimesheetEntry>,
+}
+
+impl TimesheetHours {
+    /// An empty timesheet for the pay period from `start_date` to `end_date` inclusive
+    #[must_use]
+    pub fn new(employee_id: Uuid, start_date: Date, end_date: Date) -> Self {
+        Self {
+            employee_id,
+            start_date,
+            end_date,
+            payroll_calendar_id: None,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Set the payroll calendar of the pay period
+    #[must_use]
+    pub fn with_payroll_calendar(mut self, payroll_calendar_id: Uuid) -> Self {
+        self.payroll_calendar_id = Some(payroll_calendar_id);
+        self
+    }
+
+    /// Add hours worked on a day at an earnings rate
+    #[must_use]
+    pub fn with_hours(mut self, date: Date, earnings_rate_id: Uuid, hours: Decimal) -> Self {
+        self.entries.push(TimesheetEntry {
+            date,
+            earnings_rate_id,
+            hours,
+        });
+        self
+    }
+
+    /// The total hours on the timesheet
+    #[must_use]
+    pub fn total_hours(&self) -> Decimal {
+        self.entries.iter().map(|entry| entry.hours).sum()
+    }
+
+    /// Error for the first entry dated outside the pay period, if any
+    fn period_error(&self) -> Option<Error> {
+        self.entries
+            .iter()
+            .find(|entry| entry.date < self.start_date || entry.date > self.end_date)
+            .map(|entry| Error::TimesheetEntryOutsidePeriod {
+                date: entry.date,
+                start_date: self.start_date,
+                end_date: self.end_date,
+            })
+    }
+
+    /// The AU timesheet for these hours, with one line per earnings rate holding the hours of
+    /// each day of the period
+    ///
+    /// Hours on the same day and earnings rate are added together, and entries outside the
+    /// period are left out.
+    #[must_use]
+    pub fn to_au_timesheet(&self) -> PostTimesheet {
+        let days =
+            usize::try_from((self.end_date - self.start_date).whole_days() + 1).unwrap_or_default();
+        let mut lines: Vec<timesheet::TimesheetLine> = Vec::new();
+
+        for entry in &self.entries {
+            let Some(day) = usize::try_from((entry.date - self.start_date).whole_days())
+                .ok()
+                .filter(|day| *day < days)
+            else {
+                continue;
+            };
+            let index = lines
+                .iter()
+                .position(|line| line.earnings_rate_id == entry.earnings_rate_id)
+                .unwrap_or_else(|| {
+                    lines.push(timesheet::TimesheetLine {
+                        earnings_rate_id: entry.earnings_rate_id,
+                        number_of_units: vec![0.0; days],
+                        updated_date_utc: None,
+                        tracking_item_id: None,
+                    });
+                    lines.len() - 1
+                });
+            lines[index].number_of_units[day] += entry.hours.to_f64().unwrap_or_default();
+        }
+
+        PostTimesheet {
+            timesheet_id: None,
+            employee_id: self.employee_id,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            status: None,
+            timesheet_lines: Some(lines),
+        }
+    }
+}
+
+/// A timesheet submitted through [`CommonPayroll::submit_timesheet`]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PayrollTimesheet {
+    pub timesheet_id: Uuid,
+    pub employee_id: Uuid,
+    pub start_date: Date,
+    pub end_date: Date,
+    pub total_hours: Option<Decimal>,
+}
+
+/// An employee's balance of one leave type, in any region
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PayrollLeaveBalance {
+    pub leave_type_id: Uuid,
+    pub name: Option<String>,
+    pub balance: Decimal,
+    /// "Hours", "Days" or "Weeks", depending on the region and leave type
+    pub type_of_units: Option<String>,
+}
+
+/// A pay run, in any region
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PayrollPayRun {
+    pub pay_run_id: Uuid,
+    pub payroll_calendar_id: Option<Uuid>,
+    pub period_start_date: Option<Date>,
+    pub period_end_date: Option<Date>,
+    pub payment_date: Option<Date>,
+    pub status: Option<PayRunStatus>,
+    /// Net pay to employees
+    pub total_pay: Option<Decimal>,
+}
+
+/// Payroll operations every region supports
+pub trait CommonPayroll {
+    /// The region of the payroll API
+    fn region(&self) -> PayrollRegion;
+
+    /// List all payroll employees
+    fn list_employees(&self) -> impl Future<Output = Result<Vec<PayrollEmployee>>> + Send;
+
+    /// Submit the hours an employee worked in a pay period as a draft timesheet
+    fn submit_timesheet(
+        &self,
+        hours: &TimesheetHours,
+    ) -> impl Future<Output = Result<PayrollTimesheet>> + Send;
+
+    /// List an employee's current leave balances
+    fn leave_balances(
+        &self,
+        employee_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<PayrollLeaveBalance>>> + Send;
+
+    /// List all pay runs
+    fn list_pay_runs(&self) -> impl Future<Output = Result<Vec<PayrollPayRun>>> + Send;
+}
+
+/// The payroll API of an organisation's region, see [`crate::Client::payroll`]
+#[derive(Debug)]
+pub enum RegionalPayroll<'a> {
+    Au(PayrollAuApi<'a>),
+    #[cfg(feature = "payroll-uk")]
+    Uk(PayrollUkApi<'a>),
+    #[cfg(feature = "payroll-nz")]
+    Nz(PayrollNzApi<'a>),
+}
+
+impl CommonPayroll for RegionalPayroll<'_> {
+    fn region(&self) -> PayrollRegion {
+        match self {
+            Self::Au(api) => api.region(),
+            #[cfg(feature = "payroll-uk")]
+            Self::Uk(api) => api.region(),
+            #[cfg(feature = "payroll-nz")]
+            Self::Nz(api) => api.region(),
+        }
+    }
+
+    async fn list_employees(&self) -> Result<Vec<PayrollEmployee>> {
+        match self {
+            Self::Au(api) => api.list_employees().await,
+            #[cfg(feature = "payroll-uk")]
+            Self::Uk(api) => api.list_employees().await,
+            #[cfg(feature = "payroll-nz")]
+            Self::Nz(api) => api.list_employees().await,
+        }
+    }
+
+    async fn submit_timesheet(&self, hours: &TimesheetHours) -> Result<PayrollTimesheet> {
+        match self {
+            Self::Au(api) => api.submit_timesheet(hours).await,
+            #[cfg(feature = "payroll-uk")]
+            Self::Uk(api) => api.submit_timesheet(hours).await,
+            #[cfg(feature = "payroll-nz")]
+            Self::Nz(api) => api.submit_timesheet(hours).await,
+        }
+    }
+
+    async fn leave_balances(&self, employee_id: Uuid) -> Result<Vec<PayrollLeaveBalance>> {
+        match self {
+            Self::Au(api) => api.leave_balances(employee_id).await,
+            #[cfg(feature = "payroll-uk")]
+            Self::Uk(api) => api.leave_balances(employee_id).await,
+            #[cfg(feature = "payroll-nz")]
+            Self::Nz(api) => api.leave_balances(employee_id).await,
+        }
+    }
+
+    async fn list_pay_runs(&self) -> Result<Vec<PayrollPayRun>> {
+        match self {
+            Self::Au(api) => api.list_pay_runs().await,
+            #[cfg(feature = "payroll-uk")]
+            Self::Uk(api) => api.list_pay_runs().await,
+            #[cfg(feature = "payroll-nz")]
+            Self::Nz(api) => api.list_pay_runs().await,
+        }
+    }
+}
+
+impl CommonPayroll for PayrollAuApi<'_> {
+    fn region(&self) -> PayrollRegion {
+        PayrollRegion::Au
+    }
+
+    /// AU pages its employees without reporting a page count, so this reads pages until one
+    /// comes back short
+    async fn list_employees(&self) -> Result<Vec<PayrollEmployee>> {
+        let mut all = Vec::new();
+        for page in 1.. {
+            let employees = self.employees().list_page(page).await?;
+            let last_page = employees.len() < AU_PAGE_SIZE;
+            all.extend(employees.into_iter().map(Into::into));
+            if last_page {
+                break;
+            }
+        }
+        Ok(all)
+    }
+
+    async fn submit_timesheet(&self, hours: &TimesheetHours) -> Result<PayrollTimesheet> {
+        if let Some(error) = hours.period_error() {
+            return Err(error);
+        }
+
+        let timesheet = hours.to_au_timesheet();
+        Ok(self.timesheets().create(&timesheet).await?.into())
+    }
+
+    /// AU only returns leave balances with the full employee record, so this fetches it
+    async fn leave_balances(&self, employee_id: Uuid) -> Result<Vec<PayrollLeaveBalance>> {
+        let employee = self.employees().get(employee_id).await?;
+        Ok(employee
+            .leave_balances
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    /// AU pages its pay runs without reporting a page count, so this reads pages until one
+    /// comes back short
+    async fn list_pay_runs(&self) -> Result<Vec<PayrollPayRun>> {
+        let mut all = Vec::new();
+        for page in 1.. {
+            let pay_runs = self
+                .pay_runs()
+                .list(Some(pay_run::ListParameters {
+                    page: Some(page),
+                    ..pay_run::ListParameters::default()
+                }))
+                .await?;
+            let last_page = pay_runs.len() < AU_PAGE_SIZE;
+            all.extend(pay_runs.into_iter().map(Into::into));
+            if last_page {
+                break;
+            }
+        }
+        Ok(all)
+    }
+}
+
+impl From<employee::Employee> for PayrollEmployee {
+    fn from(employee: employee::Employee) -> Self {
+        Self {
+            employee_id: employee.employee_id,
+            first_name: employee.first_name,
+            last_name: employee.last_name,
+            email: employee.email,
+            start_date: employee.start_date,
+            end_date: employee.termination_date,
+            payroll_calendar_id: employee.payroll_calendar_id,
+        }
+    }
+}
+
+impl From<timesheet::Timesheet> for PayrollTimesheet {
+    fn from(timesheet: timesheet::Timesheet) -> Self {
+        Self {
+            timesheet_id: timesheet.timesheet_id,
+            employee_id: timesheet.employee_id,
+            start_date: timesheet.start_date,
+            end_date: timesheet.end_date,
+            total_hours: Decimal::try_from(timesheet.hours).ok(),
+        }
+    }
+}
+
+impl From<employee::LeaveBalance> for PayrollLeaveBalance {
+    fn from(balance: employee::LeaveBalance) -> Self {
+        Self {
+            leave_type_id: balance.leave_type_id,
+            name: balance.leave_name,
+            balance: balance.number_of_units,
+            type_of_units: balance.type_of_units,
+        }
+    }
+}
+
+impl From<pay_run::PayRun> for PayrollPayRun {
+    fn from(pay_run: pay_run::PayRun) -> Self {
+        Self {
+            pay_run_id: pay_run.pay_run_id,
+            payroll_calendar_id: Some(pay_run.payroll_calendar_id),
+            period_start_date: pay_run.pay_run_period_start_date,
+            period_end_date: pay_run.pay_run_period_end_date,
+            payment_date: pay_run.payment_date,
+            status: Some(pay_run.pay_run_status),
+            total_pay: Some(pay_run.net_pay),
+        }
+    }
+}
+
+#[cfg(feature = "payroll-uk")]
+impl CommonPayroll for PayrollUkApi<'_> {
+    fn region(&self) -> PayrollRegion {
+        PayrollRegion::Uk
+    }
+
+    async fn list_employees(&self) -> Result<Vec<PayrollEmployee>> {
+        let employees = self.employees().list().await?;
+        Ok(employees.into_iter().map(Into::into).collect())
+    }
+
+    async fn submit_timesheet(&self, hours: &TimesheetHours) -> Result<PayrollTimesheet> {
+        if let Some(error) = hours.period_error() {
+            return Err(error);
+        }
+
+        let payroll_calendar_id = match hours.payroll_calendar_id {
+            Some(payroll_calendar_id) => payroll_calendar_id,
+            None => self
+                .employees()
+                .get(hours.employee_id)
+                .await?
+                .payroll_calendar_id
+                .ok_or(Error::MissingPayrollCalendar(hours.employee_id))?,
+        };
+
+        let timesheet = hours.entries.iter().fold(
+            uk::timesheet::PostTimesheet::new(
+                payroll_calendar_id,
+                hours.employee_id,
+                hours.start_date,
+                hours.end_date,
+            ),
+            |timesheet, entry| {
+                timesheet.with_line(uk::timesheet::TimesheetLine::new(
+                    entry.date,
+                    entry.earnings_rate_id,
+                    entry.hours,
+                ))
+            },
+        );
+        Ok(self.timesheets().create(&timesheet).await?.into())
+    }
+
+    async fn leave_balances(&self, employee_id: Uuid) -> Result<Vec<PayrollLeaveBalance>> {
+        let balances = self.employees().leave_balances(employee_id).await?;
+        Ok(balances.into_iter().map(Into::into).collect())
+    }
+
+    async fn list_pay_runs(&self) -> Result<Vec<PayrollPayRun>> {
+        let pay_runs = self.pay_runs().list(None).await?;
+        Ok(pay_runs.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(feature = "payroll-uk")]
+impl From<uk::employee::Employee> for PayrollEmployee {
+    fn from(employee: uk::employee::Employee) -> Self {
+        Self {
+            employee_id: employee.employee_id,
+            first_name: employee.first_name,
+            last_name: employee.last_name,
+            email: employee.email,
+            start_date: employee.start_date,
+            end_date: employee.end_date,
+            payroll_calendar_id: employee.payroll_calendar_id,
+        }
+    }
+}
+
+#[cfg(feature = "payroll-uk")]
+impl From<uk::timesheet::Timesheet> for PayrollTimesheet {
+    fn from(timesheet: uk::timesheet::Timesheet) -> Self {
+        Self {
+            timesheet_id: timesheet.timesheet_id,
+            employee_id: timesheet.employee_id,
+            start_date: timesheet.start_date,
+            end_date: timesheet.end_date,
+            total_hours: timesheet.total_hours,
+        }
+    }
+}
+
+#[cfg(feature = "payroll-uk")]
+impl From<uk::leave::LeaveBalance> for PayrollLeaveBalance {
+    fn from(balance: uk::leave::LeaveBalance) -> Self {
+        Self {
+            leave_type_id: balance.leave_type_id,
+            name: balance.name,
+            balance: balance.balance,
+            type_of_units: balance.type_of_units,
+        }
+    }
+}
+
+#[cfg(feature = "payroll-uk")]
+impl From<uk::pay_run::PayRun> for PayrollPayRun {
+    fn from(pay_run: uk::pay_run::PayRun) -> Self {
+        Self {
+            pay_run_id: pay_run.pay_run_id,
+            payroll_calendar_id: pay_run.payroll_calendar_id,
+            period_start_date: pay_run.period_start_date,
+            period_end_date: pay_run.period_end_date,
+            payment_date: pay_run.payment_date,
+            status: pay_run.pay_run_status.map(|status| match status {
+                uk::pay_run::PayRunStatus::Draft => PayRunStatus::Draft,
+                uk::pay_run::PayRunStatus::Posted => PayRunStatus::Posted,
+            }),
+            total_pay: pay_run.total_pay,
+        }
+    }
+}
+
+#[cfg(feature = "payroll-nz")]
+impl CommonPayroll for PayrollNzApi<'_> {
+    fn region(&self) -> PayrollRegion {
+        PayrollRegion::Nz
+    }
+
+    async fn list_employees(&self) -> Result<Vec<PayrollEmployee>> {
+        let employees = self.employees().list().await?;
+        Ok(employees.into_iter().map(Into::into).collect())
+    }
+
+    async fn submit_timesheet(&self, hours: &TimesheetHours) -> Result<PayrollTimesheet> {
+        if let Some(error) = hours.period_error() {
+            return Err(error);
+        }
+
+        let payroll_calendar_id = match hours.payroll_calendar_id {
+            Some(payroll_calendar_id) => payroll_calendar_id,
+            None => self
+                .employees()
+                .get(hours.employee_id)
+                .await?
+                .payroll_calendar_id
+                .ok_or(Error::MissingPayrollCalendar(hours.employee_id))?,
+        };
+
+        let timesheet = hours.entries.iter().fold(
+            nz::timesheet::PostTimesheet::new(
+                payroll_calendar_id,
+                hours.employee_id,
+                hours.start_date,
+                hours.end_date,
+            ),
+            |timesheet, entry| {
+                timesheet.with_line(nz::timesheet::TimesheetLine::new(
+                    entry.date,
+                    entry.earnings_rate_id,
+                    entry.hours,
+                ))
+            },
+        );
+        Ok(self.timesheets().create(&timesheet).await?.into())
+    }
+
+    async fn leave_balances(&self, employee_id: Uuid) -> Result<Vec<PayrollLeaveBalance>> {
+        let balances = self.employees().leave_balances(employee_id).await?;
+        Ok(balances.into_iter().map(Into::into).collect())
+    }
+
+    async fn list_pay_runs(&self) -> Result<Vec<PayrollPayRun>> {
+        let pay_runs = self.pay_runs().list(None).await?;
+        Ok(pay_runs.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(feature = "payroll-nz")]
+impl From<nz::employee::Employee> for PayrollEmployee {
+    fn from(employee: nz::employee::Employee) -> Self {
+        Self {
+            employee_id: employee.employee_id,
+            first_name: employee.first_name,
+            last_name: employee.last_name,
+            email: employee.email,
+            start_date: employee.start_date,
+            end_date: employee.end_date,
+            payroll_calendar_id: employee.payroll_calendar_id,
+        }
+    }
+}
+
+#[cfg(feature = "payroll-nz")]
+impl From<nz::timesheet::Timesheet> for PayrollTimesheet {
+    fn from(timesheet: nz::timesheet::Timesheet) -> Self {
+        Self {
+            timesheet_id: timesheet.timesheet_id,
+            employee_id: timesheet.employee_id,
+            start_date: timesheet.start_date,
+            end_date: timesheet.end_date,
+            total_hours: timesheet.total_hours,
+        }
+    }
+}
+
+#[cfg(feature = "payroll-nz")]
+impl From<nz::leave::LeaveBalance> for PayrollLeaveBalance {
+    fn from(balance: nz::leave::LeaveBalance) -> Self {
+        Self {
+            leave_type_id: balance.leave_type_id,
+            name: balance.name,
+            balance: balance.balance,
+            type_of_units: balance.type_of_units,
+        }
+    }
+}
+
+#[cfg(feature = "payroll-nz")]
+impl From<nz::pay_run::PayRun> for PayrollPayRun {
+    fn from(pay_run: nz::pay_run::PayRun) -> Self {
+        Self {
+            pay_run_id: pay_run.pay_run_id,
+            payroll_calendar_id: pay_run.payroll_calendar_id,
+            period_start_date: pay_run.period_start_date,
+            period_end_date: pay_run.period_end_date,
+            payment_date: pay_run.payment_date,
+            status: pay_run.pay_run_status.map(|status| match status {
+                nz::pay_run::PayRunStatus::Draft => PayRunStatus::Draft,
+                nz::pay_run::PayRunStatus::Posted => PayRunStatus::Posted,
+            }),
+            total_pay: pay_run.total_pay,
+        }
+    }
+}
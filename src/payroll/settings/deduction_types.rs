@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// How a deduction is reported to the ATO
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DeductionCategory {
+    None,
+    UnionFees,
+    WorkplaceGiving,
+}
+
+/// Represents a deduction type in Xero Payroll AU
+///
+/// Deduction types are the pay items used on the deduction lines of pay templates and
+/// payslips, such as union fees or salary sacrifice.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeductionType {
+    /// Unique identifier for the deduction type
+    #[serde(rename = "DeductionTypeID")]
+    pub deduction_type_id: Uuid,
+
+    /// Name of the deduction type
+    pub name: String,
+
+    /// Account code the deduction is posted to
+    #[serde(default)]
+    pub account_code: Option<String>,
+
+    /// Whether the deduction is taken before tax is calculated
+    #[serde(default)]
+    pub reduces_tax: Option<bool>,
+
+    /// Whether the deduction reduces the earnings super is calculated on
+    #[serde(default)]
+    pub reduces_super: Option<bool>,
+
+    /// Whether the deduction is excluded from W1 reporting
+    #[serde(rename = "IsExemptFromW1", default)]
+    pub is_exempt_from_w1: Option<bool>,
+
+    /// How the deduction is reported for single touch payroll
+    #[serde(default)]
+    pub deduction_category: Option<DeductionCategory>,
+
+    /// Whether the deduction type is active
+    #[serde(default)]
+    pub current_record: Option<bool>,
+
+    /// The date and time when the deduction type was last updated
+    #[serde(rename = "UpdatedDateUTC", default)]
+    pub updated_date_utc: Option<String>,
+}
@@ -1,23 +1,63 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::leave_types::LeaveType;
+pub use super::pay_items::PayItems;
 
 pub const ENDPOINT: &str = "https://api.xero.com/payroll.xro/1.0/PayItems";
 
-#[derive(Clone, Debug, Deserialize)]
+/// The kind of earnings an earnings rate pays, which determines how it is taxed and reported
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum EarningsType {
+    Fixed,
+    OrdinaryTimeEarnings,
+    OvertimeEarnings,
+    Allowance,
+    BonusesAndCommissions,
+    DirectorsFees,
+    PaidParentalLeave,
+    WorkersCompensation,
+    EmploymentTerminationPayment,
+    LumpSumA,
+    LumpSumB,
+    LumpSumD,
+    LumpSumE,
+    LumpSumW,
+    /// An earnings type this crate does not know about yet, kept as Xero sent it so it is
+    /// written back unchanged
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// How the amount paid for an earnings rate is worked out
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum RateType {
+    /// A fixed amount each time the rate is used
+    FixedAmount,
+    /// A multiple of the employee's ordinary earnings rate, e.g. 1.5 for time and a half
+    Multiple,
+    /// An amount per unit, e.g. per hour or per kilometre
+    RatePerUnit,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct EarningsRate {
     #[serde(rename = "EarningsRateID")]
     pub earnings_rate_id: Uuid,
     pub name: String,
-    pub earnings_type: String,
-    pub rate_type: String,
+    pub earnings_type: EarningsType,
+    pub rate_type: RateType,
     #[serde(default)]
     pub type_of_units: Option<String>,
     pub account_code: Option<String>,
     pub multiplier: Option<f64>,
     #[serde(default)]
+    pub rate_per_unit: Option<f64>,
+    #[serde(default)]
+    pub amount: Option<f64>,
+    #[serde(default)]
     pub is_exempt_from_tax: Option<bool>,
     #[serde(default)]
     pub is_exempt_from_super: Option<bool>,
@@ -25,17 +65,23 @@ pub struct EarningsRate {
     pub is_reportable_as_w1: Option<bool>,
     #[serde(default)]
     pub accrue_leave: Option<bool>,
+    #[serde(rename = "UpdatedDateUTC", default)]
     pub updated_date_utc: Option<String>,
     pub current_record: Option<bool>,
+    /// "O" or "R", for `EarningsType::EmploymentTerminationPayment`
     pub employment_termination_payment_type: Option<String>,
-}
-
-#[derive(Deserialize)]
-#[serde(rename_all = "PascalCase")]
-pub struct PayItems {
-    pub earnings_rates: Vec<EarningsRate>,
+    /// Whether an allowance paid per unit counts towards the annual leave rate
+    #[serde(default)]
+    pub allowance_contributes_to_annual_leave_rate: Option<bool>,
+    /// Whether an allowance paid per unit counts towards the overtime rate
+    #[serde(default)]
+    pub allowance_contributes_to_overtime_rate: Option<bool>,
+    /// e.g. "CAR" or "MEALS", for `EarningsType::Allowance`
+    #[serde(default)]
+    pub allowance_type: Option<String>,
+    /// e.g. "GENERAL" or "HOMEOFFICE", for `EarningsType::Allowance`
     #[serde(default)]
-    pub leave_types: Vec<LeaveType>,
+    pub allowance_category: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -1,11 +1,11 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 /// Represents a leave type in Xero Payroll AU
 ///
 /// Leave types define the categories of leave available to employees,
 /// such as Annual Leave, Personal/Carer's Leave, etc.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct LeaveType {
     /// Unique identifier for the leave type
@@ -39,9 +39,13 @@ pub struct LeaveType {
     #[serde(default)]
     pub show_balance_on_payslip: Option<bool>,
 
-    /// Account code for leave liability
+    /// Leave category reported for single touch payroll, e.g. "ANNUALLEAVE"
     pub leave_category_code: Option<String>,
 
+    /// Whether the leave type is exempt from the superannuation guarantee
+    #[serde(rename = "SGCExempt", default)]
+    pub sgc_exempt: Option<bool>,
+
     /// Whether the leave type is active
     #[serde(default)]
     pub current_record: Option<bool>,
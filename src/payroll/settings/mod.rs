@@ -1,3 +1,7 @@
+pub mod deduction_types;
 pub mod earnings_rates;
 pub mod leave_types;
 pub mod pay_calendar;
+pub mod pay_items;
+pub mod payroll_settings;
+pub mod reimbursement_types;
@@ -1,7 +1,7 @@
 use crate::utils::date_format::{xero_date_format, xero_date_format_option};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
-use time::Date;
+use time::{Date, Duration, Month};
 use uuid::Uuid;
 
 /// Calendar types supported by the Xero Payroll API
@@ -38,6 +38,85 @@ impl FromStr for CalendarType {
     }
 }
 
+impl CalendarType {
+    /// The number of pay periods in a year
+    #[must_use]
+    pub fn periods_per_year(&self) -> u32 {
+        match self {
+            CalendarType::Weekly => 52,
+            CalendarType::Fortnightly => 26,
+            CalendarType::Monthly => 12,
+            CalendarType::FourWeekly => 13,
+            CalendarType::TwiceMonthly => 24,
+            CalendarType::Quarterly => 4,
+        }
+    }
+
+    /// The start of the `n`th pay period after the one starting on `start_date`
+    ///
+    /// Monthly and quarterly periods keep the day of the month of `start_date`, moving to
+    /// the last day of shorter months. Twice-monthly periods split each month into the 1st to
+    /// the 15th and the 16th to the end of the month, starting from the half `start_date`
+    /// falls in.
+    fn period_start(&self, start_date: Date, n: u32) -> Date {
+        let days = |length: i64| start_date.saturating_add(Duration::days(length * i64::from(n)));
+        match self {
+            CalendarType::Weekly => days(7),
+            CalendarType::Fortnightly => days(14),
+            CalendarType::FourWeekly => days(28),
+            CalendarType::Monthly => add_months(start_date, n),
+            CalendarType::Quarterly => add_months(start_date, n.saturating_mul(3)),
+            CalendarType::TwiceMonthly if n == 0 => start_date,
+            CalendarType::TwiceMonthly => {
+                let halves = n.saturating_add(u32::from(start_date.day() > 15));
+                let first_of_month = start_date.replace_day(1).unwrap_or(start_date);
+                let month_start = add_months(first_of_month, halves / 2);
+                if halves.is_multiple_of(2) {
+                    month_start
+                } else {
+                    month_start.saturating_add(Duration::days(15))
+                }
+            }
+        }
+    }
+}
+
+/// The same day `months` months later, or the last day of that month if it is shorter
+fn add_months(date: Date, months: u32) -> Date {
+    let months = u32::from(u8::from(date.month()) - 1).saturating_add(months);
+    let year = i32::try_from(months / 12)
+        .ok()
+        .and_then(|years| date.year().checked_add(years));
+    let month = u8::try_from(months % 12 + 1)
+        .ok()
+        .and_then(|month| Month::try_from(month).ok());
+
+    match (year, month) {
+        (Some(year), Some(month)) => {
+            let day = date.day().min(month.length(year));
+            Date::from_calendar_date(year, month, day).unwrap_or(Date::MAX)
+        }
+        _ => Date::MAX,
+    }
+}
+
+/// One pay period of a pay calendar
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PayPeriod {
+    /// The first day of the pay period
+    pub start_date: Date,
+    /// The last day of the pay period
+    pub end_date: Date,
+}
+
+impl PayPeriod {
+    /// Whether the given date falls within the pay period
+    #[must_use]
+    pub fn contains(&self, date: Date) -> bool {
+        self.start_date <= date && date <= self.end_date
+    }
+}
+
 /// Represents a pay calendar in the Xero Payroll API
 ///
 /// Pay calendars define the pay periods for employees.
@@ -72,6 +151,20 @@ impl PayCalendar {
     pub fn end_date(&self) -> Date {
         self.payment_date.saturating_sub(time::Duration::days(1))
     }
+
+    /// The calendar's pay periods, starting with the period that starts on `start_date`
+    ///
+    /// The iterator is unbounded, so limit it with e.g. `take_while`.
+    pub fn pay_periods(&self) -> impl Iterator<Item = PayPeriod> + '_ {
+        (0..u32::MAX).map(|n| {
+            let start_date = self.calendar_type.period_start(self.start_date, n);
+            let next_start = self.calendar_type.period_start(self.start_date, n + 1);
+            PayPeriod {
+                start_date,
+                end_date: next_start.saturating_sub(Duration::days(1)),
+            }
+        })
+    }
 }
 
 /// Response wrapper for pay calendar API requests
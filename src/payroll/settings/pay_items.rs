@@ -0,0 +1,575 @@
+//! Pay items for Xero Payroll AU
+//!
+//! Pay items are the earnings rates, deduction types, leave types and reimbursement types
+//! that pay templates and payslips refer to. They are read and written together through the
+//! `/PayItems` endpoint.
+//!
+//! Xero treats each collection that is posted as the complete set for that collection, so
+//! items left out of a posted collection are deleted or archived. Start from
+//! [`PostPayItems::from`] the current pay items and upsert into it, or use the create and
+//! update helpers on [`crate::client::PayItemsApi`], which do this for one earnings rate,
+//! deduction type, leave type or reimbursement type. The conversion carries every field Xero
+//! returns, other than the read-only update time, so existing items are written back as they
+//! were.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::settings::earnings_rates::{EarningsType, RateType};
+//! use xero_rs::payroll::settings::pay_items::PostEarningsRate;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! let rate = PostEarningsRate::new(
+//!     "Saturday penalty",
+//!     "477",
+//!     EarningsType::OvertimeEarnings,
+//!     RateType::Multiple,
+//!     "Hours",
+//! )
+//! .with_multiplier(1.25);
+//! let created = client.pay_items().create_earnings_rate(&rate).await?;
+//! println!("Created earnings rate {}", created.earnings_rate_id);
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::{
+    deduction_types::{DeductionCategory, DeductionType},
+    earnings_rates::{ENDPOINT, EarningsRate, EarningsType, RateType},
+    leave_types::LeaveType,
+    reimbursement_types::ReimbursementType,
+};
+use crate::error::Result;
+
+/// All pay items of an organisation
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayItems {
+    #[serde(default)]
+    pub earnings_rates: Vec<EarningsRate>,
+    #[serde(default)]
+    pub leave_types: Vec<LeaveType>,
+    #[serde(default)]
+    pub deduction_types: Vec<DeductionType>,
+    #[serde(default)]
+    pub reimbursement_types: Vec<ReimbursementType>,
+}
+
+impl PayItems {
+    /// The earnings rate with the given ID
+    #[must_use]
+    pub fn earnings_rate(&self, earnings_rate_id: Uuid) -> Option<&EarningsRate> {
+        self.earnings_rates
+            .iter()
+            .find(|rate| rate.earnings_rate_id == earnings_rate_id)
+    }
+
+    /// The earnings rate with the given name, ignoring case
+    #[must_use]
+    pub fn earnings_rate_named(&self, name: &str) -> Option<&EarningsRate> {
+        self.earnings_rates
+            .iter()
+            .find(|rate| rate.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The deduction type with the given ID
+    #[must_use]
+    pub fn deduction_type(&self, deduction_type_id: Uuid) -> Option<&DeductionType> {
+        self.deduction_types
+            .iter()
+            .find(|deduction| deduction.deduction_type_id == deduction_type_id)
+    }
+
+    /// The leave type with the given ID
+    #[must_use]
+    pub fn leave_type(&self, leave_type_id: Uuid) -> Option<&LeaveType> {
+        self.leave_types
+            .iter()
+            .find(|leave| leave.leave_type_id == leave_type_id)
+    }
+
+    /// The reimbursement type with the given ID
+    #[must_use]
+    pub fn reimbursement_type(&self, reimbursement_type_id: Uuid) -> Option<&ReimbursementType> {
+        self.reimbursement_types
+            .iter()
+            .find(|reimbursement| reimbursement.reimbursement_type_id == reimbursement_type_id)
+    }
+}
+
+/// Request structure for creating or updating an earnings rate
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PostEarningsRate {
+    /// Set to update an existing earnings rate
+    #[serde(rename = "EarningsRateID", skip_serializing_if = "Option::is_none")]
+    pub earnings_rate_id: Option<Uuid>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_code: Option<String>,
+    pub earnings_type: EarningsType,
+    pub rate_type: RateType,
+    /// e.g. "Hours" or "Km"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_of_units: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_per_unit: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiplier: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_exempt_from_tax: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_exempt_from_super: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_reportable_as_w1: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accrue_leave: Option<bool>,
+    /// "O" or "R", for `EarningsType::EmploymentTerminationPayment`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub employment_termination_payment_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowance_contributes_to_annual_leave_rate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowance_contributes_to_overtime_rate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowance_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowance_category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_record: Option<bool>,
+}
+
+impl PostEarningsRate {
+    /// A new earnings rate with the fields Xero requires
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        account_code: impl Into<String>,
+        earnings_type: EarningsType,
+        rate_type: RateType,
+        type_of_units: impl Into<String>,
+    ) -> Self {
+        Self {
+            earnings_rate_id: None,
+            name: name.into(),
+            account_code: Some(account_code.into()),
+            earnings_type,
+            rate_type,
+            type_of_units: Some(type_of_units.into()),
+            rate_per_unit: None,
+            multiplier: None,
+            amount: None,
+            is_exempt_from_tax: None,
+            is_exempt_from_super: None,
+            is_reportable_as_w1: None,
+            accrue_leave: None,
+            employment_termination_payment_type: None,
+            allowance_contributes_to_annual_leave_rate: None,
+            allowance_contributes_to_overtime_rate: None,
+            allowance_type: None,
+            allowance_category: None,
+            current_record: None,
+        }
+    }
+
+    /// Set the amount paid per unit, for `RateType::RatePerUnit`
+    #[must_use]
+    pub fn with_rate_per_unit(mut self, rate_per_unit: f64) -> Self {
+        self.rate_per_unit = Some(rate_per_unit);
+        self
+    }
+
+    /// Set the multiple of the ordinary earnings rate, for `RateType::Multiple`
+    #[must_use]
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = Some(multiplier);
+        self
+    }
+
+    /// Set the fixed amount, for `RateType::FixedAmount`
+    #[must_use]
+    pub fn with_amount(mut self, amount: f64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Set whether the earnings are exempt from PAYG withholding
+    #[must_use]
+    pub fn with_exempt_from_tax(mut self, exempt: bool) -> Self {
+        self.is_exempt_from_tax = Some(exempt);
+        self
+    }
+
+    /// Set whether the earnings are exempt from superannuation
+    #[must_use]
+    pub fn with_exempt_from_super(mut self, exempt: bool) -> Self {
+        self.is_exempt_from_super = Some(exempt);
+        self
+    }
+
+    /// Set whether the earnings are reported at W1 on the activity statement
+    #[must_use]
+    pub fn with_reportable_as_w1(mut self, reportable: bool) -> Self {
+        self.is_reportable_as_w1 = Some(reportable);
+        self
+    }
+
+    /// Set whether leave accrues on the earnings
+    #[must_use]
+    pub fn with_accrue_leave(mut self, accrue_leave: bool) -> Self {
+        self.accrue_leave = Some(accrue_leave);
+        self
+    }
+}
+
+impl From<&EarningsRate> for PostEarningsRate {
+    fn from(rate: &EarningsRate) -> Self {
+        Self {
+            earnings_rate_id: Some(rate.earnings_rate_id),
+            name: rate.name.clone(),
+            account_code: rate.account_code.clone(),
+            earnings_type: rate.earnings_type.clone(),
+            rate_type: rate.rate_type,
+            type_of_units: rate.type_of_units.clone(),
+            rate_per_unit: rate.rate_per_unit,
+            multiplier: rate.multiplier,
+            amount: rate.amount,
+            is_exempt_from_tax: rate.is_exempt_from_tax,
+            is_exempt_from_super: rate.is_exempt_from_super,
+            is_reportable_as_w1: rate.is_reportable_as_w1,
+            accrue_leave: rate.accrue_leave,
+            employment_termination_payment_type: rate.employment_termination_payment_type.clone(),
+            allowance_contributes_to_annual_leave_rate: rate
+                .allowance_contributes_to_annual_leave_rate,
+            allowance_contributes_to_overtime_rate: rate.allowance_contributes_to_overtime_rate,
+            allowance_type: rate.allowance_type.clone(),
+            allowance_category: rate.allowance_category.clone(),
+            current_record: rate.current_record,
+        }
+    }
+}
+
+/// Request structure for creating or updating a deduction type
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PostDeductionType {
+    /// Set to update an existing deduction type
+    #[serde(rename = "DeductionTypeID", skip_serializing_if = "Option::is_none")]
+    pub deduction_type_id: Option<Uuid>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduces_tax: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduces_super: Option<bool>,
+    #[serde(rename = "IsExemptFromW1", skip_serializing_if = "Option::is_none")]
+    pub is_exempt_from_w1: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deduction_category: Option<DeductionCategory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_record: Option<bool>,
+}
+
+impl PostDeductionType {
+    /// A new deduction type posted to the given account
+    #[must_use]
+    pub fn new(name: impl Into<String>, account_code: impl Into<String>) -> Self {
+        Self {
+            deduction_type_id: None,
+            name: name.into(),
+            account_code: Some(account_code.into()),
+            reduces_tax: None,
+            reduces_super: None,
+            is_exempt_from_w1: None,
+            deduction_category: None,
+            current_record: None,
+        }
+    }
+
+    /// Set whether the deduction is taken before tax
+    #[must_use]
+    pub fn with_reduces_tax(mut self, reduces_tax: bool) -> Self {
+        self.reduces_tax = Some(reduces_tax);
+        self
+    }
+
+    /// Set whether the deduction reduces the earnings super is calculated on
+    #[must_use]
+    pub fn with_reduces_super(mut self, reduces_super: bool) -> Self {
+        self.reduces_super = Some(reduces_super);
+        self
+    }
+
+    /// Set how the deduction is reported for single touch payroll
+    #[must_use]
+    pub fn with_deduction_category(mut self, category: DeductionCategory) -> Self {
+        self.deduction_category = Some(category);
+        self
+    }
+}
+
+impl From<&DeductionType> for PostDeductionType {
+    fn from(deduction: &DeductionType) -> Self {
+        Self {
+            deduction_type_id: Some(deduction.deduction_type_id),
+            name: deduction.name.clone(),
+            account_code: deduction.account_code.clone(),
+            reduces_tax: deduction.reduces_tax,
+            reduces_super: deduction.reduces_super,
+            is_exempt_from_w1: deduction.is_exempt_from_w1,
+            deduction_category: deduction.deduction_category,
+            current_record: deduction.current_record,
+        }
+    }
+}
+
+/// Request structure for creating or updating a leave type
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PostLeaveType {
+    /// Set to update an existing leave type
+    #[serde(rename = "LeaveTypeID", skip_serializing_if = "Option::is_none")]
+    pub leave_type_id: Option<Uuid>,
+    pub name: String,
+    /// "Hours" or "Days"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_of_units: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_paid_leave: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_on_payslip: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normal_entitlement: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leave_loading_rate: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_balance_on_payslip: Option<bool>,
+    /// Leave category reported for single touch payroll, e.g. "ANNUALLEAVE"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leave_category_code: Option<String>,
+    #[serde(rename = "SGCExempt", skip_serializing_if = "Option::is_none")]
+    pub sgc_exempt: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_record: Option<bool>,
+}
+
+impl PostLeaveType {
+    /// A new leave type
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        type_of_units: impl Into<String>,
+        is_paid_leave: bool,
+        show_on_payslip: bool,
+    ) -> Self {
+        Self {
+            leave_type_id: None,
+            name: name.into(),
+            type_of_units: Some(type_of_units.into()),
+            is_paid_leave: Some(is_paid_leave),
+            show_on_payslip: Some(show_on_payslip),
+            normal_entitlement: None,
+            leave_loading_rate: None,
+            show_balance_on_payslip: None,
+            leave_category_code: None,
+            sgc_exempt: None,
+            current_record: None,
+        }
+    }
+
+    /// Set the normal entitlement in units per year
+    #[must_use]
+    pub fn with_normal_entitlement(mut self, normal_entitlement: f64) -> Self {
+        self.normal_entitlement = Some(normal_entitlement);
+        self
+    }
+
+    /// Set the leave loading percentage
+    #[must_use]
+    pub fn with_leave_loading_rate(mut self, leave_loading_rate: f64) -> Self {
+        self.leave_loading_rate = Some(leave_loading_rate);
+        self
+    }
+}
+
+impl From<&LeaveType> for PostLeaveType {
+    fn from(leave: &LeaveType) -> Self {
+        Self {
+            leave_type_id: Some(leave.leave_type_id),
+            name: leave.name.clone(),
+            type_of_units: leave.type_of_units.clone(),
+            is_paid_leave: leave.is_paid_leave,
+            show_on_payslip: leave.show_on_payslip,
+            normal_entitlement: leave.normal_entitlement,
+            leave_loading_rate: leave.leave_loading_rate,
+            show_balance_on_payslip: leave.show_balance_on_payslip,
+            leave_category_code: leave.leave_category_code.clone(),
+            sgc_exempt: leave.sgc_exempt,
+            current_record: leave.current_record,
+        }
+    }
+}
+
+/// Request structure for creating or updating a reimbursement type
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PostReimbursementType {
+    /// Set to update an existing reimbursement type
+    #[serde(
+        rename = "ReimbursementTypeID",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub reimbursement_type_id: Option<Uuid>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_record: Option<bool>,
+}
+
+impl PostReimbursementType {
+    /// A new reimbursement type posted to the given account
+    #[must_use]
+    pub fn new(name: impl Into<String>, account_code: impl Into<String>) -> Self {
+        Self {
+            reimbursement_type_id: None,
+            name: name.into(),
+            account_code: Some(account_code.into()),
+            current_record: None,
+        }
+    }
+}
+
+impl From<&ReimbursementType> for PostReimbursementType {
+    fn from(reimbursement: &ReimbursementType) -> Self {
+        Self {
+            reimbursement_type_id: Some(reimbursement.reimbursement_type_id),
+            name: reimbursement.name.clone(),
+            account_code: reimbursement.account_code.clone(),
+            current_record: reimbursement.current_record,
+        }
+    }
+}
+
+/// Request structure for writing pay items
+///
+/// Empty collections are not sent, so they are left unchanged.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PostPayItems {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub earnings_rates: Vec<PostEarningsRate>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deduction_types: Vec<PostDeductionType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub leave_types: Vec<PostLeaveType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reimbursement_types: Vec<PostReimbursementType>,
+}
+
+impl From<&PayItems> for PostPayItems {
+    fn from(pay_items: &PayItems) -> Self {
+        Self {
+            earnings_rates: pay_items.earnings_rates.iter().map(Into::into).collect(),
+            deduction_types: pay_items.deduction_types.iter().map(Into::into).collect(),
+            leave_types: pay_items.leave_types.iter().map(Into::into).collect(),
+            reimbursement_types: pay_items
+                .reimbursement_types
+                .iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl PostPayItems {
+    /// Create an empty request
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an earnings rate, replacing any with the same ID
+    #[must_use]
+    pub fn with_earnings_rate(mut self, rate: PostEarningsRate) -> Self {
+        upsert(&mut self.earnings_rates, rate, |r| r.earnings_rate_id);
+        self
+    }
+
+    /// Add a deduction type, replacing any with the same ID
+    #[must_use]
+    pub fn with_deduction_type(mut self, deduction: PostDeductionType) -> Self {
+        upsert(&mut self.deduction_types, deduction, |d| {
+            d.deduction_type_id
+        });
+        self
+    }
+
+    /// Add a leave type, replacing any with the same ID
+    #[must_use]
+    pub fn with_leave_type(mut self, leave: PostLeaveType) -> Self {
+        upsert(&mut self.leave_types, leave, |l| l.leave_type_id);
+        self
+    }
+
+    /// Add a reimbursement type, replacing any with the same ID
+    #[must_use]
+    pub fn with_reimbursement_type(mut self, reimbursement: PostReimbursementType) -> Self {
+        upsert(&mut self.reimbursement_types, reimbursement, |r| {
+            r.reimbursement_type_id
+        });
+        self
+    }
+}
+
+/// Replace the item with the same ID as `item`, or append it if it is new
+fn upsert<T>(items: &mut Vec<T>, item: T, id: impl Fn(&T) -> Option<Uuid>) {
+    let existing = id(&item).and_then(|item_id| {
+        items
+            .iter()
+            .position(|candidate| id(candidate) == Some(item_id))
+    });
+    match existing {
+        Some(index) => items[index] = item,
+        None => items.push(item),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct PayItemsResponse {
+    pub pay_items: PayItems,
+}
+
+impl PayItems {
+    /// Get all pay items
+    pub async fn get(client: &crate::client::Client) -> Result<PayItems> {
+        info!("Getting pay items");
+
+        let response: PayItemsResponse = client.get(ENDPOINT, &()).await?;
+        Ok(response.pay_items)
+    }
+
+    /// Write pay items, returning every pay item after the change
+    pub async fn post(
+        client: &crate::client::Client,
+        pay_items: &PostPayItems,
+    ) -> Result<PayItems> {
+        info!("Posting pay items");
+        debug!("Pay items data: {:?}", pay_items);
+
+        let response: PayItemsResponse = client.post(ENDPOINT, pay_items).await?;
+        Ok(response.pay_items)
+    }
+}
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use tracing::info;
+use uuid::Uuid;
+
+pub const ENDPOINT: &str = "https://api.xero.com/payroll.xro/1.0/Settings";
+
+/// The role an account plays in payroll
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SettingsAccountType {
+    Bank,
+    WagesExpense,
+    #[serde(rename = "WAGESPAYABLELIABILITY")]
+    WagesPayable,
+    SuperannuationExpense,
+    SuperannuationLiability,
+    PaygLiability,
+    /// An account type this crate does not know about yet
+    #[serde(other)]
+    Other,
+}
+
+/// An account used by payroll when posting pay runs
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SettingsAccount {
+    #[serde(rename = "AccountID")]
+    pub account_id: Uuid,
+    #[serde(rename = "Type")]
+    pub account_type: SettingsAccountType,
+    pub code: String,
+    pub name: String,
+}
+
+/// A tracking category used by payroll
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SettingsTrackingCategory {
+    #[serde(rename = "TrackingCategoryID")]
+    pub tracking_category_id: Uuid,
+    #[serde(default)]
+    pub tracking_category_name: Option<String>,
+}
+
+/// The tracking categories used to group employees and timesheet lines
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SettingsTrackingCategories {
+    #[serde(default)]
+    pub employee_groups: Option<SettingsTrackingCategory>,
+    #[serde(default)]
+    pub timesheet_categories: Option<SettingsTrackingCategory>,
+}
+
+/// Organisation-wide payroll settings
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayrollSettings {
+    #[serde(default)]
+    pub accounts: Vec<SettingsAccount>,
+    #[serde(default)]
+    pub tracking_categories: SettingsTrackingCategories,
+    /// Number of days in the payroll year, used to pro-rate annual salaries
+    #[serde(default, deserialize_with = "days_in_payroll_year")]
+    pub days_in_payroll_year: Option<u16>,
+}
+
+impl PayrollSettings {
+    /// The account used for the given purpose, if one is set
+    #[must_use]
+    pub fn account(&self, account_type: SettingsAccountType) -> Option<&SettingsAccount> {
+        self.accounts
+            .iter()
+            .find(|account| account.account_type == account_type)
+    }
+}
+
+/// Response wrapper for the settings endpoint
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayrollSettingsResponse {
+    pub settings: PayrollSettings,
+}
+
+impl PayrollSettings {
+    /// Get the organisation's payroll settings
+    pub async fn get(client: &crate::client::Client) -> crate::error::Result<PayrollSettings> {
+        info!("Getting payroll settings");
+
+        let response: PayrollSettingsResponse = client.get(ENDPOINT, &()).await?;
+        Ok(response.settings)
+    }
+}
+
+/// Xero returns `DaysInPayrollYear` as a string, e.g. `"364"`
+fn days_in_payroll_year<'de, D>(deserializer: D) -> Result<Option<u16>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Days {
+        Number(u16),
+        Text(String),
+    }
+
+    match Option::<Days>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Days::Number(days)) => Ok(Some(days)),
+        Some(Days::Text(text)) if text.trim().is_empty() => Ok(None),
+        Some(Days::Text(text)) => text
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Represents a reimbursement type in Xero Payroll AU
+///
+/// Reimbursement types are the pay items used to pay employees back for expenses, such as
+/// travel or tools.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReimbursementType {
+    /// Unique identifier for the reimbursement type
+    #[serde(rename = "ReimbursementTypeID")]
+    pub reimbursement_type_id: Uuid,
+
+    /// Name of the reimbursement type
+    pub name: String,
+
+    /// Account code the reimbursement is posted to
+    #[serde(default)]
+    pub account_code: Option<String>,
+
+    /// Whether the reimbursement type is active
+    #[serde(default)]
+    pub current_record: Option<bool>,
+
+    /// The date and time when the reimbursement type was last updated
+    #[serde(rename = "UpdatedDateUTC", default)]
+    pub updated_date_utc: Option<String>,
+}
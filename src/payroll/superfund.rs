@@ -0,0 +1,356 @@
+//! Superfunds API for Xero Payroll AU
+//!
+//! Superfunds are the funds employer super contributions are paid into. Regulated funds are
+//! identified by their USI and looked up through [`SuperfundProduct`]; self-managed funds
+//! (SMSFs) carry their own bank details and electronic service address.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::superfund;
+//!
+//! # async fn example(employee_id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! let employee = client.employees().get(employee_id).await?;
+//! let funds = client.superfunds().list().await?;
+//! for membership in superfund::unknown_memberships(&employee, &funds) {
+//!     println!("Unknown super fund: {}", membership.super_fund_id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tracing::{debug, error, info};
+use tracing_error::SpanTrace;
+use uuid::Uuid;
+
+use crate::{
+    error::Result,
+    payroll::employee::{Employee, SuperMembership},
+    utils::date_format::xero_datetime_format_option,
+};
+
+/// Base endpoint for superfunds
+pub const ENDPOINT: &str = "https://api.xero.com/payroll.xro/1.0/Superfunds";
+
+/// Endpoint for looking up regulated superfund products
+pub const PRODUCTS_ENDPOINT: &str = "https://api.xero.com/payroll.xro/1.0/SuperfundProducts";
+
+/// Whether a superfund is an APRA regulated fund or a self-managed fund
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SuperfundType {
+    Regulated,
+    #[serde(rename = "SMSF")]
+    SelfManaged,
+}
+
+/// A superfund in Xero Payroll AU
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Superfund {
+    #[serde(rename = "SuperFundID")]
+    pub super_fund_id: Uuid,
+
+    #[serde(rename = "Type")]
+    pub fund_type: SuperfundType,
+
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[serde(rename = "ABN", default)]
+    pub abn: Option<String>,
+
+    /// Unique superannuation identifier of a regulated fund
+    #[serde(rename = "USI", default)]
+    pub usi: Option<String>,
+
+    /// Superannuation product identification number, superseded by the USI
+    #[serde(rename = "SPIN", default)]
+    pub spin: Option<String>,
+
+    /// Bank details of a self-managed fund
+    #[serde(rename = "BSB", default)]
+    pub bsb: Option<String>,
+
+    #[serde(default)]
+    pub account_number: Option<String>,
+
+    #[serde(default)]
+    pub account_name: Option<String>,
+
+    /// Electronic service address a self-managed fund receives contribution data at
+    #[serde(default)]
+    pub electronic_service_address: Option<String>,
+
+    /// The employer's number with the fund
+    #[serde(default)]
+    pub employer_number: Option<String>,
+
+    #[serde(
+        default,
+        rename = "UpdatedDateUTC",
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+}
+
+/// A regulated superfund product, as registered with the ATO
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SuperfundProduct {
+    #[serde(rename = "ABN", default)]
+    pub abn: Option<String>,
+
+    #[serde(rename = "USI", default)]
+    pub usi: Option<String>,
+
+    #[serde(rename = "SPIN", default)]
+    pub spin: Option<String>,
+
+    #[serde(default)]
+    pub product_name: Option<String>,
+}
+
+/// Request structure for creating or updating a superfund
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PostSuperfund {
+    #[serde(rename = "SuperFundID", skip_serializing_if = "Option::is_none")]
+    pub super_fund_id: Option<Uuid>,
+
+    #[serde(rename = "Type")]
+    pub fund_type: SuperfundType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(rename = "ABN", skip_serializing_if = "Option::is_none")]
+    pub abn: Option<String>,
+
+    #[serde(rename = "USI", skip_serializing_if = "Option::is_none")]
+    pub usi: Option<String>,
+
+    #[serde(rename = "BSB", skip_serializing_if = "Option::is_none")]
+    pub bsb: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub electronic_service_address: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub employer_number: Option<String>,
+}
+
+impl PostSuperfund {
+    /// A regulated fund, identified by its USI
+    #[must_use]
+    pub fn regulated(usi: impl Into<String>) -> Self {
+        Self {
+            super_fund_id: None,
+            fund_type: SuperfundType::Regulated,
+            name: None,
+            abn: None,
+            usi: Some(usi.into()),
+            bsb: None,
+            account_number: None,
+            account_name: None,
+            electronic_service_address: None,
+            employer_number: None,
+        }
+    }
+
+    /// A self-managed fund with its bank account and electronic service address
+    #[must_use]
+    pub fn self_managed(
+        name: impl Into<String>,
+        abn: impl Into<String>,
+        bsb: impl Into<String>,
+        account_number: impl Into<String>,
+        electronic_service_address: impl Into<String>,
+    ) -> Self {
+        Self {
+            super_fund_id: None,
+            fund_type: SuperfundType::SelfManaged,
+            name: Some(name.into()),
+            abn: Some(abn.into()),
+            usi: None,
+            bsb: Some(bsb.into()),
+            account_number: Some(account_number.into()),
+            account_name: None,
+            electronic_service_address: Some(electronic_service_address.into()),
+            employer_number: None,
+        }
+    }
+
+    /// Set the account name of a self-managed fund
+    #[must_use]
+    pub fn with_account_name(mut self, account_name: impl Into<String>) -> Self {
+        self.account_name = Some(account_name.into());
+        self
+    }
+
+    /// Set the employer's number with the fund
+    #[must_use]
+    pub fn with_employer_number(mut self, employer_number: impl Into<String>) -> Self {
+        self.employer_number = Some(employer_number.into());
+        self
+    }
+}
+
+/// Response wrapper for superfund API calls
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SuperfundResponse {
+    #[serde(rename = "SuperFunds", default)]
+    pub super_funds: Vec<Superfund>,
+}
+
+/// Response wrapper for superfund product lookups
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SuperfundProductResponse {
+    #[serde(rename = "SuperFundProducts", default)]
+    pub super_fund_products: Vec<SuperfundProduct>,
+}
+
+/// Parameters for looking up superfund products
+#[derive(Debug, Serialize, Default)]
+pub struct ProductParameters {
+    #[serde(rename = "ABN", skip_serializing_if = "Option::is_none")]
+    pub abn: Option<String>,
+
+    #[serde(rename = "USI", skip_serializing_if = "Option::is_none")]
+    pub usi: Option<String>,
+}
+
+impl Superfund {
+    /// List superfunds
+    pub async fn list(client: &crate::client::Client) -> Result<Vec<Superfund>> {
+        info!("Listing superfunds");
+
+        let response: SuperfundResponse = client.get(ENDPOINT, &()).await?;
+        debug!(
+            "Response contains {} superfunds",
+            response.super_funds.len()
+        );
+        Ok(response.super_funds)
+    }
+
+    /// Get a single superfund by ID
+    pub async fn get(client: &crate::client::Client, super_fund_id: Uuid) -> Result<Superfund> {
+        info!("Getting superfund with ID: {}", super_fund_id);
+
+        let url = format!("{ENDPOINT}/{super_fund_id}");
+        debug!("GET URL: {}", url);
+
+        let response: SuperfundResponse = client.get(&url, &()).await?;
+        response
+            .super_funds
+            .into_iter()
+            .next()
+            .ok_or_else(|| not_found(url))
+    }
+
+    /// Create a superfund
+    pub async fn create(
+        client: &crate::client::Client,
+        superfund: &PostSuperfund,
+    ) -> Result<Superfund> {
+        info!("Creating superfund");
+        // SMSF records hold the fund's bank account, so only log the name
+        debug!(
+            "Superfund name: {}",
+            superfund.name.as_deref().unwrap_or_default()
+        );
+
+        let request = vec![superfund.clone()];
+        let response: SuperfundResponse = client.post(ENDPOINT, &request).await?;
+        response
+            .super_funds
+            .into_iter()
+            .next()
+            .ok_or_else(|| not_found(ENDPOINT.to_string()))
+    }
+
+    /// Update a superfund
+    pub async fn update(
+        client: &crate::client::Client,
+        super_fund_id: Uuid,
+        superfund: &PostSuperfund,
+    ) -> Result<Superfund> {
+        info!("Updating superfund with ID: {}", super_fund_id);
+        debug!(
+            "Superfund name: {}",
+            superfund.name.as_deref().unwrap_or_default()
+        );
+
+        let url = format!("{ENDPOINT}/{super_fund_id}");
+        debug!("POST URL: {}", url);
+
+        let mut superfund = superfund.clone();
+        superfund.super_fund_id = Some(super_fund_id);
+        let request = vec![superfund];
+        let response: SuperfundResponse = client.post(&url, &request).await?;
+        response
+            .super_funds
+            .into_iter()
+            .next()
+            .ok_or_else(|| not_found(url))
+    }
+}
+
+impl SuperfundProduct {
+    /// Look up regulated superfund products by ABN or USI
+    pub async fn lookup(
+        client: &crate::client::Client,
+        parameters: &ProductParameters,
+    ) -> Result<Vec<SuperfundProduct>> {
+        info!("Looking up superfund products: {:?}", parameters);
+
+        let response: SuperfundProductResponse = client.get(PRODUCTS_ENDPOINT, parameters).await?;
+        Ok(response.super_fund_products)
+    }
+}
+
+/// The employee's super memberships whose fund is not among `funds`
+///
+/// Pay runs fail to post when a membership refers to a fund that has been removed, so this
+/// is worth checking before creating one.
+#[must_use]
+pub fn unknown_memberships<'a>(
+    employee: &'a Employee,
+    funds: &[Superfund],
+) -> Vec<&'a SuperMembership> {
+    employee
+        .super_memberships
+        .iter()
+        .filter(|membership| {
+            !funds
+                .iter()
+                .any(|fund| fund.super_fund_id == membership.super_fund_id)
+        })
+        .collect()
+}
+
+/// Error for a response that unexpectedly contains no superfunds
+fn not_found(url: String) -> crate::error::Error {
+    error!("Received empty superfunds array in response");
+    crate::error::Error::NotFound {
+        entity: "Superfund".to_string(),
+        url,
+        status_code: reqwest::StatusCode::NOT_FOUND,
+        response_body: Some("No superfund returned in response".to_string()),
+        span_trace: SpanTrace::capture(),
+    }
+}
@@ -0,0 +1,416 @@
+//! Employees API for Xero Payroll UK
+//!
+//! Covers the employee record itself, the employment details that put an employee on a
+//! payroll calendar, and the employee's tax details.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use time::macros::date;
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::uk::employee::{Address, Employment, Gender, NiCategory, PostEmployee};
+//!
+//! # async fn example(calendar_id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! let address = Address::new("171 Midsummer Boulevard", "Milton Keynes", "MK9 1EB");
+//! let employee = PostEmployee::new("Ms", "Jane", "Smith", date!(1990 - 04 - 12), Gender::Female, address);
+//! let employee = client.payroll_uk().employees().create(&employee).await?;
+//!
+//! let employment = Employment::new(calendar_id, date!(2024 - 04 - 06), "E001", NiCategory::A);
+//! client
+//!     .payroll_uk()
+//!     .employees()
+//!     .create_employment(employee.employee_id, &employment)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::{Pagination, list_all, not_found};
+use crate::{
+    error::Result,
+    utils::date_format::{xero_date_format, xero_date_format_option, xero_datetime_format_option},
+};
+
+/// Base endpoint for UK employees
+pub const ENDPOINT: &str = "https://api.xero.com/payroll.xro/2.0/Employees";
+
+/// Gender of an employee, as reported to HMRC
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum Gender {
+    #[serde(rename = "M")]
+    Male,
+    #[serde(rename = "F")]
+    Female,
+}
+
+/// National Insurance category letter
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum NiCategory {
+    A,
+    B,
+    C,
+    F,
+    H,
+    I,
+    J,
+    L,
+    M,
+    S,
+    V,
+    X,
+    Z,
+}
+
+/// Home address of a UK employee
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Address {
+    pub address_line1: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address_line2: Option<String>,
+    pub city: String,
+    pub post_code: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_name: Option<String>,
+}
+
+impl Address {
+    /// An address with the fields the API requires
+    #[must_use]
+    pub fn new(
+        address_line1: impl Into<String>,
+        city: impl Into<String>,
+        post_code: impl Into<String>,
+    ) -> Self {
+        Self {
+            address_line1: address_line1.into(),
+            address_line2: None,
+            city: city.into(),
+            post_code: post_code.into(),
+            country_name: None,
+        }
+    }
+}
+
+/// An employee in Xero Payroll UK
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Employee {
+    #[serde(rename = "employeeID")]
+    pub employee_id: Uuid,
+    #[serde(default)]
+    pub title: Option<String>,
+    pub first_name: String,
+    pub last_name: String,
+    #[serde(default, with = "xero_date_format_option")]
+    pub date_of_birth: Option<Date>,
+    #[serde(default)]
+    pub address: Option<Address>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub gender: Option<Gender>,
+    #[serde(default)]
+    pub phone_number: Option<String>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub start_date: Option<Date>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub end_date: Option<Date>,
+    #[serde(rename = "payrollCalendarID", default)]
+    pub payroll_calendar_id: Option<Uuid>,
+    #[serde(
+        rename = "updatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+    #[serde(
+        rename = "createdDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub created_date_utc: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub national_insurance_number: Option<String>,
+    #[serde(default)]
+    pub is_off_payroll_worker: Option<bool>,
+}
+
+/// Request structure for creating or updating a UK employee
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostEmployee {
+    pub title: String,
+    pub first_name: String,
+    pub last_name: String,
+    #[serde(with = "xero_date_format")]
+    pub date_of_birth: Date,
+    pub gender: Gender,
+    pub address: Address,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub national_insurance_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_off_payroll_worker: Option<bool>,
+}
+
+impl PostEmployee {
+    /// An employee with the fields the API requires
+    #[must_use]
+    pub fn new(
+        title: impl Into<String>,
+        first_name: impl Into<String>,
+        last_name: impl Into<String>,
+        date_of_birth: Date,
+        gender: Gender,
+        address: Address,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            first_name: first_name.into(),
+            last_name: last_name.into(),
+            date_of_birth,
+            gender,
+            address,
+            email: None,
+            phone_number: None,
+            national_insurance_number: None,
+            is_off_payroll_worker: None,
+        }
+    }
+
+    /// Set the employee's email address
+    #[must_use]
+    pub fn with_email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    /// Set the employee's phone number
+    #[must_use]
+    pub fn with_phone_number(mut self, phone_number: impl Into<String>) -> Self {
+        self.phone_number = Some(phone_number.into());
+        self
+    }
+
+    /// Set the employee's National Insurance number
+    #[must_use]
+    pub fn with_national_insurance_number(mut self, number: impl Into<String>) -> Self {
+        self.national_insurance_number = Some(number.into());
+        self
+    }
+
+    /// Mark the employee as an off-payroll worker (IR35)
+    #[must_use]
+    pub fn with_off_payroll_worker(mut self, is_off_payroll_worker: bool) -> Self {
+        self.is_off_payroll_worker = Some(is_off_payroll_worker);
+        self
+    }
+}
+
+/// Employment details that place an employee on a payroll calendar
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Employment {
+    #[serde(rename = "payrollCalendarID")]
+    pub payroll_calendar_id: Uuid,
+    #[serde(with = "xero_date_format")]
+    pub start_date: Date,
+    pub employee_number: String,
+    pub ni_category: NiCategory,
+}
+
+impl Employment {
+    /// Employment starting on `start_date` on the given payroll calendar
+    #[must_use]
+    pub fn new(
+        payroll_calendar_id: Uuid,
+        start_date: Date,
+        employee_number: impl Into<String>,
+        ni_category: NiCategory,
+    ) -> Self {
+        Self {
+            payroll_calendar_id,
+            start_date,
+            employee_number: employee_number.into(),
+            ni_category,
+        }
+    }
+}
+
+/// An employee's tax details
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployeeTax {
+    #[serde(default)]
+    pub starter_type: Option<String>,
+    #[serde(default)]
+    pub starter_declaration: Option<String>,
+    #[serde(default)]
+    pub tax_code: Option<String>,
+    /// Whether the tax code is applied on a week 1 / month 1 basis
+    #[serde(rename = "w1M1", default)]
+    pub w1_m1: Option<bool>,
+    #[serde(default)]
+    pub previous_taxable_pay: Option<Decimal>,
+    #[serde(default)]
+    pub previous_tax_paid: Option<Decimal>,
+    #[serde(default)]
+    pub student_loan_deduction: Option<String>,
+    #[serde(default)]
+    pub has_post_graduate_loans: Option<bool>,
+    #[serde(default)]
+    pub is_director: Option<bool>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub directorship_start_date: Option<Date>,
+    #[serde(default)]
+    pub nic_calculation_method: Option<String>,
+}
+
+/// Response wrapper for UK employee lists
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployeesResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub employees: Vec<Employee>,
+}
+
+/// Response wrapper for a single UK employee
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployeeResponse {
+    #[serde(default)]
+    pub employee: Option<Employee>,
+}
+
+/// Response wrapper for employment details
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmploymentResponse {
+    #[serde(default)]
+    pub employment: Option<Employment>,
+}
+
+/// Response wrapper for an employee's tax details
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployeeTaxResponse {
+    #[serde(default)]
+    pub employee_tax: Option<EmployeeTax>,
+}
+
+impl Employee {
+    /// List all employees, fetching every page
+    pub async fn list(client: &crate::client::Client) -> Result<Vec<Employee>> {
+        info!("Listing UK employees");
+
+        list_all(client, ENDPOINT, &(), |response: EmployeesResponse| {
+            (response.pagination, response.employees)
+        })
+        .await
+    }
+
+    /// Get a single employee by ID
+    pub async fn get(client: &crate::client::Client, employee_id: Uuid) -> Result<Employee> {
+        info!("Getting UK employee with ID: {}", employee_id);
+
+        let url = format!("{ENDPOINT}/{employee_id}");
+        debug!("GET URL: {}", url);
+
+        let response: EmployeeResponse = client.get(&url, &()).await?;
+        response.employee.ok_or_else(|| not_found("Employee", url))
+    }
+
+    /// Create an employee
+    pub async fn create(
+        client: &crate::client::Client,
+        employee: &PostEmployee,
+    ) -> Result<Employee> {
+        info!("Creating UK employee");
+        // The full record holds the national insurance number, so only log the name
+        debug!(
+            "Employee name: {} {}",
+            employee.first_name, employee.last_name
+        );
+
+        let response: EmployeeResponse = client.post(ENDPOINT, employee).await?;
+        response
+            .employee
+            .ok_or_else(|| not_found("Employee", ENDPOINT.to_string()))
+    }
+
+    /// Update an employee
+    pub async fn update(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        employee: &PostEmployee,
+    ) -> Result<Employee> {
+        info!("Updating UK employee with ID: {}", employee_id);
+        debug!(
+            "Employee name: {} {}",
+            employee.first_name, employee.last_name
+        );
+
+        let url = format!("{ENDPOINT}/{employee_id}");
+        debug!("PUT URL: {}", url);
+
+        let response: EmployeeResponse = client.put(&url, employee).await?;
+        response.employee.ok_or_else(|| not_found("Employee", url))
+    }
+}
+
+impl Employment {
+    /// Create the employment details for an employee
+    pub async fn create(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        employment: &Employment,
+    ) -> Result<Employment> {
+        info!(
+            "Creating employment for UK employee with ID: {}",
+            employee_id
+        );
+        // Employment details include the NI category, so only log the calendar
+        debug!("Payroll calendar ID: {}", employment.payroll_calendar_id);
+
+        let url = format!("{ENDPOINT}/{employee_id}/Employment");
+        debug!("POST URL: {}", url);
+
+        let response: EmploymentResponse = client.post(&url, employment).await?;
+        response
+            .employment
+            .ok_or_else(|| not_found("Employment", url))
+    }
+}
+
+impl EmployeeTax {
+    /// Get the tax details of an employee
+    pub async fn get(client: &crate::client::Client, employee_id: Uuid) -> Result<EmployeeTax> {
+        info!(
+            "Getting tax details for UK employee with ID: {}",
+            employee_id
+        );
+
+        let url = format!("{ENDPOINT}/{employee_id}/Tax");
+        debug!("GET URL: {}", url);
+
+        let response: EmployeeTaxResponse = client.get(&url, &()).await?;
+        response
+            .employee_tax
+            .ok_or_else(|| not_found("EmployeeTax", url))
+    }
+}
@@ -0,0 +1,301 @@
+//! Employee Leave API for Xero Payroll UK
+//!
+//! Leave is recorded against an employee for a date range and split by Xero into periods
+//! that line up with the employee's pay periods. Leave balances report what each leave type
+//! has left to take.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use time::macros::date;
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::uk::leave::PostLeave;
+//!
+//! # async fn example(employee_id: uuid::Uuid, leave_type_id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! let leave = PostLeave::new(leave_type_id, "Holiday", date!(2024 - 08 - 05), date!(2024 - 08 - 09));
+//! let leave = client.payroll_uk().employees().create_leave(employee_id, &leave).await?;
+//! for period in &leave.periods {
+//!     println!("{} units from {}", period.number_of_units, period.period_start_date);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::{Pagination, employee::ENDPOINT, not_found};
+use crate::{
+    error::Result,
+    utils::date_format::{xero_date_format, xero_datetime_format_option},
+};
+
+/// Status of a leave period
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum LeavePeriodStatus {
+    /// The leave has been approved but not yet paid
+    Approved,
+    /// The leave has been paid in a posted pay run
+    Completed,
+}
+
+/// The part of a leave request falling in one pay period
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeavePeriod {
+    #[serde(with = "xero_date_format")]
+    pub period_start_date: Date,
+    #[serde(with = "xero_date_format")]
+    pub period_end_date: Date,
+    #[serde(default)]
+    pub number_of_units: Decimal,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub period_status: Option<LeavePeriodStatus>,
+}
+
+/// Leave taken or planned by a UK employee
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployeeLeave {
+    #[serde(rename = "leaveID")]
+    pub leave_id: Uuid,
+    #[serde(rename = "leaveTypeID")]
+    pub leave_type_id: Uuid,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(with = "xero_date_format")]
+    pub start_date: Date,
+    #[serde(with = "xero_date_format")]
+    pub end_date: Date,
+    #[serde(default)]
+    pub periods: Vec<LeavePeriod>,
+    #[serde(
+        rename = "updatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+}
+
+/// Request structure for creating or updating employee leave
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostLeave {
+    #[serde(rename = "leaveTypeID")]
+    pub leave_type_id: Uuid,
+    pub description: String,
+    #[serde(with = "xero_date_format")]
+    pub start_date: Date,
+    #[serde(with = "xero_date_format")]
+    pub end_date: Date,
+    /// Explicit periods; when empty Xero works them out from the employee's calendar
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub periods: Vec<LeavePeriod>,
+}
+
+impl PostLeave {
+    /// Leave of the given type between two dates, inclusive
+    #[must_use]
+    pub fn new(
+        leave_type_id: Uuid,
+        description: impl Into<String>,
+        start_date: Date,
+        end_date: Date,
+    ) -> Self {
+        Self {
+            leave_type_id,
+            description: description.into(),
+            start_date,
+            end_date,
+            periods: Vec::new(),
+        }
+    }
+
+    /// Set the periods the leave is split into
+    #[must_use]
+    pub fn with_periods(mut self, periods: Vec<LeavePeriod>) -> Self {
+        self.periods = periods;
+        self
+    }
+}
+
+/// Remaining balance of one leave type for an employee
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveBalance {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(rename = "leaveTypeID")]
+    pub leave_type_id: Uuid,
+    #[serde(default)]
+    pub balance: Decimal,
+    #[serde(default)]
+    pub type_of_units: Option<String>,
+}
+
+/// Response wrapper for employee leave, holding either a list or a single record
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub leave: LeaveRecords,
+}
+
+/// The `leave` field is an array when listing and an object for a single record
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum LeaveRecords {
+    Many(Vec<EmployeeLeave>),
+    One(Box<EmployeeLeave>),
+}
+
+impl Default for LeaveRecords {
+    fn default() -> Self {
+        Self::Many(Vec::new())
+    }
+}
+
+impl LeaveRecords {
+    /// All of the leave records
+    #[must_use]
+    pub fn into_vec(self) -> Vec<EmployeeLeave> {
+        match self {
+            Self::Many(leave) => leave,
+            Self::One(leave) => vec![*leave],
+        }
+    }
+
+    /// The first leave record, if any
+    #[must_use]
+    pub fn into_first(self) -> Option<EmployeeLeave> {
+        self.into_vec().into_iter().next()
+    }
+}
+
+/// Response wrapper for employee leave balances
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveBalancesResponse {
+    #[serde(default)]
+    pub leave_balances: Vec<LeaveBalance>,
+}
+
+impl EmployeeLeave {
+    /// List an employee's leave
+    pub async fn list(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+    ) -> Result<Vec<EmployeeLeave>> {
+        info!("Listing leave for UK employee with ID: {}", employee_id);
+
+        let url = format!("{ENDPOINT}/{employee_id}/Leave");
+        let response: LeaveResponse = client.get(&url, &()).await?;
+        let leave = response.leave.into_vec();
+        debug!("Response contains {} leave records", leave.len());
+        Ok(leave)
+    }
+
+    /// Get a single leave record
+    pub async fn get(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        leave_id: Uuid,
+    ) -> Result<EmployeeLeave> {
+        info!("Getting leave {} for UK employee {}", leave_id, employee_id);
+
+        let url = format!("{ENDPOINT}/{employee_id}/Leave/{leave_id}");
+        debug!("GET URL: {}", url);
+
+        let response: LeaveResponse = client.get(&url, &()).await?;
+        response
+            .leave
+            .into_first()
+            .ok_or_else(|| not_found("EmployeeLeave", url))
+    }
+
+    /// Record leave for an employee
+    pub async fn create(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        leave: &PostLeave,
+    ) -> Result<EmployeeLeave> {
+        info!("Creating leave for UK employee with ID: {}", employee_id);
+        debug!("Leave type ID: {}", leave.leave_type_id);
+
+        let url = format!("{ENDPOINT}/{employee_id}/Leave");
+        let response: LeaveResponse = client.post(&url, leave).await?;
+        response
+            .leave
+            .into_first()
+            .ok_or_else(|| not_found("EmployeeLeave", url))
+    }
+
+    /// Update a leave record
+    pub async fn update(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        leave_id: Uuid,
+        leave: &PostLeave,
+    ) -> Result<EmployeeLeave> {
+        info!(
+            "Updating leave {} for UK employee {}",
+            leave_id, employee_id
+        );
+        debug!("Leave type ID: {}", leave.leave_type_id);
+
+        let url = format!("{ENDPOINT}/{employee_id}/Leave/{leave_id}");
+        debug!("PUT URL: {}", url);
+
+        let response: LeaveResponse = client.put(&url, leave).await?;
+        response
+            .leave
+            .into_first()
+            .ok_or_else(|| not_found("EmployeeLeave", url))
+    }
+
+    /// Delete a leave record
+    pub async fn delete(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        leave_id: Uuid,
+    ) -> Result<()> {
+        info!(
+            "Deleting leave {} for UK employee {}",
+            leave_id, employee_id
+        );
+
+        let url = format!("{ENDPOINT}/{employee_id}/Leave/{leave_id}");
+        debug!("DELETE URL: {}", url);
+
+        client.delete(&url).await
+    }
+}
+
+impl LeaveBalance {
+    /// List an employee's leave balances
+    pub async fn list(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+    ) -> Result<Vec<LeaveBalance>> {
+        info!(
+            "Listing leave balances for UK employee with ID: {}",
+            employee_id
+        );
+
+        let url = format!("{ENDPOINT}/{employee_id}/LeaveBalances");
+        let response: LeaveBalancesResponse = client.get(&url, &()).await?;
+        debug!(
+            "Response contains {} leave balances",
+            response.leave_balances.len()
+        );
+        Ok(response.leave_balances)
+    }
+}
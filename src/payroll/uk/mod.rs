@@ -0,0 +1,43 @@
+//! Xero Payroll UK
+//!
+//! The UK payroll API is version 2.0 of Xero's payroll API. Unlike AU payroll it uses
+//! camelCase JSON, pages its collections, and reports failures with a `problem` object,
+//! which the client surfaces as [`crate::error::Error::API`] with
+//! [`crate::error::ErrorType::PayrollProblem`].
+//!
+//! This module is only available with the `payroll-uk` feature.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xero_rs::{Client, KeyPair};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! for employee in client.payroll_uk().employees().list().await? {
+//!     println!("{} {}", employee.first_name, employee.last_name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod employee;
+pub mod leave;
+pub mod pay_items;
+pub mod pay_run;
+pub mod pay_template;
+pub mod payslip;
+pub mod statutory_leave;
+pub mod timesheet;
+
+pub use super::v2::{BASE_URL, Pagination};
+pub(crate) use super::v2::{Region, list_all, not_found};
+
+/// How the shared payroll 2.0 requests are made for the UK
+pub(crate) const REGION: Region = Region {
+    name: "UK",
+    earnings_template_path: "earnings",
+    payslips_endpoint: payslip::ENDPOINT,
+};
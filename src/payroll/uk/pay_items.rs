@@ -0,0 +1,719 @@
+//! Pay Items API for Xero Payroll UK
+//!
+//! Pay items are the organisation-wide settings employees are paid from: earnings rates,
+//! deductions, benefits, leave types and reimbursements. Unlike AU payroll, each kind has its
+//! own endpoint and is created one at a time.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::uk::pay_items::{EarningsRate, EarningsType, RateType};
+//!
+//! # async fn example(expense_account_id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! let rate = EarningsRate::new(
+//!     "Overtime",
+//!     EarningsType::OvertimeEarnings,
+//!     RateType::MultipleOfOrdinaryEarningsRate,
+//!     "Hours",
+//!     expense_account_id,
+//! );
+//! let rate = client.payroll_uk().pay_items().create_earnings_rate(&rate).await?;
+//! println!("Created earnings rate {:?}", rate.earnings_rate_id);
+//! # Ok(())
+//! # }
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::{BASE_URL, Pagination, list_all, not_found};
+use crate::{error::Result, utils::date_format::xero_datetime_format_option};
+
+/// Kind of earnings an earnings rate pays
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum EarningsType {
+    Allowance,
+    BackPay,
+    Bonus,
+    Commission,
+    LumpSum,
+    OtherEarnings,
+    OvertimeEarnings,
+    RegularEarnings,
+    StatutoryAdoptionPay,
+    StatutoryAdoptionPayNonPensionable,
+    StatutoryBereavementPay,
+    StatutoryMaternityPay,
+    StatutoryMaternityPayNonPensionable,
+    StatutoryPaternityPay,
+    StatutoryPaternityPayNonPensionable,
+    StatutoryParentalBereavementPayNonPensionable,
+    StatutorySharedParentalPay,
+    StatutorySharedParentalPayNonPensionable,
+    StatutorySickPay,
+    StatutorySickPayNonPensionable,
+    TipsNonDirect,
+    TipsDirect,
+    TerminationPay,
+    /// An earnings type this crate does not know about yet
+    #[serde(other)]
+    Other,
+}
+
+/// How an earnings rate is calculated
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum RateType {
+    RatePerUnit,
+    MultipleOfOrdinaryEarningsRate,
+    FixedAmount,
+}
+
+/// How a deduction or benefit amount is calculated
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum CalculationType {
+    FixedAmount,
+    PercentageOfGross,
+}
+
+/// Category of a deduction
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum DeductionCategory {
+    CapitalContributions,
+    ChildCareVoucher,
+    MakingGood,
+    PostgraduateLoanDeductions,
+    PrivateUsePayments,
+    SalarySacrifice,
+    StakeholderPension,
+    StakeholderPensionPostTax,
+    StudentLoanDeductions,
+    UkOther,
+    /// A deduction category this crate does not know about yet
+    #[serde(other)]
+    Other,
+}
+
+/// Category of a benefit
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum BenefitCategory {
+    StakeholderPension,
+    #[serde(other)]
+    Other,
+}
+
+/// An earnings rate in Xero Payroll UK
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarningsRate {
+    #[serde(
+        rename = "earningsRateID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub earnings_rate_id: Option<Uuid>,
+    pub name: String,
+    pub earnings_type: EarningsType,
+    pub rate_type: RateType,
+    pub type_of_units: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_record: Option<bool>,
+    #[serde(rename = "expenseAccountID")]
+    pub expense_account_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_per_unit: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multiple_of_ordinary_earnings_rate: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixed_amount: Option<Decimal>,
+}
+
+impl EarningsRate {
+    /// An earnings rate with the fields the API requires
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        earnings_type: EarningsType,
+        rate_type: RateType,
+        type_of_units: impl Into<String>,
+        expense_account_id: Uuid,
+    ) -> Self {
+        Self {
+            earnings_rate_id: None,
+            name: name.into(),
+            earnings_type,
+            rate_type,
+            type_of_units: type_of_units.into(),
+            current_record: None,
+            expense_account_id,
+            rate_per_unit: None,
+            multiple_of_ordinary_earnings_rate: None,
+            fixed_amount: None,
+        }
+    }
+
+    /// Set the rate paid per unit
+    #[must_use]
+    pub fn with_rate_per_unit(mut self, rate_per_unit: Decimal) -> Self {
+        self.rate_per_unit = Some(rate_per_unit);
+        self
+    }
+
+    /// Set the multiple of the employee's ordinary earnings rate, e.g. 1.5 for time and a half
+    #[must_use]
+    pub fn with_multiple_of_ordinary_earnings_rate(mut self, multiple: Decimal) -> Self {
+        self.multiple_of_ordinary_earnings_rate = Some(multiple);
+        self
+    }
+
+    /// Set the fixed amount paid
+    #[must_use]
+    pub fn with_fixed_amount(mut self, fixed_amount: Decimal) -> Self {
+        self.fixed_amount = Some(fixed_amount);
+        self
+    }
+}
+
+/// A deduction in Xero Payroll UK
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Deduction {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deduction_id: Option<Uuid>,
+    pub deduction_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deduction_category: Option<DeductionCategory>,
+    pub liability_account_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_record: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub standard_amount: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reduces_super_liability: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reduces_tax_liability: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calculation_type: Option<CalculationType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub percentage: Option<Decimal>,
+    #[serde(
+        rename = "subjectToNIC",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub subject_to_nic: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_to_tax: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_reduced_by_basic_rate: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apply_to_pension_calculations: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_calculating_on_qualifying_earnings: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_pension: Option<bool>,
+}
+
+impl Deduction {
+    /// A deduction with the fields the API requires
+    #[must_use]
+    pub fn new(deduction_name: impl Into<String>, liability_account_id: Uuid) -> Self {
+        Self {
+            deduction_id: None,
+            deduction_name: deduction_name.into(),
+            deduction_category: None,
+            liability_account_id,
+            current_record: None,
+            standard_amount: None,
+            reduces_super_liability: None,
+            reduces_tax_liability: None,
+            calculation_type: None,
+            percentage: None,
+            subject_to_nic: None,
+            subject_to_tax: None,
+            is_reduced_by_basic_rate: None,
+            apply_to_pension_calculations: None,
+            is_calculating_on_qualifying_earnings: None,
+            is_pension: None,
+        }
+    }
+
+    /// Set the deduction category
+    #[must_use]
+    pub fn with_category(mut self, category: DeductionCategory) -> Self {
+        self.deduction_category = Some(category);
+        self
+    }
+
+    /// Deduct a fixed amount each pay period
+    #[must_use]
+    pub fn with_standard_amount(mut self, standard_amount: Decimal) -> Self {
+        self.calculation_type = Some(CalculationType::FixedAmount);
+        self.standard_amount = Some(standard_amount);
+        self
+    }
+
+    /// Deduct a percentage of gross earnings each pay period
+    #[must_use]
+    pub fn with_percentage(mut self, percentage: Decimal) -> Self {
+        self.calculation_type = Some(CalculationType::PercentageOfGross);
+        self.percentage = Some(percentage);
+        self
+    }
+}
+
+/// A benefit in Xero Payroll UK
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Benefit {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Uuid>,
+    pub name: String,
+    pub category: BenefitCategory,
+    pub liability_account_id: Uuid,
+    pub expense_account_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub standard_amount: Option<Decimal>,
+    #[serde(default)]
+    pub percentage: Decimal,
+    pub calculation_type: CalculationType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_record: Option<bool>,
+    #[serde(
+        rename = "subjectToNIC",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub subject_to_nic: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_to_pension: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject_to_tax: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_calculating_on_qualifying_earnings: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_balance_to_employee: Option<bool>,
+}
+
+impl Benefit {
+    /// A benefit calculated as a percentage of gross earnings
+    #[must_use]
+    pub fn percentage_of_gross(
+        name: impl Into<String>,
+        category: BenefitCategory,
+        liability_account_id: Uuid,
+        expense_account_id: Uuid,
+        percentage: Decimal,
+    ) -> Self {
+        Self {
+            id: None,
+            name: name.into(),
+            category,
+            liability_account_id,
+            expense_account_id,
+            standard_amount: None,
+            percentage,
+            calculation_type: CalculationType::PercentageOfGross,
+            current_record: None,
+            subject_to_nic: None,
+            subject_to_pension: None,
+            subject_to_tax: None,
+            is_calculating_on_qualifying_earnings: None,
+            show_balance_to_employee: None,
+        }
+    }
+
+    /// A benefit of a fixed amount each pay period
+    #[must_use]
+    pub fn fixed_amount(
+        name: impl Into<String>,
+        category: BenefitCategory,
+        liability_account_id: Uuid,
+        expense_account_id: Uuid,
+        standard_amount: Decimal,
+    ) -> Self {
+        Self {
+            standard_amount: Some(standard_amount),
+            calculation_type: CalculationType::FixedAmount,
+            ..Self::percentage_of_gross(
+                name,
+                category,
+                liability_account_id,
+                expense_account_id,
+                Decimal::ZERO,
+            )
+        }
+    }
+}
+
+/// A leave type in Xero Payroll UK
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveType {
+    #[serde(
+        rename = "leaveTypeID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub leave_type_id: Option<Uuid>,
+    pub name: String,
+    pub is_paid_leave: bool,
+    pub show_on_payslip: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_statutory_leave: Option<bool>,
+    #[serde(
+        rename = "updatedDateUTC",
+        default,
+        skip_serializing,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+}
+
+impl LeaveType {
+    /// A leave type with the fields the API requires
+    #[must_use]
+    pub fn new(name: impl Into<String>, is_paid_leave: bool, show_on_payslip: bool) -> Self {
+        Self {
+            leave_type_id: None,
+            name: name.into(),
+            is_paid_leave,
+            show_on_payslip,
+            is_active: None,
+            is_statutory_leave: None,
+            updated_date_utc: None,
+        }
+    }
+}
+
+/// A reimbursement in Xero Payroll UK
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reimbursement {
+    #[serde(
+        rename = "reimbursementID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub reimbursement_id: Option<Uuid>,
+    pub name: String,
+    #[serde(rename = "accountID")]
+    pub account_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_record: Option<bool>,
+}
+
+impl Reimbursement {
+    /// A reimbursement paid from the given account
+    #[must_use]
+    pub fn new(name: impl Into<String>, account_id: Uuid) -> Self {
+        Self {
+            reimbursement_id: None,
+            name: name.into(),
+            account_id,
+            current_record: None,
+        }
+    }
+}
+
+/// Response wrapper for earnings rates
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarningsRatesResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub earnings_rates: Vec<EarningsRate>,
+}
+
+/// Response wrapper for a single earnings rate
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarningsRateResponse {
+    #[serde(default)]
+    pub earnings_rate: Option<EarningsRate>,
+}
+
+/// Response wrapper for deductions
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeductionsResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub deductions: Vec<Deduction>,
+}
+
+/// Response wrapper for a single deduction
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeductionResponse {
+    #[serde(default)]
+    pub deduction: Option<Deduction>,
+}
+
+/// Response wrapper for benefits
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenefitsResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub benefits: Vec<Benefit>,
+}
+
+/// Response wrapper for a single benefit
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenefitResponse {
+    #[serde(default)]
+    pub benefit: Option<Benefit>,
+}
+
+/// Response wrapper for leave types
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveTypesResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub leave_types: Vec<LeaveType>,
+}
+
+/// Response wrapper for a single leave type
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveTypeResponse {
+    #[serde(default)]
+    pub leave_type: Option<LeaveType>,
+}
+
+/// Response wrapper for reimbursements
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReimbursementsResponse {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default)]
+    pub reimbursements: Vec<Reimbursement>,
+}
+
+/// Response wrapper for a single reimbursement
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReimbursementResponse {
+    #[serde(default)]
+    pub reimbursement: Option<Reimbursement>,
+}
+
+impl EarningsRate {
+    /// List all earnings rates, fetching every page
+    pub async fn list(client: &crate::client::Client) -> Result<Vec<EarningsRate>> {
+        info!("Listing UK earnings rates");
+
+        let url = format!("{BASE_URL}/EarningsRates");
+        list_all(client, &url, &(), |response: EarningsRatesResponse| {
+            (response.pagination, response.earnings_rates)
+        })
+        .await
+    }
+
+    /// Get a single earnings rate by ID
+    pub async fn get(
+        client: &crate::client::Client,
+        earnings_rate_id: Uuid,
+    ) -> Result<EarningsRate> {
+        info!("Getting UK earnings rate with ID: {}", earnings_rate_id);
+
+        let url = format!("{BASE_URL}/EarningsRates/{earnings_rate_id}");
+        debug!("GET URL: {}", url);
+
+        let response: EarningsRateResponse = client.get(&url, &()).await?;
+        response
+            .earnings_rate
+            .ok_or_else(|| not_found("EarningsRate", url))
+    }
+
+    /// Create an earnings rate
+    pub async fn create(
+        client: &crate::client::Client,
+        earnings_rate: &EarningsRate,
+    ) -> Result<EarningsRate> {
+        info!("Creating UK earnings rate");
+        debug!("Earnings rate data: {:?}", earnings_rate);
+
+        let url = format!("{BASE_URL}/EarningsRates");
+        let response: EarningsRateResponse = client.post(&url, earnings_rate).await?;
+        response
+            .earnings_rate
+            .ok_or_else(|| not_found("EarningsRate", url))
+    }
+}
+
+impl Deduction {
+    /// List all deductions, fetching every page
+    pub async fn list(client: &crate::client::Client) -> Result<Vec<Deduction>> {
+        info!("Listing UK deductions");
+
+        let url = format!("{BASE_URL}/Deductions");
+        list_all(client, &url, &(), |response: DeductionsResponse| {
+            (response.pagination, response.deductions)
+        })
+        .await
+    }
+
+    /// Get a single deduction by ID
+    pub async fn get(client: &crate::client::Client, deduction_id: Uuid) -> Result<Deduction> {
+        info!("Getting UK deduction with ID: {}", deduction_id);
+
+        let url = format!("{BASE_URL}/Deductions/{deduction_id}");
+        debug!("GET URL: {}", url);
+
+        let response: DeductionResponse = client.get(&url, &()).await?;
+        response
+            .deduction
+            .ok_or_else(|| not_found("Deduction", url))
+    }
+
+    /// Create a deduction
+    pub async fn create(
+        client: &crate::client::Client,
+        deduction: &Deduction,
+    ) -> Result<Deduction> {
+        info!("Creating UK deduction");
+        debug!("Deduction data: {:?}", deduction);
+
+        let url = format!("{BASE_URL}/Deductions");
+        let response: DeductionResponse = client.post(&url, deduction).await?;
+        response
+            .deduction
+            .ok_or_else(|| not_found("Deduction", url))
+    }
+}
+
+impl Benefit {
+    /// List all benefits, fetching every page
+    pub async fn list(client: &crate::client::Client) -> Result<Vec<Benefit>> {
+        info!("Listing UK benefits");
+
+        let url = format!("{BASE_URL}/Benefits");
+        list_all(client, &url, &(), |response: BenefitsResponse| {
+            (response.pagination, response.benefits)
+        })
+        .await
+    }
+
+    /// Get a single benefit by ID
+    pub async fn get(client: &crate::client::Client, benefit_id: Uuid) -> Result<Benefit> {
+        info!("Getting UK benefit with ID: {}", benefit_id);
+
+        let url = format!("{BASE_URL}/Benefits/{benefit_id}");
+        debug!("GET URL: {}", url);
+
+        let response: BenefitResponse = client.get(&url, &()).await?;
+        response.benefit.ok_or_else(|| not_found("Benefit", url))
+    }
+
+    /// Create a benefit
+    pub async fn create(client: &crate::client::Client, benefit: &Benefit) -> Result<Benefit> {
+        info!("Creating UK benefit");
+        debug!("Benefit data: {:?}", benefit);
+
+        let url = format!("{BASE_URL}/Benefits");
+        let response: BenefitResponse = client.post(&url, benefit).await?;
+        response.benefit.ok_or_else(|| not_found("Benefit", url))
+    }
+}
+
+impl LeaveType {
+    /// List all leave types, fetching every page
+    pub async fn list(client: &crate::client::Client) -> Result<Vec<LeaveType>> {
+        info!("Listing UK leave types");
+
+        let url = format!("{BASE_URL}/LeaveTypes");
+        list_all(client, &url, &(), |response: LeaveTypesResponse| {
+            (response.pagination, response.leave_types)
+        })
+        .await
+    }
+
+    /// Get a single leave type by ID
+    pub async fn get(client: &crate::client::Client, leave_type_id: Uuid) -> Result<LeaveType> {
+        info!("Getting UK leave type with ID: {}", leave_type_id);
+
+        let url = format!("{BASE_URL}/LeaveTypes/{leave_type_id}");
+        debug!("GET URL: {}", url);
+
+        let response: LeaveTypeResponse = client.get(&url, &()).await?;
+        response
+            .leave_type
+            .ok_or_else(|| not_found("LeaveType", url))
+    }
+
+    /// Create a leave type
+    pub async fn create(
+        client: &crate::client::Client,
+        leave_type: &LeaveType,
+    ) -> Result<LeaveType> {
+        info!("Creating UK leave type");
+        debug!("Leave type data: {:?}", leave_type);
+
+        let url = format!("{BASE_URL}/LeaveTypes");
+        let response: LeaveTypeResponse = client.post(&url, leave_type).await?;
+        response
+            .leave_type
+            .ok_or_else(|| not_found("LeaveType", url))
+    }
+}
+
+impl Reimbursement {
+    /// List all reimbursements, fetching every page
+    pub async fn list(client: &crate::client::Client) -> Result<Vec<Reimbursement>> {
+        info!("Listing UK reimbursements");
+
+        let url = format!("{BASE_URL}/Reimbursements");
+        list_all(client, &url, &(), |response: ReimbursementsResponse| {
+            (response.pagination, response.reimbursements)
+        })
+        .await
+    }
+
+    /// Get a single reimbursement by ID
+    pub async fn get(
+        client: &crate::client::Client,
+        reimbursement_id: Uuid,
+    ) -> Result<Reimbursement> {
+        info!("Getting UK reimbursement with ID: {}", reimbursement_id);
+
+        let url = format!("{BASE_URL}/Reimbursements/{reimbursement_id}");
+        debug!("GET URL: {}", url);
+
+        let response: ReimbursementResponse = client.get(&url, &()).await?;
+        response
+            .reimbursement
+            .ok_or_else(|| not_found("Reimbursement", url))
+    }
+
+    /// Create a reimbursement
+    pub async fn create(
+        client: &crate::client::Client,
+        reimbursement: &Reimbursement,
+    ) -> Result<Reimbursement> {
+        info!("Creating UK reimbursement");
+        debug!("Reimbursement data: {:?}", reimbursement);
+
+        let url = format!("{BASE_URL}/Reimbursements");
+        let response: ReimbursementResponse = client.post(&url, reimbursement).await?;
+        response
+            .reimbursement
+            .ok_or_else(|| not_found("Reimbursement", url))
+    }
+}
@@ -0,0 +1,121 @@
+//! Pay Runs API for Xero Payroll UK
+//!
+//! UK pay runs are created in Xero itself; the API reads them and updates draft pay runs,
+//! for example to change the payment date. Each pay run carries the payslips of its
+//! employees (see [`super::payslip`]).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::uk::pay_run::PayRunStatus;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! for pay_run in client.payroll_uk().pay_runs().list(Some(PayRunStatus::Draft)).await? {
+//!     println!("{:?}: {:?}", pay_run.payment_date, pay_run.total_pay);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::{Date, OffsetDateTime};
+use uuid::Uuid;
+
+use super::payslip::Payslip;
+use crate::{
+    payroll::v2::pay_run,
+    utils::date_format::{xero_date_format_option, xero_datetime_format_option},
+};
+
+pub use pay_run::{ENDPOINT, PayRunStatus};
+
+/// Kind of UK pay run
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PayRunType {
+    Scheduled,
+    Unscheduled,
+    /// A correction to a previous tax year reported to HMRC
+    EarlierYearUpdate,
+}
+
+/// Pay frequency of the calendar a pay run belongs to
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum CalendarType {
+    Weekly,
+    Fortnightly,
+    FourWeekly,
+    Monthly,
+    Annual,
+    Quarterly,
+}
+
+/// A pay run in Xero Payroll UK
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayRun {
+    #[serde(rename = "payRunID")]
+    pub pay_run_id: Uuid,
+    #[serde(rename = "payrollCalendarID", default)]
+    pub payroll_calendar_id: Option<Uuid>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub period_start_date: Option<Date>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub period_end_date: Option<Date>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub payment_date: Option<Date>,
+    #[serde(default)]
+    pub total_cost: Option<Decimal>,
+    #[serde(default)]
+    pub total_pay: Option<Decimal>,
+    #[serde(default)]
+    pub pay_run_status: Option<PayRunStatus>,
+    #[serde(default)]
+    pub pay_run_type: Option<PayRunType>,
+    #[serde(default)]
+    pub calendar_type: Option<CalendarType>,
+    #[serde(default, with = "xero_datetime_format_option")]
+    pub posted_date_time: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub pay_slips: Vec<Payslip>,
+}
+
+/// Request structure for updating a draft UK pay run
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostPayRun {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "xero_date_format_option"
+    )]
+    pub payment_date: Option<Date>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pay_run_status: Option<PayRunStatus>,
+}
+
+impl PostPayRun {
+    /// Set the date employees are paid
+    #[must_use]
+    pub fn with_payment_date(mut self, payment_date: Date) -> Self {
+        self.payment_date = Some(payment_date);
+        self
+    }
+
+    /// Set the pay run status
+    #[must_use]
+    pub fn with_status(mut self, status: PayRunStatus) -> Self {
+        self.pay_run_status = Some(status);
+        self
+    }
+}
+
+/// Response wrapper for UK pay runs
+pub type PayRunsResponse = pay_run::PayRunsResponse<PayRun>;
+
+/// Response wrapper for a single UK pay run
+pub type PayRunResponse = pay_run::PayRunResponse<PayRun>;
@@ -0,0 +1,30 @@
+//! Employee Pay Templates API for Xero Payroll UK
+//!
+//! A pay template holds the earnings an employee is paid every pay run. Each earnings line
+//! refers to an earnings rate (see [`super::pay_items`]) and overrides its rate, units or
+//! fixed amount for this employee.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use rust_decimal_macros::dec;
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::uk::pay_template::EarningsTemplate;
+//!
+//! # async fn example(employee_id: uuid::Uuid, earnings_rate_id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! let earnings = EarningsTemplate::rate_per_unit(earnings_rate_id, dec!(15.50), dec!(37.5));
+//! client
+//!     .payroll_uk()
+//!     .employees()
+//!     .create_earnings_template(employee_id, &earnings)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::payroll::v2::pay_template::{
+    EarningsTemplate, EarningsTemplateResponse, EmployeePayTemplate, PayTemplateResponse,
+};
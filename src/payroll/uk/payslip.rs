@@ -0,0 +1,176 @@
+//! Payslips API for Xero Payroll UK
+//!
+//! A payslip breaks one employee's pay in a pay run down into earnings, deductions,
+//! benefits, taxes and the payments made to the employee's bank accounts.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use xero_rs::{Client, KeyPair};
+//!
+//! # async fn example(pay_run_id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! for payslip in client.payroll_uk().pay_runs().payslips(pay_run_id).await? {
+//!     println!("{:?} {:?}: {:?}", payslip.first_name, payslip.last_name, payslip.total_pay);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+
+use crate::{payroll::v2::payslip, utils::date_format::xero_date_format_option};
+
+pub use payslip::{LeaveAccrualLine, PaymentLine, PaymentMethod, TaxLine};
+
+/// Base endpoint for UK payslips
+pub const ENDPOINT: &str = "https://api.xero.com/payroll.xro/2.0/Payslips";
+
+/// Earnings paid on a payslip, whether from the pay template, leave or a timesheet
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarningsLine {
+    #[serde(rename = "earningsLineID", default)]
+    pub earnings_line_id: Option<Uuid>,
+    #[serde(rename = "earningsRateID", default)]
+    pub earnings_rate_id: Option<Uuid>,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub rate_per_unit: Option<Decimal>,
+    #[serde(default)]
+    pub number_of_units: Option<Decimal>,
+    #[serde(default)]
+    pub fixed_amount: Option<Decimal>,
+    #[serde(default)]
+    pub amount: Decimal,
+    #[serde(default)]
+    pub is_linked_to_timesheet: Option<bool>,
+    #[serde(default)]
+    pub is_average_daily_pay_rate: Option<bool>,
+}
+
+/// A deduction on a payslip
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeductionLine {
+    #[serde(rename = "deductionTypeID", default)]
+    pub deduction_type_id: Option<Uuid>,
+    #[serde(default)]
+    pub amount: Decimal,
+    #[serde(default)]
+    pub subject_to_tax: Option<bool>,
+    #[serde(default)]
+    pub percentage: Option<Decimal>,
+}
+
+/// A reimbursement on a payslip
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReimbursementLine {
+    #[serde(rename = "reimbursementTypeID", default)]
+    pub reimbursement_type_id: Option<Uuid>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub amount: Decimal,
+}
+
+/// A benefit on a payslip
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenefitLine {
+    #[serde(rename = "benefitTypeID", default)]
+    pub benefit_type_id: Option<Uuid>,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub amount: Decimal,
+    #[serde(default)]
+    pub fixed_amount: Option<Decimal>,
+    #[serde(default)]
+    pub percentage: Option<Decimal>,
+}
+
+/// A court order deducted on a payslip
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CourtOrderLine {
+    #[serde(rename = "courtOrderTypeID", default)]
+    pub court_order_type_id: Option<Uuid>,
+    #[serde(default)]
+    pub amount: Decimal,
+}
+
+/// A payslip in Xero Payroll UK
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Payslip {
+    #[serde(rename = "paySlipID")]
+    pub payslip_id: Uuid,
+    #[serde(rename = "employeeID", default)]
+    pub employee_id: Option<Uuid>,
+    #[serde(rename = "payRunID", default)]
+    pub pay_run_id: Option<Uuid>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub last_edited: Option<Date>,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub last_name: Option<String>,
+    #[serde(default)]
+    pub total_earnings: Option<Decimal>,
+    #[serde(default)]
+    pub gross_earnings: Option<Decimal>,
+    #[serde(default)]
+    pub total_pay: Option<Decimal>,
+    #[serde(default)]
+    pub total_employer_taxes: Option<Decimal>,
+    #[serde(default)]
+    pub total_employee_taxes: Option<Decimal>,
+    #[serde(default)]
+    pub total_deductions: Option<Decimal>,
+    #[serde(default)]
+    pub total_reimbursements: Option<Decimal>,
+    #[serde(default)]
+    pub total_court_orders: Option<Decimal>,
+    #[serde(default)]
+    pub total_benefits: Option<Decimal>,
+    #[serde(default)]
+    pub bacs_hash: Option<String>,
+    #[serde(default)]
+    pub payment_method: Option<PaymentMethod>,
+    #[serde(default)]
+    pub earnings_lines: Vec<EarningsLine>,
+    #[serde(default)]
+    pub leave_earnings_lines: Vec<EarningsLine>,
+    #[serde(default)]
+    pub timesheet_earnings_lines: Vec<EarningsLine>,
+    #[serde(default)]
+    pub deduction_lines: Vec<DeductionLine>,
+    #[serde(default)]
+    pub reimbursement_lines: Vec<ReimbursementLine>,
+    #[serde(default)]
+    pub leave_accrual_lines: Vec<LeaveAccrualLine>,
+    #[serde(default)]
+    pub benefit_lines: Vec<BenefitLine>,
+    #[serde(default)]
+    pub payment_lines: Vec<PaymentLine>,
+    #[serde(default)]
+    pub employee_tax_lines: Vec<TaxLine>,
+    #[serde(default)]
+    pub employer_tax_lines: Vec<TaxLine>,
+    #[serde(default)]
+    pub court_order_lines: Vec<CourtOrderLine>,
+}
+
+/// Response wrapper for UK payslips
+pub type PayslipsResponse = payslip::PayslipsResponse<Payslip>;
+
+/// Response wrapper for a single UK payslip
+pub type PayslipResponse = payslip::PayslipResponse<Payslip>;
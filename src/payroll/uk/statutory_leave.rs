@@ -0,0 +1,259 @@
+//! Statutory Leave API for Xero Payroll UK
+//!
+//! Statutory leave (sick, maternity, paternity, adoption and shared parental leave) is paid
+//! at rates set by HMRC rather than from an employee's own leave balance. Xero checks
+//! entitlement when the leave is recorded and reports why an employee does not qualify.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use time::macros::date;
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::uk::statutory_leave::PostStatutorySickLeave;
+//!
+//! # async fn example(employee_id: uuid::Uuid, leave_type_id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//!
+//! let sick_leave = PostStatutorySickLeave::new(
+//!     employee_id,
+//!     leave_type_id,
+//!     date!(2024 - 02 - 05),
+//!     date!(2024 - 02 - 16),
+//!     vec!["Monday".into(), "Tuesday".into(), "Wednesday".into(), "Thursday".into(), "Friday".into()],
+//! );
+//! let sick_leave = client.payroll_uk().employees().create_statutory_sick_leave(&sick_leave).await?;
+//! if sick_leave.is_entitled != Some(true) {
+//!     println!("Not entitled: {:?}", sick_leave.entitlement_failure_reasons);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::Date;
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::{BASE_URL, not_found};
+use crate::{
+    error::Result,
+    utils::date_format::{xero_date_format, xero_date_format_option},
+};
+
+/// Kind of statutory leave
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum StatutoryLeaveType {
+    Sick,
+    Adoption,
+    Maternity,
+    Paternity,
+    #[serde(rename = "Sharedparental")]
+    SharedParental,
+}
+
+/// Progress of a statutory leave record
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum StatutoryLeaveStatus {
+    Pending,
+    #[serde(rename = "In-Progress")]
+    InProgress,
+    Completed,
+}
+
+/// Summary of one statutory leave record of an employee
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatutoryLeaveSummary {
+    #[serde(rename = "statutoryLeaveID")]
+    pub statutory_leave_id: Uuid,
+    #[serde(rename = "employeeID")]
+    pub employee_id: Uuid,
+    #[serde(rename = "type")]
+    pub leave_type: StatutoryLeaveType,
+    #[serde(default, with = "xero_date_format_option")]
+    pub start_date: Option<Date>,
+    #[serde(default, with = "xero_date_format_option")]
+    pub end_date: Option<Date>,
+    #[serde(default)]
+    pub is_entitled: Option<bool>,
+    #[serde(default)]
+    pub status: Option<StatutoryLeaveStatus>,
+}
+
+/// Statutory sick leave of an employee
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatutorySickLeave {
+    #[serde(rename = "statutoryLeaveID", default)]
+    pub statutory_leave_id: Option<Uuid>,
+    #[serde(rename = "employeeID")]
+    pub employee_id: Uuid,
+    #[serde(rename = "leaveTypeID")]
+    pub leave_type_id: Uuid,
+    #[serde(with = "xero_date_format")]
+    pub start_date: Date,
+    #[serde(with = "xero_date_format")]
+    pub end_date: Date,
+    #[serde(rename = "type", default)]
+    pub leave_type: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Days of the week the employee normally works, e.g. `"Monday"`
+    #[serde(default)]
+    pub work_pattern: Vec<String>,
+    #[serde(default)]
+    pub is_pregnancy_related: bool,
+    #[serde(default)]
+    pub sufficient_notice: bool,
+    #[serde(default)]
+    pub is_entitled: Option<bool>,
+    #[serde(default)]
+    pub entitlement_weeks_requested: Option<Decimal>,
+    #[serde(default)]
+    pub entitlement_weeks_qualified: Option<Decimal>,
+    #[serde(default)]
+    pub entitlement_weeks_remaining: Option<Decimal>,
+    #[serde(default)]
+    pub overlaps_with_other_leave: Option<bool>,
+    #[serde(default)]
+    pub entitlement_failure_reasons: Vec<String>,
+}
+
+/// Request structure for recording statutory sick leave
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostStatutorySickLeave {
+    #[serde(rename = "employeeID")]
+    pub employee_id: Uuid,
+    #[serde(rename = "leaveTypeID")]
+    pub leave_type_id: Uuid,
+    #[serde(with = "xero_date_format")]
+    pub start_date: Date,
+    #[serde(with = "xero_date_format")]
+    pub end_date: Date,
+    pub work_pattern: Vec<String>,
+    pub is_pregnancy_related: bool,
+    pub sufficient_notice: bool,
+}
+
+impl PostStatutorySickLeave {
+    /// Sick leave that is not pregnancy related and was notified in time
+    #[must_use]
+    pub fn new(
+        employee_id: Uuid,
+        leave_type_id: Uuid,
+        start_date: Date,
+        end_date: Date,
+        work_pattern: Vec<String>,
+    ) -> Self {
+        Self {
+            employee_id,
+            leave_type_id,
+            start_date,
+            end_date,
+            work_pattern,
+            is_pregnancy_related: false,
+            sufficient_notice: true,
+        }
+    }
+
+    /// Mark the sickness as pregnancy related
+    #[must_use]
+    pub fn with_pregnancy_related(mut self, is_pregnancy_related: bool) -> Self {
+        self.is_pregnancy_related = is_pregnancy_related;
+        self
+    }
+
+    /// Set whether the employee notified the employer in time
+    #[must_use]
+    pub fn with_sufficient_notice(mut self, sufficient_notice: bool) -> Self {
+        self.sufficient_notice = sufficient_notice;
+        self
+    }
+}
+
+/// Response wrapper for statutory leave summaries
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatutoryLeaveSummaryResponse {
+    #[serde(default)]
+    pub statutory_leaves: Vec<StatutoryLeaveSummary>,
+}
+
+/// Response wrapper for statutory sick leave
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatutorySickLeaveResponse {
+    #[serde(default)]
+    pub statutory_sick_leave: Option<StatutorySickLeave>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SummaryParameters {
+    active_only: bool,
+}
+
+impl StatutoryLeaveSummary {
+    /// List an employee's statutory leave, optionally only leave that is still active
+    pub async fn list(
+        client: &crate::client::Client,
+        employee_id: Uuid,
+        active_only: bool,
+    ) -> Result<Vec<StatutoryLeaveSummary>> {
+        info!(
+            "Listing statutory leave for UK employee with ID: {}",
+            employee_id
+        );
+
+        let url = format!("{BASE_URL}/StatutoryLeaves/Summary/{employee_id}");
+        let response: StatutoryLeaveSummaryResponse =
+            client.get(&url, &SummaryParameters { active_only }).await?;
+        debug!(
+            "Response contains {} statutory leave records",
+            response.statutory_leaves.len()
+        );
+        Ok(response.statutory_leaves)
+    }
+}
+
+impl StatutorySickLeave {
+    /// Get a statutory sick leave record by ID
+    pub async fn get(
+        client: &crate::client::Client,
+        statutory_sick_leave_id: Uuid,
+    ) -> Result<StatutorySickLeave> {
+        info!(
+            "Getting statutory sick leave with ID: {}",
+            statutory_sick_leave_id
+        );
+
+        let url = format!("{BASE_URL}/StatutoryLeaves/Sick/{statutory_sick_leave_id}");
+        debug!("GET URL: {}", url);
+
+        let response: StatutorySickLeaveResponse = client.get(&url, &()).await?;
+        response
+            .statutory_sick_leave
+            .ok_or_else(|| not_found("StatutorySickLeave", url))
+    }
+
+    /// Record statutory sick leave for an employee
+    pub async fn create(
+        client: &crate::client::Client,
+        sick_leave: &PostStatutorySickLeave,
+    ) -> Result<StatutorySickLeave> {
+        info!(
+            "Creating statutory sick leave for UK employee with ID: {}",
+            sick_leave.employee_id
+        );
+        debug!("Leave type ID: {}", sick_leave.leave_type_id);
+
+        let url = format!("{BASE_URL}/StatutoryLeaves/Sick");
+        let response: StatutorySickLeaveResponse = client.post(&url, sick_leave).await?;
+        response
+            .statutory_sick_leave
+            .ok_or_else(|| not_found("StatutorySickLeave", url))
+    }
+}
@@ -0,0 +1,53 @@
+//! Timesheets API for Xero Payroll UK
+//!
+//! A timesheet records the units an employee worked against earnings rates over one pay
+//! period. Lines are added and changed individually, and a timesheet is approved before it
+//! is included in a pay run.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use rust_decimal_macros::dec;
+//! use time::macros::date;
+//! use xero_rs::{Client, KeyPair};
+//! use xero_rs::payroll::uk::timesheet::{PostTimesheet, TimesheetLine};
+//!
+//! # async fn example(calendar_id: uuid::Uuid, employee_id: uuid::Uuid, rate_id: uuid::Uuid) -> Result<(), Box<dyn std::error::Error>> {
+//! let key_pair = KeyPair::from_env();
+//! let client = Client::from_client_credentials(key_pair, None).await?;
+//! let timesheets = client.payroll_uk().timesheets();
+//!
+//! let timesheet = PostTimesheet::new(calendar_id, employee_id, date!(2024 - 06 - 03), date!(2024 - 06 - 09))
+//!     .with_line(TimesheetLine::new(date!(2024 - 06 - 03), rate_id, dec!(7.5)));
+//! let timesheet = timesheets.create(&timesheet).await?;
+//! timesheets.approve(timesheet.timesheet_id).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::payroll::v2::timesheet;
+
+pub use timesheet::{ENDPOINT, PostTimesheet, TimesheetLine, TimesheetLineResponse};
+
+/// Status of a UK timesheet
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum TimesheetStatus {
+    Draft,
+    Approved,
+    /// The timesheet has been paid in a posted pay run
+    Completed,
+}
+
+/// A timesheet in Xero Payroll UK
+pub type Timesheet = timesheet::Timesheet<TimesheetStatus>;
+
+/// Filters for listing UK timesheets
+pub type ListParameters = timesheet::ListParameters<TimesheetStatus>;
+
+/// Response wrapper for UK timesheets
+pub type TimesheetsResponse = timesheet::TimesheetsResponse<TimesheetStatus>;
+
+/// Response wrapper for a single UK timesheet
+pub type TimesheetResponse = timesheet::TimesheetResponse<TimesheetStatus>;
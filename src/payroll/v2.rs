@@ -0,0 +1,100 @@
+//! Shared plumbing for version 2.0 of Xero's payroll API
+//!
+//! The UK and NZ payroll APIs share a base URL, a paging scheme and the `problem` error
+//! format, so their modules build on the helpers here. Timesheets, pay templates and the
+//! pay run and payslip requests are the same in both regions, so they live here too and
+//! take the [`Region`] they are called for.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use tracing::{debug, error};
+use tracing_error::SpanTrace;
+
+use crate::error::Result;
+
+pub mod pay_run;
+pub mod pay_template;
+pub mod payslip;
+pub mod timesheet;
+
+/// Base URL of the payroll 2.0 API
+pub const BASE_URL: &str = "https://api.xero.com/payroll.xro/2.0";
+
+/// What differs between the payroll 2.0 regions in the requests they share
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Region {
+    /// Name of the region in log messages
+    pub name: &'static str,
+    /// Path segment of pay template earnings, whose case differs between the regions
+    pub earnings_template_path: &'static str,
+    /// Endpoint of payslips, whose case differs between the regions
+    pub payslips_endpoint: &'static str,
+}
+
+/// Paging details included with payroll 2.0 collections
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Pagination {
+    #[serde(default)]
+    pub page: u32,
+    #[serde(default)]
+    pub page_size: u32,
+    #[serde(default)]
+    pub page_count: u32,
+    #[serde(default)]
+    pub item_count: u32,
+}
+
+/// Query for one page of a collection, alongside any endpoint specific filters
+#[derive(Debug, Serialize)]
+struct PageQuery<'a, Q> {
+    page: u32,
+    #[serde(flatten)]
+    query: &'a Q,
+}
+
+/// Fetch every page of a payroll 2.0 collection
+///
+/// `items` splits a page's response into its pagination details and its records.
+pub(crate) async fn list_all<R, T, Q>(
+    client: &crate::client::Client,
+    url: &str,
+    query: &Q,
+    items: impl Fn(R) -> (Option<Pagination>, Vec<T>),
+) -> Result<Vec<T>>
+where
+    R: DeserializeOwned,
+    Q: Serialize + fmt::Debug,
+{
+    let mut all = Vec::new();
+    let mut page = 1;
+    loop {
+        let response: R = client.get(url, &PageQuery { page, query }).await?;
+        let (pagination, records) = items(response);
+        debug!(
+            "Page {} of {} contains {} records",
+            page,
+            url,
+            records.len()
+        );
+        all.extend(records);
+
+        match pagination {
+            Some(pagination) if page < pagination.page_count => page += 1,
+            _ => return Ok(all),
+        }
+    }
+}
+
+/// Error for a response that unexpectedly contains no `entity`
+pub(crate) fn not_found(entity: &str, url: String) -> crate::error::Error {
+    error!("Received no {} in response", entity);
+    crate::error::Error::NotFound {
+        entity: entity.to_string(),
+        url,
+        status_code: reqwest::StatusCode::NOT_FOUND,
+        response_body: Some(format!("No {entity} returned in response")),
+        span_trace: SpanTrace::capture(),
+    }
+}
@@ -0,0 +1,122 @@
+//! Pay run requests shared by the UK and NZ payroll APIs
+//!
+//! Both regions list, read and update pay runs the same way, and each pay run carries the
+//! payslips of its employees. The pay runs themselves differ in their pay run and calendar
+//! types and in their payslips, so the response wrappers here are generic over the region's
+//! pay run type and each region module names them for its own.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::{Pagination, Region, list_all, not_found};
+use crate::error::Result;
+
+/// Base endpoint for payroll 2.0 pay runs
+pub const ENDPOINT: &str = "https://api.xero.com/payroll.xro/2.0/PayRuns";
+
+/// Status of a payroll 2.0 pay run
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PayRunStatus {
+    Draft,
+    Posted,
+}
+
+#[derive(Debug, Serialize)]
+struct ListParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<PayRunStatus>,
+}
+
+/// Response wrapper for pay runs of the region's pay run type `P`
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayRunsResponse<P> {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default = "Vec::new")]
+    pub pay_runs: Vec<P>,
+}
+
+/// Response wrapper for a single pay run of the region's pay run type `P`
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayRunResponse<P> {
+    #[serde(default = "Option::default")]
+    pub pay_run: Option<P>,
+}
+
+/// List pay runs, optionally only those with the given status, fetching every page
+pub(crate) async fn list<P: DeserializeOwned>(
+    client: &crate::client::Client,
+    region: Region,
+    status: Option<PayRunStatus>,
+) -> Result<Vec<P>> {
+    info!("Listing {} pay runs with status: {:?}", region.name, status);
+
+    list_all(
+        client,
+        ENDPOINT,
+        &ListParameters { status },
+        |response: PayRunsResponse<P>| (response.pagination, response.pay_runs),
+    )
+    .await
+}
+
+/// Get a single pay run by ID, including its payslips
+pub(crate) async fn get<P: DeserializeOwned>(
+    client: &crate::client::Client,
+    region: Region,
+    pay_run_id: Uuid,
+) -> Result<P> {
+    info!("Getting {} pay run with ID: {}", region.name, pay_run_id);
+
+    let url = format!("{ENDPOINT}/{pay_run_id}");
+    debug!("GET URL: {}", url);
+
+    let response: PayRunResponse<P> = client.get(&url, &()).await?;
+    response.pay_run.ok_or_else(|| not_found("PayRun", url))
+}
+
+/// Create a pay run, which only NZ payroll supports
+#[cfg(feature = "payroll-nz")]
+pub(crate) async fn create<P, B>(
+    client: &crate::client::Client,
+    region: Region,
+    pay_run: &B,
+) -> Result<P>
+where
+    P: DeserializeOwned,
+    B: Serialize + fmt::Debug,
+{
+    info!("Creating {} pay run", region.name);
+    debug!("Pay run data: {:?}", pay_run);
+
+    let response: PayRunResponse<P> = client.post(ENDPOINT, pay_run).await?;
+    response
+        .pay_run
+        .ok_or_else(|| not_found("PayRun", ENDPOINT.to_string()))
+}
+
+/// Update a draft pay run
+pub(crate) async fn update<P, B>(
+    client: &crate::client::Client,
+    region: Region,
+    pay_run_id: Uuid,
+    pay_run: &B,
+) -> Result<P>
+where
+    P: DeserializeOwned,
+    B: Serialize + fmt::Debug,
+{
+    info!("Updating {} pay run with ID: {}", region.name, pay_run_id);
+    debug!("Updated pay run data: {:?}", pay_run);
+
+    let url = format!("{ENDPOINT}/{pay_run_id}");
+    debug!("PUT URL: {}", url);
+
+    let response: PayRunResponse<P> = client.put(&url, pay_run).await?;
+    response.pay_run.ok_or_else(|| not_found("PayRun", url))
+}
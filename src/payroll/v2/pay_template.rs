@@ -0,0 +1,208 @@
+//! Employee pay templates shared by the UK and NZ payroll APIs
+//!
+//! A pay template holds the earnings an employee is paid every pay run. Each earnings line
+//! refers to an earnings rate and overrides its rate, units or fixed amount for this
+//! employee.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::{Region, not_found};
+use crate::error::Result;
+
+/// Base endpoint for payroll 2.0 employees, under which pay templates live
+const ENDPOINT: &str = "https://api.xero.com/payroll.xro/2.0/Employees";
+
+/// One earnings line of an employee's pay template
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarningsTemplate {
+    #[serde(
+        rename = "payTemplateEarningID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pay_template_earning_id: Option<Uuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_per_unit: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_of_units: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixed_amount: Option<Decimal>,
+    #[serde(rename = "earningsRateID")]
+    pub earnings_rate_id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl EarningsTemplate {
+    /// Earnings paid as a rate per unit, e.g. an hourly wage
+    #[must_use]
+    pub fn rate_per_unit(earnings_rate_id: Uuid, rate_per_unit: Decimal, units: Decimal) -> Self {
+        Self {
+            earnings_rate_id,
+            rate_per_unit: Some(rate_per_unit),
+            number_of_units: Some(units),
+            ..Self::default()
+        }
+    }
+
+    /// Earnings paid as a fixed amount each pay period
+    #[must_use]
+    pub fn fixed_amount(earnings_rate_id: Uuid, fixed_amount: Decimal) -> Self {
+        Self {
+            earnings_rate_id,
+            fixed_amount: Some(fixed_amount),
+            ..Self::default()
+        }
+    }
+}
+
+/// An employee's pay template
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployeePayTemplate {
+    #[serde(rename = "employeeID", default)]
+    pub employee_id: Option<Uuid>,
+    #[serde(default)]
+    pub earning_templates: Vec<EarningsTemplate>,
+}
+
+/// Response wrapper for an employee's pay template
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayTemplateResponse {
+    #[serde(default)]
+    pub pay_template: Option<EmployeePayTemplate>,
+}
+
+/// Response wrapper for a single earnings line of a pay template
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarningsTemplateResponse {
+    #[serde(default)]
+    pub earning_template: Option<EarningsTemplate>,
+}
+
+/// Get an employee's pay template
+pub(crate) async fn get(
+    client: &crate::client::Client,
+    region: Region,
+    employee_id: Uuid,
+) -> Result<EmployeePayTemplate> {
+    info!(
+        "Getting pay template for {} employee with ID: {}",
+        region.name, employee_id
+    );
+
+    let url = format!("{ENDPOINT}/{employee_id}/PayTemplates");
+    debug!("GET URL: {}", url);
+
+    let response: PayTemplateResponse = client.get(&url, &()).await?;
+    response
+        .pay_template
+        .ok_or_else(|| not_found("EmployeePayTemplate", url))
+}
+
+/// Add an earnings line to an employee's pay template
+pub(crate) async fn create_earnings(
+    client: &crate::client::Client,
+    region: Region,
+    employee_id: Uuid,
+    earnings: &EarningsTemplate,
+) -> Result<EarningsTemplate> {
+    info!(
+        "Adding pay template earnings for {} employee with ID: {}",
+        region.name, employee_id
+    );
+    debug!("Earnings template data: {:?}", earnings);
+
+    let url = earnings_url(region, employee_id, None);
+    let response: EarningsTemplateResponse = client.post(&url, earnings).await?;
+    response
+        .earning_template
+        .ok_or_else(|| not_found("EarningsTemplate", url))
+}
+
+/// Update an earnings line of an employee's pay template
+pub(crate) async fn update_earnings(
+    client: &crate::client::Client,
+    region: Region,
+    employee_id: Uuid,
+    pay_template_earning_id: Uuid,
+    earnings: &EarningsTemplate,
+) -> Result<EarningsTemplate> {
+    info!(
+        "Updating pay template earnings {} for {} employee {}",
+        pay_template_earning_id, region.name, employee_id
+    );
+    debug!("Updated earnings template data: {:?}", earnings);
+
+    let url = earnings_url(region, employee_id, Some(pay_template_earning_id));
+    debug!("PUT URL: {}", url);
+
+    let response: EarningsTemplateResponse = client.put(&url, earnings).await?;
+    response
+        .earning_template
+        .ok_or_else(|| not_found("EarningsTemplate", url))
+}
+
+/// Remove an earnings line from an employee's pay template
+pub(crate) async fn delete_earnings(
+    client: &crate::client::Client,
+    region: Region,
+    employee_id: Uuid,
+    pay_template_earning_id: Uuid,
+) -> Result<()> {
+    info!(
+        "Deleting pay template earnings {} for {} employee {}",
+        pay_template_earning_id, region.name, employee_id
+    );
+
+    let url = earnings_url(region, employee_id, Some(pay_template_earning_id));
+    debug!("DELETE URL: {}", url);
+
+    client.delete(&url).await
+}
+
+fn earnings_url(
+    region: Region,
+    employee_id: Uuid,
+    pay_template_earning_id: Option<Uuid>,
+) -> String {
+    let url = format!(
+        "{ENDPOINT}/{employee_id}/PayTemplates/{}",
+        region.earnings_template_path
+    );
+    match pay_template_earning_id {
+        Some(pay_template_earning_id) => format!("{url}/{pay_template_earning_id}"),
+        None => url,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn earnings_url_uses_the_region_path() {
+        let region = Region {
+            name: "NZ",
+            earnings_template_path: "Earnings",
+            payslips_endpoint: "https://api.xero.com/payroll.xro/2.0/PaySlips",
+        };
+        let employee_id = Uuid::nil();
+        let earning_id = Uuid::from_u128(1);
+
+        assert_eq!(
+            earnings_url(region, employee_id, None),
+            format!("{ENDPOINT}/{employee_id}/PayTemplates/Earnings")
+        );
+        assert_eq!(
+            earnings_url(region, employee_id, Some(earning_id)),
+            format!("{ENDPOINT}/{employee_id}/PayTemplates/Earnings/{earning_id}")
+        );
+    }
+}
@@ -0,0 +1,125 @@
+//! Payslip requests and lines shared by the UK and NZ payroll APIs
+//!
+//! Both regions list a pay run's payslips and read single payslips the same way, and share
+//! the payment, leave accrual and tax lines. The rest of a payslip differs, so the response
+//! wrappers here are generic over the region's payslip type and each region module names
+//! them for its own.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::{Pagination, Region, list_all, not_found};
+use crate::error::Result;
+
+/// How an employee is paid
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PaymentMethod {
+    Cheque,
+    Electronically,
+    Manual,
+}
+
+/// Leave accrued on a payslip
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveAccrualLine {
+    #[serde(rename = "leaveTypeID", default)]
+    pub leave_type_id: Option<Uuid>,
+    #[serde(default)]
+    pub number_of_units: Decimal,
+}
+
+/// A payment of net pay to one of the employee's bank accounts
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentLine {
+    #[serde(rename = "paymentLineID", default)]
+    pub payment_line_id: Option<Uuid>,
+    #[serde(default)]
+    pub amount: Decimal,
+    #[serde(default)]
+    pub account_number: Option<String>,
+    #[serde(default)]
+    pub sort_code: Option<String>,
+    #[serde(default)]
+    pub account_name: Option<String>,
+}
+
+/// Employee or employer tax on a payslip, e.g. PAYE
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaxLine {
+    #[serde(rename = "taxLineID", default)]
+    pub tax_line_id: Option<Uuid>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub is_employer_tax: Option<bool>,
+    #[serde(default)]
+    pub amount: Decimal,
+    #[serde(rename = "globalTaxTypeID", default)]
+    pub global_tax_type_id: Option<String>,
+    #[serde(default)]
+    pub manual_adjustment: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+struct ListParameters {
+    #[serde(rename = "PayRunID")]
+    pay_run_id: Uuid,
+}
+
+/// Response wrapper for payslips of the region's payslip type `P`
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayslipsResponse<P> {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default = "Vec::new")]
+    pub pay_slips: Vec<P>,
+}
+
+/// Response wrapper for a single payslip of the region's payslip type `P`
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayslipResponse<P> {
+    #[serde(default = "Option::default")]
+    pub pay_slip: Option<P>,
+}
+
+/// List the payslips of a pay run, fetching every page
+pub(crate) async fn list<P: DeserializeOwned>(
+    client: &crate::client::Client,
+    region: Region,
+    pay_run_id: Uuid,
+) -> Result<Vec<P>> {
+    info!(
+        "Listing {} payslips for pay run with ID: {}",
+        region.name, pay_run_id
+    );
+
+    list_all(
+        client,
+        region.payslips_endpoint,
+        &ListParameters { pay_run_id },
+        |response: PayslipsResponse<P>| (response.pagination, response.pay_slips),
+    )
+    .await
+}
+
+/// Get a single payslip by ID
+pub(crate) async fn get<P: DeserializeOwned>(
+    client: &crate::client::Client,
+    region: Region,
+    payslip_id: Uuid,
+) -> Result<P> {
+    info!("Getting {} payslip with ID: {}", region.name, payslip_id);
+
+    let url = format!("{}/{payslip_id}", region.payslips_endpoint);
+    debug!("GET URL: {}", url);
+
+    let response: PayslipResponse<P> = client.get(&url, &()).await?;
+    response.pay_slip.ok_or_else(|| not_found("Payslip", url))
+}
@@ -0,0 +1,386 @@
+//! Timesheets shared by the UK and NZ payroll APIs
+//!
+//! Both regions record timesheets the same way: one line per day and earnings rate, lines
+//! changed individually, and the timesheet approved before it is included in a pay run.
+//! Only the statuses differ, so the types here are generic over the region's status enum
+//! and each region module names them for its own statuses.
+
+use std::fmt;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use time::{Date, OffsetDateTime};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use super::{Pagination, Region, list_all, not_found};
+use crate::{
+    error::Result,
+    utils::date_format::{xero_date_format, xero_date_format_option, xero_datetime_format_option},
+};
+
+/// Base endpoint for payroll 2.0 timesheets
+pub const ENDPOINT: &str = "https://api.xero.com/payroll.xro/2.0/Timesheets";
+
+/// Units worked against one earnings rate on one day
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimesheetLine {
+    #[serde(
+        rename = "timesheetLineID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub timesheet_line_id: Option<Uuid>,
+    #[serde(with = "xero_date_format")]
+    pub date: Date,
+    #[serde(rename = "earningsRateID")]
+    pub earnings_rate_id: Uuid,
+    #[serde(
+        rename = "trackingItemID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tracking_item_id: Option<Uuid>,
+    pub number_of_units: Decimal,
+}
+
+impl TimesheetLine {
+    /// Units worked on `date` against an earnings rate
+    #[must_use]
+    pub fn new(date: Date, earnings_rate_id: Uuid, number_of_units: Decimal) -> Self {
+        Self {
+            timesheet_line_id: None,
+            date,
+            earnings_rate_id,
+            tracking_item_id: None,
+            number_of_units,
+        }
+    }
+
+    /// Set the tracking option the line is reported against
+    #[must_use]
+    pub fn with_tracking_item(mut self, tracking_item_id: Uuid) -> Self {
+        self.tracking_item_id = Some(tracking_item_id);
+        self
+    }
+}
+
+/// A payroll 2.0 timesheet with the region's status type `S`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Timesheet<S> {
+    #[serde(rename = "timesheetID")]
+    pub timesheet_id: Uuid,
+    #[serde(rename = "payrollCalendarID")]
+    pub payroll_calendar_id: Uuid,
+    #[serde(rename = "employeeID")]
+    pub employee_id: Uuid,
+    #[serde(with = "xero_date_format")]
+    pub start_date: Date,
+    #[serde(with = "xero_date_format")]
+    pub end_date: Date,
+    #[serde(default = "Option::default")]
+    pub status: Option<S>,
+    #[serde(default)]
+    pub total_hours: Option<Decimal>,
+    #[serde(
+        rename = "updatedDateUTC",
+        default,
+        with = "xero_datetime_format_option"
+    )]
+    pub updated_date_utc: Option<OffsetDateTime>,
+    #[serde(default)]
+    pub timesheet_lines: Vec<TimesheetLine>,
+}
+
+/// Request structure for creating a timesheet
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostTimesheet {
+    #[serde(rename = "payrollCalendarID")]
+    pub payroll_calendar_id: Uuid,
+    #[serde(rename = "employeeID")]
+    pub employee_id: Uuid,
+    #[serde(with = "xero_date_format")]
+    pub start_date: Date,
+    #[serde(with = "xero_date_format")]
+    pub end_date: Date,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub timesheet_lines: Vec<TimesheetLine>,
+}
+
+impl PostTimesheet {
+    /// An empty timesheet for one pay period of the calendar
+    #[must_use]
+    pub fn new(
+        payroll_calendar_id: Uuid,
+        employee_id: Uuid,
+        start_date: Date,
+        end_date: Date,
+    ) -> Self {
+        Self {
+            payroll_calendar_id,
+            employee_id,
+            start_date,
+            end_date,
+            timesheet_lines: Vec::new(),
+        }
+    }
+
+    /// Add a line to the timesheet
+    #[must_use]
+    pub fn with_line(mut self, line: TimesheetLine) -> Self {
+        self.timesheet_lines.push(line);
+        self
+    }
+}
+
+/// Filters for listing timesheets with the region's status type `S`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListParameters<S> {
+    /// Filter expression, e.g. `employeeId==00000000-0000-0000-0000-000000000000`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<S>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "xero_date_format_option"
+    )]
+    pub start_date: Option<Date>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "xero_date_format_option"
+    )]
+    pub end_date: Option<Date>,
+}
+
+impl<S> Default for ListParameters<S> {
+    fn default() -> Self {
+        Self {
+            filter: None,
+            status: None,
+            start_date: None,
+            end_date: None,
+        }
+    }
+}
+
+impl<S> ListParameters<S> {
+    /// Only timesheets of this employee
+    #[must_use]
+    pub fn with_employee_id(mut self, employee_id: Uuid) -> Self {
+        self.filter = Some(format!("employeeId=={employee_id}"));
+        self
+    }
+
+    /// Only timesheets with this status
+    #[must_use]
+    pub fn with_status(mut self, status: S) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// Response wrapper for timesheets
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimesheetsResponse<S> {
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+    #[serde(default = "Vec::new")]
+    pub timesheets: Vec<Timesheet<S>>,
+}
+
+/// Response wrapper for a single timesheet
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimesheetResponse<S> {
+    #[serde(default = "Option::default")]
+    pub timesheet: Option<Timesheet<S>>,
+}
+
+/// Response wrapper for a single timesheet line
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimesheetLineResponse {
+    #[serde(default)]
+    pub timesheet_line: Option<TimesheetLine>,
+}
+
+/// List timesheets matching the parameters, fetching every page
+pub(crate) async fn list<S>(
+    client: &crate::client::Client,
+    region: Region,
+    parameters: &ListParameters<S>,
+) -> Result<Vec<Timesheet<S>>>
+where
+    S: Serialize + DeserializeOwned + fmt::Debug,
+{
+    info!("Listing {} timesheets: {:?}", region.name, parameters);
+
+    list_all(
+        client,
+        ENDPOINT,
+        parameters,
+        |response: TimesheetsResponse<S>| (response.pagination, response.timesheets),
+    )
+    .await
+}
+
+/// Get a single timesheet by ID
+pub(crate) async fn get<S: DeserializeOwned>(
+    client: &crate::client::Client,
+    region: Region,
+    timesheet_id: Uuid,
+) -> Result<Timesheet<S>> {
+    info!(
+        "Getting {} timesheet with ID: {}",
+        region.name, timesheet_id
+    );
+
+    let url = format!("{ENDPOINT}/{timesheet_id}");
+    debug!("GET URL: {}", url);
+
+    let response: TimesheetResponse<S> = client.get(&url, &()).await?;
+    response
+        .timesheet
+        .ok_or_else(|| not_found("Timesheet", url))
+}
+
+/// Create a timesheet
+pub(crate) async fn create<S: DeserializeOwned>(
+    client: &crate::client::Client,
+    region: Region,
+    timesheet: &PostTimesheet,
+) -> Result<Timesheet<S>> {
+    info!("Creating {} timesheet", region.name);
+    debug!("Timesheet data: {:?}", timesheet);
+
+    let response: TimesheetResponse<S> = client.post(ENDPOINT, timesheet).await?;
+    response
+        .timesheet
+        .ok_or_else(|| not_found("Timesheet", ENDPOINT.to_string()))
+}
+
+/// Delete a draft timesheet
+pub(crate) async fn delete(
+    client: &crate::client::Client,
+    region: Region,
+    timesheet_id: Uuid,
+) -> Result<()> {
+    info!(
+        "Deleting {} timesheet with ID: {}",
+        region.name, timesheet_id
+    );
+
+    let url = format!("{ENDPOINT}/{timesheet_id}");
+    debug!("DELETE URL: {}", url);
+
+    client.delete(&url).await
+}
+
+/// Approve a draft timesheet so it is included in the next pay run
+pub(crate) async fn approve<S: DeserializeOwned>(
+    client: &crate::client::Client,
+    region: Region,
+    timesheet_id: Uuid,
+) -> Result<Timesheet<S>> {
+    info!(
+        "Approving {} timesheet with ID: {}",
+        region.name, timesheet_id
+    );
+    transition(client, timesheet_id, "Approve").await
+}
+
+/// Revert an approved timesheet to draft
+pub(crate) async fn revert_to_draft<S: DeserializeOwned>(
+    client: &crate::client::Client,
+    region: Region,
+    timesheet_id: Uuid,
+) -> Result<Timesheet<S>> {
+    info!(
+        "Reverting {} timesheet with ID: {} to draft",
+        region.name, timesheet_id
+    );
+    transition(client, timesheet_id, "RevertToDraft").await
+}
+
+/// Add a line to a draft timesheet
+pub(crate) async fn create_line(
+    client: &crate::client::Client,
+    region: Region,
+    timesheet_id: Uuid,
+    line: &TimesheetLine,
+) -> Result<TimesheetLine> {
+    info!(
+        "Adding line to {} timesheet with ID: {}",
+        region.name, timesheet_id
+    );
+    debug!("Timesheet line data: {:?}", line);
+
+    let url = format!("{ENDPOINT}/{timesheet_id}/Lines");
+    let response: TimesheetLineResponse = client.post(&url, line).await?;
+    response
+        .timesheet_line
+        .ok_or_else(|| not_found("TimesheetLine", url))
+}
+
+/// Update a line of a draft timesheet
+pub(crate) async fn update_line(
+    client: &crate::client::Client,
+    region: Region,
+    timesheet_id: Uuid,
+    timesheet_line_id: Uuid,
+    line: &TimesheetLine,
+) -> Result<TimesheetLine> {
+    info!(
+        "Updating line {} of {} timesheet {}",
+        timesheet_line_id, region.name, timesheet_id
+    );
+    debug!("Updated timesheet line data: {:?}", line);
+
+    let url = format!("{ENDPOINT}/{timesheet_id}/Lines/{timesheet_line_id}");
+    debug!("PUT URL: {}", url);
+
+    let response: TimesheetLineResponse = client.put(&url, line).await?;
+    response
+        .timesheet_line
+        .ok_or_else(|| not_found("TimesheetLine", url))
+}
+
+/// Delete a line of a draft timesheet
+pub(crate) async fn delete_line(
+    client: &crate::client::Client,
+    region: Region,
+    timesheet_id: Uuid,
+    timesheet_line_id: Uuid,
+) -> Result<()> {
+    info!(
+        "Deleting line {} of {} timesheet {}",
+        timesheet_line_id, region.name, timesheet_id
+    );
+
+    let url = format!("{ENDPOINT}/{timesheet_id}/Lines/{timesheet_line_id}");
+    debug!("DELETE URL: {}", url);
+
+    client.delete(&url).await
+}
+
+async fn transition<S: DeserializeOwned>(
+    client: &crate::client::Client,
+    timesheet_id: Uuid,
+    action: &str,
+) -> Result<Timesheet<S>> {
+    let url = format!("{ENDPOINT}/{timesheet_id}/{action}");
+    debug!("POST URL: {}", url);
+
+    let response: TimesheetResponse<S> = client.post(&url, &()).await?;
+    response
+        .timesheet
+        .ok_or_else(|| not_found("Timesheet", url))
+}
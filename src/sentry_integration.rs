@@ -102,6 +102,7 @@ impl<'a> From<&'a Error> for Breadcrumb {
                     ErrorType::NotAvailableException => "NotAvailableException",
                     ErrorType::RateLimitExceededException => "RateLimitExceededException",
                     ErrorType::SystemUnavailableException => "SystemUnavailableException",
+                    ErrorType::PayrollProblem { .. } => "PayrollProblem",
                     ErrorType::Other(s) => s.as_str(),
                 };
                 data.insert("error_type".to_string(), Value::from(error_type));
@@ -159,6 +160,137 @@ impl<'a> From<&'a Error> for Breadcrumb {
                 "Attachment too large".to_string(),
                 BTreeMap::new(),
             ),
+
+            Error::InvalidCurrencyCode(code) => {
+                let mut data = BTreeMap::new();
+                data.insert("currency_code".to_string(), Value::from(code.clone()));
+                ("xero.validation", "Invalid currency code".to_string(), data)
+            }
+
+            Error::BatchPaymentEmpty => (
+                "xero.validation",
+                "Batch payment has no payments".to_string(),
+                BTreeMap::new(),
+            ),
+
+            Error::InvalidBatchPaymentAmount { invoice_id, amount } => {
+                let mut data = BTreeMap::new();
+                data.insert(
+                    "invoice_id".to_string(),
+                    Value::from(invoice_id.to_string()),
+                );
+                data.insert("amount".to_string(), Value::from(amount.to_string()));
+                (
+                    "xero.validation",
+                    "Invalid batch payment amount".to_string(),
+                    data,
+                )
+            }
+
+            Error::BatchPaymentTotalMismatch { expected, actual } => {
+                let mut data = BTreeMap::new();
+                data.insert("expected".to_string(), Value::from(expected.to_string()));
+                data.insert("actual".to_string(), Value::from(actual.to_string()));
+                (
+                    "xero.validation",
+                    "Batch payment total mismatch".to_string(),
+                    data,
+                )
+            }
+
+            Error::ConversionBalancesUnbalanced { debits, credits } => {
+                let mut data = BTreeMap::new();
+                data.insert("debits".to_string(), Value::from(debits.to_string()));
+                data.insert("credits".to_string(), Value::from(credits.to_string()));
+                (
+                    "xero.validation",
+                    "Conversion balances unbalanced".to_string(),
+                    data,
+                )
+            }
+
+            Error::UnsupportedPayrollRegion(country_code) => {
+                let mut data = BTreeMap::new();
+                data.insert(
+                    "country_code".to_string(),
+                    Value::from(country_code.clone()),
+                );
+                (
+                    "xero.config",
+                    "Unsupported payroll region".to_string(),
+                    data,
+                )
+            }
+
+            Error::PayrollFeatureDisabled {
+                country_code,
+                feature,
+            } => {
+                let mut data = BTreeMap::new();
+                data.insert(
+                    "country_code".to_string(),
+                    Value::from(country_code.clone()),
+                );
+                data.insert("feature".to_string(), Value::from(*feature));
+                ("xero.config", "Payroll feature disabled".to_string(), data)
+            }
+
+            Error::MissingPayrollCalendar(employee_id) => {
+                let mut data = BTreeMap::new();
+                data.insert(
+                    "employee_id".to_string(),
+                    Value::from(employee_id.to_string()),
+                );
+                (
+                    "xero.validation",
+                    "Missing payroll calendar".to_string(),
+                    data,
+                )
+            }
+
+            Error::TimesheetEntryOutsidePeriod {
+                date,
+                start_date,
+                end_date,
+            } => {
+                let mut data = BTreeMap::new();
+                data.insert("date".to_string(), Value::from(date.to_string()));
+                data.insert(
+                    "start_date".to_string(),
+                    Value::from(start_date.to_string()),
+                );
+                data.insert("end_date".to_string(), Value::from(end_date.to_string()));
+                (
+                    "xero.validation",
+                    "Timesheet entry outside pay period".to_string(),
+                    data,
+                )
+            }
+
+            Error::InvalidStatusTransition { entity, from, to } => {
+                let mut data = BTreeMap::new();
+                data.insert("entity".to_string(), Value::from(entity.clone()));
+                data.insert("from".to_string(), Value::from(from.clone()));
+                data.insert("to".to_string(), Value::from(to.clone()));
+                (
+                    "xero.validation",
+                    format!("Invalid {entity} status transition"),
+                    data,
+                )
+            }
+
+            Error::LeaveApplicationWithoutPeriods(leave_application_id) => {
+                let mut data = BTreeMap::new();
+                data.insert(
+                    "leave_application_id".to_string(),
+                    Value::from(leave_application_id.to_string()),
+                );
+                (
+                    "xero.validation",
+                    "Leave application without periods".to_string(),
+                    data,
+                )
+            }
         };
 
         Breadcrumb {
@@ -183,7 +315,7 @@ impl<'a> From<&'a Error> for Breadcrumb {
 /// use sentry::configure_scope;
 /// use xero_rs::sentry_integration::error_to_sentry_context;
 ///
-/// if let Err(e) = client.contacts().list().await {
+/// if let Err(e) = client.contacts().list_all().await {
 ///     configure_scope(|scope| {
 ///         let context = error_to_sentry_context(&e);
 ///         for (key, value) in context {
@@ -1,4 +1,4 @@
-use serde::{Deserialize, Deserializer, de::IntoDeserializer};
+use serde::{Deserialize, Deserializer, Serialize, de::IntoDeserializer};
 
 /// Deserializes a value, treating empty strings as None.
 /// Useful for Xero API fields that return "" instead of null.
@@ -20,6 +20,40 @@ where
     }
 }
 
+/// Deserializes a number that may also be sent as a string, e.g. `400` or `"400"`.
+/// Useful for Xero API fields whose type differs between APIs.
+pub fn number_or_string<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + std::str::FromStr,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString<T> {
+        Number(T),
+        String(String),
+    }
+
+    match Option::<NumberOrString<T>>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(NumberOrString::Number(n)) => Ok(Some(n)),
+        Some(NumberOrString::String(s)) if s.is_empty() => Ok(None),
+        Some(NumberOrString::String(s)) => s
+            .parse()
+            .map(Some)
+            .map_err(|_| serde::de::Error::custom(format!("invalid number: {s}"))),
+    }
+}
+
+/// The name serde writes for an enum variant, e.g. `"AUTHORISED"`.
+/// Values that do not serialize to a string fall back to their `Debug` output.
+pub fn variant_name<T: Serialize + std::fmt::Debug>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => format!("{value:?}"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -76,6 +110,26 @@ mod tests {
         assert_eq!(account.bank_account_type, Some(BankAccountType::PayPal));
     }
 
+    #[derive(Debug, Deserialize)]
+    struct TestStatus {
+        #[serde(default, deserialize_with = "number_or_string")]
+        status: Option<u16>,
+    }
+
+    #[test]
+    fn test_number_or_string_accepts_both() {
+        let number: TestStatus = serde_json::from_str(r#"{"status": 400}"#).unwrap();
+        let string: TestStatus = serde_json::from_str(r#"{"status": "400"}"#).unwrap();
+        assert_eq!(number.status, Some(400));
+        assert_eq!(string.status, Some(400));
+    }
+
+    #[test]
+    fn test_variant_name_uses_the_serde_name() {
+        assert_eq!(variant_name(&BankAccountType::CreditCard), "CREDITCARD");
+        assert_eq!(variant_name(&400), "400");
+    }
+
     #[test]
     fn test_invalid_enum_value_fails() {
         let json = r#"{"name": "Test Account", "bank_account_type": "INVALID"}"#;
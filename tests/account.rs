@@ -4,45 +4,11 @@ extern crate tracing;
 mod test_utils;
 
 use anyhow::Result;
-use std::env;
-use uuid::Uuid;
-use xero_rs::KeyPair;
 use xero_rs::entities::account::{AccountStatus, AccountType, Builder, ListParameters};
 
-/// Try to set up a client. Will return None if the required environment variables are not set.
-async fn try_setup_client() -> Option<xero_rs::Client> {
-    test_utils::do_setup();
-
-    // Check if required environment variables are set
-    let client_id = env::var("XERO_CLIENT_ID").ok()?;
-    let client_secret = env::var("XERO_CLIENT_SECRET").ok()?;
-    let tenant_id_str = env::var("XERO_TENANT_ID").ok()?;
-
-    let tenant_id = match Uuid::parse_str(&tenant_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            warn!("Invalid XERO_TENANT_ID format");
-            return None;
-        }
-    };
-
-    // Create client with credentials and full scopes
-    let client = xero_rs::Client::from_client_credentials(
-        KeyPair::new(client_id, Some(client_secret)),
-        xero_rs::Scope::all_accounting(),
-    )
-    .await
-    .ok()?;
-
-    // Set the tenant ID and return the configured client
-    client.set_tenant(Some(tenant_id)).await;
-
-    Some(client)
-}
-
 #[tokio::test]
 async fn list_accounts() -> Result<()> {
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -65,7 +31,7 @@ async fn list_accounts() -> Result<()> {
 
 #[tokio::test]
 async fn get_account() -> Result<()> {
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -93,7 +59,7 @@ async fn get_account() -> Result<()> {
 
 #[tokio::test]
 async fn filter_accounts_by_type() -> Result<()> {
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -130,7 +96,7 @@ async fn filter_accounts_by_type() -> Result<()> {
 
 #[tokio::test]
 async fn filter_accounts_by_status() -> Result<()> {
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -153,7 +119,7 @@ async fn filter_accounts_by_status() -> Result<()> {
 
 #[tokio::test]
 async fn create_update_account() -> Result<()> {
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -234,7 +200,7 @@ async fn create_update_account() -> Result<()> {
 
 #[tokio::test]
 async fn account_attachments() -> Result<()> {
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -0,0 +1,99 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use uuid::Uuid;
+use xero_rs::accounting_employee::{AccountingEmployee, Builder, ListParameters, Status};
+
+#[tokio::test]
+async fn list_accounting_employees() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let params = ListParameters::builder()
+        .with_where("Status==\"ACTIVE\"")
+        .with_order("LastName");
+    let employees = client.accounting_employees().list(params).await?;
+    info!("Found {} accounting employees", employees.len());
+
+    if let Some(first) = employees.first() {
+        let employee = client.accounting_employees().get(first.employee_id).await?;
+        assert_eq!(employee.employee_id, first.employee_id);
+        info!("Employee: {}", employee.full_name());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_and_update_accounting_employee() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let suffix = &Uuid::new_v4().to_string()[..8];
+    let builder = Builder::new("Test", format!("Employee {suffix}"));
+    let employee = client.accounting_employees().create(&builder).await?;
+    assert_eq!(employee.first_name.as_deref(), Some("Test"));
+
+    let update = Builder::default().with_external_link(
+        "https://example.com/staff/1",
+        Some("Staff profile".to_string()),
+    );
+    let updated = client
+        .accounting_employees()
+        .update(employee.employee_id, &update)
+        .await?;
+    assert_eq!(updated.employee_id, employee.employee_id);
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_accounting_employee() -> Result<()> {
+    let json = r#"{
+        "EmployeeID": "4a5b6c7d-8e9f-4a0b-9c1d-2e3f4a5b6c7d",
+        "Status": "ACTIVE",
+        "FirstName": "Jane",
+        "LastName": "Citizen",
+        "ExternalLink": {"Url": "https://example.com/jane", "Description": "Profile"},
+        "UpdatedDateUTC": "/Date(1729209600000+0000)/"
+    }"#;
+
+    let employee: AccountingEmployee = serde_json::from_str(json)?;
+    assert_eq!(employee.status, Some(Status::Active));
+    assert_eq!(employee.full_name(), "Jane Citizen");
+    assert_eq!(
+        employee.external_link.map(|link| link.url).as_deref(),
+        Some("https://example.com/jane")
+    );
+    assert!(employee.updated_date_utc.is_some());
+
+    Ok(())
+}
+
+#[test]
+fn serialize_accounting_employee_builder() -> Result<()> {
+    let builder = Builder::new("Jane", "Citizen").with_status(Status::Archived);
+    let json = serde_json::to_value(&builder)?;
+
+    assert_eq!(json["FirstName"], "Jane");
+    assert_eq!(json["LastName"], "Citizen");
+    assert_eq!(json["Status"], "ARCHIVED");
+    assert!(json.get("ExternalLink").is_none());
+
+    Ok(())
+}
@@ -40,7 +40,7 @@ async fn test_method_based_api() -> Result<()> {
     info!("=== Using method-based API ===");
 
     // List contacts
-    let contacts = client.contacts().list().await?;
+    let contacts = client.contacts().list_all().await?;
     info!("Found {} contacts", contacts.len());
 
     if !contacts.is_empty() {
@@ -0,0 +1,206 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use rust_decimal::Decimal;
+use time::macros::date;
+use uuid::Uuid;
+use xero_rs::Error;
+use xero_rs::batch_payment::{
+    BatchPayment, Builder, PaymentBuilder, Status, Type, ValidationError,
+};
+use xero_rs::payment::AccountIdentifier;
+
+#[tokio::test]
+async fn list_batch_payments() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let batch_payments = client.batch_payments().list_all().await?;
+    info!("Found {} batch payments", batch_payments.len());
+
+    if let Some(first) = batch_payments.first() {
+        let batch_payment = client.batch_payments().get(first.batch_payment_id).await?;
+        assert_eq!(batch_payment.batch_payment_id, first.batch_payment_id);
+
+        let history = client
+            .batch_payments()
+            .get_history(first.batch_payment_id)
+            .await?;
+        debug!("Batch payment has {} history records", history.len());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_rejects_mismatched_total() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    // The total is checked before the request is sent, so no invoices need to exist
+    let builder = Builder::new(
+        AccountIdentifier::Code("090".to_string()),
+        date!(2024 - 03 - 15),
+    )
+    .with_payment(Uuid::new_v4(), Decimal::new(10000, 2))
+    .with_expected_total(Decimal::new(5000, 2));
+
+    match client.batch_payments().create(&builder).await {
+        Err(Error::BatchPaymentTotalMismatch { expected, actual }) => {
+            assert_eq!(expected, Decimal::new(5000, 2));
+            assert_eq!(actual, Decimal::new(10000, 2));
+        }
+        other => panic!("expected a total mismatch, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn builder_totals() {
+    let builder = Builder::new(
+        AccountIdentifier::Code("090".to_string()),
+        date!(2024 - 03 - 15),
+    )
+    .with_payment(Uuid::new_v4(), Decimal::new(12550, 2))
+    .with_payment(Uuid::new_v4(), Decimal::new(7450, 2));
+    assert_eq!(builder.total(), Decimal::new(200, 0));
+    assert!(builder.total_matches());
+
+    let builder = builder.with_expected_total(Decimal::new(200, 0));
+    assert!(builder.total_matches());
+
+    let builder = builder.with_expected_total(Decimal::new(199, 0));
+    assert!(!builder.total_matches());
+}
+
+#[test]
+fn validate_rejects_empty_batches() {
+    let builder = Builder::new(
+        AccountIdentifier::Code("090".to_string()),
+        date!(2024 - 03 - 15),
+    );
+    assert_eq!(builder.validate(), Err(ValidationError::Empty));
+}
+
+#[test]
+fn validate_rejects_payments_that_are_not_positive() {
+    let zero_invoice = Uuid::new_v4();
+    let builder = Builder::new(
+        AccountIdentifier::Code("090".to_string()),
+        date!(2024 - 03 - 15),
+    )
+    .with_payment(Uuid::new_v4(), Decimal::new(5000, 2))
+    .with_payment(zero_invoice, Decimal::ZERO);
+    assert_eq!(
+        builder.validate(),
+        Err(ValidationError::InvalidAmount {
+            invoice_id: zero_invoice,
+            amount: Decimal::ZERO,
+        })
+    );
+
+    let builder = Builder::new(
+        AccountIdentifier::Code("090".to_string()),
+        date!(2024 - 03 - 15),
+    )
+    .with_payment(Uuid::new_v4(), Decimal::new(-1000, 2));
+    assert!(matches!(
+        builder.validate(),
+        Err(ValidationError::InvalidAmount { .. })
+    ));
+}
+
+#[test]
+fn validate_checks_the_expected_total_only_when_set() {
+    let builder = Builder::new(
+        AccountIdentifier::Code("090".to_string()),
+        date!(2024 - 03 - 15),
+    )
+    .with_payment(Uuid::new_v4(), Decimal::new(10000, 2));
+    assert!(builder.validate().is_ok());
+
+    let builder = builder.with_expected_total(Decimal::new(5000, 2));
+    assert_eq!(
+        builder.validate(),
+        Err(ValidationError::TotalMismatch {
+            expected: Decimal::new(5000, 2),
+            actual: Decimal::new(10000, 2),
+        })
+    );
+}
+
+#[test]
+fn serialize_builder() {
+    let invoice_id = Uuid::parse_str("e9a6e1a1-f8c6-4b3b-9e1e-9c1a5b0e1b72").unwrap();
+    let account_id = Uuid::parse_str("ac993f75-035b-433c-82e0-7b7a2d40802c").unwrap();
+    let builder = Builder::new(AccountIdentifier::ID(account_id), date!(2024 - 03 - 15))
+        .with_reference("Weekly run")
+        .with_payment_line(
+            PaymentBuilder::new(invoice_id, Decimal::new(5000, 2))
+                .with_bank_account_number("12-3456-7890123-00"),
+        )
+        .with_expected_total(Decimal::new(5000, 2));
+
+    assert_eq!(
+        serde_json::to_value(&builder).unwrap(),
+        serde_json::json!({
+            "Account": { "AccountID": "ac993f75-035b-433c-82e0-7b7a2d40802c" },
+            "Date": "2024-03-15",
+            "Reference": "Weekly run",
+            "Payments": [
+                {
+                    "Invoice": { "InvoiceID": "e9a6e1a1-f8c6-4b3b-9e1e-9c1a5b0e1b72" },
+                    "Amount": "50.00",
+                    "BankAccountNumber": "12-3456-7890123-00"
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn deserialize_batch_payment() {
+    let json = r#"{
+        "Account": { "AccountID": "ac993f75-035b-433c-82e0-7b7a2d40802c" },
+        "Reference": "Weekly run",
+        "BatchPaymentID": "d318c343-208e-49fe-b04a-45642349bcf1",
+        "DateString": "2024-03-15T00:00:00",
+        "Date": "/Date(1710460800000+0000)/",
+        "Payments": [
+            {
+                "Invoice": { "InvoiceID": "e9a6e1a1-f8c6-4b3b-9e1e-9c1a5b0e1b72" },
+                "PaymentID": "0d666415-cf77-43fa-80c7-56775591d426",
+                "Amount": 50.00
+            }
+        ],
+        "Type": "PAYBATCH",
+        "Status": "AUTHORISED",
+        "TotalAmount": 50.00,
+        "UpdatedDateUTC": "/Date(1710496008000+0000)/",
+        "IsReconciled": false
+    }"#;
+
+    let batch_payment: BatchPayment = serde_json::from_str(json).unwrap();
+    assert_eq!(batch_payment.r#type, Type::PayBatch);
+    assert_eq!(batch_payment.status, Status::Authorised);
+    assert_eq!(batch_payment.date, Some(date!(2024 - 03 - 15)));
+    assert_eq!(batch_payment.total_amount, Decimal::new(5000, 2));
+    assert_eq!(batch_payment.payments.len(), 1);
+    assert_eq!(batch_payment.is_reconciled, Some(false));
+}
@@ -0,0 +1,85 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use uuid::Uuid;
+use xero_rs::branding_theme::{self, BrandingTheme};
+
+#[tokio::test]
+async fn list_and_lookup_branding_themes() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let themes = client.branding_themes().list().await?;
+    info!("Found {} branding themes", themes.len());
+
+    if let Some(theme) = themes.first() {
+        let fetched = client
+            .branding_themes()
+            .get(theme.branding_theme_id)
+            .await?;
+        assert_eq!(fetched.branding_theme_id, theme.branding_theme_id);
+
+        let by_name = client
+            .branding_themes()
+            .get_by_name(&theme.name.to_uppercase())
+            .await?;
+        assert_eq!(by_name.branding_theme_id, theme.branding_theme_id);
+    }
+
+    let missing = client
+        .branding_themes()
+        .get_by_name("No Such Theme 7f1c2a")
+        .await;
+    assert!(missing.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_branding_themes() {
+    let json = r#"{
+        "BrandingThemes": [
+            {
+                "BrandingThemeID": "dfe23d27-a3a6-4ef3-a5ca-b9e02b142dde",
+                "Name": "Special Projects",
+                "LogoUrl": "https://in.xero.com/logo?id=abc",
+                "Type": "INVOICE",
+                "SortOrder": 1,
+                "CreatedDateUTC": "/Date(946684800000+0000)/"
+            },
+            {
+                "BrandingThemeID": "4c82c365-35cb-467f-bb11-dce1f2f2f67c",
+                "Name": "Standard",
+                "Type": "INVOICE",
+                "SortOrder": 0,
+                "CreatedDateUTC": "/Date(946684800000+0000)/"
+            }
+        ]
+    }"#;
+
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Response {
+        branding_themes: Vec<BrandingTheme>,
+    }
+
+    let response: Response = serde_json::from_str(json).unwrap();
+    assert_eq!(response.branding_themes.len(), 2);
+    assert!(!response.branding_themes[0].is_default());
+
+    let standard = branding_theme::find_by_name(response.branding_themes, "  standard ").unwrap();
+    assert_eq!(
+        standard.branding_theme_id,
+        Uuid::parse_str("4c82c365-35cb-467f-bb11-dce1f2f2f67c").unwrap()
+    );
+    assert!(standard.is_default());
+}
@@ -0,0 +1,120 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use rust_decimal::Decimal;
+use time::Month;
+use time::macros::date;
+use uuid::Uuid;
+use xero_rs::budget::{Budget, GetParameters, ListParameters, Period, Type};
+
+#[tokio::test]
+async fn list_and_get_budgets() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let budgets = client.budgets().list_all().await?;
+    info!("Found {} budgets", budgets.len());
+
+    if let Some(first) = budgets.first() {
+        let params =
+            GetParameters::builder().with_date_range(date!(2024 - 01 - 01), date!(2024 - 12 - 31));
+        let budget = client.budgets().get(first.budget_id, params).await?;
+        assert_eq!(budget.budget_id, first.budget_id);
+        info!("Budget has {} lines", budget.budget_lines.len());
+    }
+
+    Ok(())
+}
+
+const BUDGET: &str = r#"{
+    "Budgets": [{
+        "BudgetID": "5c2a2f5e-9a2b-4d7e-8d6b-0f1a2b3c4d5e",
+        "Type": "TRACKING",
+        "Description": "Sydney office",
+        "UpdatedDateUTC": "/Date(1729209600000)/",
+        "BudgetLines": [{
+            "AccountID": "b0b6e4c8-2bd1-4a3f-a5e3-8e3a1c3c7a10",
+            "AccountCode": "200",
+            "BudgetBalances": [
+                {"Period": "2024-01", "Amount": 1000.00, "Notes": "New year sale"},
+                {"Period": "2024-02", "Amount": 1250.50}
+            ]
+        }],
+        "Tracking": [{
+            "TrackingCategoryID": "3c1b0d7e-6f0a-4c3e-9f3b-2d1e5f6a7b8c",
+            "TrackingOptionID": "7a6b5c4d-3e2f-4a1b-8c9d-0e1f2a3b4c5d",
+            "Name": "Region",
+            "Option": "Sydney"
+        }]
+    }]
+}"#;
+
+#[test]
+fn deserialize_budget() -> Result<()> {
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Response {
+        budgets: Vec<Budget>,
+    }
+
+    let response: Response = serde_json::from_str(BUDGET)?;
+    let budget = &response.budgets[0];
+
+    assert_eq!(budget.r#type, Type::Tracking);
+    assert_eq!(budget.description.as_deref(), Some("Sydney office"));
+    assert!(budget.updated_date_utc.is_some());
+    assert!(
+        budget.applies_to_tracking_option(Uuid::parse_str("7a6b5c4d-3e2f-4a1b-8c9d-0e1f2a3b4c5d")?)
+    );
+
+    let account_id = Uuid::parse_str("b0b6e4c8-2bd1-4a3f-a5e3-8e3a1c3c7a10")?;
+    let line = budget.line_for_account(account_id).expect("budget line");
+    assert_eq!(line.account_code.as_deref(), Some("200"));
+    assert_eq!(
+        line.budget_balances[0].period,
+        Period::new(2024, Month::January)
+    );
+    assert_eq!(line.total(), Decimal::new(225_050, 2));
+
+    assert_eq!(
+        budget.amount_for(account_id, Period::containing(date!(2024 - 02 - 15))),
+        Some(Decimal::new(125_050, 2))
+    );
+    assert_eq!(
+        budget.amount_for(account_id, Period::new(2024, Month::March)),
+        None
+    );
+
+    Ok(())
+}
+
+#[test]
+fn budget_period_round_trip() -> Result<()> {
+    let period: Period = "2024-09".parse().map_err(anyhow::Error::msg)?;
+    assert_eq!(period, Period::new(2024, Month::September));
+    assert_eq!(period.to_string(), "2024-09");
+    assert_eq!(serde_json::to_value(period)?, "2024-09");
+    assert_eq!(period.start_date(), Some(date!(2024 - 09 - 01)));
+    assert!(period.contains(date!(2024 - 09 - 30)));
+    assert!(!period.contains(date!(2024 - 10 - 01)));
+    assert!("2024-13".parse::<Period>().is_err());
+    assert!("September".parse::<Period>().is_err());
+
+    let params =
+        ListParameters::builder().with_date_range(date!(2024 - 01 - 01), date!(2024 - 06 - 30));
+    let json = serde_json::to_value(&params)?;
+    assert_eq!(json["DateFrom"], "2024-01-01");
+    assert_eq!(json["DateTo"], "2024-06-30");
+    assert!(json.get("IDs").is_none());
+
+    Ok(())
+}
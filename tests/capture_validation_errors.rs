@@ -46,7 +46,7 @@ async fn capture_quote_validation_error_missing_contact() -> Result<()> {
     let client = test_utils::create_test_client(Some(test_utils::accounting_scopes())).await?;
 
     // Get a real contact first
-    let contacts = client.contacts().list().await?;
+    let contacts = client.contacts().list_all().await?;
     if contacts.is_empty() {
         info!("No contacts found, skipping test");
         return Ok(());
@@ -4,8 +4,14 @@ extern crate tracing;
 mod test_utils;
 
 use anyhow::Result;
+use rust_decimal::Decimal;
 use std::env;
-use xero_rs::KeyPair;
+use uuid::Uuid;
+use xero_rs::contact::{
+    Address, AddressType, Builder, Contact, ContactPerson, ListParameters, PaymentTerm,
+    PaymentTermType, PaymentTerms, Phone, PhoneType, Status,
+};
+use xero_rs::{CurrencyCode, KeyPair};
 
 #[tokio::test]
 async fn list_contacts() -> Result<()> {
@@ -34,7 +40,7 @@ async fn list_contacts() -> Result<()> {
     client.set_tenant(Some(tenant.tenant_id)).await;
 
     // List contacts
-    let contacts = client.contacts().list().await?;
+    let contacts = client.contacts().list_all().await?;
     info!("Found {} contacts", contacts.len());
     Ok(())
 }
@@ -62,7 +68,7 @@ async fn get_contact() -> Result<()> {
     client.set_tenant(Some(tenant.tenant_id)).await;
 
     // First list contacts to get an ID
-    let contacts = client.contacts().list().await?;
+    let contacts = client.contacts().list_all().await?;
 
     if contacts.is_empty() {
         info!("No contacts found, skipping get_contact test");
@@ -78,3 +84,261 @@ async fn get_contact() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn create_update_archive_contact() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let name = format!("Test Contact {}", Uuid::new_v4());
+    let builder = Builder::new(&name)
+        .with_first_name("Test")
+        .with_last_name("Contact")
+        .with_email_address("test.contact@example.com")
+        .with_address(
+            Address::new(AddressType::Street)
+                .with_lines(["1 Test Street"])
+                .with_city("Wellington")
+                .with_country("New Zealand"),
+        )
+        .with_phone(Phone::new(PhoneType::Default, "5551234").with_area_code("04"));
+
+    let contact = client.contacts().create(&builder).await?;
+    info!("Created contact: {} ({})", contact.name, contact.contact_id);
+    assert_eq!(contact.name, name);
+
+    let update = Builder::default().with_account_number("TEST-ACC-001");
+    let updated = client
+        .contacts()
+        .update(contact.contact_id, &update)
+        .await?;
+    assert_eq!(updated.contact_id, contact.contact_id);
+    assert!(updated.account_number.is_some());
+
+    let archived = client.contacts().archive(contact.contact_id).await?;
+    assert_eq!(archived.contact_status, Some(Status::Archived));
+
+    Ok(())
+}
+
+#[test]
+fn serialize_contact_builder() {
+    let builder = Builder::new("ABC Limited")
+        .with_email_address("accounts@abc.example")
+        .with_accounts_receivable_tax_type("OUTPUT2")
+        .with_address(
+            Address::new(AddressType::Pobox)
+                .with_lines(["PO Box 123", "Lambton Quay"])
+                .with_postal_code("6011"),
+        )
+        .with_phone(Phone::new(PhoneType::Mobile, "0211234567"))
+        .with_contact_person(
+            ContactPerson::new("Jane", "Doe", "jane@abc.example").with_include_in_emails(true),
+        )
+        .with_payment_terms(PaymentTerms {
+            sales: Some(PaymentTerm::new(20, PaymentTermType::OfFollowingMonth)),
+            ..Default::default()
+        })
+        .with_default_currency(CurrencyCode::NZD)
+        .with_bank_account_details("01-0123-0123456-00");
+
+    let json = serde_json::to_value(&builder).unwrap();
+    assert_eq!(json["Name"], "ABC Limited");
+    assert_eq!(json["AccountsReceivableTaxType"], "OUTPUT2");
+    assert_eq!(json["Addresses"][0]["AddressType"], "POBOX");
+    assert_eq!(json["Addresses"][0]["AddressLine2"], "Lambton Quay");
+    assert!(json["Addresses"][0].get("AddressLine3").is_none());
+    assert_eq!(json["Phones"][0]["PhoneType"], "MOBILE");
+    assert_eq!(json["ContactPersons"][0]["IncludeInEmails"], true);
+    assert_eq!(json["PaymentTerms"]["Sales"]["Type"], "OFFOLLOWINGMONTH");
+    assert_eq!(json["PaymentTerms"]["Sales"]["Day"], 20);
+    assert!(json["PaymentTerms"].get("Bills").is_none());
+    assert_eq!(json["DefaultCurrency"], "NZD");
+    assert!(json.get("ContactID").is_none());
+
+    // Archiving only sends the status
+    let archive = Builder::default().with_contact_status(Status::Archived);
+    assert_eq!(
+        serde_json::to_string(&archive).unwrap(),
+        r#"{"ContactStatus":"ARCHIVED"}"#
+    );
+}
+
+#[tokio::test]
+async fn search_and_find_contacts() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let params = ListParameters::builder()
+        .with_page(1)
+        .with_page_size(10)
+        .with_summary_only(true)
+        .with_order("Name ASC");
+    let page = client.contacts().list(params).await?;
+    info!("Found {} contacts on the first page", page.len());
+    assert!(page.len() <= 10);
+
+    if let Some(first) = page.first() {
+        let found = client.contacts().find_by_name(&first.name).await?;
+        assert_eq!(found.map(|c| c.contact_id), Some(first.contact_id));
+
+        let by_id = client
+            .contacts()
+            .list(ListParameters::builder().with_ids(vec![first.contact_id]))
+            .await?;
+        assert_eq!(by_id.len(), 1);
+    }
+
+    let missing = client
+        .contacts()
+        .find_by_email("no-such-contact-7f1c2a@example.com")
+        .await?;
+    assert!(missing.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn serialize_contact_list_parameters() {
+    let params = ListParameters::builder()
+        .with_page(2)
+        .with_page_size(500)
+        .with_search_term("acme")
+        .with_include_archived(true)
+        .with_summary_only(true)
+        .with_if_modified_since(time::macros::datetime!(2024-01-01 00:00 UTC));
+    let query = serde_json::to_value(&params).unwrap();
+    assert_eq!(
+        query,
+        serde_json::json!({
+            "page": 2,
+            "pageSize": 500,
+            "searchTerm": "acme",
+            "includeArchived": true,
+            "summaryOnly": true,
+        })
+    );
+
+    let params = ListParameters::builder().with_name(r#"Bob "The Builder" Ltd"#);
+    assert_eq!(
+        params.r#where.as_deref(),
+        Some(r#"Name=="Bob \"The Builder\" Ltd""#)
+    );
+
+    let params = ListParameters::builder().with_email_address("a@b.com");
+    assert_eq!(
+        params.r#where.as_deref(),
+        Some(r#"EmailAddress=="a@b.com""#)
+    );
+}
+
+#[tokio::test]
+async fn contact_history_and_attachments() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let contacts = client
+        .contacts()
+        .list(ListParameters::builder().with_page(1))
+        .await?;
+    let Some(contact) = contacts.first() else {
+        info!("No contacts found, skipping contact_history_and_attachments test");
+        return Ok(());
+    };
+
+    let detailed = client.contacts().get(contact.contact_id).await?;
+    info!("Balances for {}: {:?}", detailed.name, detailed.balances);
+
+    client
+        .contacts()
+        .create_history(contact.contact_id, "Note added by integration test")
+        .await?;
+    let history = client.contacts().get_history(contact.contact_id).await?;
+    assert!(!history.is_empty());
+
+    let attachment = client
+        .contacts()
+        .upload_attachment(contact.contact_id, "test.txt", b"contact attachment")
+        .await?;
+    assert_eq!(attachment.file_name, "test.txt");
+
+    let attachments = client
+        .contacts()
+        .list_attachments(contact.contact_id)
+        .await?;
+    assert!(
+        attachments
+            .iter()
+            .any(|a| a.attachment_id == attachment.attachment_id)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_contact_balances_and_terms() {
+    let contact: Contact = serde_json::from_str(
+        r#"{
+            "ContactID": "bd2270c3-8706-4c11-9cfb-000b551c3f51",
+            "ContactStatus": "ACTIVE",
+            "Name": "ABC Limited",
+            "HasAttachments": true,
+            "Balances": {
+                "AccountsReceivable": { "Outstanding": 760.00, "Overdue": 920.00 },
+                "AccountsPayable": { "Outstanding": 231.60, "Overdue": 360.00 }
+            },
+            "PaymentTerms": {
+                "Bills": { "Day": 15, "Type": "OFCURRENTMONTH" },
+                "Sales": { "Day": 10, "Type": "DAYSAFTERBILLMONTH" }
+            },
+            "BatchPayments": {
+                "BankAccountNumber": "0102030405060708",
+                "BankAccountName": "ABC Limited",
+                "Details": "Invoice"
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let balances = contact.balances.unwrap();
+    assert_eq!(
+        balances.accounts_receivable.outstanding,
+        Decimal::new(760, 0)
+    );
+    assert_eq!(balances.accounts_payable.overdue, Decimal::new(360, 0));
+
+    let terms = contact.payment_terms.unwrap();
+    assert_eq!(
+        terms.bills,
+        Some(PaymentTerm::new(15, PaymentTermType::OfCurrentMonth))
+    );
+    assert_eq!(
+        terms.sales,
+        Some(PaymentTerm::new(10, PaymentTermType::DaysAfterBillMonth))
+    );
+
+    let batch = contact.batch_payments.unwrap();
+    assert_eq!(
+        batch.bank_account_number.as_deref(),
+        Some("0102030405060708")
+    );
+    assert!(contact.has_attachments);
+}
@@ -0,0 +1,113 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use uuid::Uuid;
+use xero_rs::contact::{self, Contact};
+use xero_rs::contact_group::{ContactGroup, Status};
+
+#[tokio::test]
+async fn contact_group_lifecycle() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let groups = client.contact_groups().list_all().await?;
+    info!("Found {} contact groups", groups.len());
+
+    let name = format!("Test Group {}", Uuid::new_v4());
+    let group = client.contact_groups().create(&name).await?;
+    assert_eq!(group.name, name);
+
+    let renamed = client
+        .contact_groups()
+        .rename(group.contact_group_id, &format!("{name} (renamed)"))
+        .await?;
+    assert!(renamed.name.ends_with("(renamed)"));
+
+    let contact = client
+        .contacts()
+        .create(&contact::Builder::new(format!(
+            "Group Member {}",
+            Uuid::new_v4()
+        )))
+        .await?;
+    client
+        .contact_groups()
+        .add_contacts(group.contact_group_id, &[contact.contact_id])
+        .await?;
+
+    let fetched = client.contact_groups().get(group.contact_group_id).await?;
+    assert!(
+        fetched
+            .contacts
+            .iter()
+            .any(|c| c.contact_id == contact.contact_id)
+    );
+
+    client
+        .contact_groups()
+        .remove_contact(group.contact_group_id, contact.contact_id)
+        .await?;
+
+    let deleted = client
+        .contact_groups()
+        .delete(group.contact_group_id)
+        .await?;
+    assert_eq!(deleted.status, Some(Status::Deleted));
+
+    client.contacts().archive(contact.contact_id).await?;
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_contact_group_and_membership() {
+    let group: ContactGroup = serde_json::from_str(
+        r#"{
+            "ContactGroupID": "17b44ed7-4389-4162-91cb-3dd5766e4e22",
+            "Name": "Oasis",
+            "Status": "ACTIVE",
+            "Contacts": [
+                {
+                    "ContactID": "4e1753b9-018a-4775-b6aa-1bc7871cfee3",
+                    "Name": "Noel Gallagher",
+                    "Addresses": [],
+                    "Phones": [],
+                    "ContactGroups": [],
+                    "ContactPersons": [],
+                    "HasValidationErrors": false
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(group.status, Some(Status::Active));
+    assert_eq!(group.contacts.len(), 1);
+    assert_eq!(group.contacts[0].name, "Noel Gallagher");
+
+    let contact: Contact = serde_json::from_str(
+        r#"{
+            "ContactID": "4e1753b9-018a-4775-b6aa-1bc7871cfee3",
+            "Name": "Noel Gallagher",
+            "ContactGroups": [
+                {
+                    "ContactGroupID": "17b44ed7-4389-4162-91cb-3dd5766e4e22",
+                    "Name": "Oasis",
+                    "Status": "ACTIVE",
+                    "Contacts": []
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(contact.contact_groups.len(), 1);
+    assert_eq!(contact.contact_groups[0].name, "Oasis");
+}
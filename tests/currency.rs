@@ -0,0 +1,81 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use rust_decimal::Decimal;
+use xero_rs::CurrencyCode;
+use xero_rs::currency::{self, ListParameters};
+
+#[tokio::test]
+async fn list_currencies() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let currencies = client.currencies().list_all().await?;
+    info!("Found {} currencies", currencies.len());
+    for currency in &currencies {
+        debug!("{}: {:?}", currency.code, currency.description);
+    }
+
+    if let Some(first) = currencies.first() {
+        let params = ListParameters::builder().with_code(&first.code);
+        let filtered = client.currencies().list(params).await?;
+        assert!(filtered.iter().all(|c| c.code == first.code));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn currency_code_parsing() {
+    let code: CurrencyCode = "nzd".parse().unwrap();
+    assert_eq!(code, CurrencyCode::NZD);
+    assert_eq!(code.as_str(), "NZD");
+
+    assert!("NZ".parse::<CurrencyCode>().is_err());
+    assert!("US1".parse::<CurrencyCode>().is_err());
+    assert!(CurrencyCode::try_from("DOLLARS").is_err());
+}
+
+#[test]
+fn currency_code_serde_roundtrip() {
+    let code: CurrencyCode = serde_json::from_str("\"AUD\"").unwrap();
+    assert_eq!(code, CurrencyCode::AUD);
+    assert_eq!(
+        serde_json::to_string(&CurrencyCode::GBP).unwrap(),
+        "\"GBP\""
+    );
+}
+
+#[test]
+fn base_currency_conversion() {
+    // 1 NZD = 0.6 USD, so a USD 150.00 invoice is NZD 250.00
+    assert_eq!(
+        currency::to_base_currency(Decimal::new(15000, 2), Some(Decimal::new(6, 1))),
+        Decimal::new(25000, 2)
+    );
+
+    // Results are rounded to two decimal places
+    assert_eq!(
+        currency::to_base_currency(Decimal::new(100, 0), Some(Decimal::new(3, 0))),
+        Decimal::new(3333, 2)
+    );
+
+    // Missing or zero rates leave the amount unchanged
+    assert_eq!(
+        currency::to_base_currency(Decimal::new(100, 0), None),
+        Decimal::new(100, 0)
+    );
+    assert_eq!(
+        currency::to_base_currency(Decimal::new(100, 0), Some(Decimal::ZERO)),
+        Decimal::new(100, 0)
+    );
+}
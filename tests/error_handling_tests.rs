@@ -1,5 +1,6 @@
 use serde_json::json;
-use xero_rs::error::{ErrorType, Response as ErrorResponse};
+use tracing_error::SpanTrace;
+use xero_rs::error::{Error, ErrorType, Response as ErrorResponse};
 
 #[test]
 fn test_query_parse_exception_handling() {
@@ -165,3 +166,52 @@ fn test_all_error_types_deserialize() {
         );
     }
 }
+
+#[test]
+fn test_timesheet_validation_errors_map_to_request_index() {
+    // Xero echoes every timesheet of a rejected batch back in request order
+    let error_json = json!({
+        "ErrorNumber": 10,
+        "Type": "ValidationException",
+        "Message": "A validation error occurred",
+        "Timesheets": [
+            {
+                "EmployeeID": "b34e89ff-770d-4099-b7e5-f968767118bc",
+                "StartDate": "/Date(1573171200000+0000)/",
+                "EndDate": "/Date(1573689600000+0000)/",
+                "Status": "DRAFT",
+                "Hours": 8.0,
+                "TimesheetLines": []
+            },
+            {
+                "EmployeeID": "7aa04979-ded5-4f04-8f1d-5b7ab4e85a8e",
+                "StartDate": "/Date(1573171200000+0000)/",
+                "EndDate": "/Date(1573689600000+0000)/",
+                "Status": "DRAFT",
+                "Hours": 0.0,
+                "TimesheetLines": [],
+                "ValidationErrors": [{
+                    "Message": "The employee's payroll calendar does not match the timesheet period"
+                }]
+            }
+        ]
+    });
+
+    let response: ErrorResponse = serde_json::from_value(error_json).unwrap();
+    let error = Error::API {
+        response,
+        span_trace: SpanTrace::capture(),
+    };
+
+    let failures = error.timesheet_validation_errors();
+    assert_eq!(failures.len(), 1);
+    let (index, timesheet) = failures[0];
+    assert_eq!(index, 1);
+    assert_eq!(
+        timesheet.employee_id.map(|id| id.to_string()).as_deref(),
+        Some("7aa04979-ded5-4f04-8f1d-5b7ab4e85a8e")
+    );
+
+    let not_found = Error::InvalidEndpoint;
+    assert!(not_found.timesheet_validation_errors().is_empty());
+}
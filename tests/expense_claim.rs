@@ -0,0 +1,93 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use rust_decimal::Decimal;
+use time::macros::date;
+use uuid::Uuid;
+use xero_rs::expense_claim::{Builder, ExpenseClaim, Status};
+
+#[tokio::test]
+async fn list_expense_claims() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let expense_claims = client.expense_claims().list_all().await?;
+    info!("Found {} expense claims", expense_claims.len());
+
+    if let Some(first) = expense_claims.first() {
+        let expense_claim = client.expense_claims().get(first.expense_claim_id).await?;
+        assert_eq!(expense_claim.expense_claim_id, first.expense_claim_id);
+
+        let history = client
+            .expense_claims()
+            .get_history(first.expense_claim_id)
+            .await?;
+        debug!("Expense claim has {} history records", history.len());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_expense_claim() {
+    let json = r#"{
+        "ExpenseClaimID": "646b15ab-b874-4e13-82ae-f4385b2ac4b6",
+        "Status": "AUTHORISED",
+        "UpdatedDateUTC": "/Date(1710065408000+0000)/",
+        "User": { "UserID": "7cf47fe2-c3dd-4c6b-9895-7ba767ba529c" },
+        "Receipts": [
+            {
+                "ReceiptID": "e59a2c7f-1306-4078-a0f3-73537afcbba9",
+                "Status": "AUTHORISED",
+                "Total": 20.00
+            }
+        ],
+        "Payments": [],
+        "Total": 20.00,
+        "AmountDue": 20.00,
+        "AmountPaid": 0.00,
+        "PaymentDueDate": "/Date(1710979200000+0000)/",
+        "ReportingDate": "/Date(1710028800000+0000)/"
+    }"#;
+
+    let expense_claim: ExpenseClaim = serde_json::from_str(json).unwrap();
+    assert_eq!(expense_claim.status, Status::Authorised);
+    assert_eq!(expense_claim.receipts.len(), 1);
+    assert_eq!(expense_claim.amount_due, Decimal::new(2000, 2));
+    assert_eq!(expense_claim.payment_due_date, Some(date!(2024 - 03 - 21)));
+    assert_eq!(expense_claim.reporting_date, Some(date!(2024 - 03 - 10)));
+}
+
+#[test]
+fn status_transitions() {
+    assert!(Status::Submitted.can_transition_to(Status::Authorised));
+    assert!(Status::Submitted.can_transition_to(Status::Voided));
+    assert!(Status::Authorised.can_transition_to(Status::Voided));
+    assert!(!Status::Authorised.can_transition_to(Status::Paid));
+    assert!(!Status::Paid.can_transition_to(Status::Voided));
+    assert!(!Status::Voided.can_transition_to(Status::Authorised));
+}
+
+#[test]
+fn serialize_builder() {
+    let user_id = Uuid::parse_str("7cf47fe2-c3dd-4c6b-9895-7ba767ba529c").unwrap();
+    let receipt_id = Uuid::parse_str("e59a2c7f-1306-4078-a0f3-73537afcbba9").unwrap();
+    let builder = Builder::new(user_id, [receipt_id]);
+
+    assert_eq!(
+        serde_json::to_value(&builder).unwrap(),
+        serde_json::json!({
+            "User": { "UserID": "7cf47fe2-c3dd-4c6b-9895-7ba767ba529c" },
+            "Receipts": [{ "ReceiptID": "e59a2c7f-1306-4078-a0f3-73537afcbba9" }]
+        })
+    );
+}
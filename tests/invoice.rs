@@ -5,49 +5,16 @@ mod test_utils;
 
 use anyhow::Result;
 use rust_decimal::Decimal;
-use std::env;
 use std::fs;
 use time::macros::date;
-use uuid::Uuid;
 use xero_rs::contact::ContactIdentifier;
 use xero_rs::invoice::{Builder, ListParameters, Type};
-use xero_rs::{KeyPair, line_item::LineAmountType};
-
-/// Try to set up a client. Will return None if the required environment variables are not set.
-async fn try_setup_client() -> Option<xero_rs::Client> {
-    test_utils::do_setup();
-
-    // Check if required environment variables are set
-    let client_id = env::var("XERO_CLIENT_ID").ok()?;
-    let client_secret = env::var("XERO_CLIENT_SECRET").ok()?;
-    let tenant_id_str = env::var("XERO_TENANT_ID").ok()?;
-
-    let tenant_id = match Uuid::parse_str(&tenant_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            warn!("Invalid XERO_TENANT_ID format");
-            return None;
-        }
-    };
-
-    // Create client with credentials and full scopes
-    let mut client = xero_rs::Client::from_client_credentials(
-        KeyPair::new(client_id, Some(client_secret)),
-        xero_rs::Scope::all_accounting(),
-    )
-    .await
-    .ok()?;
-
-    // Set the tenant ID and return the configured client
-    client.set_tenant(Some(tenant_id));
-
-    Some(client)
-}
+use xero_rs::line_item::LineAmountType;
 
 #[tokio::test]
 async fn list_invoices() -> Result<()> {
     // Try to set up the client
-    let mut client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -70,7 +37,7 @@ async fn list_invoices() -> Result<()> {
 #[tokio::test]
 async fn get_invoice() -> Result<()> {
     // Try to set up the client
-    let mut client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -99,7 +66,7 @@ async fn get_invoice() -> Result<()> {
 #[tokio::test]
 async fn create_update_invoice() -> Result<()> {
     // Try to set up the client
-    let mut client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -108,7 +75,7 @@ async fn create_update_invoice() -> Result<()> {
     };
 
     // First get a contact to use
-    let contacts = match client.contacts().list().await {
+    let contacts = match client.contacts().list_all().await {
         Ok(contacts) => contacts,
         Err(e) => {
             info!("Skipping test: Could not retrieve contacts: {}", e);
@@ -203,7 +170,7 @@ async fn create_update_invoice() -> Result<()> {
 #[tokio::test]
 async fn invoice_history() -> Result<()> {
     // Try to set up the client
-    let mut client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -243,7 +210,7 @@ async fn invoice_history() -> Result<()> {
 #[tokio::test]
 async fn invoice_pdf() -> Result<()> {
     // Try to set up the client
-    let mut client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -283,7 +250,7 @@ async fn invoice_pdf() -> Result<()> {
 #[tokio::test]
 async fn invoice_attachments() -> Result<()> {
     // Try to set up the client
-    let mut client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -373,7 +340,7 @@ async fn invoice_attachments() -> Result<()> {
 #[tokio::test]
 async fn invoice_online_url() -> Result<()> {
     // Try to set up the client
-    let mut client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -403,7 +370,7 @@ async fn invoice_online_url() -> Result<()> {
 #[tokio::test]
 async fn invoice_email() -> Result<()> {
     // Try to set up the client
-    let mut client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
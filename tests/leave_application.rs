@@ -3,10 +3,13 @@
 //! These tests require a valid Xero API connection with payroll permissions.
 
 use tracing::{error, info};
+use xero_rs::Error;
 
 mod test_utils;
 
-use xero_rs::payroll::leave_application::{LeavePeriodStatus, ListParameters};
+use xero_rs::payroll::leave_application::{
+    LeaveApplication, LeavePeriodStatus, LeavePeriodsResponse, ListParameters,
+};
 
 #[tokio::test]
 async fn test_list_leave_applications() -> miette::Result<()> {
@@ -265,3 +268,136 @@ async fn test_list_leave_with_employee_filter() -> miette::Result<()> {
     info!("Leave applications filter test completed successfully");
     Ok(())
 }
+
+#[tokio::test]
+async fn test_preview_leave_periods() -> miette::Result<()> {
+    info!("Starting leave period preview test");
+
+    let Some(client) = test_utils::try_setup_client(Some(test_utils::payroll_scopes())).await
+    else {
+        info!("Skipping test: Required environment variables not set");
+        return Ok(());
+    };
+
+    // Preview leave for an existing application so the employee and leave type are valid
+    let leave_apps = match client.leave_applications().list_all().await {
+        Ok(apps) => apps,
+        Err(xero_rs::error::Error::Forbidden(_)) => {
+            info!("Payroll scopes not available, skipping test");
+            return Ok(());
+        }
+        Err(e) => {
+            error!("Failed to list leave applications: {:?}", e);
+            return Err(miette::miette!(
+                "Failed to list leave applications: {:?}",
+                e
+            ));
+        }
+    };
+
+    let Some(app) = leave_apps.first() else {
+        info!("No leave applications found, skipping preview test");
+        return Ok(());
+    };
+
+    match client
+        .leave_applications()
+        .preview_periods(
+            app.employee_id,
+            app.leave_type_id,
+            app.start_date,
+            app.end_date,
+        )
+        .await
+    {
+        Ok(periods) => {
+            info!("Xero calculated {} leave periods", periods.len());
+            for period in &periods {
+                info!(
+                    "Period {:?} to {:?}: {:?} units",
+                    period.pay_period_start_date,
+                    period.pay_period_end_date,
+                    period.number_of_units
+                );
+            }
+        }
+        Err(e) => {
+            error!("Failed to preview leave periods: {:?}", e);
+            return Err(miette::miette!("Failed to preview leave periods: {:?}", e));
+        }
+    }
+
+    test_utils::do_cleanup().await;
+    info!("Leave period preview test completed successfully");
+    Ok(())
+}
+
+#[test]
+fn test_leave_periods_response_deserializes() {
+    let body = r#"{
+        "LeavePeriods": [
+            {
+                "PayPeriodStartDate": "/Date(1735689600000+0000)/",
+                "PayPeriodEndDate": "/Date(1736899200000+0000)/",
+                "NumberOfUnits": 22.8
+            },
+            {
+                "PayPeriodStartDate": "/Date(1736985600000+0000)/",
+                "PayPeriodEndDate": "/Date(1738195200000+0000)/",
+                "NumberOfUnits": 7.6
+            }
+        ]
+    }"#;
+
+    let response: LeavePeriodsResponse =
+        serde_json::from_str(body).expect("leave periods should deserialize");
+    assert_eq!(response.leave_periods.len(), 2);
+    assert_eq!(response.leave_periods[0].number_of_units, Some(22.8));
+    assert_eq!(
+        response.leave_periods[1].pay_period_end_date,
+        Some(time::macros::date!(2025 - 01 - 30))
+    );
+    assert_eq!(response.leave_periods[1].leave_period_status, None);
+}
+
+#[test]
+fn test_only_approved_unprocessed_leave_is_cancellable() {
+    let application = |statuses: &[&str]| -> LeaveApplication {
+        let periods: Vec<serde_json::Value> = statuses
+            .iter()
+            .map(|status| {
+                serde_json::json!({
+                    "PayPeriodStartDate": "/Date(1735689600000+0000)/",
+                    "PayPeriodEndDate": "/Date(1736899200000+0000)/",
+                    "NumberOfUnits": 7.6,
+                    "LeavePeriodStatus": status,
+                })
+            })
+            .collect();
+        serde_json::from_value(serde_json::json!({
+            "LeaveApplicationID": "e0eb6747-7c17-4075-b804-989f8d4e5d39",
+            "EmployeeID": "cdfb8371-0b21-4b8a-8903-1024df6c391e",
+            "LeaveTypeID": "184ea8f7-d143-46dd-bef3-0c60e1aa6fca",
+            "StartDate": "/Date(1735689600000+0000)/",
+            "EndDate": "/Date(1738195200000+0000)/",
+            "LeavePeriods": periods,
+        }))
+        .expect("leave application should deserialize")
+    };
+
+    assert!(application(&["SCHEDULED"]).is_cancellable());
+    assert!(application(&["PROCESSED", "SCHEDULED"]).is_cancellable());
+    assert!(!application(&["PROCESSED", "PROCESSED"]).is_cancellable());
+    assert!(!application(&["REQUESTED"]).is_cancellable());
+    assert!(!application(&["REJECTED", "REJECTED"]).is_cancellable());
+    assert!(!application(&[]).is_cancellable());
+
+    assert!(matches!(
+        application(&["PROCESSED"]).cancel_error(),
+        Some(Error::InvalidStatusTransition { from, .. }) if from == "PROCESSED"
+    ));
+    assert!(matches!(
+        application(&[]).cancel_error(),
+        Some(Error::LeaveApplicationWithoutPeriods(_))
+    ));
+}
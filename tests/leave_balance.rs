@@ -0,0 +1,236 @@
+use anyhow::Result;
+use rust_decimal_macros::dec;
+use time::macros::date;
+use xero_rs::payroll::{
+    employee::LeaveBalance,
+    leave_application::{LeavePeriod, LeavePeriodStatus},
+    leave_balance::LeaveTypeBalance,
+    settings::{
+        leave_types::LeaveType,
+        pay_calendar::{PayCalendar, PayPeriod},
+    },
+};
+
+const ANNUAL_LEAVE_ID: &str = "544d9292-4329-4512-bfff-a9f15236d776";
+const SICK_LEAVE_ID: &str = "8e0fe7fc-55bf-4e51-a3b5-a7d6fd9bb3e5";
+
+fn calendar(calendar_type: &str, start_date: &str) -> Result<PayCalendar> {
+    Ok(serde_json::from_value(serde_json::json!({
+        "PayrollCalendarID": "bfac31bd-ea62-4fc8-a5e7-7965d9504b15",
+        "Name": "Test calendar",
+        "CalendarType": calendar_type,
+        "StartDate": start_date,
+        "PaymentDate": start_date,
+    }))?)
+}
+
+fn leave_period(units: f64, end_date: time::Date, status: LeavePeriodStatus) -> LeavePeriod {
+    LeavePeriod {
+        number_of_units: Some(units),
+        pay_period_start_date: None,
+        pay_period_end_date: Some(end_date),
+        leave_period_status: Some(status),
+    }
+}
+
+fn annual_leave_balance() -> Result<Vec<LeaveTypeBalance>> {
+    let balances: Vec<LeaveBalance> = serde_json::from_str(&format!(
+        r#"[
+            {{"LeaveName": "Annual Leave", "LeaveTypeID": "{ANNUAL_LEAVE_ID}",
+              "NumberOfUnits": 40.0, "TypeOfUnits": "Hours"}},
+            {{"LeaveTypeID": "{SICK_LEAVE_ID}", "NumberOfUnits": 12.5}}
+        ]"#
+    ))?;
+    let leave_types: Vec<LeaveType> = serde_json::from_str(&format!(
+        r#"[
+            {{"LeaveTypeID": "{ANNUAL_LEAVE_ID}", "Name": "Annual Leave", "TypeOfUnits": "Hours",
+              "NormalEntitlement": 152.0, "LeaveCategoryCode": "ANNUALLEAVE"}},
+            {{"LeaveTypeID": "{SICK_LEAVE_ID}", "Name": "Personal/Carer's Leave",
+              "TypeOfUnits": "Hours", "LeaveCategoryCode": "PERSONALCARERSLEAVE"}}
+        ]"#
+    ))?;
+
+    Ok(LeaveTypeBalance::from_balances(balances, &leave_types))
+}
+
+#[test]
+fn weekly_pay_periods_run_back_to_back() -> Result<()> {
+    let calendar = calendar("WEEKLY", "/Date(1735689600000+0000)/")?;
+    let periods: Vec<PayPeriod> = calendar.pay_periods().take(2).collect();
+
+    assert_eq!(
+        periods,
+        vec![
+            PayPeriod {
+                start_date: date!(2025 - 01 - 01),
+                end_date: date!(2025 - 01 - 07),
+            },
+            PayPeriod {
+                start_date: date!(2025 - 01 - 08),
+                end_date: date!(2025 - 01 - 14),
+            },
+        ]
+    );
+    assert!(periods[1].contains(date!(2025 - 01 - 10)));
+    Ok(())
+}
+
+#[test]
+fn monthly_pay_periods_keep_the_day_of_month() -> Result<()> {
+    // 2024-01-31
+    let calendar = calendar("MONTHLY", "/Date(1706659200000+0000)/")?;
+    let starts: Vec<time::Date> = calendar
+        .pay_periods()
+        .take(3)
+        .map(|period| period.start_date)
+        .collect();
+
+    assert_eq!(
+        starts,
+        vec![
+            date!(2024 - 01 - 31),
+            date!(2024 - 02 - 29),
+            date!(2024 - 03 - 31),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn twice_monthly_pay_periods_split_each_month() -> Result<()> {
+    // 2025-01-01
+    let calendar = calendar("TWICEMONTHLY", "/Date(1735689600000+0000)/")?;
+    let periods: Vec<PayPeriod> = calendar.pay_periods().take(3).collect();
+
+    assert_eq!(periods[0].end_date, date!(2025 - 01 - 15));
+    assert_eq!(periods[1].start_date, date!(2025 - 01 - 16));
+    assert_eq!(periods[1].end_date, date!(2025 - 01 - 31));
+    assert_eq!(periods[2].start_date, date!(2025 - 02 - 01));
+    Ok(())
+}
+
+#[test]
+fn twice_monthly_pay_periods_starting_on_the_16th_keep_month_halves() -> Result<()> {
+    // 2025-01-16
+    let calendar = calendar("TWICEMONTHLY", "/Date(1736985600000+0000)/")?;
+    let periods: Vec<PayPeriod> = calendar.pay_periods().take(4).collect();
+
+    assert_eq!(
+        periods,
+        vec![
+            PayPeriod {
+                start_date: date!(2025 - 01 - 16),
+                end_date: date!(2025 - 01 - 31),
+            },
+            PayPeriod {
+                start_date: date!(2025 - 02 - 01),
+                end_date: date!(2025 - 02 - 15),
+            },
+            PayPeriod {
+                start_date: date!(2025 - 02 - 16),
+                end_date: date!(2025 - 02 - 28),
+            },
+            PayPeriod {
+                start_date: date!(2025 - 03 - 01),
+                end_date: date!(2025 - 03 - 15),
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn twice_monthly_pay_periods_cross_february_in_a_leap_year() -> Result<()> {
+    // 2024-02-01
+    let calendar = calendar("TWICEMONTHLY", "/Date(1706745600000+0000)/")?;
+    let periods: Vec<PayPeriod> = calendar.pay_periods().take(3).collect();
+
+    assert_eq!(periods[0].end_date, date!(2024 - 02 - 15));
+    assert_eq!(periods[1].start_date, date!(2024 - 02 - 16));
+    assert_eq!(periods[1].end_date, date!(2024 - 02 - 29));
+    assert_eq!(periods[2].start_date, date!(2024 - 03 - 01));
+    assert_eq!(periods[2].end_date, date!(2024 - 03 - 15));
+    Ok(())
+}
+
+#[test]
+fn leave_balances_are_paired_with_leave_types() -> Result<()> {
+    let balances = annual_leave_balance()?;
+
+    assert_eq!(balances.len(), 2);
+    assert_eq!(balances[0].name.as_deref(), Some("Annual Leave"));
+    assert_eq!(balances[0].balance, dec!(40));
+    assert_eq!(
+        balances[0]
+            .leave_type
+            .as_ref()
+            .and_then(|leave_type| leave_type.normal_entitlement),
+        Some(152.0)
+    );
+    // Missing name and units are filled in from the leave type
+    assert_eq!(balances[1].name.as_deref(), Some("Personal/Carer's Leave"));
+    assert_eq!(balances[1].type_of_units.as_deref(), Some("Hours"));
+    Ok(())
+}
+
+#[test]
+fn projection_accrues_per_period_and_takes_booked_leave() -> Result<()> {
+    let balances = annual_leave_balance()?;
+    let annual_leave = &balances[0];
+    // Fortnightly from 2025-01-01
+    let calendar = calendar("FORTNIGHTLY", "/Date(1735689600000+0000)/")?;
+
+    assert_eq!(
+        annual_leave.accrual_per_period(&calendar.calendar_type),
+        dec!(152) / dec!(26)
+    );
+
+    let leave_periods = vec![
+        leave_period(7.6, date!(2025 - 01 - 28), LeavePeriodStatus::Scheduled),
+        leave_period(15.2, date!(2025 - 02 - 11), LeavePeriodStatus::Requested),
+        // Already deducted from the balance, or never taken
+        leave_period(7.6, date!(2024 - 12 - 31), LeavePeriodStatus::Processed),
+        leave_period(7.6, date!(2025 - 02 - 11), LeavePeriodStatus::Rejected),
+        // After the projection date
+        leave_period(7.6, date!(2025 - 03 - 11), LeavePeriodStatus::Scheduled),
+    ];
+
+    let projection = annual_leave.project(&calendar, date!(2025 - 02 - 11), &leave_periods);
+    assert_eq!(projection.pay_periods, 3);
+    assert_eq!(projection.accrued, dec!(17.5385));
+    assert_eq!(projection.taken, dec!(22.8));
+    assert_eq!(projection.balance, dec!(34.7385));
+
+    // Leave types without a normal entitlement do not accrue
+    let sick_leave = balances[1].project(&calendar, date!(2025 - 02 - 11), &[]);
+    assert_eq!(sick_leave.accrued, dec!(0));
+    assert_eq!(sick_leave.balance, dec!(12.5));
+    Ok(())
+}
+
+#[test]
+fn leave_projection_includes_a_request_ending_mid_period() -> Result<()> {
+    let balances = annual_leave_balance()?;
+    let annual_leave = &balances[0];
+    // Fortnightly from 2025-01-01, so the third period runs 2025-01-29 to 2025-02-11
+    let calendar = calendar("FORTNIGHTLY", "/Date(1735689600000+0000)/")?;
+
+    // Leave ending on 2025-02-05 is paid in the period ending 2025-02-11
+    let leave_periods = vec![leave_period(
+        22.8,
+        date!(2025 - 02 - 11),
+        LeavePeriodStatus::Requested,
+    )];
+
+    let projection = annual_leave.project_leave(&calendar, date!(2025 - 02 - 05), &leave_periods);
+    assert_eq!(projection.date, date!(2025 - 02 - 11));
+    assert_eq!(projection.pay_periods, 3);
+    assert_eq!(projection.taken, dec!(22.8));
+    assert_eq!(projection.balance, dec!(34.7385));
+
+    // Leave ending before the calendar starts is projected to its own end date
+    let projection = annual_leave.project_leave(&calendar, date!(2024 - 12 - 20), &[]);
+    assert_eq!(projection.date, date!(2024 - 12 - 20));
+    assert_eq!(projection.pay_periods, 0);
+    Ok(())
+}
@@ -5,50 +5,16 @@ mod test_utils;
 
 use anyhow::Result;
 use rust_decimal::Decimal;
-use std::env;
-use uuid::Uuid;
 use xero_rs::{
-    KeyPair,
     contact::ContactIdentifier,
     invoice::{Builder, Type},
     line_item::LineAmountType,
 };
 
-/// Try to set up a client. Will return None if the required environment variables are not set.
-async fn try_setup_client() -> Option<xero_rs::Client> {
-    test_utils::do_setup();
-
-    // Check if required environment variables are set
-    let client_id = env::var("XERO_CLIENT_ID").ok()?;
-    let client_secret = env::var("XERO_CLIENT_SECRET").ok()?;
-    let tenant_id_str = env::var("XERO_TENANT_ID").ok()?;
-
-    let tenant_id = match Uuid::parse_str(&tenant_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            warn!("Invalid XERO_TENANT_ID format");
-            return None;
-        }
-    };
-
-    // Create client with credentials and full scopes
-    let client = xero_rs::Client::from_client_credentials(
-        KeyPair::new(client_id, Some(client_secret)),
-        xero_rs::Scope::all_accounting(),
-    )
-    .await
-    .ok()?;
-
-    // Set the tenant ID and return the configured client
-    client.set_tenant(Some(tenant_id)).await;
-
-    Some(client)
-}
-
 #[tokio::test]
 async fn test_line_item_with_discount_amount() -> Result<()> {
     // Try to set up the client
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -57,7 +23,7 @@ async fn test_line_item_with_discount_amount() -> Result<()> {
     };
 
     // First get a contact to use
-    let contacts = match client.contacts().list().await {
+    let contacts = match client.contacts().list_all().await {
         Ok(contacts) => contacts,
         Err(e) => {
             info!("Skipping test: Could not retrieve contacts: {}", e);
@@ -0,0 +1,109 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use uuid::Uuid;
+use xero_rs::line_item::LineItem;
+use xero_rs::linked_transaction::{
+    Builder, LinkedTransaction, ListParameters, SourceTransactionType, Status,
+};
+
+#[tokio::test]
+async fn list_linked_transactions() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let linked_transactions = client.linked_transactions().list_all().await?;
+    info!("Found {} linked transactions", linked_transactions.len());
+
+    if let Some(first) = linked_transactions.first() {
+        let linked_transaction = client
+            .linked_transactions()
+            .get(first.linked_transaction_id)
+            .await?;
+        assert_eq!(
+            linked_transaction.linked_transaction_id,
+            first.linked_transaction_id
+        );
+
+        let params =
+            ListParameters::builder().with_source_transaction_id(first.source_transaction_id);
+        let from_source = client.linked_transactions().list(params).await?;
+        assert!(
+            from_source
+                .iter()
+                .all(|l| l.source_transaction_id == first.source_transaction_id)
+        );
+    }
+
+    let params = ListParameters::builder().with_status(Status::Approved);
+    let approved = client.linked_transactions().list(params).await?;
+    assert!(approved.iter().all(|l| l.status == Status::Approved));
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_linked_transaction() {
+    let json = r#"{
+        "LinkedTransactionID": "e9684b1a-7634-4d8a-a7e3-6eec1c8a8d5f",
+        "SourceTransactionID": "7f8a0d66-6a19-4c1a-a4df-a55b4d6a8b6c",
+        "SourceLineItemID": "3b6a8b8f-4e18-4c1e-8d7e-7a2d6d6c6e3b",
+        "ContactID": "c7127731-d324-4e26-a03e-854ce9e0b28b",
+        "Status": "ONDRAFT",
+        "Type": "BILLABLEEXPENSE",
+        "UpdatedDateUTC": "/Date(1710065408000+0000)/",
+        "SourceTransactionTypeCode": "ACCPAY"
+    }"#;
+
+    let linked_transaction: LinkedTransaction = serde_json::from_str(json).unwrap();
+    assert_eq!(linked_transaction.status, Status::OnDraft);
+    assert_eq!(
+        linked_transaction.source_transaction_type_code,
+        Some(SourceTransactionType::AccPay)
+    );
+    assert!(linked_transaction.target_transaction_id.is_none());
+}
+
+#[test]
+fn serialize_builder_from_line_item() {
+    let line_item: LineItem = serde_json::from_str(
+        r#"{ "LineItemID": "3b6a8b8f-4e18-4c1e-8d7e-7a2d6d6c6e3b", "Description": "Subcontractor" }"#,
+    )
+    .unwrap();
+    let bill_id = Uuid::parse_str("7f8a0d66-6a19-4c1a-a4df-a55b4d6a8b6c").unwrap();
+    let contact_id = Uuid::parse_str("c7127731-d324-4e26-a03e-854ce9e0b28b").unwrap();
+
+    let builder = Builder::from_line_item(bill_id, &line_item).with_contact_id(contact_id);
+    assert_eq!(
+        serde_json::to_value(&builder).unwrap(),
+        serde_json::json!({
+            "SourceTransactionID": "7f8a0d66-6a19-4c1a-a4df-a55b4d6a8b6c",
+            "SourceLineItemID": "3b6a8b8f-4e18-4c1e-8d7e-7a2d6d6c6e3b",
+            "ContactID": "c7127731-d324-4e26-a03e-854ce9e0b28b"
+        })
+    );
+}
+
+#[test]
+fn serialize_list_parameters() {
+    let contact_id = Uuid::parse_str("c7127731-d324-4e26-a03e-854ce9e0b28b").unwrap();
+    let params = ListParameters::builder()
+        .with_contact_id(contact_id)
+        .with_status(Status::Billed);
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({
+            "ContactID": "c7127731-d324-4e26-a03e-854ce9e0b28b",
+            "Status": "BILLED"
+        })
+    );
+}
@@ -0,0 +1,124 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use rust_decimal::Decimal;
+use time::macros::date;
+use uuid::Uuid;
+use xero_rs::allocation;
+use xero_rs::overpayment::{ListParameters, Overpayment, Status, Type};
+use xero_rs::payment::{AccountIdentifier, Refund};
+
+#[tokio::test]
+async fn list_overpayments() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let overpayments = client.overpayments().list_all().await?;
+    info!("Found {} overpayments", overpayments.len());
+
+    if let Some(first) = overpayments.first() {
+        let overpayment = client.overpayments().get(first.overpayment_id).await?;
+        assert_eq!(overpayment.overpayment_id, first.overpayment_id);
+
+        let history = client
+            .overpayments()
+            .get_history(first.overpayment_id)
+            .await?;
+        debug!("Overpayment has {} history records", history.len());
+    }
+
+    let params = ListParameters::builder().with_status(Status::Authorised);
+    let authorised = client.overpayments().list(params).await?;
+    assert!(authorised.iter().all(|o| o.status == Status::Authorised));
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_overpayment() {
+    let json = r#"{
+        "Contact": { "ContactID": "c7127731-d324-4e26-a03e-854ce9e0b28b", "Name": "Mr Contact" },
+        "DateString": "2024-03-10T00:00:00",
+        "Status": "AUTHORISED",
+        "LineAmountTypes": "Inclusive",
+        "SubTotal": 86.96,
+        "TotalTax": 13.04,
+        "Total": 100.00,
+        "UpdatedDateUTC": "/Date(1710065408000+0000)/",
+        "CurrencyCode": "NZD",
+        "Type": "RECEIVE-OVERPAYMENT",
+        "OverpaymentID": "aea95d78-ea48-456b-9b08-6bc012600072",
+        "CurrencyRate": 1.0,
+        "RemainingCredit": 80.00,
+        "Allocations": [
+            {
+                "AllocationID": "b12335f4-a1e5-4431-aeb4-488e5547558e",
+                "Amount": 20.00,
+                "Date": "2024-03-11T00:00:00",
+                "Invoice": { "InvoiceID": "e9a6e1a1-f8c6-4b3b-9e1e-9c1a5b0e1b72", "InvoiceNumber": "INV-0001" }
+            }
+        ],
+        "HasAttachments": false
+    }"#;
+
+    let overpayment: Overpayment = serde_json::from_str(json).unwrap();
+    assert_eq!(overpayment.r#type, Type::Receive);
+    assert_eq!(overpayment.status, Status::Authorised);
+    assert_eq!(overpayment.date, Some(date!(2024 - 03 - 10)));
+    assert_eq!(overpayment.remaining_credit, Some(Decimal::new(8000, 2)));
+    assert_eq!(overpayment.allocations.len(), 1);
+    assert_eq!(
+        overpayment.allocations[0]
+            .invoice
+            .as_ref()
+            .and_then(|i| i.invoice_number.as_deref()),
+        Some("INV-0001")
+    );
+    assert!(overpayment.payments.is_empty());
+}
+
+#[test]
+fn serialize_allocation_and_refund() {
+    let invoice_id = Uuid::parse_str("e9a6e1a1-f8c6-4b3b-9e1e-9c1a5b0e1b72").unwrap();
+    let allocation = allocation::Builder::new(invoice_id, Decimal::new(2000, 2))
+        .with_date(date!(2024 - 03 - 11));
+    assert_eq!(
+        serde_json::to_value(&allocation).unwrap(),
+        serde_json::json!({
+            "Invoice": { "InvoiceID": "e9a6e1a1-f8c6-4b3b-9e1e-9c1a5b0e1b72" },
+            "Amount": "20.00",
+            "Date": "2024-03-11"
+        })
+    );
+
+    let refund = Refund::new(
+        AccountIdentifier::Code("090".to_string()),
+        date!(2024 - 03 - 12),
+        Decimal::new(8000, 2),
+    )
+    .with_reference("Refund");
+    assert_eq!(
+        serde_json::to_value(&refund).unwrap(),
+        serde_json::json!({
+            "Account": { "Code": "090" },
+            "Date": "2024-03-12",
+            "Amount": "80.00",
+            "Reference": "Refund"
+        })
+    );
+}
+
+#[test]
+fn status_filter_uses_the_serde_status_name() {
+    let params = ListParameters::builder().with_status(Status::Voided);
+    assert_eq!(params.r#where.as_deref(), Some("Status==\"VOIDED\""));
+}
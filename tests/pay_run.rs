@@ -0,0 +1,245 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use rust_decimal::Decimal;
+use time::macros::date;
+use uuid::Uuid;
+use xero_rs::payroll::pay_run::{PayRun, PayRunStatus, PostPayRun, UpdatePayRun};
+use xero_rs::payroll::payslip::{
+    DeductionCalculationType, DeductionLine, EarningsLine, Payslip, SuperCalculationType,
+    SuperContributionType, UpdatePayslip,
+};
+
+#[tokio::test]
+async fn list_and_get_pay_runs() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::payroll_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let pay_runs = match client.pay_runs().list_all().await {
+        Ok(pay_runs) => pay_runs,
+        Err(xero_rs::error::Error::Forbidden(_)) => {
+            info!("Payroll scopes not available, skipping test");
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    info!("Found {} pay runs", pay_runs.len());
+
+    if let Some(first) = pay_runs.first() {
+        let pay_run = client.pay_runs().get(first.pay_run_id).await?;
+        assert_eq!(pay_run.pay_run_id, first.pay_run_id);
+        info!(
+            "Pay run cost {} across {} payslips (reconciles: {})",
+            pay_run.employer_cost(),
+            pay_run.payslips.len(),
+            pay_run.payslips_reconcile()
+        );
+
+        if let Some(summary) = pay_run.payslips.first() {
+            let payslip = client.payslips().get(summary.payslip_id).await?;
+            assert_eq!(payslip.employee_id, summary.employee_id);
+        }
+    }
+
+    Ok(())
+}
+
+const PAY_RUN: &str = r#"{
+    "PayRuns": [{
+        "PayRunID": "21d6317b-5319-4b3d-8d78-48904db6b665",
+        "PayrollCalendarID": "78bb86b9-e1ea-47ac-b75d-f087a81931de",
+        "PayRunPeriodStartDate": "/Date(1572566400000+0000)/",
+        "PayRunPeriodEndDate": "/Date(1573084800000+0000)/",
+        "PaymentDate": "/Date(1573171200000+0000)/",
+        "PayRunStatus": "DRAFT",
+        "Wages": 2000.00,
+        "Deductions": 50.00,
+        "Tax": 400.00,
+        "Super": 190.00,
+        "Reimbursement": 25.00,
+        "NetPay": 1575.00,
+        "UpdatedDateUTC": "/Date(1573077687000+0000)/",
+        "Payslips": [
+            {
+                "EmployeeID": "cdfb8371-0b21-4b8a-8903-1024df6c391e",
+                "PayslipID": "c81e8bcc-56b0-4740-b46b-767753a6ee45",
+                "FirstName": "Albus",
+                "LastName": "Dumbledore",
+                "Wages": 1200.00,
+                "Deductions": 50.00,
+                "Tax": 250.00,
+                "Super": 114.00,
+                "Reimbursements": 25.00,
+                "NetPay": 925.00
+            },
+            {
+                "EmployeeID": "7aa04979-ded5-44d9-b09a-793749425844",
+                "PayslipID": "9f6b4e4d-6ae5-4d2b-a5e8-3e1b1a3b0d5c",
+                "FirstName": "Minerva",
+                "LastName": "McGonagall",
+                "Wages": 800.00,
+                "Deductions": 0.00,
+                "Tax": 150.00,
+                "Super": 76.00,
+                "Reimbursements": 0.00,
+                "NetPay": 650.00
+            }
+        ]
+    }]
+}"#;
+
+#[test]
+fn deserialize_pay_run() -> Result<()> {
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Response {
+        pay_runs: Vec<PayRun>,
+    }
+
+    let response: Response = serde_json::from_str(PAY_RUN)?;
+    let pay_run = &response.pay_runs[0];
+
+    assert_eq!(pay_run.pay_run_status, PayRunStatus::Draft);
+    assert_eq!(
+        pay_run.pay_run_period_start_date,
+        Some(date!(2019 - 11 - 01))
+    );
+    assert_eq!(pay_run.payment_date, Some(date!(2019 - 11 - 08)));
+    assert_eq!(pay_run.payslips.len(), 2);
+    assert_eq!(pay_run.superannuation, Decimal::new(190, 0));
+    assert_eq!(pay_run.employer_cost(), Decimal::new(2215, 0));
+    assert!(pay_run.payslips_reconcile());
+
+    let mut unreconciled = pay_run.clone();
+    unreconciled.payslips[1].net_pay = Decimal::new(600, 0);
+    assert!(!unreconciled.payslips_reconcile());
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_payslip_lines() -> Result<()> {
+    // Example response from GET /Payslip/{PayslipID} in the OpenAPI spec
+    let json = r#"{
+        "EmployeeID": "cdfb8371-0b21-4b8a-8903-1024df6c391e",
+        "PayslipID": "c81e8bcc-56b0-4740-b46b-767753a6ee45",
+        "FirstName": "Albus",
+        "LastName": "Dumbledore",
+        "Tax": 0,
+        "NetPay": 1.4,
+        "UpdatedDateUTC": "/Date(1573692155000+0000)/",
+        "EarningsLines": [
+            {"EarningsRateID": "ab874dfb-ab09-4c91-954e-43acf6fc23b4", "RatePerUnit": 3, "NumberOfUnits": 1.8}
+        ],
+        "LeaveEarningsLines": [
+            {"EarningsRateID": "ab874dfb-ab09-4c91-954e-43acf6fc23b4", "RatePerUnit": 0, "NumberOfUnits": 0.6, "PayOutType": "DEFAULT"},
+            {"EarningsRateID": "ab874dfb-ab09-4c91-954e-43acf6fc23b4", "RatePerUnit": 3, "NumberOfUnits": 0.6, "PayOutType": "CASHED_OUT"}
+        ],
+        "TimesheetEarningsLines": [],
+        "DeductionLines": [
+            {"Amount": 4, "CalculationType": "FIXEDAMOUNT", "DeductionTypeID": "ed05ea82-e40a-4eb6-9c2e-4b3c03e7e938"}
+        ],
+        "LeaveAccrualLines": [
+            {"LeaveTypeID": "184ea8f7-d143-46dd-bef3-0c60e1aa6fca", "NumberOfUnits": 0.0769, "AutoCalculate": true}
+        ],
+        "ReimbursementLines": [
+            {"ReimbursementTypeID": "aa8cfa40-d872-4be0-8a94-bb7f00962f74", "Description": "boo", "ExpenseAccount": "850", "Amount": 55}
+        ],
+        "SuperannuationLines": [
+            {
+                "ContributionType": "SGC",
+                "CalculationType": "STATUTORY",
+                "MinimumMonthlyEarnings": 450,
+                "ExpenseAccountCode": "478",
+                "LiabilityAccountCode": "826",
+                "PaymentDateForThisPeriod": "/Date(1580169600000+0000)/",
+                "Amount": 0
+            }
+        ],
+        "TaxLines": [
+            {
+                "PayslipTaxLineID": "c129696e-36ef-4677-a54c-96095787ca20",
+                "TaxTypeName": "PAYG Tax",
+                "Description": "No tax file number (Australian resident)",
+                "Amount": 0,
+                "LiabilityAccount": "825"
+            }
+        ]
+    }"#;
+
+    let payslip: Payslip = serde_json::from_str(json)?;
+    assert_eq!(
+        payslip.earnings_lines[0].number_of_units,
+        Some(Decimal::new(18, 1))
+    );
+    assert_eq!(payslip.leave_earnings_lines.len(), 2);
+    assert_eq!(
+        payslip.deduction_lines[0].calculation_type,
+        DeductionCalculationType::FixedAmount
+    );
+    let super_line = &payslip.superannuation_lines[0];
+    assert_eq!(super_line.super_membership_id, None);
+    assert_eq!(super_line.contribution_type, SuperContributionType::Sgc);
+    assert_eq!(super_line.calculation_type, SuperCalculationType::Statutory);
+    assert_eq!(
+        super_line.payment_date_for_this_period,
+        Some(date!(2020 - 01 - 28))
+    );
+    assert!(payslip.timesheet_earnings_lines.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn serialize_pay_run_and_payslip_requests() -> Result<()> {
+    let calendar_id = Uuid::new_v4();
+    let request = PostPayRun::new(calendar_id);
+    let json = serde_json::to_value(&request)?;
+    assert_eq!(json["PayrollCalendarID"], calendar_id.to_string());
+    assert!(json.get("PayRunPeriodStartDate").is_none());
+
+    let request = request.with_period(
+        date!(2024 - 07 - 01),
+        date!(2024 - 07 - 07),
+        date!(2024 - 07 - 09),
+    );
+    let json = serde_json::to_value(&request)?;
+    assert_eq!(json["PayRunPeriodStartDate"], "2024-07-01");
+    assert_eq!(json["PaymentDate"], "2024-07-09");
+
+    let update = UpdatePayRun::new(Uuid::new_v4()).with_status(PayRunStatus::Posted);
+    let json = serde_json::to_value(&update)?;
+    assert_eq!(json["PayRunStatus"], "POSTED");
+    assert!(json.get("PayslipMessage").is_none());
+
+    let rate_id = Uuid::new_v4();
+    let update = UpdatePayslip::new()
+        .with_earnings_lines(vec![
+            EarningsLine::units(rate_id, Decimal::new(38, 0))
+                .with_rate_per_unit(Decimal::new(3250, 2)),
+        ])
+        .with_deduction_lines(vec![DeductionLine::fixed_amount(
+            Uuid::new_v4(),
+            Decimal::new(20, 0),
+        )]);
+    let json = serde_json::to_value(&update)?;
+    assert_eq!(
+        json["EarningsLines"][0]["EarningsRateID"],
+        rate_id.to_string()
+    );
+    assert_eq!(json["EarningsLines"][0]["NumberOfUnits"], "38");
+    assert!(json["EarningsLines"][0].get("FixedAmount").is_none());
+    assert_eq!(json["DeductionLines"][0]["CalculationType"], "FIXEDAMOUNT");
+    assert!(json.get("TaxLines").is_none());
+
+    Ok(())
+}
@@ -0,0 +1,360 @@
+#![cfg(feature = "payroll-nz")]
+
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use rust_decimal_macros::dec;
+use time::macros::{date, datetime};
+use uuid::Uuid;
+use xero_rs::error::{Error, ErrorType, Response};
+use xero_rs::payroll::nz::{
+    employee::{
+        Address, EmployeeTaxResponse, EmployeesResponse, Employment, EngagementType,
+        KiwiSaverContributions, OpeningBalance, PostEmployee, TaxCode,
+    },
+    leave::{LeavePeriodStatus, LeaveResponse, LeaveSetup},
+    pay_items::{
+        EarningsRatesResponse, EarningsType, Reimbursement, ReimbursementUnits,
+        StatutoryDeductionCategory, StatutoryDeductionsResponse, Superannuation,
+        SuperannuationCategory,
+    },
+    pay_run::{CalendarType, PayRunResponse, PayRunStatus, PostPayRun},
+    timesheet::{TimesheetResponse, TimesheetStatus},
+};
+
+#[tokio::test]
+async fn list_nz_employees_and_pay_runs() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::payroll_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let employees = match client.payroll_nz().employees().list().await {
+        Ok(employees) => employees,
+        Err(Error::Forbidden(_)) => {
+            info!("Payroll scopes not available, skipping test");
+            return Ok(());
+        }
+        Err(Error::API { response, .. }) => {
+            info!("Organisation does not use NZ payroll, skipping test: {response}");
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    info!("Found {} NZ employees", employees.len());
+
+    if let Some(first) = employees.first() {
+        let tax = client
+            .payroll_nz()
+            .employees()
+            .tax(first.employee_id)
+            .await?;
+        info!("First employee has tax code {:?}", tax.tax_code);
+    }
+
+    let pay_runs = client
+        .payroll_nz()
+        .pay_runs()
+        .list(Some(PayRunStatus::Posted))
+        .await?;
+    info!("Found {} posted NZ pay runs", pay_runs.len());
+
+    Ok(())
+}
+
+#[test]
+fn employee_response_parses_nz_fields() -> Result<()> {
+    let body = r#"{
+        "pagination": {"page": 1, "pageSize": 100, "pageCount": 1, "itemCount": 1},
+        "problem": null,
+        "employees": [{
+            "employeeID": "1a6c2bf6-1c69-4f54-8d6a-3a0b7e4a2f01",
+            "firstName": "Aroha",
+            "lastName": "Ngata",
+            "dateOfBirth": "1992-03-18T00:00:00",
+            "address": {
+                "addressLine1": "1 Queen Street",
+                "city": "Auckland",
+                "suburb": "Auckland Central",
+                "postCode": "1010"
+            },
+            "jobTitle": "Barista",
+            "engagementType": "FixedTerm",
+            "fixedTermEndDate": "2025-03-31T00:00:00"
+        }]
+    }"#;
+
+    let response: EmployeesResponse = serde_json::from_str(body)?;
+    let employee = &response.employees[0];
+    assert_eq!(employee.title, None);
+    assert_eq!(employee.job_title.as_deref(), Some("Barista"));
+    assert_eq!(employee.engagement_type, Some(EngagementType::FixedTerm));
+    assert_eq!(employee.fixed_term_end_date, Some(date!(2025 - 03 - 31)));
+    assert_eq!(
+        employee.address.as_ref().and_then(|a| a.suburb.as_deref()),
+        Some("Auckland Central")
+    );
+    Ok(())
+}
+
+#[test]
+fn employee_requests_serialize_as_camel_case() -> Result<()> {
+    let employee = PostEmployee::new(
+        "Aroha",
+        "Ngata",
+        date!(1992 - 03 - 18),
+        Address::new("1 Queen Street", "Auckland", "1010"),
+    )
+    .with_job_title("Barista");
+
+    let json = serde_json::to_value(&employee)?;
+    assert_eq!(json["firstName"], "Aroha");
+    assert_eq!(json["dateOfBirth"], "1992-03-18");
+    assert_eq!(json["jobTitle"], "Barista");
+    assert!(json.get("title").is_none());
+    assert!(json.get("gender").is_none());
+
+    let employment = Employment::new(
+        Uuid::nil(),
+        date!(2024 - 04 - 01),
+        EngagementType::FixedTerm,
+    )
+    .with_fixed_term_end_date(date!(2025 - 03 - 31));
+    let json = serde_json::to_value(&employment)?;
+    assert_eq!(json["engagementType"], "FixedTerm");
+    assert_eq!(json["fixedTermEndDate"], "2025-03-31");
+    assert!(json.get("payRunCalendarID").is_none());
+
+    let opening_balances = vec![OpeningBalance {
+        period_end_date: date!(2024 - 03 - 31),
+        days_paid: 5,
+        unpaid_weeks: 0,
+        gross_earnings: dec!(1500),
+    }];
+    let json = serde_json::to_value(&opening_balances)?;
+    assert_eq!(json[0]["periodEndDate"], "2024-03-31");
+    assert_eq!(json[0]["daysPaid"], 5);
+    Ok(())
+}
+
+#[test]
+fn employee_tax_parses_tax_code_and_kiwi_saver() -> Result<()> {
+    let body = r#"{
+        "employeeTax": {
+            "irdNumber": "123456789",
+            "taxCode": "MSL",
+            "isEligibleForKiwiSaver": true,
+            "esctRatePercentage": 17.5,
+            "kiwiSaverContributions": "MakeContributions",
+            "kiwiSaverEmployeeContributionRatePercentage": 3,
+            "kiwiSaverEmployerContributionRatePercentage": 3,
+            "hasStudentLoanBalance": true,
+            "studentLoanBalance": 12000,
+            "studentLoanAsAt": "2024-04-01T00:00:00"
+        }
+    }"#;
+
+    let response: EmployeeTaxResponse = serde_json::from_str(body)?;
+    let tax = response.employee_tax.expect("employee tax");
+    assert_eq!(tax.tax_code, Some(TaxCode::Msl));
+    assert_eq!(
+        tax.kiwi_saver_contributions,
+        Some(KiwiSaverContributions::MakeContributions)
+    );
+    assert_eq!(tax.esct_rate_percentage, Some(dec!(17.5)));
+    assert_eq!(tax.student_loan_as_at, Some(date!(2024 - 04 - 01)));
+
+    let json = serde_json::to_value(&tax)?;
+    assert_eq!(json["taxCode"], "MSL");
+    assert!(json.get("kiwiSaverOptOutDate").is_none());
+    Ok(())
+}
+
+#[test]
+fn leave_periods_and_setup_use_nz_fields() -> Result<()> {
+    let body = r#"{
+        "leave": {
+            "leaveID": "3a5d0e71-4d76-4c02-9a0c-3a2a54c4d93f",
+            "leaveTypeID": "ed08dffe-788e-4b24-9630-f0fa2f4d164c",
+            "description": "Annual leave",
+            "startDate": "2024-12-23T00:00:00",
+            "endDate": "2025-01-03T00:00:00",
+            "periods": [{
+                "periodStartDate": "2024-12-16T00:00:00",
+                "periodEndDate": "2024-12-29T00:00:00",
+                "numberOfUnits": 40,
+                "numberOfUnitsTaken": 40,
+                "typeOfUnits": "Hours",
+                "typeOfUnitsTaken": "Hours",
+                "periodStatus": "Estimated"
+            }]
+        }
+    }"#;
+
+    let response: LeaveResponse = serde_json::from_str(body)?;
+    let leave = response.leave.into_first().expect("leave record");
+    assert_eq!(
+        leave.periods[0].period_status,
+        Some(LeavePeriodStatus::Estimated)
+    );
+    assert_eq!(leave.periods[0].number_of_units_taken, Some(dec!(40)));
+
+    let setup = LeaveSetup {
+        include_holiday_pay: Some(true),
+        sick_leave_to_accrue_annually: Some(dec!(10)),
+        annual_leave_anniversary_date: Some(date!(2025 - 04 - 01)),
+        ..LeaveSetup::default()
+    };
+    let json = serde_json::to_value(&setup)?;
+    assert_eq!(json["includeHolidayPay"], true);
+    assert_eq!(json["SickLeaveToAccrueAnnually"], "10");
+    assert_eq!(json["AnnualLeaveAnniversaryDate"], "2025-04-01");
+    assert!(json.get("sickLeaveOpeningBalance").is_none());
+    Ok(())
+}
+
+#[test]
+fn pay_items_use_nz_enums() -> Result<()> {
+    let body = r#"{
+        "earningsRates": [{
+            "earningsRateID": "f5ec4a63-07a0-4b5d-8da3-40d1e57eec06",
+            "name": "Tips",
+            "earningsType": "Tips(Non-Direct)",
+            "rateType": "FixedAmount",
+            "typeOfUnits": "Fixed",
+            "expenseAccountID": "4b03500d-32fd-4616-8d70-e1e56e0519c6"
+        }]
+    }"#;
+    let response: EarningsRatesResponse = serde_json::from_str(body)?;
+    assert_eq!(
+        response.earnings_rates[0].earnings_type,
+        EarningsType::TipsNonDirect
+    );
+
+    let body = r#"{
+        "statutoryDeductions": [{
+            "id": "b1f2f3b4-3c2d-4a1b-9e8f-7a6b5c4d3e2f",
+            "name": "Student Loan",
+            "statutoryDeductionCategory": "StudentLoan",
+            "currentRecord": true
+        }]
+    }"#;
+    let response: StatutoryDeductionsResponse = serde_json::from_str(body)?;
+    assert_eq!(
+        response.statutory_deductions[0].statutory_deduction_category,
+        Some(StatutoryDeductionCategory::StudentLoan)
+    );
+
+    let kiwi_saver = Superannuation::percentage_of_taxable_earnings(
+        "KiwiSaver",
+        SuperannuationCategory::KiwiSaver,
+        Uuid::nil(),
+        Uuid::nil(),
+        dec!(3),
+    );
+    let json = serde_json::to_value(&kiwi_saver)?;
+    assert_eq!(json["category"], "KiwiSaver");
+    assert_eq!(json["calculationTypeNZ"], "PercentageOfTaxableEarnings");
+    assert!(json.get("standardAmount").is_none());
+
+    let mileage = Reimbursement::new("Mileage", Uuid::nil())
+        .with_rate_per_unit(ReimbursementUnits::Kilometres, dec!(0.95));
+    let json = serde_json::to_value(&mileage)?;
+    assert_eq!(json["calculationType"], "RatePerUnit");
+    assert_eq!(json["standardTypeOfUnits"], "km");
+    Ok(())
+}
+
+#[test]
+fn timesheets_and_pay_runs_parse_nz_statuses() -> Result<()> {
+    let body = r#"{
+        "timesheet": {
+            "timesheetID": "a0b1c2d3-e4f5-4a6b-8c7d-9e0f1a2b3c4d",
+            "payrollCalendarID": "216d80e6-af55-47b1-b718-9457c3f5d2fe",
+            "employeeID": "1a6c2bf6-1c69-4f54-8d6a-3a0b7e4a2f01",
+            "startDate": "2024-06-03T00:00:00",
+            "endDate": "2024-06-09T00:00:00",
+            "status": "Requested",
+            "totalHours": 37.5,
+            "timesheetLines": [{
+                "timesheetLineID": "b0b1c2d3-e4f5-4a6b-8c7d-9e0f1a2b3c4d",
+                "date": "2024-06-03T00:00:00",
+                "earningsRateID": "f5ec4a63-07a0-4b5d-8da3-40d1e57eec06",
+                "numberOfUnits": 7.5
+            }]
+        }
+    }"#;
+    let response: TimesheetResponse = serde_json::from_str(body)?;
+    let timesheet = response.timesheet.expect("timesheet");
+    assert_eq!(timesheet.status, Some(TimesheetStatus::Requested));
+    assert_eq!(timesheet.timesheet_lines[0].number_of_units, dec!(7.5));
+
+    let body = r#"{
+        "payRun": {
+            "payRunID": "c8a1f5b2-7a9e-4f5e-8e52-3c4b8a0f2d11",
+            "payRunStatus": "Draft",
+            "calendarType": "TwiceMonthly",
+            "postedDateTime": "2024-05-30T23:14:08",
+            "paySlips": [{
+                "paySlipID": "5b0a4a43-6d4c-46b5-9d1a-0d3b3f0bde7a",
+                "totalStatutoryDeductions": 120,
+                "totalSuperannuation": 90,
+                "superannuationLines": [{"displayName": "KiwiSaver", "amount": 90, "percentage": 3}],
+                "statutoryDeductionLines": [{"amount": 120, "manualAdjustment": false}],
+                "taxSettings": {"periodUnits": 2, "periodType": "weeks", "taxCode": "M"}
+            }]
+        }
+    }"#;
+    let response: PayRunResponse = serde_json::from_str(body)?;
+    let pay_run = response.pay_run.expect("pay run");
+    assert_eq!(pay_run.calendar_type, Some(CalendarType::TwiceMonthly));
+    assert_eq!(
+        pay_run.posted_date_time,
+        Some(datetime!(2024 - 05 - 30 23:14:08 UTC))
+    );
+    let payslip = &pay_run.pay_slips[0];
+    assert_eq!(payslip.total_superannuation, Some(dec!(90)));
+    assert_eq!(payslip.superannuation_lines[0].amount, dec!(90));
+    assert_eq!(payslip.statutory_deduction_lines[0].amount, dec!(120));
+    assert_eq!(
+        payslip.tax_settings.as_ref().and_then(|t| t.tax_code),
+        Some(TaxCode::M)
+    );
+
+    let json = serde_json::to_value(PostPayRun::scheduled(Uuid::nil()))?;
+    assert_eq!(json["payrollCalendarID"], Uuid::nil().to_string());
+    assert_eq!(json["payRunType"], "Scheduled");
+    assert!(json.get("paymentDate").is_none());
+    Ok(())
+}
+
+#[test]
+fn problem_with_string_status_maps_to_api_error() {
+    let body = r#"{
+        "problem": {
+            "type": "application/problem+json",
+            "title": "BadRequest",
+            "status": "400",
+            "detail": "Validation error occurred.",
+            "invalidFields": [
+                {"name": "IrdNumber", "reason": "The IRD number is invalid."}
+            ]
+        }
+    }"#;
+
+    let response = Response::from_problem(body).expect("problem body");
+    assert_eq!(response.status, Some(400));
+    match &response.error {
+        ErrorType::PayrollProblem { invalid_fields } => {
+            assert_eq!(invalid_fields[0].name, "IrdNumber");
+        }
+        other => panic!("expected PayrollProblem, got {other:?}"),
+    }
+}
@@ -0,0 +1,175 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use rust_decimal_macros::dec;
+use time::macros::date;
+use uuid::Uuid;
+use xero_rs::error::Error;
+use xero_rs::organisation::{Organisation, OrganisationType};
+use xero_rs::payroll::region::{CommonPayroll, PayrollRegion, TimesheetHours};
+
+#[tokio::test]
+async fn detect_region_and_list_employees() -> Result<()> {
+    // Try to set up the client
+    // Region detection reads the organisation, so it needs the settings scope too
+    let scopes = xero_rs::scopes![
+        xero_rs::ScopeType::AccountingSettings(xero_rs::Permission::ReadOnly),
+        xero_rs::ScopeType::PayrollEmployees(xero_rs::Permission::ReadOnly),
+        xero_rs::ScopeType::PayrollPayruns(xero_rs::Permission::ReadOnly)
+    ];
+    let client = match test_utils::try_setup_client(Some(scopes)).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let payroll = match client.payroll().await {
+        Ok(payroll) => payroll,
+        Err(Error::UnsupportedPayrollRegion(country_code)) => {
+            info!("No payroll for organisations in {country_code:?}, skipping test");
+            return Ok(());
+        }
+        Err(Error::PayrollFeatureDisabled { feature, .. }) => {
+            info!("The {feature} feature is not enabled, skipping test");
+            return Ok(());
+        }
+        Err(Error::Forbidden(_)) => {
+            info!("Settings scope not available, skipping test");
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    info!("Organisation uses {:?} payroll", payroll.region());
+
+    let employees = match payroll.list_employees().await {
+        Ok(employees) => employees,
+        Err(Error::Forbidden(_)) => {
+            info!("Payroll scopes not available, skipping test");
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    info!("Found {} payroll employees", employees.len());
+
+    if let Some(first) = employees.first() {
+        let balances = payroll.leave_balances(first.employee_id).await?;
+        info!("First employee has {} leave balances", balances.len());
+    }
+
+    let pay_runs = payroll.list_pay_runs().await?;
+    info!("Found {} pay runs", pay_runs.len());
+
+    Ok(())
+}
+
+#[test]
+fn payroll_region_from_country_code() {
+    assert_eq!(
+        PayrollRegion::from_country_code("AU"),
+        Some(PayrollRegion::Au)
+    );
+    assert_eq!(
+        PayrollRegion::from_country_code("GB"),
+        Some(PayrollRegion::Uk)
+    );
+    assert_eq!(
+        PayrollRegion::from_country_code("uk"),
+        Some(PayrollRegion::Uk)
+    );
+    assert_eq!(
+        PayrollRegion::from_country_code(" nz "),
+        Some(PayrollRegion::Nz)
+    );
+    assert_eq!(PayrollRegion::from_country_code("US"), None);
+    assert_eq!(PayrollRegion::from_country_code(""), None);
+
+    assert_eq!(PayrollRegion::Uk.country_code(), "GB");
+    assert!(PayrollRegion::Au.is_enabled());
+    assert_eq!(PayrollRegion::Au.feature(), None);
+    assert_eq!(PayrollRegion::Nz.feature(), Some("payroll-nz"));
+}
+
+#[test]
+fn disabled_payroll_feature_is_named_in_the_error() {
+    let error = Error::PayrollFeatureDisabled {
+        country_code: "GB".to_string(),
+        feature: PayrollRegion::Uk.feature().unwrap(),
+    };
+
+    assert_eq!(
+        error.to_string(),
+        r#"payroll for organisations in country "GB" needs the payroll-uk feature"#
+    );
+}
+
+#[test]
+fn organisation_parses_and_detects_payroll_region() -> Result<()> {
+    let body = r#"{
+        "OrganisationID": "b2c885a9-4bb9-4a00-9b6e-6c2bf60b1a2b",
+        "APIKey": "CTJ60UH519MXQIXEJSDPDALS3EOZ5Y",
+        "Name": "Dev Evangelist - Sid Test 3 (NZ-2016-02)",
+        "LegalName": "Dev Evangelist - Sid Test 3 (NZ-2016-02)",
+        "PaysTax": true,
+        "Version": "NZ",
+        "OrganisationType": "COMPANY",
+        "BaseCurrency": "NZD",
+        "CountryCode": "NZ",
+        "IsDemoCompany": false,
+        "OrganisationStatus": "ACTIVE",
+        "TaxNumber": "071-138-054",
+        "FinancialYearEndDay": 31,
+        "FinancialYearEndMonth": 3,
+        "Timezone": "NEWZEALANDSTANDARDTIME",
+        "ShortCode": "!23eYt",
+        "CreatedDateUTC": "/Date(1455050401000)/"
+    }"#;
+
+    let organisation: Organisation = serde_json::from_str(body)?;
+    assert_eq!(
+        organisation.organisation_type,
+        Some(OrganisationType::Company)
+    );
+    assert_eq!(organisation.financial_year_end_month, Some(3));
+    assert!(organisation.created_date_utc.is_some());
+    assert_eq!(organisation.payroll_region(), Some(PayrollRegion::Nz));
+
+    let us: Organisation = serde_json::from_str(
+        r#"{"OrganisationID": "b2c885a9-4bb9-4a00-9b6e-6c2bf60b1a2b", "CountryCode": "US",
+            "OrganisationType": "S_CORPORATION"}"#,
+    )?;
+    assert_eq!(us.payroll_region(), None);
+    Ok(())
+}
+
+#[test]
+fn timesheet_hours_build_au_daily_lines() {
+    let ordinary = Uuid::new_v4();
+    let overtime = Uuid::new_v4();
+    let hours = TimesheetHours::new(Uuid::new_v4(), date!(2025 - 03 - 03), date!(2025 - 03 - 09))
+        .with_hours(date!(2025 - 03 - 03), ordinary, dec!(7.5))
+        .with_hours(date!(2025 - 03 - 04), ordinary, dec!(7.5))
+        .with_hours(date!(2025 - 03 - 04), overtime, dec!(2))
+        .with_hours(date!(2025 - 03 - 04), overtime, dec!(0.5))
+        .with_hours(date!(2025 - 03 - 10), ordinary, dec!(8));
+
+    assert_eq!(hours.total_hours(), dec!(25.5));
+
+    let timesheet = hours.to_au_timesheet();
+    let lines = timesheet.timesheet_lines.unwrap_or_default();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].earnings_rate_id, ordinary);
+    assert_eq!(
+        lines[0].number_of_units,
+        vec![7.5, 7.5, 0.0, 0.0, 0.0, 0.0, 0.0]
+    );
+    assert_eq!(lines[1].earnings_rate_id, overtime);
+    assert_eq!(
+        lines[1].number_of_units,
+        vec![0.0, 2.5, 0.0, 0.0, 0.0, 0.0, 0.0]
+    );
+}
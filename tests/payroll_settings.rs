@@ -0,0 +1,175 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use uuid::Uuid;
+use xero_rs::payroll::employee::{Employee, SuperMembership};
+use xero_rs::payroll::settings::deduction_types::{DeductionCategory, DeductionType};
+use xero_rs::payroll::settings::payroll_settings::{PayrollSettingsResponse, SettingsAccountType};
+use xero_rs::payroll::superfund::{
+    self, PostSuperfund, SuperfundProductResponse, SuperfundResponse, SuperfundType,
+};
+
+#[tokio::test]
+async fn get_settings_and_superfunds() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::payroll_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let settings = match client.payroll_settings().get().await {
+        Ok(settings) => settings,
+        Err(xero_rs::error::Error::Forbidden(_)) => {
+            info!("Payroll scopes not available, skipping test");
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    info!(
+        "Payroll uses {} accounts and {:?} days per year",
+        settings.accounts.len(),
+        settings.days_in_payroll_year
+    );
+
+    let funds = client.superfunds().list().await?;
+    info!("Found {} superfunds", funds.len());
+    if let Some(first) = funds.first() {
+        let fund = client.superfunds().get(first.super_fund_id).await?;
+        assert_eq!(fund.super_fund_id, first.super_fund_id);
+
+        if let Some(usi) = &fund.usi {
+            let products = client.superfund_products().by_usi(usi).await?;
+            info!("USI {} matches {} products", usi, products.len());
+        }
+    }
+
+    let deduction_types = client.deduction_types().list().await?;
+    let reimbursement_types = client.reimbursement_types().list().await?;
+    info!(
+        "Found {} deduction types and {} reimbursement types",
+        deduction_types.len(),
+        reimbursement_types.len()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_payroll_settings() -> Result<()> {
+    let json = r#"{
+        "Settings": {
+            "Accounts": [
+                {"AccountID": "85bd2954-7ef5-4fbe-9e40-a1990d0fd63f", "Type": "BANK", "Code": "090", "Name": "Business Bank Account"},
+                {"AccountID": "7e65fa75-1c64-43d7-b0b4-c8ec3c0ffe8e", "Type": "PAYGLIABILITY", "Code": "825", "Name": "PAYG Withholdings Payable"},
+                {"AccountID": "7e65fa75-1c64-43d7-b0b4-c05f196e2190", "Type": "WAGESPAYABLELIABILITY", "Code": "804", "Name": "Wages Payable - Payroll"},
+                {"AccountID": "1e4c2d1a-1c3d-4b17-8f5e-2a9b3d8b2f11", "Type": "SOMETHINGNEW", "Code": "999", "Name": "Future account"}
+            ],
+            "TrackingCategories": {
+                "EmployeeGroups": {"TrackingCategoryID": "a28f419f-6ec3-4dcf-9be0-7959ea983630", "TrackingCategoryName": "Region"}
+            },
+            "DaysInPayrollYear": "364"
+        }
+    }"#;
+
+    let settings = serde_json::from_str::<PayrollSettingsResponse>(json)?.settings;
+    assert_eq!(settings.days_in_payroll_year, Some(364));
+    assert_eq!(
+        settings
+            .account(SettingsAccountType::PaygLiability)
+            .map(|account| account.code.as_str()),
+        Some("825")
+    );
+    assert_eq!(
+        settings
+            .account(SettingsAccountType::WagesPayable)
+            .map(|account| account.code.as_str()),
+        Some("804")
+    );
+    assert_eq!(
+        settings.accounts[3].account_type,
+        SettingsAccountType::Other
+    );
+    assert!(settings.tracking_categories.employee_groups.is_some());
+    assert!(settings.tracking_categories.timesheet_categories.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_superfunds_and_pay_items() -> Result<()> {
+    let json = r#"{
+        "SuperFunds": [
+            {"SuperFundID": "fde8e070-bf59-4e56-b1d7-c75a09474b8d", "Name": "AMG Super", "Type": "REGULATED", "ABN": "30099320583", "USI": "PTC0133AU", "UpdatedDateUTC": "/Date(1573570941547+0000)/"},
+            {"SuperFundID": "46f7e7d4-1c93-4d0e-a5ad-e3ea86e5f4a4", "Name": "Family SMSF", "Type": "SMSF", "ABN": "53004085616", "BSB": "159357", "AccountNumber": "12345678", "ElectronicServiceAddress": "SMSFDataFlow"}
+        ]
+    }"#;
+    let funds = serde_json::from_str::<SuperfundResponse>(json)?.super_funds;
+    assert_eq!(funds[0].fund_type, SuperfundType::Regulated);
+    assert!(funds[0].updated_date_utc.is_some());
+    assert_eq!(funds[1].fund_type, SuperfundType::SelfManaged);
+    assert_eq!(funds[1].bsb.as_deref(), Some("159357"));
+
+    let products = r#"{"SuperFundProducts": [{"ABN": "30099320583", "USI": "PTC0133AU", "ProductName": "AMG Super"}]}"#;
+    let products = serde_json::from_str::<SuperfundProductResponse>(products)?.super_fund_products;
+    assert_eq!(products[0].usi.as_deref(), Some("PTC0133AU"));
+
+    let deduction = r#"{"DeductionTypeID": "727af5e8-b347-4ae7-85fc-9b82266d0aec", "Name": "Union Fees", "AccountCode": "850", "ReducesTax": false, "ReducesSuper": false, "IsExemptFromW1": false, "DeductionCategory": "UNIONFEES", "CurrentRecord": true}"#;
+    let deduction: DeductionType = serde_json::from_str(deduction)?;
+    assert_eq!(
+        deduction.deduction_category,
+        Some(DeductionCategory::UnionFees)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn serialize_superfund_requests() -> Result<()> {
+    let regulated = serde_json::to_value(PostSuperfund::regulated("PTC0133AU"))?;
+    assert_eq!(regulated["Type"], "REGULATED");
+    assert_eq!(regulated["USI"], "PTC0133AU");
+    assert!(regulated.get("BSB").is_none());
+
+    let smsf = serde_json::to_value(
+        PostSuperfund::self_managed(
+            "Family SMSF",
+            "53004085616",
+            "159357",
+            "12345678",
+            "SMSFDataFlow",
+        )
+        .with_account_name("Family Super"),
+    )?;
+    assert_eq!(smsf["Type"], "SMSF");
+    assert_eq!(smsf["ElectronicServiceAddress"], "SMSFDataFlow");
+    assert_eq!(smsf["AccountName"], "Family Super");
+
+    Ok(())
+}
+
+#[test]
+fn unknown_memberships_finds_missing_funds() -> Result<()> {
+    let json = r#"{"SuperFunds": [{"SuperFundID": "fde8e070-bf59-4e56-b1d7-c75a09474b8d", "Type": "REGULATED", "USI": "PTC0133AU"}]}"#;
+    let funds = serde_json::from_str::<SuperfundResponse>(json)?.super_funds;
+
+    let mut employee: Employee = serde_json::from_str(
+        r#"{"EmployeeID": "cdfb8371-0b21-4b8a-8903-1024df6c391e", "FirstName": "Albus", "LastName": "Dumbledore", "Status": "ACTIVE"}"#,
+    )?;
+    let missing_fund = Uuid::new_v4();
+    employee.super_memberships = vec![
+        SuperMembership::new(funds[0].super_fund_id, "1"),
+        SuperMembership::new(missing_fund, "2"),
+    ];
+
+    let unknown = superfund::unknown_memberships(&employee, &funds);
+    assert_eq!(unknown.len(), 1);
+    assert_eq!(unknown[0].super_fund_id, missing_fund);
+
+    Ok(())
+}
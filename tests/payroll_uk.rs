@@ -0,0 +1,320 @@
+#![cfg(feature = "payroll-uk")]
+
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use rust_decimal_macros::dec;
+use time::macros::{date, datetime};
+use uuid::Uuid;
+use xero_rs::error::{Error, ErrorType, Response};
+use xero_rs::payroll::uk::{
+    employee::{Address, EmployeesResponse, Employment, Gender, NiCategory, PostEmployee},
+    leave::LeaveResponse,
+    pay_items::{Deduction, DeductionCategory, EarningsRatesResponse, EarningsType, RateType},
+    pay_run::{PayRunResponse, PayRunStatus, PayRunType},
+    timesheet::{PostTimesheet, TimesheetLine},
+};
+
+#[tokio::test]
+async fn list_uk_employees_and_pay_runs() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::payroll_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let employees = match client.payroll_uk().employees().list().await {
+        Ok(employees) => employees,
+        Err(Error::Forbidden(_)) => {
+            info!("Payroll scopes not available, skipping test");
+            return Ok(());
+        }
+        Err(Error::API { response, .. }) => {
+            info!("Organisation does not use UK payroll, skipping test: {response}");
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    info!("Found {} UK employees", employees.len());
+
+    if let Some(first) = employees.first() {
+        let employee = client
+            .payroll_uk()
+            .employees()
+            .get(first.employee_id)
+            .await?;
+        assert_eq!(employee.employee_id, first.employee_id);
+    }
+
+    let pay_runs = client
+        .payroll_uk()
+        .pay_runs()
+        .list(Some(PayRunStatus::Posted))
+        .await?;
+    info!("Found {} posted UK pay runs", pay_runs.len());
+
+    Ok(())
+}
+
+#[test]
+fn employee_list_response_parses_camel_case_and_pagination() -> Result<()> {
+    let body = r#"{
+        "id": "7d9bd0d4-5a3b-4f0f-9d0c-0b4b1e2a6c11",
+        "providerName": "xero-rs",
+        "dateTimeUTC": "2024-06-01T10:15:00.1234567",
+        "httpStatusCode": "OK",
+        "pagination": {"page": 1, "pageSize": 100, "pageCount": 2, "itemCount": 101},
+        "problem": null,
+        "employees": [{
+            "employeeID": "d90457c4-f1be-4f2e-b4e3-f766390a7e30",
+            "title": "Ms",
+            "firstName": "Jane",
+            "lastName": "Smith",
+            "dateOfBirth": "1990-04-12T00:00:00",
+            "gender": "F",
+            "address": {
+                "addressLine1": "171 Midsummer Boulevard",
+                "city": "Milton Keynes",
+                "postCode": "MK9 1EB"
+            },
+            "startDate": "2024-04-06T00:00:00",
+            "endDate": null,
+            "payrollCalendarID": "216d80e6-af55-47b1-b718-9457c3f5d2fe",
+            "updatedDateUTC": "2024-05-01T09:00:00",
+            "nationalInsuranceNumber": "AB123456C",
+            "isOffPayrollWorker": false
+        }]
+    }"#;
+
+    let response: EmployeesResponse = serde_json::from_str(body)?;
+    let pagination = response.pagination.expect("pagination");
+    assert_eq!(pagination.page_count, 2);
+    assert_eq!(pagination.item_count, 101);
+
+    let employee = &response.employees[0];
+    assert_eq!(employee.first_name, "Jane");
+    assert_eq!(employee.gender, Some(Gender::Female));
+    assert_eq!(employee.date_of_birth, Some(date!(1990 - 04 - 12)));
+    assert_eq!(employee.start_date, Some(date!(2024 - 04 - 06)));
+    assert_eq!(employee.end_date, None);
+    assert_eq!(
+        employee.address.as_ref().map(|a| a.post_code.as_str()),
+        Some("MK9 1EB")
+    );
+    assert!(employee.updated_date_utc.is_some());
+    Ok(())
+}
+
+#[test]
+fn post_employee_and_employment_serialize_as_camel_case() -> Result<()> {
+    let employee = PostEmployee::new(
+        "Mr",
+        "John",
+        "Doe",
+        date!(1985 - 01 - 31),
+        Gender::Male,
+        Address::new("1 High Street", "London", "SW1A 1AA"),
+    )
+    .with_national_insurance_number("QQ123456C");
+
+    let json = serde_json::to_value(&employee)?;
+    assert_eq!(json["firstName"], "John");
+    assert_eq!(json["dateOfBirth"], "1985-01-31");
+    assert_eq!(json["gender"], "M");
+    assert_eq!(json["address"]["addressLine1"], "1 High Street");
+    assert_eq!(json["nationalInsuranceNumber"], "QQ123456C");
+    assert!(json.get("email").is_none());
+    assert!(json["address"].get("addressLine2").is_none());
+
+    let employment = Employment::new(Uuid::nil(), date!(2024 - 04 - 06), "E001", NiCategory::A);
+    let json = serde_json::to_value(&employment)?;
+    assert_eq!(json["payrollCalendarID"], Uuid::nil().to_string());
+    assert_eq!(json["employeeNumber"], "E001");
+    assert_eq!(json["niCategory"], "A");
+    Ok(())
+}
+
+#[test]
+fn leave_response_accepts_a_list_or_a_single_record() -> Result<()> {
+    let leave = r#"{
+        "leaveID": "3a5d0e71-4d76-4c02-9a0c-3a2a54c4d93f",
+        "leaveTypeID": "ed08dffe-788e-4b24-9630-f0fa2f4d164c",
+        "description": "Holiday",
+        "startDate": "2024-08-05T00:00:00",
+        "endDate": "2024-08-09T00:00:00",
+        "periods": [{
+            "periodStartDate": "2024-08-05T00:00:00",
+            "periodEndDate": "2024-08-11T00:00:00",
+            "numberOfUnits": 37.5,
+            "periodStatus": "Approved"
+        }]
+    }"#;
+
+    let list: LeaveResponse = serde_json::from_str(&format!(r#"{{"leave": [{leave}]}}"#))?;
+    assert_eq!(list.leave.into_vec().len(), 1);
+
+    let single: LeaveResponse = serde_json::from_str(&format!(r#"{{"leave": {leave}}}"#))?;
+    let record = single.leave.into_first().expect("leave record");
+    assert_eq!(record.description.as_deref(), Some("Holiday"));
+    assert_eq!(record.periods[0].number_of_units, dec!(37.5));
+    Ok(())
+}
+
+#[test]
+fn earnings_rates_and_deductions_use_typed_enums() -> Result<()> {
+    let body = r#"{
+        "earningsRates": [
+            {
+                "earningsRateID": "f5ec4a63-07a0-4b5d-8da3-40d1e57eec06",
+                "name": "Regular Hours",
+                "earningsType": "RegularEarnings",
+                "rateType": "RatePerUnit",
+                "typeOfUnits": "Hours",
+                "currentRecord": true,
+                "expenseAccountID": "4b03500d-32fd-4616-8d70-e1e56e0519c6",
+                "ratePerUnit": 12.5
+            },
+            {
+                "earningsRateID": "9a1d0f54-93a4-4c1a-8b0a-6f1b6c2bdc39",
+                "name": "Something new",
+                "earningsType": "SomethingXeroAddedLater",
+                "rateType": "FixedAmount",
+                "typeOfUnits": "Fixed",
+                "expenseAccountID": "4b03500d-32fd-4616-8d70-e1e56e0519c6"
+            }
+        ]
+    }"#;
+
+    let response: EarningsRatesResponse = serde_json::from_str(body)?;
+    assert_eq!(
+        response.earnings_rates[0].earnings_type,
+        EarningsType::RegularEarnings
+    );
+    assert_eq!(response.earnings_rates[0].rate_type, RateType::RatePerUnit);
+    assert_eq!(response.earnings_rates[0].rate_per_unit, Some(dec!(12.5)));
+    assert_eq!(
+        response.earnings_rates[1].earnings_type,
+        EarningsType::Other
+    );
+
+    let deduction = Deduction::new("Pension", Uuid::nil())
+        .with_category(DeductionCategory::StakeholderPension)
+        .with_percentage(dec!(5));
+    let json = serde_json::to_value(&deduction)?;
+    assert_eq!(json["deductionName"], "Pension");
+    assert_eq!(json["liabilityAccountId"], Uuid::nil().to_string());
+    assert_eq!(json["deductionCategory"], "StakeholderPension");
+    assert_eq!(json["calculationType"], "PercentageOfGross");
+    assert!(json.get("deductionId").is_none());
+    Ok(())
+}
+
+#[test]
+fn timesheet_request_serializes_lines() -> Result<()> {
+    let timesheet = PostTimesheet::new(
+        Uuid::nil(),
+        Uuid::nil(),
+        date!(2024 - 06 - 03),
+        date!(2024 - 06 - 09),
+    )
+    .with_line(TimesheetLine::new(
+        date!(2024 - 06 - 03),
+        Uuid::nil(),
+        dec!(7.5),
+    ));
+
+    let json = serde_json::to_value(&timesheet)?;
+    assert_eq!(json["startDate"], "2024-06-03");
+    assert_eq!(json["timesheetLines"][0]["date"], "2024-06-03");
+    assert!(json["timesheetLines"][0].get("timesheetLineID").is_none());
+    Ok(())
+}
+
+#[test]
+fn pay_run_response_includes_payslips() -> Result<()> {
+    let body = r#"{
+        "payRun": {
+            "payRunID": "c8a1f5b2-7a9e-4f5e-8e52-3c4b8a0f2d11",
+            "payrollCalendarID": "216d80e6-af55-47b1-b718-9457c3f5d2fe",
+            "periodStartDate": "2024-05-01T00:00:00",
+            "periodEndDate": "2024-05-31T00:00:00",
+            "paymentDate": "2024-05-31T00:00:00",
+            "totalCost": 3200.5,
+            "totalPay": 2400.25,
+            "payRunStatus": "Posted",
+            "payRunType": "Scheduled",
+            "calendarType": "Monthly",
+            "postedDateTime": "2024-05-30T23:14:08",
+            "paySlips": [{
+                "paySlipID": "5b0a4a43-6d4c-46b5-9d1a-0d3b3f0bde7a",
+                "employeeID": "d90457c4-f1be-4f2e-b4e3-f766390a7e30",
+                "firstName": "Jane",
+                "lastName": "Smith",
+                "totalPay": 2400.25,
+                "paymentMethod": "Electronically",
+                "earningsLines": [{"earningsRateID": "f5ec4a63-07a0-4b5d-8da3-40d1e57eec06", "amount": 3000}],
+                "employeeTaxLines": [{"description": "PAYE", "amount": 400.5, "isEmployerTax": false}]
+            }]
+        }
+    }"#;
+
+    let response: PayRunResponse = serde_json::from_str(body)?;
+    let pay_run = response.pay_run.expect("pay run");
+    assert_eq!(pay_run.pay_run_status, Some(PayRunStatus::Posted));
+    assert_eq!(pay_run.pay_run_type, Some(PayRunType::Scheduled));
+    assert_eq!(pay_run.payment_date, Some(date!(2024 - 05 - 31)));
+    assert_eq!(
+        pay_run.posted_date_time,
+        Some(datetime!(2024 - 05 - 30 23:14:08 UTC))
+    );
+    assert_eq!(pay_run.total_pay, Some(dec!(2400.25)));
+
+    let payslip = &pay_run.pay_slips[0];
+    assert_eq!(payslip.earnings_lines[0].amount, dec!(3000));
+    assert_eq!(payslip.employee_tax_lines[0].amount, dec!(400.5));
+    Ok(())
+}
+
+#[test]
+fn problem_response_maps_to_api_error() {
+    let body = r#"{
+        "problem": {
+            "type": "application/problem+json",
+            "title": "BadRequest",
+            "status": 400,
+            "detail": "Validation error occurred.",
+            "instance": null,
+            "invalidFields": [
+                {"name": "FirstName", "reason": "The First Name is required."}
+            ]
+        }
+    }"#;
+
+    let response = Response::from_problem(body).expect("problem body");
+    assert_eq!(response.status, Some(400));
+    assert_eq!(response.title.as_deref(), Some("BadRequest"));
+    assert_eq!(
+        response.message.as_deref(),
+        Some("Validation error occurred.")
+    );
+    match &response.error {
+        ErrorType::PayrollProblem { invalid_fields } => {
+            assert_eq!(invalid_fields.len(), 1);
+            assert_eq!(invalid_fields[0].name, "FirstName");
+        }
+        other => panic!("expected PayrollProblem, got {other:?}"),
+    }
+    assert!(
+        response
+            .to_string()
+            .contains("FirstName: The First Name is required.")
+    );
+
+    assert!(Response::from_problem(r#"{"Type": "ValidationException"}"#).is_none());
+}
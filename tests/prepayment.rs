@@ -0,0 +1,78 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use rust_decimal::Decimal;
+use time::macros::date;
+use xero_rs::prepayment::{ListParameters, Prepayment, Status, Type};
+
+#[tokio::test]
+async fn list_prepayments() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let prepayments = client.prepayments().list_all().await?;
+    info!("Found {} prepayments", prepayments.len());
+
+    if let Some(first) = prepayments.first() {
+        let prepayment = client.prepayments().get(first.prepayment_id).await?;
+        assert_eq!(prepayment.prepayment_id, first.prepayment_id);
+
+        let history = client
+            .prepayments()
+            .get_history(first.prepayment_id)
+            .await?;
+        debug!("Prepayment has {} history records", history.len());
+    }
+
+    let params = ListParameters::builder().with_status(Status::Paid);
+    let paid = client.prepayments().list(params).await?;
+    assert!(paid.iter().all(|p| p.status == Status::Paid));
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_prepayment() {
+    let json = r#"{
+        "Contact": { "ContactID": "c7127731-d324-4e26-a03e-854ce9e0b28b", "Name": "Mr Supplier" },
+        "DateString": "2024-03-10T00:00:00",
+        "Status": "PAID",
+        "LineAmountTypes": "Exclusive",
+        "LineItems": [],
+        "SubTotal": 500.00,
+        "TotalTax": 75.00,
+        "Total": 575.00,
+        "Reference": "Deposit",
+        "CurrencyCode": "NZD",
+        "Type": "SPEND-PREPAYMENT",
+        "PrepaymentID": "aea95d78-ea48-456b-9b08-6bc012600072",
+        "RemainingCredit": 0.00,
+        "Payments": [
+            {
+                "PaymentID": "0d666415-cf77-43fa-80c7-56775591d426",
+                "Date": "/Date(1710115200000+0000)/",
+                "Amount": 575.00,
+                "Reference": "Refund"
+            }
+        ]
+    }"#;
+
+    let prepayment: Prepayment = serde_json::from_str(json).unwrap();
+    assert_eq!(prepayment.r#type, Type::Spend);
+    assert_eq!(prepayment.status, Status::Paid);
+    assert_eq!(prepayment.date, Some(date!(2024 - 03 - 10)));
+    assert_eq!(prepayment.reference.as_deref(), Some("Deposit"));
+    assert_eq!(prepayment.remaining_credit, Some(Decimal::ZERO));
+    assert_eq!(prepayment.payments.len(), 1);
+    assert_eq!(prepayment.payments[0].date, date!(2024 - 03 - 11));
+    assert!(prepayment.allocations.is_empty());
+}
@@ -83,7 +83,13 @@ async fn create_purchase_order() -> Result<()> {
     client.set_tenant(Some(tenant_id)).await;
 
     // Use the new method-based API
-    let contact = client.contacts().list().await?.into_iter().next().unwrap();
+    let contact = client
+        .contacts()
+        .list_all()
+        .await?
+        .into_iter()
+        .next()
+        .unwrap();
 
     let description = "test description";
     let quantity = dec!(3.00);
@@ -128,7 +134,13 @@ async fn update_purchase_order() -> Result<()> {
 
     client.set_tenant(Some(tenant_id)).await;
 
-    let contact = client.contacts().list().await?.into_iter().next().unwrap();
+    let contact = client
+        .contacts()
+        .list_all()
+        .await?
+        .into_iter()
+        .next()
+        .unwrap();
     let line_item_builder = line_item::Builder::new(
         Some("update test".to_string()),
         Some(dec!(1.00)),
@@ -5,49 +5,16 @@ mod test_utils;
 
 use anyhow::Result;
 use rust_decimal::Decimal;
-use std::env;
 use std::fs;
 use time::macros::date;
-use uuid::Uuid;
 use xero_rs::contact::ContactIdentifier;
 use xero_rs::quote::{ListParameters, QuoteBuilder, Status};
-use xero_rs::{KeyPair, line_item::LineAmountType};
-
-/// Try to set up a client. Will return None if the required environment variables are not set.
-async fn try_setup_client() -> Option<xero_rs::Client> {
-    test_utils::do_setup();
-
-    // Check if required environment variables are set
-    let client_id = env::var("XERO_CLIENT_ID").ok()?;
-    let client_secret = env::var("XERO_CLIENT_SECRET").ok()?;
-    let tenant_id_str = env::var("XERO_TENANT_ID").ok()?;
-
-    let tenant_id = match Uuid::parse_str(&tenant_id_str) {
-        Ok(id) => id,
-        Err(_) => {
-            warn!("Invalid XERO_TENANT_ID format");
-            return None;
-        }
-    };
-
-    // Create client with credentials and full scopes
-    let client = xero_rs::Client::from_client_credentials(
-        KeyPair::new(client_id, Some(client_secret)),
-        xero_rs::Scope::all_accounting(),
-    )
-    .await
-    .ok()?;
-
-    // Set the tenant ID and return the configured client
-    client.set_tenant(Some(tenant_id)).await;
-
-    Some(client)
-}
+use xero_rs::{CurrencyCode, line_item::LineAmountType};
 
 #[tokio::test]
 async fn list_quotes() -> Result<()> {
     // Try to set up the client
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -70,7 +37,7 @@ async fn list_quotes() -> Result<()> {
 #[tokio::test]
 async fn get_quote() -> Result<()> {
     // Try to set up the client
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -96,7 +63,7 @@ async fn get_quote() -> Result<()> {
 #[tokio::test]
 async fn create_update_quote() -> Result<()> {
     // Try to set up the client
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -105,7 +72,7 @@ async fn create_update_quote() -> Result<()> {
     };
 
     // First get a contact to use
-    let contacts = match client.contacts().list().await {
+    let contacts = match client.contacts().list_all().await {
         Ok(contacts) => contacts,
         Err(e) => {
             info!("Skipping test: Could not retrieve contacts: {}", e);
@@ -132,7 +99,7 @@ async fn create_update_quote() -> Result<()> {
         summary: Some("This is a test quote".to_string()),
         terms: Some("30 days".to_string()),
         reference: Some("TEST-REF-001".to_string()),
-        currency_code: Some("USD".to_string()),
+        currency_code: Some(CurrencyCode::USD),
         branding_theme_id: None,
         quote_id: None,
         quote_number: None,
@@ -162,7 +129,7 @@ async fn create_update_quote() -> Result<()> {
         summary: Some("This quote has been updated".to_string()),
         terms: Some("30 days".to_string()),
         reference: Some("TEST-REF-001-UPDATED".to_string()),
-        currency_code: Some("USD".to_string()),
+        currency_code: Some(CurrencyCode::USD),
         branding_theme_id: None,
         quote_id: None,
         quote_number: None,
@@ -192,7 +159,7 @@ async fn create_update_quote() -> Result<()> {
 #[tokio::test]
 async fn quote_history() -> Result<()> {
     // Try to set up the client
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -232,7 +199,7 @@ async fn quote_history() -> Result<()> {
 #[tokio::test]
 async fn quote_pdf() -> Result<()> {
     // Try to set up the client
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -272,7 +239,7 @@ async fn quote_pdf() -> Result<()> {
 #[tokio::test]
 async fn quote_attachments() -> Result<()> {
     // Try to set up the client
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -361,7 +328,7 @@ async fn quote_attachments() -> Result<()> {
 
 #[tokio::test]
 async fn create_quote_with_line_items() -> Result<()> {
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -370,7 +337,7 @@ async fn create_quote_with_line_items() -> Result<()> {
     };
 
     // Get a contact to use
-    let contacts = match client.contacts().list().await {
+    let contacts = match client.contacts().list_all().await {
         Ok(contacts) => contacts,
         Err(e) => {
             info!("Skipping test: Could not retrieve contacts: {}", e);
@@ -458,7 +425,7 @@ async fn create_quote_with_line_items() -> Result<()> {
 
 #[tokio::test]
 async fn update_quote_line_items() -> Result<()> {
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -467,7 +434,7 @@ async fn update_quote_line_items() -> Result<()> {
     };
 
     // Get a contact
-    let contacts = match client.contacts().list().await {
+    let contacts = match client.contacts().list_all().await {
         Ok(contacts) => contacts,
         Err(e) => {
             info!("Skipping test: Could not retrieve contacts: {}", e);
@@ -598,7 +565,7 @@ async fn update_quote_line_items() -> Result<()> {
 
 #[tokio::test]
 async fn quote_status_transitions() -> Result<()> {
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -607,7 +574,7 @@ async fn quote_status_transitions() -> Result<()> {
     };
 
     // Get a contact
-    let contacts = match client.contacts().list().await {
+    let contacts = match client.contacts().list_all().await {
         Ok(contacts) => contacts,
         Err(e) => {
             info!("Skipping test: Could not retrieve contacts: {}", e);
@@ -703,7 +670,7 @@ async fn quote_status_transitions() -> Result<()> {
 
 #[tokio::test]
 async fn list_quotes_with_filters() -> Result<()> {
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -743,7 +710,7 @@ async fn list_quotes_with_filters() -> Result<()> {
     }
 
     // Test contact filter (if we have quotes and contacts)
-    let contacts = client.contacts().list().await.unwrap_or_default();
+    let contacts = client.contacts().list_all().await.unwrap_or_default();
     if !contacts.is_empty() {
         let contact_params = ListParameters::builder().with_contact_id(contacts[0].contact_id);
         match client.quotes().list(contact_params).await {
@@ -768,7 +735,7 @@ async fn list_quotes_with_filters() -> Result<()> {
 
 #[tokio::test]
 async fn update_quote_number_prefix() -> Result<()> {
-    let client = match try_setup_client().await {
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
         Some(client) => client,
         None => {
             info!("Skipping test: Required environment variables not set");
@@ -777,7 +744,7 @@ async fn update_quote_number_prefix() -> Result<()> {
     };
 
     // Get a contact
-    let contacts = match client.contacts().list().await {
+    let contacts = match client.contacts().list_all().await {
         Ok(contacts) => contacts,
         Err(e) => {
             info!("Skipping test: Could not retrieve contacts: {}", e);
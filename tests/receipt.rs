@@ -0,0 +1,108 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use rust_decimal::Decimal;
+use time::macros::date;
+use uuid::Uuid;
+use xero_rs::contact::ContactIdentifier;
+use xero_rs::line_item;
+use xero_rs::receipt::{Builder, Receipt, Status};
+
+#[tokio::test]
+async fn list_receipts() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let receipts = client.receipts().list_all().await?;
+    info!("Found {} receipts", receipts.len());
+
+    if let Some(first) = receipts.first() {
+        let receipt = client.receipts().get(first.receipt_id).await?;
+        assert_eq!(receipt.receipt_id, first.receipt_id);
+
+        let attachments = client.receipts().list_attachments(first.receipt_id).await?;
+        debug!("Receipt has {} attachments", attachments.len());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_receipt() {
+    let json = r#"{
+        "ReceiptID": "e59a2c7f-1306-4078-a0f3-73537afcbba9",
+        "ReceiptNumber": 1,
+        "Status": "DRAFT",
+        "User": { "UserID": "7cf47fe2-c3dd-4c6b-9895-7ba767ba529c", "FirstName": "John", "LastName": "Smith" },
+        "Contact": { "ContactID": "c7127731-d324-4e26-a03e-854ce9e0b28b", "Name": "Coffee Shop" },
+        "Date": "/Date(1710028800000+0000)/",
+        "UpdatedDateUTC": "/Date(1710065408000+0000)/",
+        "Reference": "Team coffee",
+        "LineAmountTypes": "Inclusive",
+        "LineItems": [],
+        "SubTotal": 17.39,
+        "TotalTax": 2.61,
+        "Total": 20.00,
+        "HasAttachments": true
+    }"#;
+
+    let receipt: Receipt = serde_json::from_str(json).unwrap();
+    assert_eq!(receipt.status, Status::Draft);
+    assert_eq!(receipt.date, Some(date!(2024 - 03 - 10)));
+    assert_eq!(receipt.total, Decimal::new(2000, 2));
+    assert_eq!(
+        receipt.user.map(|u| u.user_id),
+        Uuid::parse_str("7cf47fe2-c3dd-4c6b-9895-7ba767ba529c").ok()
+    );
+    assert!(receipt.has_attachments);
+}
+
+#[test]
+fn status_transitions() {
+    assert!(Status::Draft.can_transition_to(Status::Submitted));
+    assert!(Status::Draft.can_transition_to(Status::Voided));
+    assert!(Status::Submitted.can_transition_to(Status::Authorised));
+    assert!(Status::Submitted.can_transition_to(Status::Declined));
+    assert!(Status::Authorised.can_transition_to(Status::Voided));
+    assert!(!Status::Draft.can_transition_to(Status::Authorised));
+    assert!(!Status::Declined.can_transition_to(Status::Submitted));
+    assert!(!Status::Declined.can_transition_to(Status::Voided));
+    assert!(!Status::Voided.can_transition_to(Status::Draft));
+}
+
+#[test]
+fn serialize_builder() {
+    let user_id = Uuid::parse_str("7cf47fe2-c3dd-4c6b-9895-7ba767ba529c").unwrap();
+    let contact_id = Uuid::parse_str("c7127731-d324-4e26-a03e-854ce9e0b28b").unwrap();
+    let builder = Builder::new(
+        user_id,
+        ContactIdentifier::ID(contact_id),
+        date!(2024 - 03 - 10),
+        vec![],
+    )
+    .with_line_item(line_item::Builder::default())
+    .with_reference("Team coffee");
+
+    let value = serde_json::to_value(&builder).unwrap();
+    assert_eq!(
+        value["User"],
+        serde_json::json!({ "UserID": "7cf47fe2-c3dd-4c6b-9895-7ba767ba529c" })
+    );
+    assert_eq!(
+        value["Contact"],
+        serde_json::json!({ "ContactID": "c7127731-d324-4e26-a03e-854ce9e0b28b" })
+    );
+    assert_eq!(value["Date"], "2024-03-10");
+    assert_eq!(value["Reference"], "Team coffee");
+    assert_eq!(value["LineItems"].as_array().map(Vec::len), Some(1));
+    assert!(value.get("Status").is_none());
+}
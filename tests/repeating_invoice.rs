@@ -0,0 +1,192 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use time::macros::date;
+use xero_rs::invoice::Type;
+use xero_rs::repeating_invoice::{DueDateType, RepeatingInvoice, Schedule, Status, Unit};
+
+#[tokio::test]
+async fn list_repeating_invoices() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let repeating_invoices = client.repeating_invoices().list_all().await?;
+    info!("Found {} repeating invoices", repeating_invoices.len());
+
+    if let Some(first) = repeating_invoices.first() {
+        let repeating_invoice = client
+            .repeating_invoices()
+            .get(first.repeating_invoice_id)
+            .await?;
+        assert_eq!(
+            repeating_invoice.repeating_invoice_id,
+            first.repeating_invoice_id
+        );
+        debug!(
+            "Next invoices on {:?}",
+            repeating_invoice.schedule.next_dates(3)
+        );
+
+        let history = client
+            .repeating_invoices()
+            .get_history(first.repeating_invoice_id)
+            .await?;
+        debug!("Repeating invoice has {} history records", history.len());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_repeating_invoice() {
+    let json = r#"{
+        "Schedule": {
+            "Period": 1,
+            "Unit": "MONTHLY",
+            "DueDate": 20,
+            "DueDateType": "OFFOLLOWINGMONTH",
+            "StartDate": "/Date(1706659200000+0000)/",
+            "NextScheduledDate": "/Date(1711843200000+0000)/"
+        },
+        "RepeatingInvoiceID": "428c0d75-909f-4b04-8403-a48dc27283b0",
+        "Type": "ACCREC",
+        "Reference": "Subscription",
+        "HasAttachments": false,
+        "ID": "428c0d75-909f-4b04-8403-a48dc27283b0",
+        "Contact": { "ContactID": "c7127731-d324-4e26-a03e-854ce9e0b28b", "Name": "Mr Contact" },
+        "Status": "AUTHORISED",
+        "LineAmountTypes": "Exclusive",
+        "LineItems": [],
+        "SubTotal": 100.00,
+        "TotalTax": 15.00,
+        "Total": 115.00,
+        "CurrencyCode": "NZD",
+        "ApprovedForSending": true,
+        "SendCopy": false,
+        "MarkAsSent": false,
+        "IncludePDF": true
+    }"#;
+
+    let repeating_invoice: RepeatingInvoice = serde_json::from_str(json).unwrap();
+    assert!(matches!(repeating_invoice.r#type, Type::AccountsReceivable));
+    assert_eq!(repeating_invoice.status, Status::Authorised);
+    assert_eq!(repeating_invoice.include_pdf, Some(true));
+
+    let schedule = &repeating_invoice.schedule;
+    assert_eq!(schedule.unit, Unit::Monthly);
+    assert_eq!(schedule.due_date_type, DueDateType::OfFollowingMonth);
+    assert_eq!(schedule.start_date, Some(date!(2024 - 01 - 31)));
+    assert_eq!(schedule.next_scheduled_date, Some(date!(2024 - 03 - 31)));
+    assert_eq!(
+        schedule.next_dates(3),
+        vec![
+            date!(2024 - 03 - 31),
+            date!(2024 - 04 - 30),
+            date!(2024 - 05 - 31)
+        ]
+    );
+}
+
+#[test]
+fn weekly_schedule_projection_stops_at_end_date() {
+    let schedule =
+        Schedule::new(2, Unit::Weekly, date!(2024 - 01 - 01)).with_end_date(date!(2024 - 02 - 01));
+    assert_eq!(
+        schedule.next_dates(5),
+        vec![
+            date!(2024 - 01 - 01),
+            date!(2024 - 01 - 15),
+            date!(2024 - 01 - 29)
+        ]
+    );
+}
+
+#[test]
+fn schedule_projection_stops_when_dates_run_out() {
+    let schedule = Schedule::new(u32::MAX, Unit::Monthly, date!(2024 - 01 - 31));
+    assert_eq!(schedule.next_dates(usize::MAX), vec![date!(2024 - 01 - 31)]);
+
+    let schedule = Schedule::new(1, Unit::Weekly, date!(9999 - 12 - 01));
+    assert_eq!(schedule.next_dates(usize::MAX).len(), 5);
+}
+
+#[test]
+fn due_date_calculation() {
+    let bill_date = date!(2024 - 02 - 10);
+
+    let schedule = Schedule::new(1, Unit::Monthly, bill_date);
+    assert_eq!(schedule.due_date_for(bill_date), Some(bill_date));
+
+    let schedule = schedule.with_due_date(14, DueDateType::DaysAfterBillDate);
+    assert_eq!(
+        schedule.due_date_for(bill_date),
+        Some(date!(2024 - 02 - 24))
+    );
+
+    let schedule = schedule.with_due_date(5, DueDateType::DaysAfterBillMonth);
+    assert_eq!(
+        schedule.due_date_for(bill_date),
+        Some(date!(2024 - 03 - 05))
+    );
+
+    let schedule = schedule.with_due_date(31, DueDateType::OfCurrentMonth);
+    assert_eq!(
+        schedule.due_date_for(bill_date),
+        Some(date!(2024 - 02 - 29))
+    );
+
+    let schedule = schedule.with_due_date(20, DueDateType::OfFollowingMonth);
+    assert_eq!(
+        schedule.due_date_for(bill_date),
+        Some(date!(2024 - 03 - 20))
+    );
+}
+
+#[test]
+fn deserialize_due_date_types() {
+    let cases = [
+        ("DAYSAFTERBILLDATE", DueDateType::DaysAfterBillDate),
+        ("DAYSAFTERBILLMONTH", DueDateType::DaysAfterBillMonth),
+        ("OFCURRENTMONTH", DueDateType::OfCurrentMonth),
+        ("OFFOLLOWINGMONTH", DueDateType::OfFollowingMonth),
+        ("DAYSAFTERINVOICEDATE", DueDateType::DaysAfterInvoiceDate),
+        ("DAYSAFTERINVOICEMONTH", DueDateType::DaysAfterInvoiceMonth),
+        ("SOMENEWTYPE", DueDateType::Other),
+    ];
+
+    for (value, expected) in cases {
+        let schedule: Schedule = serde_json::from_value(serde_json::json!({
+            "Period": 1,
+            "Unit": "MONTHLY",
+            "DueDate": 20,
+            "DueDateType": value,
+        }))
+        .unwrap();
+        assert_eq!(schedule.due_date_type, expected, "{value}");
+    }
+}
+
+#[test]
+fn serialize_schedule() {
+    let schedule = Schedule::new(1, Unit::Monthly, date!(2024 - 01 - 31))
+        .with_due_date(20, DueDateType::OfFollowingMonth);
+    assert_eq!(
+        serde_json::to_value(&schedule).unwrap(),
+        serde_json::json!({
+            "Period": 1,
+            "Unit": "MONTHLY",
+            "DueDate": 20,
+            "DueDateType": "OFFOLLOWINGMONTH",
+            "StartDate": "2024-01-31"
+        })
+    );
+}
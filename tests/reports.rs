@@ -0,0 +1,187 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use rust_decimal::Decimal;
+use time::macros::date;
+use uuid::Uuid;
+use xero_rs::reports::{
+    AgedParameters, BudgetSummaryParameters, ProfitAndLossParameters, Report, ReportRow, Timeframe,
+    TrialBalanceParameters,
+};
+#[tokio::test]
+async fn get_profit_and_loss() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let params = ProfitAndLossParameters::builder()
+        .with_date_range(date!(2024 - 01 - 01), date!(2024 - 12 - 31))
+        .with_periods(2, Timeframe::Quarter)
+        .with_standard_layout(true);
+    let report = client.reports().profit_and_loss(params).await?;
+    info!("Retrieved report: {:?}", report.report_name);
+
+    assert!(report.header().is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_trial_balance() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let params = TrialBalanceParameters::builder().with_date(date!(2024 - 12 - 31));
+    let report = client.reports().trial_balance(params).await?;
+    info!("Trial balance has {} rows", report.all_rows().len());
+
+    Ok(())
+}
+
+const PROFIT_AND_LOSS: &str = r#"{
+    "Reports": [{
+        "ReportID": "ProfitAndLoss",
+        "ReportName": "Profit and Loss",
+        "ReportType": "ProfitAndLoss",
+        "ReportTitles": ["Profit & Loss", "Demo Company (AU)", "1 January 2024 to 31 March 2024"],
+        "ReportDate": "18 October 2026",
+        "UpdatedDateUTC": "/Date(1729209600000)/",
+        "Rows": [
+            {
+                "RowType": "Header",
+                "Cells": [{"Value": ""}, {"Value": "31 Mar 24"}, {"Value": "31 Dec 23"}]
+            },
+            {
+                "RowType": "Section",
+                "Title": "Income",
+                "Rows": [
+                    {
+                        "RowType": "Row",
+                        "Cells": [
+                            {"Value": "Sales", "Attributes": [{"Value": "b0b6e4c8-2bd1-4a3f-a5e3-8e3a1c3c7a10", "Id": "account"}]},
+                            {"Value": "12,345.67", "Attributes": [{"Value": "b0b6e4c8-2bd1-4a3f-a5e3-8e3a1c3c7a10", "Id": "account"}]},
+                            {"Value": "-200.00", "Attributes": [{"Value": "b0b6e4c8-2bd1-4a3f-a5e3-8e3a1c3c7a10", "Id": "account"}]}
+                        ]
+                    },
+                    {
+                        "RowType": "SummaryRow",
+                        "Cells": [{"Value": "Total Income"}, {"Value": "12345.67"}, {"Value": "-200.00"}]
+                    }
+                ]
+            },
+            {
+                "RowType": "Section",
+                "Rows": [
+                    {
+                        "RowType": "Row",
+                        "Cells": [{"Value": "Net Profit"}, {"Value": "12345.67"}, {"Value": "-200.00"}]
+                    }
+                ]
+            }
+        ]
+    }]
+}"#;
+
+#[test]
+fn deserialize_report_rows() -> Result<()> {
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Response {
+        reports: Vec<Report>,
+    }
+
+    let response: Response = serde_json::from_str(PROFIT_AND_LOSS)?;
+    let report = &response.reports[0];
+
+    assert_eq!(report.report_id.as_deref(), Some("ProfitAndLoss"));
+    assert_eq!(report.report_titles.len(), 3);
+    assert!(report.updated_date_utc.is_some());
+    assert_eq!(report.header().map(<[_]>::len), Some(3));
+    assert_eq!(report.all_rows().len(), 6);
+
+    let income = report.section("income").expect("income section");
+    assert_eq!(income.label(), Some("Income"));
+    let summary = income.summary().expect("summary row");
+    assert!(matches!(summary, ReportRow::SummaryRow { .. }));
+    assert_eq!(summary.value(1), Some(Decimal::new(1_234_567, 2)));
+
+    let account_id = Uuid::parse_str("b0b6e4c8-2bd1-4a3f-a5e3-8e3a1c3c7a10")?;
+    let row = report.account_row(account_id).expect("sales row");
+    assert_eq!(row.label(), Some("Sales"));
+    assert_eq!(row.value(0), None);
+    assert_eq!(
+        report.account_value(account_id, 1),
+        Some(Decimal::new(1_234_567, 2))
+    );
+    assert_eq!(
+        report.account_value(account_id, 2),
+        Some(Decimal::new(-20_000, 2))
+    );
+    assert_eq!(report.account_value(Uuid::nil(), 1), None);
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_unknown_row_types() -> Result<()> {
+    let rows: Vec<ReportRow> = serde_json::from_str(
+        r#"[
+            {"RowType": "Header", "Cells": [{"Value": ""}, {"Value": "31 Mar 24"}]},
+            {"RowType": "Spacer", "Cells": [{"Value": ""}]},
+            {"RowType": "Row", "Cells": [{"Value": "Sales"}, {"Value": "100.00"}]}
+        ]"#,
+    )?;
+
+    assert_eq!(rows.len(), 3);
+    assert!(matches!(rows[1], ReportRow::Unknown));
+    assert!(rows[1].cells().is_empty());
+    assert_eq!(rows[2].value(1), Some(Decimal::new(10_000, 2)));
+    Ok(())
+}
+
+#[test]
+fn serialize_report_parameters() -> Result<()> {
+    let category_id = Uuid::parse_str("3c1b0d7e-6f0a-4c3e-9f3b-2d1e5f6a7b8c")?;
+    let params = ProfitAndLossParameters::builder()
+        .with_date_range(date!(2024 - 01 - 01), date!(2024 - 03 - 31))
+        .with_periods(3, Timeframe::Month)
+        .with_tracking_category(category_id, None)
+        .with_payments_only(true);
+    let json = serde_json::to_value(&params)?;
+
+    assert_eq!(json["fromDate"], "2024-01-01");
+    assert_eq!(json["toDate"], "2024-03-31");
+    assert_eq!(json["periods"], 3);
+    assert_eq!(json["timeframe"], "MONTH");
+    assert_eq!(json["trackingCategoryID"], category_id.to_string());
+    assert_eq!(json["paymentsOnly"], true);
+    assert!(json.get("trackingOptionID").is_none());
+    assert!(json.get("standardLayout").is_none());
+
+    let params = BudgetSummaryParameters::builder().with_periods(4, Timeframe::Quarter);
+    let json = serde_json::to_value(&params)?;
+    assert_eq!(json["timeframe"], 3);
+    assert!(json.get("date").is_none());
+
+    let contact_id = Uuid::new_v4();
+    let params = AgedParameters::new(contact_id).with_date(date!(2024 - 06 - 30));
+    let json = serde_json::to_value(&params)?;
+    assert_eq!(json["contactId"], contact_id.to_string());
+    assert_eq!(json["date"], "2024-06-30");
+
+    Ok(())
+}
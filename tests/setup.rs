@@ -0,0 +1,99 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use rust_decimal::Decimal;
+use time::Month;
+use xero_rs::account;
+use xero_rs::setup::{Builder, ConversionBalance, ConversionBalances, ConversionDate};
+use xero_rs::{AccountType, CurrencyCode, Error};
+
+#[tokio::test]
+async fn unbalanced_setup_is_rejected_before_sending() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let setup = Builder::new()
+        .with_conversion_date(ConversionDate::new(2024, Month::July))
+        .with_conversion_balances(
+            ConversionBalances::new()
+                .with_debit("090", Decimal::new(100_000, 2))
+                .with_credit("970", Decimal::new(90_000, 2)),
+        );
+
+    let result = client.setup().post(&setup).await;
+    assert!(matches!(
+        result,
+        Err(Error::ConversionBalancesUnbalanced { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn conversion_balances_totals() {
+    let balances = ConversionBalances::new()
+        .with_debit("090", Decimal::new(150_000, 2))
+        .with_debit("610", Decimal::new(25_000, 2))
+        .with_credit("800", Decimal::new(-75_000, 2))
+        .with_credit("970", Decimal::new(100_000, 2));
+
+    assert_eq!(balances.total_debits(), Decimal::new(175_000, 2));
+    assert_eq!(balances.total_credits(), Decimal::new(175_000, 2));
+    assert!(balances.is_balanced());
+
+    let unbalanced = balances.with_debit("090", Decimal::ONE);
+    assert!(!unbalanced.is_balanced());
+}
+
+#[test]
+fn serialize_setup_builder() -> Result<()> {
+    let setup = Builder::new()
+        .with_conversion_date(ConversionDate::new(2024, Month::July))
+        .with_conversion_balances(
+            ConversionBalances::new()
+                .with_balance(
+                    ConversionBalance::debit("610", Decimal::new(50_000, 2)).with_balance_detail(
+                        Decimal::new(30_000, 2),
+                        CurrencyCode::USD,
+                        Some(Decimal::new(6, 1)),
+                    ),
+                )
+                .with_credit("970", Decimal::new(50_000, 2)),
+        )
+        .with_account(account::Builder::new(
+            "090",
+            "Business Bank",
+            AccountType::Bank,
+        ));
+    let json = serde_json::to_value(&setup)?;
+
+    assert_eq!(json["ConversionDate"]["Month"], 7);
+    assert_eq!(json["ConversionDate"]["Year"], 2024);
+    assert_eq!(json["ConversionBalances"][0]["AccountCode"], "610");
+    assert_eq!(json["ConversionBalances"][0]["Balance"], "500.00");
+    assert_eq!(
+        json["ConversionBalances"][0]["BalanceDetails"][0]["CurrencyCode"],
+        "USD"
+    );
+    assert_eq!(json["ConversionBalances"][1]["Balance"], "-500.00");
+    assert!(
+        json["ConversionBalances"][1]
+            .get("BalanceDetails")
+            .is_none()
+    );
+    assert_eq!(json["Accounts"][0]["Code"], "090");
+
+    let empty = serde_json::to_value(Builder::new())?;
+    assert_eq!(empty, serde_json::json!({}));
+
+    Ok(())
+}
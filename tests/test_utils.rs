@@ -1,5 +1,5 @@
 use miette::{IntoDiagnostic, Result};
-use tracing::{Level, debug, error, info};
+use tracing::{Level, debug, error, info, warn};
 use uuid::Uuid;
 
 use std::sync::Once;
@@ -39,6 +39,24 @@ pub async fn create_test_client(scopes: Option<xero_rs::Scope>) -> Result<Client
     Ok(client)
 }
 
+/// Try to set up a client with the given scopes. Will return None if the required environment
+/// variables are not set.
+#[allow(dead_code)]
+pub async fn try_setup_client(scopes: Option<xero_rs::Scope>) -> Option<Client> {
+    do_setup();
+
+    for var in ["XERO_CLIENT_ID", "XERO_CLIENT_SECRET", "XERO_TENANT_ID"] {
+        if std::env::var(var).is_err() {
+            return None;
+        }
+    }
+
+    create_test_client(scopes)
+        .await
+        .map_err(|e| warn!("Failed to set up test client: {:?}", e))
+        .ok()
+}
+
 /// Provides common scopes for payroll tests
 #[allow(dead_code)]
 pub fn payroll_scopes() -> xero_rs::Scope {
@@ -0,0 +1,70 @@
+#[macro_use]
+extern crate tracing;
+
+mod test_utils;
+
+use anyhow::Result;
+use xero_rs::user::{ListParameters, OrganisationRole, User};
+
+#[tokio::test]
+async fn list_users() -> Result<()> {
+    // Try to set up the client
+    let client = match test_utils::try_setup_client(Some(test_utils::accounting_scopes())).await {
+        Some(client) => client,
+        None => {
+            info!("Skipping test: Required environment variables not set");
+            return Ok(());
+        }
+    };
+
+    let users = client.users().list_all().await?;
+    info!("Found {} users", users.len());
+
+    if let Some(first) = users.first() {
+        let user = client.users().get(first.user_id).await?;
+        assert_eq!(user.user_id, first.user_id);
+
+        if let Some(email_address) = &first.email_address {
+            let found = client.users().find_by_email(email_address).await?;
+            assert_eq!(found.map(|u| u.user_id), Some(first.user_id));
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn deserialize_user() {
+    let json = r#"{
+        "UserID": "7cf47fe2-c3dd-4c6b-9895-7ba767ba529c",
+        "EmailAddress": "john.smith@example.com",
+        "FirstName": "John",
+        "LastName": "Smith",
+        "UpdatedDateUTC": "/Date(1516230549137+0000)/",
+        "IsSubscriber": false,
+        "OrganisationRole": "FINANCIALADVISER"
+    }"#;
+
+    let user: User = serde_json::from_str(json).unwrap();
+    assert_eq!(user.full_name(), "John Smith");
+    assert_eq!(
+        user.organisation_role,
+        Some(OrganisationRole::FinancialAdviser)
+    );
+
+    let user: User = serde_json::from_str(
+        r#"{"UserID": "7cf47fe2-c3dd-4c6b-9895-7ba767ba529c", "OrganisationRole": "PAYROLLADMIN"}"#,
+    )
+    .unwrap();
+    assert_eq!(user.organisation_role, Some(OrganisationRole::Unknown));
+    assert_eq!(user.full_name(), "");
+}
+
+#[test]
+fn email_address_filter_escapes_quotes_and_backslashes() {
+    let parameters = ListParameters::builder().with_email_address(r#"a\"b"@example.com"#);
+    assert_eq!(
+        parameters.r#where.as_deref(),
+        Some(r#"EmailAddress=="a\\\"b\"@example.com""#)
+    );
+}